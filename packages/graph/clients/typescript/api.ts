@@ -44,6 +44,54 @@ import {
   RequiredError,
 } from "./base";
 
+/**
+ *
+ * @export
+ * @interface AddAccountMemberRequest
+ */
+export interface AddAccountMemberRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof AddAccountMemberRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof AddAccountMemberRequest
+   */
+  memberId: string;
+}
+/**
+ * @type Aggregate
+ * @export
+ */
+export type Aggregate =
+  | CountAggregate
+  | MaxAggregate
+  | MinAggregate
+  | SumAggregate;
+
+/**
+ * The result of an aggregation for a single group of records.
+ * @export
+ * @interface AggregationGroup
+ */
+export interface AggregationGroup {
+  /**
+   * The values of the group-by paths shared by all records of the group, in the order the paths were requested.
+   * @type {Array<object>}
+   * @memberof AggregationGroup
+   */
+  group: Array<object>;
+  /**
+   * The values of the aggregates, in the order the aggregates were requested.  `min`, `max` and `sum` are `null` if the path did not resolve to a number for any record of the group.
+   * @type {Array<object>}
+   * @memberof AggregationGroup
+   */
+  values: Array<object>;
+}
 /**
  *
  * @export
@@ -92,2226 +140,4156 @@ export interface ArchiveEntityRequest {
 /**
  *
  * @export
- * @interface CreateDataTypeRequest
- */
-export interface CreateDataTypeRequest {
-  /**
-   *
-   * @type {string}
-   * @memberof CreateDataTypeRequest
-   */
-  actorId: string;
-  /**
-   *
-   * @type {string}
-   * @memberof CreateDataTypeRequest
-   */
-  ownedById: string;
-  /**
-   *
-   * @type {DataType}
-   * @memberof CreateDataTypeRequest
-   */
-  schema: DataType;
-}
-/**
- *
- * @export
- * @interface CreateEntityRequest
+ * @interface BatchCreateEntityRequest
  */
-export interface CreateEntityRequest {
+export interface BatchCreateEntityRequest {
   /**
    *
    * @type {string}
-   * @memberof CreateEntityRequest
+   * @memberof BatchCreateEntityRequest
    */
   actorId: string;
   /**
    *
    * @type {string}
-   * @memberof CreateEntityRequest
+   * @memberof BatchCreateEntityRequest
    */
   entityTypeId: string;
   /**
    *
    * @type {string}
-   * @memberof CreateEntityRequest
+   * @memberof BatchCreateEntityRequest
    */
   entityUuid?: string;
   /**
    *
-   * @type {LinkData}
-   * @memberof CreateEntityRequest
+   * @type {BatchLinkData}
+   * @memberof BatchCreateEntityRequest
    */
-  linkData?: LinkData;
+  linkData?: BatchLinkData;
   /**
    *
    * @type {string}
-   * @memberof CreateEntityRequest
+   * @memberof BatchCreateEntityRequest
    */
   ownedById: string;
   /**
    *
    * @type {object}
-   * @memberof CreateEntityRequest
+   * @memberof BatchCreateEntityRequest
    */
   properties: object;
 }
 /**
- *
+ * The link data of an entity created inside of a batch.
  * @export
- * @interface CreateEntityTypeRequest
+ * @interface BatchLinkData
  */
-export interface CreateEntityTypeRequest {
-  /**
-   *
-   * @type {string}
-   * @memberof CreateEntityTypeRequest
-   */
-  actorId: string;
+export interface BatchLinkData {
   /**
    *
-   * @type {string}
-   * @memberof CreateEntityTypeRequest
-   */
-  ownedById: string;
-  /**
-   *
-   * @type {EntityType}
-   * @memberof CreateEntityTypeRequest
+   * @type {number}
+   * @memberof BatchLinkData
    */
-  schema: EntityType;
-}
-/**
- *
- * @export
- * @interface CreatePropertyTypeRequest
- */
-export interface CreatePropertyTypeRequest {
+  leftToRightOrder?: number;
   /**
    *
-   * @type {string}
-   * @memberof CreatePropertyTypeRequest
+   * @type {number}
+   * @memberof BatchLinkData
    */
-  actorId: string;
+  rightToLeftOrder?: number;
   /**
    *
-   * @type {string}
-   * @memberof CreatePropertyTypeRequest
+   * @type {EntityReference}
+   * @memberof BatchLinkData
    */
-  ownedById: string;
+  leftEntity: EntityReference;
   /**
    *
-   * @type {PropertyType}
-   * @memberof CreatePropertyTypeRequest
+   * @type {EntityReference}
+   * @memberof BatchLinkData
    */
-  schema: PropertyType;
+  rightEntity: EntityReference;
 }
 /**
- * Specifies the structure of a Data Type
+ *
  * @export
- * @interface DataType
+ * @interface BatchLinkDataAllOf
  */
-export interface DataType {
-  [key: string]: any;
-
-  /**
-   *
-   * @type {object}
-   * @memberof DataType
-   */
-  kind: DataTypeKindEnum;
-  /**
-   *
-   * @type {string}
-   * @memberof DataType
-   */
-  $id: string;
-  /**
-   *
-   * @type {string}
-   * @memberof DataType
-   */
-  title: string;
+export interface BatchLinkDataAllOf {
   /**
    *
-   * @type {string}
-   * @memberof DataType
+   * @type {EntityReference}
+   * @memberof BatchLinkDataAllOf
    */
-  description?: string;
+  leftEntity: EntityReference;
   /**
    *
-   * @type {string}
-   * @memberof DataType
+   * @type {EntityReference}
+   * @memberof BatchLinkDataAllOf
    */
-  type: string;
+  rightEntity: EntityReference;
 }
-
-export const DataTypeKindEnum = {
-  DataType: "dataType",
-} as const;
-
-export type DataTypeKindEnum =
-  typeof DataTypeKindEnum[keyof typeof DataTypeKindEnum];
-
 /**
- * A single token in a [`DataTypeQueryPath`].
+ * @type BatchOperationRequest
+ * A single operation of a batch.  The operations have the same shape as the requests of the corresponding routes, except that entities are referenced by an [`EntityReference`], so operations can refer to entities created by preceding operations.
  * @export
- * @enum {string}
  */
-
-export const DataTypeQueryToken = {
-  BaseUri: "baseUri",
-  Version: "version",
-  VersionedUri: "versionedUri",
-  OwnedById: "ownedById",
-  UpdatedById: "updatedById",
-  Title: "title",
-  Description: "description",
-  Type: "type",
-} as const;
-
-export type DataTypeQueryToken =
-  typeof DataTypeQueryToken[keyof typeof DataTypeQueryToken];
+export type BatchOperationRequest =
+  | BatchOperationRequestOneOf
+  | BatchOperationRequestOneOf1
+  | BatchOperationRequestOneOf2
+  | BatchOperationRequestOneOf3
+  | BatchOperationRequestOneOf4
+  | BatchOperationRequestOneOf5
+  | BatchOperationRequestOneOf6
+  | BatchOperationRequestOneOf7;
 
 /**
  *
  * @export
- * @interface DataTypeReference
+ * @interface BatchOperationRequestOneOf
  */
-export interface DataTypeReference {
+export interface BatchOperationRequestOneOf {
   /**
    *
-   * @type {string}
-   * @memberof DataTypeReference
+   * @type {CreateDataTypeRequest}
+   * @memberof BatchOperationRequestOneOf
    */
-  $ref: string;
+  createDataType: CreateDataTypeRequest;
 }
 /**
  *
  * @export
- * @interface DataTypeReferenceUpdate
+ * @interface BatchOperationRequestOneOf1
  */
-export interface DataTypeReferenceUpdate {
+export interface BatchOperationRequestOneOf1 {
   /**
    *
-   * @type {string}
-   * @memberof DataTypeReferenceUpdate
+   * @type {UpdateDataTypeRequest}
+   * @memberof BatchOperationRequestOneOf1
    */
-  $ref: string;
+  updateDataType: UpdateDataTypeRequest;
 }
 /**
- * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter
+ *
  * @export
- * @interface DataTypeStructuralQuery
+ * @interface BatchOperationRequestOneOf2
  */
-export interface DataTypeStructuralQuery {
+export interface BatchOperationRequestOneOf2 {
   /**
    *
-   * @type {Filter}
-   * @memberof DataTypeStructuralQuery
-   */
-  filter: Filter;
-  /**
-   *
-   * @type {GraphResolveDepths}
-   * @memberof DataTypeStructuralQuery
+   * @type {CreatePropertyTypeRequest}
+   * @memberof BatchOperationRequestOneOf2
    */
-  graphResolveDepths: GraphResolveDepths;
+  createPropertyType: CreatePropertyTypeRequest;
 }
 /**
  *
  * @export
- * @interface DataTypeWithMetadata
+ * @interface BatchOperationRequestOneOf3
  */
-export interface DataTypeWithMetadata {
+export interface BatchOperationRequestOneOf3 {
   /**
    *
-   * @type {OntologyElementMetadata}
-   * @memberof DataTypeWithMetadata
+   * @type {UpdatePropertyTypeRequest}
+   * @memberof BatchOperationRequestOneOf3
    */
-  metadata: OntologyElementMetadata;
+  updatePropertyType: UpdatePropertyTypeRequest;
+}
+/**
+ *
+ * @export
+ * @interface BatchOperationRequestOneOf4
+ */
+export interface BatchOperationRequestOneOf4 {
   /**
    *
-   * @type {DataType}
-   * @memberof DataTypeWithMetadata
+   * @type {CreateEntityTypeRequest}
+   * @memberof BatchOperationRequestOneOf4
    */
-  schema: DataType;
+  createEntityType: CreateEntityTypeRequest;
 }
 /**
  *
  * @export
- * @interface EdgeResolveDepths
+ * @interface BatchOperationRequestOneOf5
  */
-export interface EdgeResolveDepths {
+export interface BatchOperationRequestOneOf5 {
   /**
    *
-   * @type {number}
-   * @memberof EdgeResolveDepths
+   * @type {UpdateEntityTypeRequest}
+   * @memberof BatchOperationRequestOneOf5
    */
-  incoming: number;
+  updateEntityType: UpdateEntityTypeRequest;
+}
+/**
+ *
+ * @export
+ * @interface BatchOperationRequestOneOf6
+ */
+export interface BatchOperationRequestOneOf6 {
   /**
    *
-   * @type {number}
-   * @memberof EdgeResolveDepths
+   * @type {BatchCreateEntityRequest}
+   * @memberof BatchOperationRequestOneOf6
    */
-  outgoing: number;
+  createEntity: BatchCreateEntityRequest;
 }
 /**
  *
  * @export
- * @interface Edges
+ * @interface BatchOperationRequestOneOf7
  */
-export interface Edges {
-  [key: string]: { [key: string]: Array<EdgesValueValueInner> };
+export interface BatchOperationRequestOneOf7 {
+  /**
+   *
+   * @type {BatchUpdateEntityRequest}
+   * @memberof BatchOperationRequestOneOf7
+   */
+  updateEntity: BatchUpdateEntityRequest;
 }
 /**
- * @type EdgesValueValueInner
+ * @type BatchOperationResult
+ * The result of a single [`BatchOperation`].
  * @export
  */
-export type EdgesValueValueInner =
-  | KnowledgeGraphOutwardEdges
-  | OntologyOutwardEdges;
+export type BatchOperationResult =
+  | BatchOperationResultOneOf
+  | BatchOperationResultOneOf1
+  | BatchOperationResultOneOf2;
 
 /**
- * A record of an [`Entity`] that has been persisted in the datastore, with its associated metadata.
+ *
  * @export
- * @interface Entity
+ * @interface BatchOperationResultOneOf
  */
-export interface Entity {
+export interface BatchOperationResultOneOf {
   /**
    *
-   * @type {LinkData}
-   * @memberof Entity
+   * @type {OntologyElementMetadata}
+   * @memberof BatchOperationResultOneOf
    */
-  linkData?: LinkData;
+  ontology: OntologyElementMetadata;
+}
+/**
+ *
+ * @export
+ * @interface BatchOperationResultOneOf1
+ */
+export interface BatchOperationResultOneOf1 {
   /**
    *
-   * @type {EntityMetadata}
-   * @memberof Entity
+   * @type {OntologyUpdateMetadata}
+   * @memberof BatchOperationResultOneOf1
    */
-  metadata: EntityMetadata;
+  ontologyUpdate: OntologyUpdateMetadata;
+}
+/**
+ *
+ * @export
+ * @interface BatchOperationResultOneOf2
+ */
+export interface BatchOperationResultOneOf2 {
   /**
    *
-   * @type {object}
-   * @memberof Entity
+   * @type {EntityMetadata}
+   * @memberof BatchOperationResultOneOf2
    */
-  properties: object;
+  entity: EntityMetadata;
 }
 /**
  *
  * @export
- * @interface EntityEditionId
+ * @interface BatchUpdateEntityRequest
  */
-export interface EntityEditionId {
+export interface BatchUpdateEntityRequest {
   /**
    *
-   * @type {string}
-   * @memberof EntityEditionId
+   * @type {number}
+   * @memberof BatchUpdateEntityRequest
    */
-  baseId: string;
+  leftToRightOrder?: number;
   /**
    *
    * @type {number}
-   * @memberof EntityEditionId
+   * @memberof BatchUpdateEntityRequest
    */
-  recordId: number;
+  rightToLeftOrder?: number;
   /**
    *
    * @type {string}
-   * @memberof EntityEditionId
+   * @memberof BatchUpdateEntityRequest
    */
-  version: string;
-}
-/**
- *
- * @export
- * @interface EntityIdAndTimestamp
- */
-export interface EntityIdAndTimestamp {
+  actorId: string;
   /**
    *
-   * @type {string}
-   * @memberof EntityIdAndTimestamp
+   * @type {boolean}
+   * @memberof BatchUpdateEntityRequest
    */
-  baseId: string;
+  archived?: boolean;
   /**
    *
-   * @type {string}
-   * @memberof EntityIdAndTimestamp
+   * @type {EntityReference}
+   * @memberof BatchUpdateEntityRequest
    */
-  timestamp: string;
-}
-/**
- *
- * @export
- * @interface EntityLinkOrder
- */
-export interface EntityLinkOrder {
+  entity: EntityReference;
   /**
    *
-   * @type {number}
-   * @memberof EntityLinkOrder
+   * @type {string}
+   * @memberof BatchUpdateEntityRequest
    */
-  leftToRightOrder?: number;
+  entityTypeId: string;
   /**
    *
-   * @type {number}
-   * @memberof EntityLinkOrder
+   * @type {object}
+   * @memberof BatchUpdateEntityRequest
    */
-  rightToLeftOrder?: number;
+  properties: object;
 }
 /**
- * The metadata of an [`Entity`] record.
+ *
  * @export
- * @interface EntityMetadata
+ * @interface BatchUpdateEntityRequestAllOf
  */
-export interface EntityMetadata {
-  /**
-   *
-   * @type {boolean}
-   * @memberof EntityMetadata
-   */
-  archived: boolean;
+export interface BatchUpdateEntityRequestAllOf {
   /**
    *
-   * @type {EntityEditionId}
-   * @memberof EntityMetadata
+   * @type {string}
+   * @memberof BatchUpdateEntityRequestAllOf
    */
-  editionId: EntityEditionId;
+  actorId: string;
   /**
    *
-   * @type {string}
-   * @memberof EntityMetadata
+   * @type {boolean}
+   * @memberof BatchUpdateEntityRequestAllOf
    */
-  entityTypeId: string;
+  archived?: boolean;
   /**
    *
-   * @type {ProvenanceMetadata}
-   * @memberof EntityMetadata
+   * @type {EntityReference}
+   * @memberof BatchUpdateEntityRequestAllOf
    */
-  provenance: ProvenanceMetadata;
-}
-/**
- * A single token in an [`EntityQueryPath`].
- * @export
- * @enum {string}
- */
-
-export const EntityQueryToken = {
-  Uuid: "uuid",
-  Version: "version",
-  RecordId: "recordId",
-  Archived: "archived",
-  OwnedById: "ownedById",
-  UpdatedById: "updatedById",
-  Type: "type",
-  Properties: "properties",
-  IncomingLinks: "incomingLinks",
-  OutgoingLinks: "outgoingLinks",
-  LeftEntity: "leftEntity",
-  RightEntity: "rightEntity",
-  LeftToRightOrder: "leftToRightOrder",
-  RightToLeftOrder: "rightToLeftOrder",
-} as const;
-
-export type EntityQueryToken =
-  typeof EntityQueryToken[keyof typeof EntityQueryToken];
-
-/**
- * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter
- * @export
- * @interface EntityStructuralQuery
- */
-export interface EntityStructuralQuery {
+  entity: EntityReference;
   /**
    *
-   * @type {Filter}
-   * @memberof EntityStructuralQuery
+   * @type {string}
+   * @memberof BatchUpdateEntityRequestAllOf
    */
-  filter: Filter;
+  entityTypeId: string;
   /**
    *
-   * @type {GraphResolveDepths}
-   * @memberof EntityStructuralQuery
+   * @type {object}
+   * @memberof BatchUpdateEntityRequestAllOf
    */
-  graphResolveDepths: GraphResolveDepths;
+  properties: object;
 }
 /**
- * Specifies the structure of an Entity Type
+ * A single change of the graph as recorded in the change feed.
  * @export
- * @interface EntityType
+ * @interface ChangeEvent
  */
-export interface EntityType {
-  /**
-   *
-   * @type {object}
-   * @memberof EntityType
-   */
-  kind: EntityTypeKindEnum;
+export interface ChangeEvent {
   /**
    *
-   * @type {string}
-   * @memberof EntityType
+   * @type {ChangedElement}
+   * @memberof ChangeEvent
    */
-  $id: string;
+  element: ChangedElement;
   /**
    *
-   * @type {object}
-   * @memberof EntityType
+   * @type {ChangeKind}
+   * @memberof ChangeEvent
    */
-  type: EntityTypeTypeEnum;
+  kind: ChangeKind;
   /**
    *
-   * @type {string}
-   * @memberof EntityType
+   * @type {number}
+   * @memberof ChangeEvent
    */
-  title: string;
+  offset: number;
   /**
    *
    * @type {string}
-   * @memberof EntityType
+   * @memberof ChangeEvent
    */
-  description?: string;
+  recordedAt: string;
+}
+/**
+ *
+ * @export
+ * @interface ChangeFeedResponse
+ */
+export interface ChangeFeedResponse {
   /**
    *
-   * @type {object}
-   * @memberof EntityType
+   * @type {Array<ChangeEvent>}
+   * @memberof ChangeFeedResponse
    */
-  default?: object;
+  events: Array<ChangeEvent>;
   /**
    *
-   * @type {Array<object>}
-   * @memberof EntityType
+   * @type {number}
+   * @memberof ChangeFeedResponse
    */
-  examples?: Array<object>;
+  nextOffset?: number;
+}
+/**
+ * The kind of a change to an element of the graph.
+ * @export
+ * @enum {string}
+ */
+
+export const ChangeKind = {
+  Create: "create",
+  Update: "update",
+  Archive: "archive",
+} as const;
+
+export type ChangeKind = typeof ChangeKind[keyof typeof ChangeKind];
+
+/**
+ * @type ChangedElement
+ * The element of the graph affected by a change with the metadata of the written edition.
+ * @export
+ */
+export type ChangedElement =
+  | ChangedElementOneOf
+  | ChangedElementOneOf1
+  | ChangedElementOneOf2
+  | ChangedElementOneOf3;
+
+/**
+ *
+ * @export
+ * @interface ChangedElementOneOf
+ */
+export interface ChangedElementOneOf {
   /**
    *
-   * @type {object}
-   * @memberof EntityType
+   * @type {OntologyElementMetadata}
+   * @memberof ChangedElementOneOf
    */
-  properties: object;
+  dataType: OntologyElementMetadata;
+}
+/**
+ *
+ * @export
+ * @interface ChangedElementOneOf1
+ */
+export interface ChangedElementOneOf1 {
   /**
    *
-   * @type {Array<string>}
-   * @memberof EntityType
+   * @type {OntologyElementMetadata}
+   * @memberof ChangedElementOneOf1
    */
-  required?: Array<string>;
+  propertyType: OntologyElementMetadata;
+}
+/**
+ *
+ * @export
+ * @interface ChangedElementOneOf2
+ */
+export interface ChangedElementOneOf2 {
   /**
    *
-   * @type {object}
-   * @memberof EntityType
+   * @type {OntologyElementMetadata}
+   * @memberof ChangedElementOneOf2
    */
-  links?: object;
+  entityType: OntologyElementMetadata;
+}
+/**
+ *
+ * @export
+ * @interface ChangedElementOneOf3
+ */
+export interface ChangedElementOneOf3 {
   /**
    *
-   * @type {Array<string>}
-   * @memberof EntityType
+   * @type {EntityMetadata}
+   * @memberof ChangedElementOneOf3
    */
-  requiredLinks?: Array<string>;
+  entity: EntityMetadata;
 }
+/**
+ * The action taken for an update of an ontology type.  Actions are ordered by their strictness, i.e. `Accept < Warn < Reject`.
+ * @export
+ * @enum {string}
+ */
 
-export const EntityTypeKindEnum = {
-  EntityType: "entityType",
-} as const;
-
-export type EntityTypeKindEnum =
-  typeof EntityTypeKindEnum[keyof typeof EntityTypeKindEnum];
-export const EntityTypeTypeEnum = {
-  Object: "object",
+export const CompatibilityAction = {
+  Accept: "accept",
+  Warn: "warn",
+  Reject: "reject",
 } as const;
 
-export type EntityTypeTypeEnum =
-  typeof EntityTypeTypeEnum[keyof typeof EntityTypeTypeEnum];
+export type CompatibilityAction =
+  typeof CompatibilityAction[keyof typeof CompatibilityAction];
 
 /**
- * A single token in a [`EntityTypeQueryPath`].
+ * The effect of a change to an ontology type on values, which were valid before.
  * @export
  * @enum {string}
  */
 
-export const EntityTypeQueryToken = {
-  BaseUri: "baseUri",
-  Version: "version",
-  VersionedUri: "versionedUri",
-  OwnedById: "ownedById",
-  UpdatedById: "updatedById",
-  Title: "title",
-  Description: "description",
-  Default: "default",
-  Examples: "examples",
-  Properties: "properties",
-  Required: "required",
-  Links: "links",
-  RequiredLinks: "requiredLinks",
-  InheritsFrom: "inheritsFrom",
+export const CompatibilityLevel = {
+  Additive: "additive",
+  Breaking: "breaking",
+  Incompatible: "incompatible",
 } as const;
 
-export type EntityTypeQueryToken =
-  typeof EntityTypeQueryToken[keyof typeof EntityTypeQueryToken];
+export type CompatibilityLevel =
+  typeof CompatibilityLevel[keyof typeof CompatibilityLevel];
 
 /**
- * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter
+ * Specifies how updates of ontology types are handled depending on their [`CompatibilityLevel`].  Additive updates are always accepted. By default, breaking updates are accepted with a warning and incompatible updates are rejected.
  * @export
- * @interface EntityTypeStructuralQuery
+ * @interface CompatibilityPolicy
  */
-export interface EntityTypeStructuralQuery {
+export interface CompatibilityPolicy {
   /**
    *
-   * @type {Filter}
-   * @memberof EntityTypeStructuralQuery
+   * @type {CompatibilityAction}
+   * @memberof CompatibilityPolicy
    */
-  filter: Filter;
+  breaking?: CompatibilityAction;
   /**
    *
-   * @type {GraphResolveDepths}
-   * @memberof EntityTypeStructuralQuery
+   * @type {CompatibilityAction}
+   * @memberof CompatibilityPolicy
    */
-  graphResolveDepths: GraphResolveDepths;
+  incompatible?: CompatibilityAction;
 }
 /**
- *
+ * The changes between two versions of an ontology type.
  * @export
- * @interface EntityTypeWithMetadata
+ * @interface CompatibilityReport
  */
-export interface EntityTypeWithMetadata {
+export interface CompatibilityReport {
   /**
    *
-   * @type {OntologyElementMetadata}
-   * @memberof EntityTypeWithMetadata
+   * @type {Array<SchemaChange>}
+   * @memberof CompatibilityReport
    */
-  metadata: OntologyElementMetadata;
+  changes: Array<SchemaChange>;
   /**
    *
-   * @type {EntityType}
-   * @memberof EntityTypeWithMetadata
+   * @type {CompatibilityLevel}
+   * @memberof CompatibilityReport
    */
-  schema: EntityType;
+  level: CompatibilityLevel;
 }
 /**
  *
  * @export
- * @interface EqualFilter
+ * @interface ContainsSegmentFilter
  */
-export interface EqualFilter {
+export interface ContainsSegmentFilter {
   /**
    *
    * @type {Array<FilterExpression>}
-   * @memberof EqualFilter
+   * @memberof ContainsSegmentFilter
    */
-  equal: Array<FilterExpression>;
+  containsSegment: Array<FilterExpression>;
 }
 /**
- * @type Filter
+ *
  * @export
+ * @enum {string}
  */
-export type Filter =
-  | AllFilter
-  | AnyFilter
-  | EqualFilter
-  | NotEqualFilter
-  | NotFilter;
 
-/**
- * @type FilterExpression
- * @export
- */
-export type FilterExpression = ParameterExpression | PathExpression;
+export const CountAggregate = {
+  Count: "count",
+} as const;
 
-/**
- * @type GraphElementEditionId
- * @export
- */
-export type GraphElementEditionId =
-  | GraphElementEditionIdOneOf
-  | GraphElementEditionIdOneOf1;
+export type CountAggregate = typeof CountAggregate[keyof typeof CountAggregate];
 
 /**
  *
  * @export
- * @interface GraphElementEditionIdOneOf
+ * @interface CreateDataTypeRequest
  */
-export interface GraphElementEditionIdOneOf {
+export interface CreateDataTypeRequest {
   /**
    *
    * @type {string}
-   * @memberof GraphElementEditionIdOneOf
+   * @memberof CreateDataTypeRequest
    */
-  baseId: string;
+  actorId: string;
   /**
    *
-   * @type {number}
-   * @memberof GraphElementEditionIdOneOf
-   */
-  version: number;
+   * @type {string}
+   * @memberof CreateDataTypeRequest
+   */
+  ownedById: string;
+  /**
+   *
+   * @type {DataType}
+   * @memberof CreateDataTypeRequest
+   */
+  schema: DataType;
 }
 /**
  *
  * @export
- * @interface GraphElementEditionIdOneOf1
+ * @interface CreateEntityRequest
  */
-export interface GraphElementEditionIdOneOf1 {
+export interface CreateEntityRequest {
   /**
    *
    * @type {string}
-   * @memberof GraphElementEditionIdOneOf1
+   * @memberof CreateEntityRequest
    */
-  baseId: string;
+  actorId: string;
   /**
    *
-   * @type {number}
-   * @memberof GraphElementEditionIdOneOf1
+   * @type {string}
+   * @memberof CreateEntityRequest
    */
-  recordId: number;
+  entityTypeId: string;
   /**
    *
    * @type {string}
-   * @memberof GraphElementEditionIdOneOf1
+   * @memberof CreateEntityRequest
    */
-  version: string;
+  entityUuid?: string;
+  /**
+   *
+   * @type {LinkData}
+   * @memberof CreateEntityRequest
+   */
+  linkData?: LinkData;
+  /**
+   *
+   * @type {string}
+   * @memberof CreateEntityRequest
+   */
+  ownedById: string;
+  /**
+   *
+   * @type {object}
+   * @memberof CreateEntityRequest
+   */
+  properties: object;
 }
 /**
- * @type GraphElementId
+ *
  * @export
+ * @interface CreateEntityTypeRequest
  */
-export type GraphElementId = string;
-
+export interface CreateEntityTypeRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof CreateEntityTypeRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof CreateEntityTypeRequest
+   */
+  ownedById: string;
+  /**
+   *
+   * @type {EntityType}
+   * @memberof CreateEntityTypeRequest
+   */
+  schema: EntityType;
+}
 /**
- * TODO: DOC - <https://app.asana.com/0/0/1203438518991188/f>
+ *
  * @export
- * @interface GraphResolveDepths
+ * @interface CreatePropertyTypeRequest
  */
-export interface GraphResolveDepths {
+export interface CreatePropertyTypeRequest {
   /**
    *
-   * @type {OutgoingEdgeResolveDepth}
-   * @memberof GraphResolveDepths
+   * @type {string}
+   * @memberof CreatePropertyTypeRequest
    */
-  constrainsLinkDestinationsOn: OutgoingEdgeResolveDepth;
+  actorId: string;
   /**
    *
-   * @type {OutgoingEdgeResolveDepth}
-   * @memberof GraphResolveDepths
+   * @type {string}
+   * @memberof CreatePropertyTypeRequest
    */
-  constrainsLinksOn: OutgoingEdgeResolveDepth;
+  ownedById: string;
   /**
    *
-   * @type {OutgoingEdgeResolveDepth}
-   * @memberof GraphResolveDepths
+   * @type {PropertyType}
+   * @memberof CreatePropertyTypeRequest
    */
-  constrainsPropertiesOn: OutgoingEdgeResolveDepth;
+  schema: PropertyType;
+}
+/**
+ * Specifies the structure of a Data Type
+ * @export
+ * @interface DataType
+ */
+export interface DataType {
+  [key: string]: any;
+
   /**
    *
-   * @type {OutgoingEdgeResolveDepth}
-   * @memberof GraphResolveDepths
+   * @type {object}
+   * @memberof DataType
    */
-  constrainsValuesOn: OutgoingEdgeResolveDepth;
+  kind: DataTypeKindEnum;
   /**
    *
-   * @type {EdgeResolveDepths}
-   * @memberof GraphResolveDepths
+   * @type {string}
+   * @memberof DataType
    */
-  hasLeftEntity: EdgeResolveDepths;
+  $id: string;
   /**
    *
-   * @type {EdgeResolveDepths}
-   * @memberof GraphResolveDepths
+   * @type {string}
+   * @memberof DataType
    */
-  hasRightEntity: EdgeResolveDepths;
+  title: string;
   /**
    *
-   * @type {OutgoingEdgeResolveDepth}
-   * @memberof GraphResolveDepths
+   * @type {string}
+   * @memberof DataType
    */
-  inheritsFrom: OutgoingEdgeResolveDepth;
+  description?: string;
   /**
    *
-   * @type {OutgoingEdgeResolveDepth}
-   * @memberof GraphResolveDepths
+   * @type {string}
+   * @memberof DataType
    */
-  isOfType: OutgoingEdgeResolveDepth;
+  type: string;
 }
+
+export const DataTypeKindEnum = {
+  DataType: "dataType",
+} as const;
+
+export type DataTypeKindEnum =
+  typeof DataTypeKindEnum[keyof typeof DataTypeKindEnum];
+
 /**
- *
+ * A single token in a [`DataTypeQueryPath`].
  * @export
  * @enum {string}
  */
 
-export const KnowledgeGraphEdgeKind = {
-  LeftEntity: "HAS_LEFT_ENTITY",
-  RightEntity: "HAS_RIGHT_ENTITY",
+export const DataTypeQueryToken = {
+  BaseUri: "baseUri",
+  Version: "version",
+  VersionedUri: "versionedUri",
+  OwnedById: "ownedById",
+  UpdatedById: "updatedById",
+  Title: "title",
+  Description: "description",
+  Type: "type",
 } as const;
 
-export type KnowledgeGraphEdgeKind =
-  typeof KnowledgeGraphEdgeKind[keyof typeof KnowledgeGraphEdgeKind];
+export type DataTypeQueryToken =
+  typeof DataTypeQueryToken[keyof typeof DataTypeQueryToken];
 
 /**
- * @type KnowledgeGraphOutwardEdges
+ *
  * @export
+ * @interface DataTypeReference
  */
-export type KnowledgeGraphOutwardEdges =
-  | KnowledgeGraphOutwardEdgesOneOf
-  | KnowledgeGraphOutwardEdgesOneOf1;
-
+export interface DataTypeReference {
+  /**
+   *
+   * @type {string}
+   * @memberof DataTypeReference
+   */
+  $ref: string;
+}
 /**
  *
  * @export
- * @interface KnowledgeGraphOutwardEdgesOneOf
+ * @interface DataTypeReferenceUpdate
  */
-export interface KnowledgeGraphOutwardEdgesOneOf {
+export interface DataTypeReferenceUpdate {
   /**
    *
    * @type {string}
-   * @memberof KnowledgeGraphOutwardEdgesOneOf
+   * @memberof DataTypeReferenceUpdate
    */
-  kind: KnowledgeGraphOutwardEdgesOneOfKindEnum;
+  $ref: string;
+}
+/**
+ * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Pagination  Optionally, the root vertices can be split into pages by specifying a [`Pagination`]. The roots are then ordered by a stable sort key and at most `limit` roots are returned. If more roots are available, the returned [`Subgraph`] contains a `nextCursor`, which can be passed as `after` to read the next page:  ```json { \"limit\": 100, \"after\": \"68747470733a2f2f6578616d706c652e636f6d2f\" } ```  # Temporal axes  Entities are versioned in time. By default, the query is resolved with the decision time pinned to now. To query the graph as it was at a point in time, the [`TemporalAxes`] can be pinned. The following will return the graph as it was recorded at the given transaction time, while the decision time defaults to now:  ```json { \"transactionTime\": \"2022-12-01T12:00:00Z\" } ```  The root vertices as well as all vertices reached while following edges are resolved in the same temporal slice. Ontology types are not versioned in time and are not affected.  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter [`Subgraph`]: crate::subgraph::Subgraph
+ * @export
+ * @interface DataTypeStructuralQuery
+ */
+export interface DataTypeStructuralQuery {
   /**
    *
-   * @type {boolean}
-   * @memberof KnowledgeGraphOutwardEdgesOneOf
+   * @type {Filter}
+   * @memberof DataTypeStructuralQuery
    */
-  reversed: boolean;
+  filter: Filter;
   /**
    *
-   * @type {KnowledgeGraphOutwardEdgesOneOfRightEndpoint}
-   * @memberof KnowledgeGraphOutwardEdgesOneOf
+   * @type {GraphResolveDepths}
+   * @memberof DataTypeStructuralQuery
    */
-  rightEndpoint: KnowledgeGraphOutwardEdgesOneOfRightEndpoint;
+  graphResolveDepths: GraphResolveDepths;
+  /**
+   *
+   * @type {Pagination}
+   * @memberof DataTypeStructuralQuery
+   */
+  pagination?: Pagination;
+  /**
+   *
+   * @type {TemporalAxes}
+   * @memberof DataTypeStructuralQuery
+   */
+  temporalAxes?: TemporalAxes;
 }
-
-export const KnowledgeGraphOutwardEdgesOneOfKindEnum = {
-  LeftEntity: "HAS_LEFT_ENTITY",
-  RightEntity: "HAS_RIGHT_ENTITY",
-} as const;
-
-export type KnowledgeGraphOutwardEdgesOneOfKindEnum =
-  typeof KnowledgeGraphOutwardEdgesOneOfKindEnum[keyof typeof KnowledgeGraphOutwardEdgesOneOfKindEnum];
-
 /**
  *
  * @export
- * @interface KnowledgeGraphOutwardEdgesOneOf1
+ * @interface DataTypeWithMetadata
  */
-export interface KnowledgeGraphOutwardEdgesOneOf1 {
-  /**
-   *
-   * @type {string}
-   * @memberof KnowledgeGraphOutwardEdgesOneOf1
-   */
-  kind: KnowledgeGraphOutwardEdgesOneOf1KindEnum;
+export interface DataTypeWithMetadata {
   /**
    *
-   * @type {boolean}
-   * @memberof KnowledgeGraphOutwardEdgesOneOf1
+   * @type {OntologyElementMetadata}
+   * @memberof DataTypeWithMetadata
    */
-  reversed: boolean;
+  metadata: OntologyElementMetadata;
   /**
    *
-   * @type {GraphElementEditionIdOneOf}
-   * @memberof KnowledgeGraphOutwardEdgesOneOf1
+   * @type {DataType}
+   * @memberof DataTypeWithMetadata
    */
-  rightEndpoint: GraphElementEditionIdOneOf;
+  schema: DataType;
 }
-
-export const KnowledgeGraphOutwardEdgesOneOf1KindEnum = {
-  IsOfType: "IS_OF_TYPE",
-} as const;
-
-export type KnowledgeGraphOutwardEdgesOneOf1KindEnum =
-  typeof KnowledgeGraphOutwardEdgesOneOf1KindEnum[keyof typeof KnowledgeGraphOutwardEdgesOneOf1KindEnum];
-
 /**
- *
+ * The number of edges of a kind to follow in each direction.  Following an edge in `outgoing` direction resolves the vertex the edge points to, e.g. the [`PropertyType`]s an [`EntityType`] constrains its properties on. Following it in `incoming` direction resolves the vertices pointing to the current vertex, e.g. the [`EntityType`]s constraining their properties on a [`PropertyType`]. Omitted directions default to `0`.  [`PropertyType`]: type_system::PropertyType [`EntityType`]: type_system::EntityType
  * @export
- * @interface KnowledgeGraphOutwardEdgesOneOfRightEndpoint
+ * @interface EdgeResolveDepths
  */
-export interface KnowledgeGraphOutwardEdgesOneOfRightEndpoint {
+export interface EdgeResolveDepths {
   /**
    *
-   * @type {string}
-   * @memberof KnowledgeGraphOutwardEdgesOneOfRightEndpoint
+   * @type {number}
+   * @memberof EdgeResolveDepths
    */
-  baseId: string;
+  incoming?: number;
   /**
    *
-   * @type {string}
-   * @memberof KnowledgeGraphOutwardEdgesOneOfRightEndpoint
+   * @type {number}
+   * @memberof EdgeResolveDepths
    */
-  timestamp: string;
+  outgoing?: number;
 }
 /**
  *
  * @export
- * @interface KnowledgeGraphRootedEdges
+ * @interface Edges
  */
-export interface KnowledgeGraphRootedEdges {
-  [key: string]: { [key: string]: Array<KnowledgeGraphOutwardEdges> };
+export interface Edges {
+  [key: string]: { [key: string]: Array<EdgesValueValueInner> };
 }
 /**
- * @type KnowledgeGraphVertex
+ * @type EdgesValueValueInner
  * @export
  */
-export type KnowledgeGraphVertex = KnowledgeGraphVertexOneOf;
+export type EdgesValueValueInner =
+  | KnowledgeGraphOutwardEdges
+  | OntologyOutwardEdges;
 
 /**
  *
  * @export
- * @interface KnowledgeGraphVertexOneOf
+ * @interface EndsWithFilter
  */
-export interface KnowledgeGraphVertexOneOf {
-  /**
-   *
-   * @type {KnowledgeGraphVertexOneOfInner}
-   * @memberof KnowledgeGraphVertexOneOf
-   */
-  inner: KnowledgeGraphVertexOneOfInner;
+export interface EndsWithFilter {
   /**
    *
-   * @type {object}
-   * @memberof KnowledgeGraphVertexOneOf
+   * @type {Array<FilterExpression>}
+   * @memberof EndsWithFilter
    */
-  kind: KnowledgeGraphVertexOneOfKindEnum;
+  endsWith: Array<FilterExpression>;
 }
-
-export const KnowledgeGraphVertexOneOfKindEnum = {
-  Entity: "entity",
-} as const;
-
-export type KnowledgeGraphVertexOneOfKindEnum =
-  typeof KnowledgeGraphVertexOneOfKindEnum[keyof typeof KnowledgeGraphVertexOneOfKindEnum];
-
 /**
  * A record of an [`Entity`] that has been persisted in the datastore, with its associated metadata.
  * @export
- * @interface KnowledgeGraphVertexOneOfInner
+ * @interface Entity
  */
-export interface KnowledgeGraphVertexOneOfInner {
+export interface Entity {
   /**
    *
    * @type {LinkData}
-   * @memberof KnowledgeGraphVertexOneOfInner
+   * @memberof Entity
    */
   linkData?: LinkData;
   /**
    *
    * @type {EntityMetadata}
-   * @memberof KnowledgeGraphVertexOneOfInner
+   * @memberof Entity
    */
   metadata: EntityMetadata;
   /**
    *
    * @type {object}
-   * @memberof KnowledgeGraphVertexOneOfInner
+   * @memberof Entity
    */
   properties: object;
 }
 /**
- *
- * @export
- * @interface KnowledgeGraphVertices
- */
-export interface KnowledgeGraphVertices {
-  [key: string]: { [key: string]: KnowledgeGraphVertex };
-}
-/**
- * The associated information for \'Link\' entities
+ * Aggregation queries compute aggregates over groups of records instead of returning the records.  The records are selected by a [`Filter`] and resolved in the [`TemporalAxes`] as for a [`StructuralQuery`]. They are then grouped by the values of the `groupBy` paths and every [`Aggregate`] is computed per group. Without any `groupBy` paths, all records form a single group.  # Examples  The following `AggregationQuery<Entity>` counts the latest entities per entity type and sums up the `age` property of them:  ```json { \"filter\": { \"equal\": [ { \"path\": [\"version\"] }, { \"parameter\": \"latest\" } ] }, \"groupBy\": [ [\"type\", \"versionedUri\"] ], \"aggregates\": [ \"count\", { \"sum\": [\"properties\", \"https://example.com/property-type/age/\"] } ] } ```
  * @export
- * @interface LinkData
+ * @interface EntityAggregationQuery
  */
-export interface LinkData {
+export interface EntityAggregationQuery {
   /**
    *
-   * @type {number}
-   * @memberof LinkData
+   * @type {Array<Aggregate>}
+   * @memberof EntityAggregationQuery
    */
-  leftToRightOrder?: number;
+  aggregates: Array<Aggregate>;
   /**
    *
-   * @type {number}
-   * @memberof LinkData
+   * @type {Filter}
+   * @memberof EntityAggregationQuery
    */
-  rightToLeftOrder?: number;
+  filter: Filter;
   /**
    *
-   * @type {string}
-   * @memberof LinkData
+   * @type {Array<QueryPath>}
+   * @memberof EntityAggregationQuery
    */
-  leftEntityId: string;
+  groupBy?: Array<QueryPath>;
   /**
    *
-   * @type {string}
-   * @memberof LinkData
+   * @type {TemporalAxes}
+   * @memberof EntityAggregationQuery
    */
-  rightEntityId: string;
+  temporalAxes?: TemporalAxes;
 }
 /**
  *
  * @export
- * @interface LinkDataAllOf
+ * @interface EntityEditionId
  */
-export interface LinkDataAllOf {
+export interface EntityEditionId {
   /**
    *
    * @type {string}
-   * @memberof LinkDataAllOf
+   * @memberof EntityEditionId
    */
-  leftEntityId: string;
+  baseId: string;
+  /**
+   *
+   * @type {number}
+   * @memberof EntityEditionId
+   */
+  recordId: number;
   /**
    *
    * @type {string}
-   * @memberof LinkDataAllOf
+   * @memberof EntityEditionId
    */
-  rightEntityId: string;
+  version: string;
 }
 /**
  *
  * @export
- * @interface NotEqualFilter
+ * @interface EntityIdAndTimestamp
  */
-export interface NotEqualFilter {
+export interface EntityIdAndTimestamp {
   /**
    *
-   * @type {Array<FilterExpression>}
-   * @memberof NotEqualFilter
+   * @type {string}
+   * @memberof EntityIdAndTimestamp
    */
-  notEqual: Array<FilterExpression>;
+  baseId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof EntityIdAndTimestamp
+   */
+  timestamp: string;
 }
 /**
  *
  * @export
- * @interface NotFilter
+ * @interface EntityLinkOrder
  */
-export interface NotFilter {
+export interface EntityLinkOrder {
   /**
    *
-   * @type {Filter}
-   * @memberof NotFilter
+   * @type {number}
+   * @memberof EntityLinkOrder
    */
-  not: Filter;
+  leftToRightOrder?: number;
+  /**
+   *
+   * @type {number}
+   * @memberof EntityLinkOrder
+   */
+  rightToLeftOrder?: number;
 }
 /**
- *
- * @export
- * @enum {string}
- */
-
-export const OntologyEdgeKind = {
-  InheritsFrom: "INHERITS_FROM",
-  ConstrainsValuesOn: "CONSTRAINS_VALUES_ON",
-  ConstrainsPropertiesOn: "CONSTRAINS_PROPERTIES_ON",
-  ConstrainsLinksOn: "CONSTRAINS_LINKS_ON",
-  ConstrainsLinkDestinationsOn: "CONSTRAINS_LINK_DESTINATIONS_ON",
-} as const;
-
-export type OntologyEdgeKind =
-  typeof OntologyEdgeKind[keyof typeof OntologyEdgeKind];
-
-/**
- *
+ * The metadata of an [`Entity`] record.
  * @export
- * @interface OntologyElementMetadata
+ * @interface EntityMetadata
  */
-export interface OntologyElementMetadata {
+export interface EntityMetadata {
   /**
    *
-   * @type {OntologyTypeEditionId}
-   * @memberof OntologyElementMetadata
+   * @type {boolean}
+   * @memberof EntityMetadata
    */
-  editionId: OntologyTypeEditionId;
+  archived: boolean;
+  /**
+   *
+   * @type {EntityEditionId}
+   * @memberof EntityMetadata
+   */
+  editionId: EntityEditionId;
   /**
    *
    * @type {string}
-   * @memberof OntologyElementMetadata
+   * @memberof EntityMetadata
    */
-  ownedById: string;
+  entityTypeId: string;
   /**
    *
    * @type {ProvenanceMetadata}
-   * @memberof OntologyElementMetadata
+   * @memberof EntityMetadata
    */
   provenance: ProvenanceMetadata;
 }
 /**
- * @type OntologyOutwardEdges
- * @export
- */
-export type OntologyOutwardEdges =
-  | OntologyOutwardEdgesOneOf
-  | OntologyOutwardEdgesOneOf1;
-
-/**
- *
+ * Moves all entities of an entity type to a newer version of the same entity type.  The [`PropertyMapping`]s are applied in order to the properties of every entity.
  * @export
- * @interface OntologyOutwardEdgesOneOf
+ * @interface EntityMigration
  */
-export interface OntologyOutwardEdgesOneOf {
+export interface EntityMigration {
   /**
    *
-   * @type {string}
-   * @memberof OntologyOutwardEdgesOneOf
+   * @type {Array<PropertyMapping>}
+   * @memberof EntityMigration
    */
-  kind: OntologyOutwardEdgesOneOfKindEnum;
+  mappings?: Array<PropertyMapping>;
   /**
    *
-   * @type {boolean}
-   * @memberof OntologyOutwardEdgesOneOf
+   * @type {string}
+   * @memberof EntityMigration
    */
-  reversed: boolean;
+  sourceEntityTypeId: string;
   /**
    *
-   * @type {GraphElementEditionIdOneOf}
-   * @memberof OntologyOutwardEdgesOneOf
+   * @type {string}
+   * @memberof EntityMigration
    */
-  rightEndpoint: GraphElementEditionIdOneOf;
+  targetEntityTypeId: string;
 }
+/**
+ * A single token in an [`EntityQueryPath`].
+ * @export
+ * @enum {string}
+ */
 
-export const OntologyOutwardEdgesOneOfKindEnum = {
-  InheritsFrom: "INHERITS_FROM",
-  ConstrainsValuesOn: "CONSTRAINS_VALUES_ON",
-  ConstrainsPropertiesOn: "CONSTRAINS_PROPERTIES_ON",
-  ConstrainsLinksOn: "CONSTRAINS_LINKS_ON",
-  ConstrainsLinkDestinationsOn: "CONSTRAINS_LINK_DESTINATIONS_ON",
+export const EntityQueryToken = {
+  Uuid: "uuid",
+  Version: "version",
+  RecordId: "recordId",
+  Archived: "archived",
+  OwnedById: "ownedById",
+  UpdatedById: "updatedById",
+  Type: "type",
+  Properties: "properties",
+  IncomingLinks: "incomingLinks",
+  OutgoingLinks: "outgoingLinks",
+  LeftEntity: "leftEntity",
+  RightEntity: "rightEntity",
+  LeftToRightOrder: "leftToRightOrder",
+  RightToLeftOrder: "rightToLeftOrder",
 } as const;
 
-export type OntologyOutwardEdgesOneOfKindEnum =
-  typeof OntologyOutwardEdgesOneOfKindEnum[keyof typeof OntologyOutwardEdgesOneOfKindEnum];
+export type EntityQueryToken =
+  typeof EntityQueryToken[keyof typeof EntityQueryToken];
+
+/**
+ * @type EntityReference
+ * References an entity from inside of a batch of [`BatchOperation`]s.
+ * @export
+ */
+export type EntityReference = EntityReferenceOneOf | EntityReferenceOneOf1;
 
 /**
  *
  * @export
- * @interface OntologyOutwardEdgesOneOf1
+ * @interface EntityReferenceOneOf
  */
-export interface OntologyOutwardEdgesOneOf1 {
+export interface EntityReferenceOneOf {
   /**
    *
    * @type {string}
-   * @memberof OntologyOutwardEdgesOneOf1
-   */
-  kind: OntologyOutwardEdgesOneOf1KindEnum;
-  /**
-   *
-   * @type {boolean}
-   * @memberof OntologyOutwardEdgesOneOf1
-   */
-  reversed: boolean;
-  /**
-   *
-   * @type {GraphElementEditionIdOneOf1}
-   * @memberof OntologyOutwardEdgesOneOf1
+   * @memberof EntityReferenceOneOf
    */
-  rightEndpoint: GraphElementEditionIdOneOf1;
+  entityId: string;
 }
-
-export const OntologyOutwardEdgesOneOf1KindEnum = {
-  IsOfType: "IS_OF_TYPE",
-} as const;
-
-export type OntologyOutwardEdgesOneOf1KindEnum =
-  typeof OntologyOutwardEdgesOneOf1KindEnum[keyof typeof OntologyOutwardEdgesOneOf1KindEnum];
-
 /**
  *
  * @export
- * @interface OntologyRootedEdges
+ * @interface EntityReferenceOneOf1
  */
-export interface OntologyRootedEdges {
-  [key: string]: { [key: string]: Array<OntologyOutwardEdges> };
+export interface EntityReferenceOneOf1 {
+  /**
+   * The entity created or updated by the operation at this index of the batch.  The referenced operation has to precede the operation referencing it.
+   * @type {number}
+   * @memberof EntityReferenceOneOf1
+   */
+  operation: number;
 }
 /**
- *
+ * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Pagination  Optionally, the root vertices can be split into pages by specifying a [`Pagination`]. The roots are then ordered by a stable sort key and at most `limit` roots are returned. If more roots are available, the returned [`Subgraph`] contains a `nextCursor`, which can be passed as `after` to read the next page:  ```json { \"limit\": 100, \"after\": \"68747470733a2f2f6578616d706c652e636f6d2f\" } ```  # Temporal axes  Entities are versioned in time. By default, the query is resolved with the decision time pinned to now. To query the graph as it was at a point in time, the [`TemporalAxes`] can be pinned. The following will return the graph as it was recorded at the given transaction time, while the decision time defaults to now:  ```json { \"transactionTime\": \"2022-12-01T12:00:00Z\" } ```  The root vertices as well as all vertices reached while following edges are resolved in the same temporal slice. Ontology types are not versioned in time and are not affected.  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter [`Subgraph`]: crate::subgraph::Subgraph
  * @export
- * @interface OntologyTypeEditionId
+ * @interface EntityStructuralQuery
  */
-export interface OntologyTypeEditionId {
+export interface EntityStructuralQuery {
   /**
    *
-   * @type {string}
-   * @memberof OntologyTypeEditionId
+   * @type {Filter}
+   * @memberof EntityStructuralQuery
    */
-  baseId: string;
+  filter: Filter;
   /**
    *
-   * @type {number}
-   * @memberof OntologyTypeEditionId
+   * @type {GraphResolveDepths}
+   * @memberof EntityStructuralQuery
    */
-  version: number;
+  graphResolveDepths: GraphResolveDepths;
+  /**
+   *
+   * @type {Pagination}
+   * @memberof EntityStructuralQuery
+   */
+  pagination?: Pagination;
+  /**
+   *
+   * @type {TemporalAxes}
+   * @memberof EntityStructuralQuery
+   */
+  temporalAxes?: TemporalAxes;
 }
 /**
- * @type OntologyVertex
- * @export
- */
-export type OntologyVertex =
-  | OntologyVertexOneOf
-  | OntologyVertexOneOf1
-  | OntologyVertexOneOf2;
-
-/**
- *
+ * Specifies the structure of an Entity Type
  * @export
- * @interface OntologyVertexOneOf
+ * @interface EntityType
  */
-export interface OntologyVertexOneOf {
+export interface EntityType {
   /**
    *
-   * @type {OntologyVertexOneOfInner}
-   * @memberof OntologyVertexOneOf
+   * @type {object}
+   * @memberof EntityType
    */
-  inner: OntologyVertexOneOfInner;
+  kind: EntityTypeKindEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof EntityType
+   */
+  $id: string;
   /**
    *
    * @type {object}
-   * @memberof OntologyVertexOneOf
+   * @memberof EntityType
    */
-  kind: OntologyVertexOneOfKindEnum;
-}
-
-export const OntologyVertexOneOfKindEnum = {
-  DataType: "dataType",
-} as const;
-
-export type OntologyVertexOneOfKindEnum =
-  typeof OntologyVertexOneOfKindEnum[keyof typeof OntologyVertexOneOfKindEnum];
-
-/**
- *
- * @export
- * @interface OntologyVertexOneOf1
- */
-export interface OntologyVertexOneOf1 {
+  type: EntityTypeTypeEnum;
   /**
    *
-   * @type {OntologyVertexOneOf1Inner}
-   * @memberof OntologyVertexOneOf1
+   * @type {string}
+   * @memberof EntityType
    */
-  inner: OntologyVertexOneOf1Inner;
+  title: string;
+  /**
+   *
+   * @type {string}
+   * @memberof EntityType
+   */
+  description?: string;
   /**
    *
    * @type {object}
-   * @memberof OntologyVertexOneOf1
+   * @memberof EntityType
    */
-  kind: OntologyVertexOneOf1KindEnum;
-}
-
-export const OntologyVertexOneOf1KindEnum = {
-  PropertyType: "propertyType",
-} as const;
-
-export type OntologyVertexOneOf1KindEnum =
-  typeof OntologyVertexOneOf1KindEnum[keyof typeof OntologyVertexOneOf1KindEnum];
-
-/**
- *
- * @export
- * @interface OntologyVertexOneOf1Inner
- */
-export interface OntologyVertexOneOf1Inner {
+  default?: object;
   /**
    *
-   * @type {OntologyElementMetadata}
-   * @memberof OntologyVertexOneOf1Inner
+   * @type {Array<object>}
+   * @memberof EntityType
    */
-  metadata: OntologyElementMetadata;
+  examples?: Array<object>;
   /**
    *
-   * @type {PropertyType}
-   * @memberof OntologyVertexOneOf1Inner
+   * @type {object}
+   * @memberof EntityType
    */
-  schema: PropertyType;
-}
-/**
- *
- * @export
- * @interface OntologyVertexOneOf2
- */
-export interface OntologyVertexOneOf2 {
+  properties: object;
   /**
    *
-   * @type {OntologyVertexOneOf2Inner}
-   * @memberof OntologyVertexOneOf2
+   * @type {Array<string>}
+   * @memberof EntityType
    */
-  inner: OntologyVertexOneOf2Inner;
+  required?: Array<string>;
   /**
    *
    * @type {object}
-   * @memberof OntologyVertexOneOf2
+   * @memberof EntityType
    */
-  kind: OntologyVertexOneOf2KindEnum;
+  links?: object;
+  /**
+   *
+   * @type {Array<string>}
+   * @memberof EntityType
+   */
+  requiredLinks?: Array<string>;
 }
 
-export const OntologyVertexOneOf2KindEnum = {
+export const EntityTypeKindEnum = {
   EntityType: "entityType",
 } as const;
 
-export type OntologyVertexOneOf2KindEnum =
-  typeof OntologyVertexOneOf2KindEnum[keyof typeof OntologyVertexOneOf2KindEnum];
+export type EntityTypeKindEnum =
+  typeof EntityTypeKindEnum[keyof typeof EntityTypeKindEnum];
+export const EntityTypeTypeEnum = {
+  Object: "object",
+} as const;
+
+export type EntityTypeTypeEnum =
+  typeof EntityTypeTypeEnum[keyof typeof EntityTypeTypeEnum];
 
 /**
- *
+ * A single token in a [`EntityTypeQueryPath`].
  * @export
- * @interface OntologyVertexOneOf2Inner
+ * @enum {string}
  */
-export interface OntologyVertexOneOf2Inner {
+
+export const EntityTypeQueryToken = {
+  BaseUri: "baseUri",
+  Version: "version",
+  VersionedUri: "versionedUri",
+  OwnedById: "ownedById",
+  UpdatedById: "updatedById",
+  Title: "title",
+  Description: "description",
+  Default: "default",
+  Examples: "examples",
+  Properties: "properties",
+  Required: "required",
+  Links: "links",
+  RequiredLinks: "requiredLinks",
+  InheritsFrom: "inheritsFrom",
+} as const;
+
+export type EntityTypeQueryToken =
+  typeof EntityTypeQueryToken[keyof typeof EntityTypeQueryToken];
+
+/**
+ * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Pagination  Optionally, the root vertices can be split into pages by specifying a [`Pagination`]. The roots are then ordered by a stable sort key and at most `limit` roots are returned. If more roots are available, the returned [`Subgraph`] contains a `nextCursor`, which can be passed as `after` to read the next page:  ```json { \"limit\": 100, \"after\": \"68747470733a2f2f6578616d706c652e636f6d2f\" } ```  # Temporal axes  Entities are versioned in time. By default, the query is resolved with the decision time pinned to now. To query the graph as it was at a point in time, the [`TemporalAxes`] can be pinned. The following will return the graph as it was recorded at the given transaction time, while the decision time defaults to now:  ```json { \"transactionTime\": \"2022-12-01T12:00:00Z\" } ```  The root vertices as well as all vertices reached while following edges are resolved in the same temporal slice. Ontology types are not versioned in time and are not affected.  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter [`Subgraph`]: crate::subgraph::Subgraph
+ * @export
+ * @interface EntityTypeStructuralQuery
+ */
+export interface EntityTypeStructuralQuery {
   /**
    *
-   * @type {OntologyElementMetadata}
-   * @memberof OntologyVertexOneOf2Inner
+   * @type {Filter}
+   * @memberof EntityTypeStructuralQuery
    */
-  metadata: OntologyElementMetadata;
+  filter: Filter;
   /**
    *
-   * @type {EntityType}
-   * @memberof OntologyVertexOneOf2Inner
+   * @type {GraphResolveDepths}
+   * @memberof EntityTypeStructuralQuery
    */
-  schema: EntityType;
+  graphResolveDepths: GraphResolveDepths;
+  /**
+   *
+   * @type {Pagination}
+   * @memberof EntityTypeStructuralQuery
+   */
+  pagination?: Pagination;
+  /**
+   *
+   * @type {TemporalAxes}
+   * @memberof EntityTypeStructuralQuery
+   */
+  temporalAxes?: TemporalAxes;
 }
 /**
  *
  * @export
- * @interface OntologyVertexOneOfInner
+ * @interface EntityTypeWithMetadata
  */
-export interface OntologyVertexOneOfInner {
+export interface EntityTypeWithMetadata {
   /**
    *
    * @type {OntologyElementMetadata}
-   * @memberof OntologyVertexOneOfInner
+   * @memberof EntityTypeWithMetadata
    */
   metadata: OntologyElementMetadata;
   /**
    *
-   * @type {DataType}
-   * @memberof OntologyVertexOneOfInner
+   * @type {EntityType}
+   * @memberof EntityTypeWithMetadata
    */
-  schema: DataType;
-}
-/**
- *
- * @export
- * @interface OntologyVertices
- */
-export interface OntologyVertices {
-  [key: string]: { [key: string]: OntologyVertex };
+  schema: EntityType;
 }
 /**
  *
  * @export
- * @interface OutgoingEdgeResolveDepth
+ * @interface EqualFilter
  */
-export interface OutgoingEdgeResolveDepth {
+export interface EqualFilter {
   /**
    *
-   * @type {number}
-   * @memberof OutgoingEdgeResolveDepth
+   * @type {Array<FilterExpression>}
+   * @memberof EqualFilter
    */
-  outgoing: number;
+  equal: Array<FilterExpression>;
 }
+/**
+ * A stable, machine-readable identifier of the kind of a [`Problem`].  In contrast to the status code, the code distinguishes between different problems with the same status, e.g. a base URI, which already exists, and an incompatible update.
+ * @export
+ * @enum {string}
+ */
+
+export const ErrorCode = {
+  BaseUriAlreadyExists: "base-uri-already-exists",
+  BaseUriDoesNotExist: "base-uri-does-not-exist",
+  VersionedUriAlreadyExists: "versioned-uri-already-exists",
+  IncompatibleUpdate: "incompatible-update",
+  InvalidDomain: "invalid-domain",
+  EntityDoesNotExist: "entity-does-not-exist",
+  EntityIsReferenced: "entity-is-referenced",
+  PurgedEntitiesChanged: "purged-entities-changed",
+  ChangeOffsetDoesNotExist: "change-offset-does-not-exist",
+  InvalidEntity: "invalid-entity",
+  InvalidMigration: "invalid-migration",
+  InvalidReference: "invalid-reference",
+  ConcurrentUpdate: "concurrent-update",
+  InvalidQuery: "invalid-query",
+  InsertionFailed: "insertion-failed",
+  UpdateFailed: "update-failed",
+  BadRequest: "bad-request",
+  Unauthenticated: "unauthenticated",
+  Forbidden: "forbidden",
+  NotFound: "not-found",
+  Conflict: "conflict",
+  UnprocessableEntity: "unprocessable-entity",
+  InternalError: "internal-error",
+} as const;
+
+export type ErrorCode = typeof ErrorCode[keyof typeof ErrorCode];
+
 /**
  *
  * @export
- * @interface ParameterExpression
+ * @interface ExistsFilter
  */
-export interface ParameterExpression {
+export interface ExistsFilter {
   /**
    *
-   * @type {boolean | number | string}
-   * @memberof ParameterExpression
+   * @type {FilterExpression}
+   * @memberof ExistsFilter
    */
-  parameter: boolean | number | string;
+  exists: FilterExpression;
 }
+/**
+ * @type Filter
+ * @export
+ */
+export type Filter =
+  | AllFilter
+  | AnyFilter
+  | ContainsSegmentFilter
+  | EndsWithFilter
+  | EqualFilter
+  | ExistsFilter
+  | GreaterFilter
+  | GreaterOrEqualFilter
+  | InFilter
+  | LessFilter
+  | LessOrEqualFilter
+  | NotEqualFilter
+  | NotFilter
+  | SearchFilter
+  | StartsWithFilter;
+
+/**
+ * @type FilterExpression
+ * @export
+ */
+export type FilterExpression = ParameterExpression | PathExpression;
+
+/**
+ * @type GraphElementEditionId
+ * @export
+ */
+export type GraphElementEditionId =
+  | GraphElementEditionIdOneOf
+  | GraphElementEditionIdOneOf1;
+
 /**
  *
  * @export
- * @interface PathExpression
+ * @interface GraphElementEditionIdOneOf
  */
-export interface PathExpression {
+export interface GraphElementEditionIdOneOf {
   /**
    *
-   * @type {Array<DataTypeQueryToken | PropertyTypeQueryToken | EntityTypeQueryToken | EntityQueryToken | Selector>}
-   * @memberof PathExpression
+   * @type {string}
+   * @memberof GraphElementEditionIdOneOf
    */
-  path: Array<
-    | DataTypeQueryToken
-    | PropertyTypeQueryToken
-    | EntityTypeQueryToken
-    | EntityQueryToken
-    | Selector
-  >;
+  baseId: string;
+  /**
+   *
+   * @type {number}
+   * @memberof GraphElementEditionIdOneOf
+   */
+  version: number;
 }
 /**
  *
  * @export
- * @interface PropertyArrayValue
+ * @interface GraphElementEditionIdOneOf1
  */
-export interface PropertyArrayValue {
+export interface GraphElementEditionIdOneOf1 {
   /**
    *
    * @type {string}
-   * @memberof PropertyArrayValue
-   */
-  type: PropertyArrayValueTypeEnum;
-  /**
-   *
-   * @type {PropertyArrayValueItems}
-   * @memberof PropertyArrayValue
+   * @memberof GraphElementEditionIdOneOf1
    */
-  items: PropertyArrayValueItems;
+  baseId: string;
   /**
    *
    * @type {number}
-   * @memberof PropertyArrayValue
+   * @memberof GraphElementEditionIdOneOf1
    */
-  minItems?: number;
+  recordId: number;
   /**
    *
-   * @type {number}
-   * @memberof PropertyArrayValue
+   * @type {string}
+   * @memberof GraphElementEditionIdOneOf1
    */
-  maxItems?: number;
+  version: string;
 }
-
-export const PropertyArrayValueTypeEnum = {
-  Array: "array",
-} as const;
-
-export type PropertyArrayValueTypeEnum =
-  typeof PropertyArrayValueTypeEnum[keyof typeof PropertyArrayValueTypeEnum];
+/**
+ * @type GraphElementId
+ * @export
+ */
+export type GraphElementId = string;
 
 /**
- *
+ * TODO: DOC - <https://app.asana.com/0/0/1203438518991188/f>
  * @export
- * @interface PropertyArrayValueItems
+ * @interface GraphResolveDepths
  */
-export interface PropertyArrayValueItems {
+export interface GraphResolveDepths {
   /**
    *
-   * @type {Array<PropertyValues>}
-   * @memberof PropertyArrayValueItems
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
    */
-  oneOf: Array<PropertyValues>;
-}
-/**
- *
- * @export
- * @interface PropertyArrayValueUpdate
- */
-export interface PropertyArrayValueUpdate {
+  constrainsLinkDestinationsOn: EdgeResolveDepths;
   /**
    *
-   * @type {string}
-   * @memberof PropertyArrayValueUpdate
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
    */
-  type: PropertyArrayValueUpdateTypeEnum;
+  constrainsLinksOn: EdgeResolveDepths;
   /**
    *
-   * @type {PropertyArrayValueUpdateItems}
-   * @memberof PropertyArrayValueUpdate
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
    */
-  items: PropertyArrayValueUpdateItems;
+  constrainsPropertiesOn: EdgeResolveDepths;
   /**
    *
-   * @type {number}
-   * @memberof PropertyArrayValueUpdate
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
    */
-  minItems?: number;
+  constrainsValuesOn: EdgeResolveDepths;
   /**
    *
-   * @type {number}
-   * @memberof PropertyArrayValueUpdate
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
    */
-  maxItems?: number;
+  hasLeftEntity: EdgeResolveDepths;
+  /**
+   *
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
+   */
+  hasRightEntity: EdgeResolveDepths;
+  /**
+   *
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
+   */
+  inheritsFrom: EdgeResolveDepths;
+  /**
+   *
+   * @type {EdgeResolveDepths}
+   * @memberof GraphResolveDepths
+   */
+  isOfType: EdgeResolveDepths;
 }
-
-export const PropertyArrayValueUpdateTypeEnum = {
-  Array: "array",
-} as const;
-
-export type PropertyArrayValueUpdateTypeEnum =
-  typeof PropertyArrayValueUpdateTypeEnum[keyof typeof PropertyArrayValueUpdateTypeEnum];
-
 /**
  *
  * @export
- * @interface PropertyArrayValueUpdateItems
+ * @interface GreaterFilter
  */
-export interface PropertyArrayValueUpdateItems {
+export interface GreaterFilter {
   /**
    *
-   * @type {Array<PropertyValuesUpdate>}
-   * @memberof PropertyArrayValueUpdateItems
+   * @type {Array<FilterExpression>}
+   * @memberof GreaterFilter
    */
-  oneOf: Array<PropertyValuesUpdate>;
+  greater: Array<FilterExpression>;
 }
 /**
  *
  * @export
- * @interface PropertyObjectValue
+ * @interface GreaterOrEqualFilter
  */
-export interface PropertyObjectValue {
+export interface GreaterOrEqualFilter {
   /**
    *
-   * @type {string}
-   * @memberof PropertyObjectValue
+   * @type {Array<FilterExpression>}
+   * @memberof GreaterOrEqualFilter
    */
-  type: PropertyObjectValueTypeEnum;
+  greaterOrEqual: Array<FilterExpression>;
+}
+/**
+ *
+ * @export
+ * @interface InFilter
+ */
+export interface InFilter {
   /**
    *
-   * @type {object}
-   * @memberof PropertyObjectValue
+   * @type {Array<FilterExpression | Array<FilterExpression>>}
+   * @memberof InFilter
    */
-  properties: object;
+  in: Array<FilterExpression | Array<FilterExpression>>;
 }
+/**
+ *
+ * @export
+ * @enum {string}
+ */
 
-export const PropertyObjectValueTypeEnum = {
-  Object: "object",
+export const KnowledgeGraphEdgeKind = {
+  LeftEntity: "HAS_LEFT_ENTITY",
+  RightEntity: "HAS_RIGHT_ENTITY",
 } as const;
 
-export type PropertyObjectValueTypeEnum =
-  typeof PropertyObjectValueTypeEnum[keyof typeof PropertyObjectValueTypeEnum];
+export type KnowledgeGraphEdgeKind =
+  typeof KnowledgeGraphEdgeKind[keyof typeof KnowledgeGraphEdgeKind];
 
 /**
- * Specifies the structure of a Property Type
+ * @type KnowledgeGraphOutwardEdges
  * @export
- * @interface PropertyType
  */
-export interface PropertyType {
-  /**
-   *
-   * @type {object}
-   * @memberof PropertyType
-   */
-  kind: PropertyTypeKindEnum;
-  /**
-   *
-   * @type {string}
-   * @memberof PropertyType
-   */
-  $id: string;
+export type KnowledgeGraphOutwardEdges =
+  | KnowledgeGraphOutwardEdgesOneOf
+  | KnowledgeGraphOutwardEdgesOneOf1;
+
+/**
+ *
+ * @export
+ * @interface KnowledgeGraphOutwardEdgesOneOf
+ */
+export interface KnowledgeGraphOutwardEdgesOneOf {
   /**
    *
    * @type {string}
-   * @memberof PropertyType
+   * @memberof KnowledgeGraphOutwardEdgesOneOf
    */
-  title: string;
+  kind: KnowledgeGraphOutwardEdgesOneOfKindEnum;
   /**
    *
-   * @type {string}
-   * @memberof PropertyType
+   * @type {boolean}
+   * @memberof KnowledgeGraphOutwardEdgesOneOf
    */
-  description?: string;
+  reversed: boolean;
   /**
    *
-   * @type {Array<PropertyValues>}
-   * @memberof PropertyType
+   * @type {KnowledgeGraphOutwardEdgesOneOfRightEndpoint}
+   * @memberof KnowledgeGraphOutwardEdgesOneOf
    */
-  oneOf: Array<PropertyValues>;
+  rightEndpoint: KnowledgeGraphOutwardEdgesOneOfRightEndpoint;
 }
 
-export const PropertyTypeKindEnum = {
-  PropertyType: "propertyType",
+export const KnowledgeGraphOutwardEdgesOneOfKindEnum = {
+  LeftEntity: "HAS_LEFT_ENTITY",
+  RightEntity: "HAS_RIGHT_ENTITY",
 } as const;
 
-export type PropertyTypeKindEnum =
-  typeof PropertyTypeKindEnum[keyof typeof PropertyTypeKindEnum];
+export type KnowledgeGraphOutwardEdgesOneOfKindEnum =
+  typeof KnowledgeGraphOutwardEdgesOneOfKindEnum[keyof typeof KnowledgeGraphOutwardEdgesOneOfKindEnum];
 
 /**
- * A single token in a [`DataTypeQueryPath`].
+ *
  * @export
- * @enum {string}
+ * @interface KnowledgeGraphOutwardEdgesOneOf1
  */
+export interface KnowledgeGraphOutwardEdgesOneOf1 {
+  /**
+   *
+   * @type {string}
+   * @memberof KnowledgeGraphOutwardEdgesOneOf1
+   */
+  kind: KnowledgeGraphOutwardEdgesOneOf1KindEnum;
+  /**
+   *
+   * @type {boolean}
+   * @memberof KnowledgeGraphOutwardEdgesOneOf1
+   */
+  reversed: boolean;
+  /**
+   *
+   * @type {GraphElementEditionIdOneOf}
+   * @memberof KnowledgeGraphOutwardEdgesOneOf1
+   */
+  rightEndpoint: GraphElementEditionIdOneOf;
+}
 
-export const PropertyTypeQueryToken = {
-  BaseUri: "baseUri",
-  Version: "version",
-  VersionedUri: "versionedUri",
-  OwnedById: "ownedById",
-  UpdatedById: "updatedById",
-  Title: "title",
-  Description: "description",
-  DataTypes: "dataTypes",
-  PropertyTypes: "propertyTypes",
+export const KnowledgeGraphOutwardEdgesOneOf1KindEnum = {
+  IsOfType: "IS_OF_TYPE",
 } as const;
 
-export type PropertyTypeQueryToken =
-  typeof PropertyTypeQueryToken[keyof typeof PropertyTypeQueryToken];
+export type KnowledgeGraphOutwardEdgesOneOf1KindEnum =
+  typeof KnowledgeGraphOutwardEdgesOneOf1KindEnum[keyof typeof KnowledgeGraphOutwardEdgesOneOf1KindEnum];
 
 /**
- * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter
+ *
  * @export
- * @interface PropertyTypeStructuralQuery
+ * @interface KnowledgeGraphOutwardEdgesOneOfRightEndpoint
  */
-export interface PropertyTypeStructuralQuery {
+export interface KnowledgeGraphOutwardEdgesOneOfRightEndpoint {
   /**
    *
-   * @type {Filter}
-   * @memberof PropertyTypeStructuralQuery
+   * @type {string}
+   * @memberof KnowledgeGraphOutwardEdgesOneOfRightEndpoint
    */
-  filter: Filter;
+  baseId: string;
   /**
    *
-   * @type {GraphResolveDepths}
-   * @memberof PropertyTypeStructuralQuery
+   * @type {string}
+   * @memberof KnowledgeGraphOutwardEdgesOneOfRightEndpoint
    */
-  graphResolveDepths: GraphResolveDepths;
+  timestamp: string;
 }
 /**
  *
  * @export
- * @interface PropertyTypeWithMetadata
+ * @interface KnowledgeGraphRootedEdges
  */
-export interface PropertyTypeWithMetadata {
-  /**
-   *
-   * @type {OntologyElementMetadata}
-   * @memberof PropertyTypeWithMetadata
-   */
-  metadata: OntologyElementMetadata;
-  /**
-   *
-   * @type {PropertyType}
-   * @memberof PropertyTypeWithMetadata
-   */
-  schema: PropertyType;
+export interface KnowledgeGraphRootedEdges {
+  [key: string]: { [key: string]: Array<KnowledgeGraphOutwardEdges> };
 }
 /**
- * @type PropertyValues
- * @export
- */
-export type PropertyValues =
-  | DataTypeReference
-  | PropertyArrayValue
-  | PropertyObjectValue;
-
-/**
- * @type PropertyValuesUpdate
+ * @type KnowledgeGraphVertex
  * @export
  */
-export type PropertyValuesUpdate =
-  | DataTypeReferenceUpdate
-  | PropertyArrayValueUpdate
-  | PropertyObjectValue;
+export type KnowledgeGraphVertex = KnowledgeGraphVertexOneOf;
 
 /**
  *
  * @export
- * @interface ProvenanceMetadata
+ * @interface KnowledgeGraphVertexOneOf
  */
-export interface ProvenanceMetadata {
+export interface KnowledgeGraphVertexOneOf {
   /**
    *
-   * @type {string}
-   * @memberof ProvenanceMetadata
+   * @type {KnowledgeGraphVertexOneOfInner}
+   * @memberof KnowledgeGraphVertexOneOf
    */
-  updatedById: string;
+  inner: KnowledgeGraphVertexOneOfInner;
+  /**
+   *
+   * @type {object}
+   * @memberof KnowledgeGraphVertexOneOf
+   */
+  kind: KnowledgeGraphVertexOneOfKindEnum;
 }
-/**
- *
- * @export
- * @enum {string}
- */
-
-export const Selector = {
-  Star: "*",
-} as const;
-
-export type Selector = typeof Selector[keyof typeof Selector];
-
-/**
- *
- * @export
- * @enum {string}
- */
 
-export const SharedEdgeKind = {
-  IsOfType: "IS_OF_TYPE",
+export const KnowledgeGraphVertexOneOfKindEnum = {
+  Entity: "entity",
 } as const;
 
-export type SharedEdgeKind = typeof SharedEdgeKind[keyof typeof SharedEdgeKind];
+export type KnowledgeGraphVertexOneOfKindEnum =
+  typeof KnowledgeGraphVertexOneOfKindEnum[keyof typeof KnowledgeGraphVertexOneOfKindEnum];
 
 /**
- *
+ * A record of an [`Entity`] that has been persisted in the datastore, with its associated metadata.
  * @export
- * @interface Subgraph
+ * @interface KnowledgeGraphVertexOneOfInner
  */
-export interface Subgraph {
-  /**
-   *
-   * @type {GraphResolveDepths}
-   * @memberof Subgraph
-   */
-  depths: GraphResolveDepths;
+export interface KnowledgeGraphVertexOneOfInner {
   /**
    *
-   * @type {Edges}
-   * @memberof Subgraph
+   * @type {LinkData}
+   * @memberof KnowledgeGraphVertexOneOfInner
    */
-  edges: Edges;
+  linkData?: LinkData;
   /**
    *
-   * @type {Array<GraphElementEditionId>}
-   * @memberof Subgraph
+   * @type {EntityMetadata}
+   * @memberof KnowledgeGraphVertexOneOfInner
    */
-  roots: Array<GraphElementEditionId>;
+  metadata: EntityMetadata;
   /**
    *
-   * @type {Vertices}
-   * @memberof Subgraph
+   * @type {object}
+   * @memberof KnowledgeGraphVertexOneOfInner
    */
-  vertices: Vertices;
+  properties: object;
 }
 /**
- * The contents of a Data Type update request
+ *
  * @export
- * @interface UpdateDataType
+ * @interface KnowledgeGraphVertices
  */
-export interface UpdateDataType {
-  [key: string]: any;
-
-  /**
-   *
-   * @type {object}
-   * @memberof UpdateDataType
-   */
-  kind: UpdateDataTypeKindEnum;
-  /**
-   *
-   * @type {string}
-   * @memberof UpdateDataType
-   */
-  title: string;
-  /**
-   *
-   * @type {string}
-   * @memberof UpdateDataType
-   */
-  description?: string;
-  /**
-   *
-   * @type {string}
-   * @memberof UpdateDataType
-   */
-  type: string;
+export interface KnowledgeGraphVertices {
+  [key: string]: { [key: string]: KnowledgeGraphVertex };
 }
-
-export const UpdateDataTypeKindEnum = {
-  DataType: "dataType",
-} as const;
-
-export type UpdateDataTypeKindEnum =
-  typeof UpdateDataTypeKindEnum[keyof typeof UpdateDataTypeKindEnum];
-
 /**
  *
  * @export
- * @interface UpdateDataTypeRequest
+ * @interface LessFilter
  */
-export interface UpdateDataTypeRequest {
-  /**
-   *
-   * @type {string}
-   * @memberof UpdateDataTypeRequest
-   */
-  actorId: string;
+export interface LessFilter {
   /**
    *
-   * @type {UpdateDataType}
-   * @memberof UpdateDataTypeRequest
+   * @type {Array<FilterExpression>}
+   * @memberof LessFilter
    */
-  schema: UpdateDataType;
+  less: Array<FilterExpression>;
+}
+/**
+ *
+ * @export
+ * @interface LessOrEqualFilter
+ */
+export interface LessOrEqualFilter {
   /**
    *
-   * @type {string}
-   * @memberof UpdateDataTypeRequest
+   * @type {Array<FilterExpression>}
+   * @memberof LessOrEqualFilter
    */
-  typeToUpdate: string;
+  lessOrEqual: Array<FilterExpression>;
 }
 /**
- *
+ * The associated information for \'Link\' entities
  * @export
- * @interface UpdateEntityRequest
+ * @interface LinkData
  */
-export interface UpdateEntityRequest {
+export interface LinkData {
   /**
    *
    * @type {number}
-   * @memberof UpdateEntityRequest
+   * @memberof LinkData
    */
   leftToRightOrder?: number;
   /**
    *
    * @type {number}
-   * @memberof UpdateEntityRequest
+   * @memberof LinkData
    */
   rightToLeftOrder?: number;
   /**
    *
    * @type {string}
-   * @memberof UpdateEntityRequest
+   * @memberof LinkData
    */
-  actorId: string;
+  leftEntityId: string;
   /**
    *
-   * @type {boolean}
-   * @memberof UpdateEntityRequest
+   * @type {string}
+   * @memberof LinkData
    */
-  archived?: boolean;
+  rightEntityId: string;
+}
+/**
+ *
+ * @export
+ * @interface LinkDataAllOf
+ */
+export interface LinkDataAllOf {
   /**
    *
    * @type {string}
-   * @memberof UpdateEntityRequest
+   * @memberof LinkDataAllOf
    */
-  entityId: string;
+  leftEntityId: string;
   /**
    *
    * @type {string}
-   * @memberof UpdateEntityRequest
+   * @memberof LinkDataAllOf
    */
-  entityTypeId: string;
+  rightEntityId: string;
+}
+/**
+ * Specifies how to handle link entities referencing an entity, which is purged.
+ * @export
+ * @enum {string}
+ */
+
+export const LinkPurgeBehavior = {
+  Refuse: "refuse",
+  Cascade: "cascade",
+} as const;
+
+export type LinkPurgeBehavior =
+  typeof LinkPurgeBehavior[keyof typeof LinkPurgeBehavior];
+
+/**
+ *
+ * @export
+ * @interface MaxAggregate
+ */
+export interface MaxAggregate {
   /**
    *
-   * @type {object}
-   * @memberof UpdateEntityRequest
+   * @type {QueryPath}
+   * @memberof MaxAggregate
    */
-  properties: object;
+  max: QueryPath;
 }
 /**
  *
  * @export
- * @interface UpdateEntityRequestAllOf
+ * @interface MigrateEntitiesRequest
  */
-export interface UpdateEntityRequestAllOf {
+export interface MigrateEntitiesRequest {
   /**
    *
    * @type {string}
-   * @memberof UpdateEntityRequestAllOf
+   * @memberof MigrateEntitiesRequest
    */
   actorId: string;
   /**
-   *
+   * If `true`, the migrated entities are returned without persisting them.
    * @type {boolean}
-   * @memberof UpdateEntityRequestAllOf
+   * @memberof MigrateEntitiesRequest
    */
-  archived?: boolean;
+  dryRun?: boolean;
   /**
    *
-   * @type {string}
-   * @memberof UpdateEntityRequestAllOf
+   * @type {EntityMigration}
+   * @memberof MigrateEntitiesRequest
    */
-  entityId: string;
+  migration: EntityMigration;
+}
+/**
+ * An entity moved to the target entity type of an [`EntityMigration`].
+ * @export
+ * @interface MigratedEntity
+ */
+export interface MigratedEntity {
   /**
    *
-   * @type {string}
-   * @memberof UpdateEntityRequestAllOf
+   * @type {EntityMetadata}
+   * @memberof MigratedEntity
    */
-  entityTypeId: string;
+  metadata: EntityMetadata;
   /**
    *
    * @type {object}
-   * @memberof UpdateEntityRequestAllOf
+   * @memberof MigratedEntity
    */
   properties: object;
 }
 /**
- * The contents of an Entity Type update request
+ *
  * @export
- * @interface UpdateEntityType
+ * @interface MinAggregate
  */
-export interface UpdateEntityType {
+export interface MinAggregate {
   /**
    *
-   * @type {object}
-   * @memberof UpdateEntityType
+   * @type {QueryPath}
+   * @memberof MinAggregate
    */
-  kind: UpdateEntityTypeKindEnum;
+  min: QueryPath;
+}
+/**
+ *
+ * @export
+ * @interface NotEqualFilter
+ */
+export interface NotEqualFilter {
   /**
    *
-   * @type {object}
-   * @memberof UpdateEntityType
+   * @type {Array<FilterExpression>}
+   * @memberof NotEqualFilter
    */
-  type: UpdateEntityTypeTypeEnum;
-  /**
-   *
-   * @type {string}
-   * @memberof UpdateEntityType
-   */
-  title: string;
+  notEqual: Array<FilterExpression>;
+}
+/**
+ *
+ * @export
+ * @interface NotFilter
+ */
+export interface NotFilter {
   /**
    *
-   * @type {string}
-   * @memberof UpdateEntityType
+   * @type {Filter}
+   * @memberof NotFilter
    */
-  description?: string;
+  not: Filter;
+}
+/**
+ *
+ * @export
+ * @enum {string}
+ */
+
+export const OntologyEdgeKind = {
+  InheritsFrom: "INHERITS_FROM",
+  ConstrainsValuesOn: "CONSTRAINS_VALUES_ON",
+  ConstrainsPropertiesOn: "CONSTRAINS_PROPERTIES_ON",
+  ConstrainsLinksOn: "CONSTRAINS_LINKS_ON",
+  ConstrainsLinkDestinationsOn: "CONSTRAINS_LINK_DESTINATIONS_ON",
+} as const;
+
+export type OntologyEdgeKind =
+  typeof OntologyEdgeKind[keyof typeof OntologyEdgeKind];
+
+/**
+ *
+ * @export
+ * @interface OntologyElementMetadata
+ */
+export interface OntologyElementMetadata {
   /**
    *
-   * @type {object}
-   * @memberof UpdateEntityType
+   * @type {OntologyTypeEditionId}
+   * @memberof OntologyElementMetadata
    */
-  default?: object;
+  editionId: OntologyTypeEditionId;
   /**
    *
-   * @type {Array<object>}
-   * @memberof UpdateEntityType
+   * @type {string}
+   * @memberof OntologyElementMetadata
    */
-  examples?: Array<object>;
+  ownedById: string;
   /**
    *
-   * @type {object}
-   * @memberof UpdateEntityType
+   * @type {ProvenanceMetadata}
+   * @memberof OntologyElementMetadata
    */
-  properties: object;
+  provenance: ProvenanceMetadata;
+}
+/**
+ * @type OntologyOutwardEdges
+ * @export
+ */
+export type OntologyOutwardEdges =
+  | OntologyOutwardEdgesOneOf
+  | OntologyOutwardEdgesOneOf1;
+
+/**
+ *
+ * @export
+ * @interface OntologyOutwardEdgesOneOf
+ */
+export interface OntologyOutwardEdgesOneOf {
   /**
    *
-   * @type {Array<string>}
-   * @memberof UpdateEntityType
+   * @type {string}
+   * @memberof OntologyOutwardEdgesOneOf
    */
-  required?: Array<string>;
+  kind: OntologyOutwardEdgesOneOfKindEnum;
   /**
    *
-   * @type {object}
-   * @memberof UpdateEntityType
+   * @type {boolean}
+   * @memberof OntologyOutwardEdgesOneOf
    */
-  links?: object;
+  reversed: boolean;
   /**
    *
-   * @type {Array<string>}
-   * @memberof UpdateEntityType
+   * @type {GraphElementEditionIdOneOf}
+   * @memberof OntologyOutwardEdgesOneOf
    */
-  requiredLinks?: Array<string>;
+  rightEndpoint: GraphElementEditionIdOneOf;
 }
 
-export const UpdateEntityTypeKindEnum = {
-  EntityType: "entityType",
-} as const;
-
-export type UpdateEntityTypeKindEnum =
-  typeof UpdateEntityTypeKindEnum[keyof typeof UpdateEntityTypeKindEnum];
-export const UpdateEntityTypeTypeEnum = {
-  Object: "object",
+export const OntologyOutwardEdgesOneOfKindEnum = {
+  InheritsFrom: "INHERITS_FROM",
+  ConstrainsValuesOn: "CONSTRAINS_VALUES_ON",
+  ConstrainsPropertiesOn: "CONSTRAINS_PROPERTIES_ON",
+  ConstrainsLinksOn: "CONSTRAINS_LINKS_ON",
+  ConstrainsLinkDestinationsOn: "CONSTRAINS_LINK_DESTINATIONS_ON",
 } as const;
 
-export type UpdateEntityTypeTypeEnum =
-  typeof UpdateEntityTypeTypeEnum[keyof typeof UpdateEntityTypeTypeEnum];
+export type OntologyOutwardEdgesOneOfKindEnum =
+  typeof OntologyOutwardEdgesOneOfKindEnum[keyof typeof OntologyOutwardEdgesOneOfKindEnum];
 
 /**
  *
  * @export
- * @interface UpdateEntityTypeRequest
+ * @interface OntologyOutwardEdgesOneOf1
  */
-export interface UpdateEntityTypeRequest {
+export interface OntologyOutwardEdgesOneOf1 {
   /**
    *
    * @type {string}
-   * @memberof UpdateEntityTypeRequest
+   * @memberof OntologyOutwardEdgesOneOf1
    */
-  actorId: string;
+  kind: OntologyOutwardEdgesOneOf1KindEnum;
   /**
    *
-   * @type {UpdateEntityType}
-   * @memberof UpdateEntityTypeRequest
+   * @type {boolean}
+   * @memberof OntologyOutwardEdgesOneOf1
    */
-  schema: UpdateEntityType;
+  reversed: boolean;
+  /**
+   *
+   * @type {GraphElementEditionIdOneOf1}
+   * @memberof OntologyOutwardEdgesOneOf1
+   */
+  rightEndpoint: GraphElementEditionIdOneOf1;
+}
+
+export const OntologyOutwardEdgesOneOf1KindEnum = {
+  IsOfType: "IS_OF_TYPE",
+} as const;
+
+export type OntologyOutwardEdgesOneOf1KindEnum =
+  typeof OntologyOutwardEdgesOneOf1KindEnum[keyof typeof OntologyOutwardEdgesOneOf1KindEnum];
+
+/**
+ *
+ * @export
+ * @interface OntologyRootedEdges
+ */
+export interface OntologyRootedEdges {
+  [key: string]: { [key: string]: Array<OntologyOutwardEdges> };
+}
+/**
+ *
+ * @export
+ * @interface OntologyTypeEditionId
+ */
+export interface OntologyTypeEditionId {
   /**
    *
    * @type {string}
-   * @memberof UpdateEntityTypeRequest
+   * @memberof OntologyTypeEditionId
    */
-  typeToUpdate: string;
+  baseId: string;
+  /**
+   *
+   * @type {number}
+   * @memberof OntologyTypeEditionId
+   */
+  version: number;
 }
 /**
- * The contents of a Property Type update request
+ * The metadata of an updated ontology type alongside the changes to the previous version.
  * @export
- * @interface UpdatePropertyType
+ * @interface OntologyUpdateMetadata
  */
-export interface UpdatePropertyType {
+export interface OntologyUpdateMetadata {
   /**
    *
-   * @type {object}
-   * @memberof UpdatePropertyType
+   * @type {OntologyTypeEditionId}
+   * @memberof OntologyUpdateMetadata
    */
-  kind: UpdatePropertyTypeKindEnum;
+  editionId: OntologyTypeEditionId;
   /**
    *
    * @type {string}
-   * @memberof UpdatePropertyType
+   * @memberof OntologyUpdateMetadata
    */
-  title: string;
+  ownedById: string;
   /**
    *
-   * @type {string}
-   * @memberof UpdatePropertyType
+   * @type {ProvenanceMetadata}
+   * @memberof OntologyUpdateMetadata
    */
-  description?: string;
+  provenance: ProvenanceMetadata;
   /**
    *
-   * @type {Array<PropertyValuesUpdate>}
-   * @memberof UpdatePropertyType
+   * @type {CompatibilityReport}
+   * @memberof OntologyUpdateMetadata
    */
-  oneOf: Array<PropertyValuesUpdate>;
+  compatibility: CompatibilityReport;
 }
-
-export const UpdatePropertyTypeKindEnum = {
-  PropertyType: "propertyType",
-} as const;
-
-export type UpdatePropertyTypeKindEnum =
-  typeof UpdatePropertyTypeKindEnum[keyof typeof UpdatePropertyTypeKindEnum];
-
 /**
  *
  * @export
- * @interface UpdatePropertyTypeRequest
+ * @interface OntologyUpdateMetadataAllOf
  */
-export interface UpdatePropertyTypeRequest {
+export interface OntologyUpdateMetadataAllOf {
   /**
    *
-   * @type {string}
-   * @memberof UpdatePropertyTypeRequest
-   */
-  actorId: string;
-  /**
-   *
-   * @type {UpdatePropertyType}
-   * @memberof UpdatePropertyTypeRequest
+   * @type {CompatibilityReport}
+   * @memberof OntologyUpdateMetadataAllOf
    */
-  schema: UpdatePropertyType;
-  /**
-   *
-   * @type {string}
-   * @memberof UpdatePropertyTypeRequest
-   */
-  typeToUpdate: string;
+  compatibility: CompatibilityReport;
 }
 /**
- * @type Vertex
+ * @type OntologyVertex
  * @export
  */
-export type Vertex = KnowledgeGraphVertex | OntologyVertex;
+export type OntologyVertex =
+  | OntologyVertexOneOf
+  | OntologyVertexOneOf1
+  | OntologyVertexOneOf2;
 
 /**
  *
  * @export
- * @interface Vertices
+ * @interface OntologyVertexOneOf
  */
-export interface Vertices {
-  [key: string]: { [key: string]: VerticesValueValue };
+export interface OntologyVertexOneOf {
+  /**
+   *
+   * @type {OntologyVertexOneOfInner}
+   * @memberof OntologyVertexOneOf
+   */
+  inner: OntologyVertexOneOfInner;
+  /**
+   *
+   * @type {object}
+   * @memberof OntologyVertexOneOf
+   */
+  kind: OntologyVertexOneOfKindEnum;
 }
-/**
- * @type VerticesValueValue
- * @export
- */
-export type VerticesValueValue = KnowledgeGraphVertex | OntologyVertex;
+
+export const OntologyVertexOneOfKindEnum = {
+  DataType: "dataType",
+} as const;
+
+export type OntologyVertexOneOfKindEnum =
+  typeof OntologyVertexOneOfKindEnum[keyof typeof OntologyVertexOneOfKindEnum];
 
 /**
- * AccountApi - axios parameter creator
+ *
  * @export
+ * @interface OntologyVertexOneOf1
  */
-export const AccountApiAxiosParamCreator = function (
-  configuration?: Configuration,
-) {
-  return {
-    /**
-     *
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    createAccountId: async (
-      options: AxiosRequestConfig = {},
-    ): Promise<RequestArgs> => {
-      const localVarPath = `/accounts`;
-      // use dummy base URL string because the URL constructor only accepts absolute URLs.
-      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
-      let baseOptions;
-      if (configuration) {
-        baseOptions = configuration.baseOptions;
-      }
-
-      const localVarRequestOptions = {
-        method: "POST",
-        ...baseOptions,
-        ...options,
-      };
-      const localVarHeaderParameter = {} as any;
-      const localVarQueryParameter = {} as any;
+export interface OntologyVertexOneOf1 {
+  /**
+   *
+   * @type {OntologyVertexOneOf1Inner}
+   * @memberof OntologyVertexOneOf1
+   */
+  inner: OntologyVertexOneOf1Inner;
+  /**
+   *
+   * @type {object}
+   * @memberof OntologyVertexOneOf1
+   */
+  kind: OntologyVertexOneOf1KindEnum;
+}
 
-      setSearchParams(localVarUrlObj, localVarQueryParameter);
-      let headersFromBaseOptions =
-        baseOptions && baseOptions.headers ? baseOptions.headers : {};
-      localVarRequestOptions.headers = {
-        ...localVarHeaderParameter,
-        ...headersFromBaseOptions,
-        ...options.headers,
-      };
+export const OntologyVertexOneOf1KindEnum = {
+  PropertyType: "propertyType",
+} as const;
 
-      return {
-        url: toPathString(localVarUrlObj),
-        options: localVarRequestOptions,
-      };
-    },
-  };
-};
+export type OntologyVertexOneOf1KindEnum =
+  typeof OntologyVertexOneOf1KindEnum[keyof typeof OntologyVertexOneOf1KindEnum];
 
 /**
- * AccountApi - functional programming interface
+ *
  * @export
+ * @interface OntologyVertexOneOf1Inner
  */
-export const AccountApiFp = function (configuration?: Configuration) {
-  const localVarAxiosParamCreator = AccountApiAxiosParamCreator(configuration);
-  return {
-    /**
-     *
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    async createAccountId(
-      options?: AxiosRequestConfig,
-    ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<string>
-    > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.createAccountId(
-        options,
-      );
-      return createRequestFunction(
-        localVarAxiosArgs,
-        globalAxios,
-        BASE_PATH,
-        configuration,
-      );
-    },
-  };
-};
-
+export interface OntologyVertexOneOf1Inner {
+  /**
+   *
+   * @type {OntologyElementMetadata}
+   * @memberof OntologyVertexOneOf1Inner
+   */
+  metadata: OntologyElementMetadata;
+  /**
+   *
+   * @type {PropertyType}
+   * @memberof OntologyVertexOneOf1Inner
+   */
+  schema: PropertyType;
+}
 /**
- * AccountApi - factory interface
+ *
  * @export
+ * @interface OntologyVertexOneOf2
  */
-export const AccountApiFactory = function (
-  configuration?: Configuration,
-  basePath?: string,
-  axios?: AxiosInstance,
-) {
-  const localVarFp = AccountApiFp(configuration);
-  return {
-    /**
-     *
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    createAccountId(options?: any): AxiosPromise<string> {
-      return localVarFp
-        .createAccountId(options)
-        .then((request) => request(axios, basePath));
-    },
-  };
-};
+export interface OntologyVertexOneOf2 {
+  /**
+   *
+   * @type {OntologyVertexOneOf2Inner}
+   * @memberof OntologyVertexOneOf2
+   */
+  inner: OntologyVertexOneOf2Inner;
+  /**
+   *
+   * @type {object}
+   * @memberof OntologyVertexOneOf2
+   */
+  kind: OntologyVertexOneOf2KindEnum;
+}
+
+export const OntologyVertexOneOf2KindEnum = {
+  EntityType: "entityType",
+} as const;
+
+export type OntologyVertexOneOf2KindEnum =
+  typeof OntologyVertexOneOf2KindEnum[keyof typeof OntologyVertexOneOf2KindEnum];
 
 /**
- * AccountApi - interface
+ *
  * @export
- * @interface AccountApi
+ * @interface OntologyVertexOneOf2Inner
  */
-export interface AccountApiInterface {
+export interface OntologyVertexOneOf2Inner {
   /**
    *
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof AccountApiInterface
+   * @type {OntologyElementMetadata}
+   * @memberof OntologyVertexOneOf2Inner
    */
-  createAccountId(options?: AxiosRequestConfig): AxiosPromise<string>;
+  metadata: OntologyElementMetadata;
+  /**
+   *
+   * @type {EntityType}
+   * @memberof OntologyVertexOneOf2Inner
+   */
+  schema: EntityType;
 }
-
 /**
- * AccountApi - object-oriented interface
+ *
  * @export
- * @class AccountApi
- * @extends {BaseAPI}
+ * @interface OntologyVertexOneOfInner
  */
-export class AccountApi extends BaseAPI implements AccountApiInterface {
+export interface OntologyVertexOneOfInner {
   /**
    *
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof AccountApi
+   * @type {OntologyElementMetadata}
+   * @memberof OntologyVertexOneOfInner
    */
-  public createAccountId(options?: AxiosRequestConfig) {
-    return AccountApiFp(this.configuration)
-      .createAccountId(options)
-      .then((request) => request(this.axios, this.basePath));
-  }
+  metadata: OntologyElementMetadata;
+  /**
+   *
+   * @type {DataType}
+   * @memberof OntologyVertexOneOfInner
+   */
+  schema: DataType;
 }
-
 /**
- * DataTypeApi - axios parameter creator
+ *
  * @export
+ * @interface OntologyVertices
  */
-export const DataTypeApiAxiosParamCreator = function (
-  configuration?: Configuration,
-) {
-  return {
-    /**
-     *
-     * @param {CreateDataTypeRequest} createDataTypeRequest
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    createDataType: async (
-      createDataTypeRequest: CreateDataTypeRequest,
-      options: AxiosRequestConfig = {},
-    ): Promise<RequestArgs> => {
-      // verify required parameter 'createDataTypeRequest' is not null or undefined
-      assertParamExists(
-        "createDataType",
-        "createDataTypeRequest",
-        createDataTypeRequest,
-      );
-      const localVarPath = `/data-types`;
-      // use dummy base URL string because the URL constructor only accepts absolute URLs.
-      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
-      let baseOptions;
-      if (configuration) {
-        baseOptions = configuration.baseOptions;
-      }
-
-      const localVarRequestOptions = {
-        method: "POST",
-        ...baseOptions,
-        ...options,
-      };
-      const localVarHeaderParameter = {} as any;
-      const localVarQueryParameter = {} as any;
-
-      localVarHeaderParameter["Content-Type"] = "application/json";
-
-      setSearchParams(localVarUrlObj, localVarQueryParameter);
-      let headersFromBaseOptions =
-        baseOptions && baseOptions.headers ? baseOptions.headers : {};
-      localVarRequestOptions.headers = {
-        ...localVarHeaderParameter,
-        ...headersFromBaseOptions,
-        ...options.headers,
-      };
-      localVarRequestOptions.data = serializeDataIfNeeded(
-        createDataTypeRequest,
-        localVarRequestOptions,
-        configuration,
-      );
-
-      return {
-        url: toPathString(localVarUrlObj),
-        options: localVarRequestOptions,
-      };
-    },
-    /**
-     *
-     * @param {string} uri The URI of the data type
-     * @param {*} [options] Override http request option.
-     * @deprecated
-     * @throws {RequiredError}
-     */
-    getDataType: async (
-      uri: string,
-      options: AxiosRequestConfig = {},
-    ): Promise<RequestArgs> => {
-      // verify required parameter 'uri' is not null or undefined
-      assertParamExists("getDataType", "uri", uri);
-      const localVarPath = `/data-types/{uri}`.replace(
+export interface OntologyVertices {
+  [key: string]: { [key: string]: OntologyVertex };
+}
+/**
+ * Restricts a read to a single page of records.  Records are returned in the order of a stable sort key, which depends on the kind of record. To continue reading after a page, the [`Cursor`] returned with the page is passed as `after`.
+ * @export
+ * @interface Pagination
+ */
+export interface Pagination {
+  /**
+   *
+   * @type {string}
+   * @memberof Pagination
+   */
+  after?: string;
+  /**
+   * The maximum number of records to return, between 1 and 1000.
+   * @type {number}
+   * @memberof Pagination
+   */
+  limit: number;
+}
+/**
+ *
+ * @export
+ * @interface ParameterExpression
+ */
+export interface ParameterExpression {
+  /**
+   *
+   * @type {boolean | number | string}
+   * @memberof ParameterExpression
+   */
+  parameter: boolean | number | string;
+}
+/**
+ *
+ * @export
+ * @interface PathExpression
+ */
+export interface PathExpression {
+  /**
+   *
+   * @type {Array<DataTypeQueryToken | PropertyTypeQueryToken | EntityTypeQueryToken | EntityQueryToken | Selector>}
+   * @memberof PathExpression
+   */
+  path: Array<
+    | DataTypeQueryToken
+    | PropertyTypeQueryToken
+    | EntityTypeQueryToken
+    | EntityQueryToken
+    | Selector
+  >;
+}
+/**
+ * An error response following [RFC 7807].  The problem `type` is omitted, so it defaults to `about:blank` and the `title` is the reason phrase of the status code. The kind of the problem is identified by its `code` instead.  [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+ * @export
+ * @interface Problem
+ */
+export interface Problem {
+  /**
+   * Additional information attached to the errors, starting with the outermost error.
+   * @type {Array<string>}
+   * @memberof Problem
+   */
+  attachments: Array<string>;
+  /**
+   *
+   * @type {ErrorCode}
+   * @memberof Problem
+   */
+  code: ErrorCode;
+  /**
+   * The chain of errors, which caused the problem, starting with the outermost error.  Only errors defined by the graph are listed.
+   * @type {Array<string>}
+   * @memberof Problem
+   */
+  contexts: Array<string>;
+  /**
+   * A human-readable explanation of this occurrence of the problem.
+   * @type {string}
+   * @memberof Problem
+   */
+  detail?: string;
+  /**
+   * The HTTP status code of the response.
+   * @type {number}
+   * @memberof Problem
+   */
+  status: number;
+  /**
+   * The reason phrase of the status code.
+   * @type {string}
+   * @memberof Problem
+   */
+  title: string;
+}
+/**
+ *
+ * @export
+ * @interface PropertyArrayValue
+ */
+export interface PropertyArrayValue {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyArrayValue
+   */
+  type: PropertyArrayValueTypeEnum;
+  /**
+   *
+   * @type {PropertyArrayValueItems}
+   * @memberof PropertyArrayValue
+   */
+  items: PropertyArrayValueItems;
+  /**
+   *
+   * @type {number}
+   * @memberof PropertyArrayValue
+   */
+  minItems?: number;
+  /**
+   *
+   * @type {number}
+   * @memberof PropertyArrayValue
+   */
+  maxItems?: number;
+}
+
+export const PropertyArrayValueTypeEnum = {
+  Array: "array",
+} as const;
+
+export type PropertyArrayValueTypeEnum =
+  typeof PropertyArrayValueTypeEnum[keyof typeof PropertyArrayValueTypeEnum];
+
+/**
+ *
+ * @export
+ * @interface PropertyArrayValueItems
+ */
+export interface PropertyArrayValueItems {
+  /**
+   *
+   * @type {Array<PropertyValues>}
+   * @memberof PropertyArrayValueItems
+   */
+  oneOf: Array<PropertyValues>;
+}
+/**
+ *
+ * @export
+ * @interface PropertyArrayValueUpdate
+ */
+export interface PropertyArrayValueUpdate {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyArrayValueUpdate
+   */
+  type: PropertyArrayValueUpdateTypeEnum;
+  /**
+   *
+   * @type {PropertyArrayValueUpdateItems}
+   * @memberof PropertyArrayValueUpdate
+   */
+  items: PropertyArrayValueUpdateItems;
+  /**
+   *
+   * @type {number}
+   * @memberof PropertyArrayValueUpdate
+   */
+  minItems?: number;
+  /**
+   *
+   * @type {number}
+   * @memberof PropertyArrayValueUpdate
+   */
+  maxItems?: number;
+}
+
+export const PropertyArrayValueUpdateTypeEnum = {
+  Array: "array",
+} as const;
+
+export type PropertyArrayValueUpdateTypeEnum =
+  typeof PropertyArrayValueUpdateTypeEnum[keyof typeof PropertyArrayValueUpdateTypeEnum];
+
+/**
+ *
+ * @export
+ * @interface PropertyArrayValueUpdateItems
+ */
+export interface PropertyArrayValueUpdateItems {
+  /**
+   *
+   * @type {Array<PropertyValuesUpdate>}
+   * @memberof PropertyArrayValueUpdateItems
+   */
+  oneOf: Array<PropertyValuesUpdate>;
+}
+/**
+ * @type PropertyMapping
+ * A change applied to the properties of every entity of an [`EntityMigration`].
+ * @export
+ */
+export type PropertyMapping =
+  | PropertyMappingOneOf
+  | PropertyMappingOneOf1
+  | PropertyMappingOneOf2;
+
+/**
+ *
+ * @export
+ * @interface PropertyMappingOneOf
+ */
+export interface PropertyMappingOneOf {
+  /**
+   *
+   * @type {PropertyMappingOneOfRename}
+   * @memberof PropertyMappingOneOf
+   */
+  rename: PropertyMappingOneOfRename;
+}
+/**
+ *
+ * @export
+ * @interface PropertyMappingOneOf1
+ */
+export interface PropertyMappingOneOf1 {
+  /**
+   *
+   * @type {PropertyMappingOneOf1Drop}
+   * @memberof PropertyMappingOneOf1
+   */
+  drop: PropertyMappingOneOf1Drop;
+}
+/**
+ * Removes the property.
+ * @export
+ * @interface PropertyMappingOneOf1Drop
+ */
+export interface PropertyMappingOneOf1Drop {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyMappingOneOf1Drop
+   */
+  property: string;
+}
+/**
+ *
+ * @export
+ * @interface PropertyMappingOneOf2
+ */
+export interface PropertyMappingOneOf2 {
+  /**
+   *
+   * @type {PropertyMappingOneOf2Default}
+   * @memberof PropertyMappingOneOf2
+   */
+  default: PropertyMappingOneOf2Default;
+}
+/**
+ * Sets the property to `value` if the entity does not have a value for it yet.
+ * @export
+ * @interface PropertyMappingOneOf2Default
+ */
+export interface PropertyMappingOneOf2Default {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyMappingOneOf2Default
+   */
+  property: string;
+  /**
+   *
+   * @type {object}
+   * @memberof PropertyMappingOneOf2Default
+   */
+  value: object;
+}
+/**
+ * Moves the value of the property `from` to the property `to`.  An existing value of `to` is overwritten. Entities without the property `from` are not changed.
+ * @export
+ * @interface PropertyMappingOneOfRename
+ */
+export interface PropertyMappingOneOfRename {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyMappingOneOfRename
+   */
+  from: string;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyMappingOneOfRename
+   */
+  to: string;
+}
+/**
+ *
+ * @export
+ * @interface PropertyObjectValue
+ */
+export interface PropertyObjectValue {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyObjectValue
+   */
+  type: PropertyObjectValueTypeEnum;
+  /**
+   *
+   * @type {object}
+   * @memberof PropertyObjectValue
+   */
+  properties: object;
+}
+
+export const PropertyObjectValueTypeEnum = {
+  Object: "object",
+} as const;
+
+export type PropertyObjectValueTypeEnum =
+  typeof PropertyObjectValueTypeEnum[keyof typeof PropertyObjectValueTypeEnum];
+
+/**
+ * @type PropertyPatch
+ * A single operation of a [JSON Patch], which changes the properties of an entity.  The `path` is a JSON Pointer into the properties, so the base URI of a property is its first segment, e.g. `/https:~1~1example.com~1property-type~1name~1`.  [JSON Patch]: https://www.rfc-editor.org/rfc/rfc6902
+ * @export
+ */
+export type PropertyPatch =
+  | PropertyPatchOneOf
+  | PropertyPatchOneOf1
+  | PropertyPatchOneOf2;
+
+/**
+ *
+ * @export
+ * @interface PropertyPatchOneOf
+ */
+export interface PropertyPatchOneOf {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyPatchOneOf
+   */
+  op: PropertyPatchOneOfOpEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyPatchOneOf
+   */
+  path: string;
+  /**
+   *
+   * @type {object}
+   * @memberof PropertyPatchOneOf
+   */
+  value: object;
+}
+
+export const PropertyPatchOneOfOpEnum = {
+  Add: "add",
+} as const;
+
+export type PropertyPatchOneOfOpEnum =
+  typeof PropertyPatchOneOfOpEnum[keyof typeof PropertyPatchOneOfOpEnum];
+
+/**
+ *
+ * @export
+ * @interface PropertyPatchOneOf1
+ */
+export interface PropertyPatchOneOf1 {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyPatchOneOf1
+   */
+  op: PropertyPatchOneOf1OpEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyPatchOneOf1
+   */
+  path: string;
+}
+
+export const PropertyPatchOneOf1OpEnum = {
+  Remove: "remove",
+} as const;
+
+export type PropertyPatchOneOf1OpEnum =
+  typeof PropertyPatchOneOf1OpEnum[keyof typeof PropertyPatchOneOf1OpEnum];
+
+/**
+ *
+ * @export
+ * @interface PropertyPatchOneOf2
+ */
+export interface PropertyPatchOneOf2 {
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyPatchOneOf2
+   */
+  op: PropertyPatchOneOf2OpEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyPatchOneOf2
+   */
+  path: string;
+  /**
+   *
+   * @type {object}
+   * @memberof PropertyPatchOneOf2
+   */
+  value: object;
+}
+
+export const PropertyPatchOneOf2OpEnum = {
+  Replace: "replace",
+} as const;
+
+export type PropertyPatchOneOf2OpEnum =
+  typeof PropertyPatchOneOf2OpEnum[keyof typeof PropertyPatchOneOf2OpEnum];
+
+/**
+ * Specifies the structure of a Property Type
+ * @export
+ * @interface PropertyType
+ */
+export interface PropertyType {
+  /**
+   *
+   * @type {object}
+   * @memberof PropertyType
+   */
+  kind: PropertyTypeKindEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyType
+   */
+  $id: string;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyType
+   */
+  title: string;
+  /**
+   *
+   * @type {string}
+   * @memberof PropertyType
+   */
+  description?: string;
+  /**
+   *
+   * @type {Array<PropertyValues>}
+   * @memberof PropertyType
+   */
+  oneOf: Array<PropertyValues>;
+}
+
+export const PropertyTypeKindEnum = {
+  PropertyType: "propertyType",
+} as const;
+
+export type PropertyTypeKindEnum =
+  typeof PropertyTypeKindEnum[keyof typeof PropertyTypeKindEnum];
+
+/**
+ * A single token in a [`DataTypeQueryPath`].
+ * @export
+ * @enum {string}
+ */
+
+export const PropertyTypeQueryToken = {
+  BaseUri: "baseUri",
+  Version: "version",
+  VersionedUri: "versionedUri",
+  OwnedById: "ownedById",
+  UpdatedById: "updatedById",
+  Title: "title",
+  Description: "description",
+  DataTypes: "dataTypes",
+  PropertyTypes: "propertyTypes",
+} as const;
+
+export type PropertyTypeQueryToken =
+  typeof PropertyTypeQueryToken[keyof typeof PropertyTypeQueryToken];
+
+/**
+ * Structural queries are the main entry point to read data from the Graph.  They are used to query the graph for a set of vertices and edges that match a set of filters. Alongside the filters, the query can specify the depth of the query, which determines how many edges the query will follow from the root vertices. The root vertices are determined by the filters. For example, if the query is for all entities of a certain type, the root vertices will be the entities of that type.  # Filters  [`Filter`]s are used to specify which root vertices to include in the query. They consist of a variety of different types of filters, which are described in the [`Filter`] documentation. At the leaf level, filters are composed of [`RecordPath`]s and [`Parameter`]s, which identify the root vertices to include in the query.  Each [`RecordPath`] is a sequence of tokens, which are used to traverse the graph. For example, a `StructuralQuery<Entity>` with the path `[\"type\", \"version\"]` will traverse the graph from an entity to its type to the version. When associating the above path with a [`Parameter`] with the value `1` in an equality filter, the query will return all entities whose type has version `1` as a root vertex.  Depending on the type of the [`StructuralQuery`], different [`RecordPath`]s are valid. Please see the documentation on the implementation of [`QueryRecord::Path`] for the valid paths for each type.  # Depth  The depth of a query determines how many edges the query will follow from the root vertices. For an in-depth explanation of the depth of a query, please see the documentation on [`GraphResolveDepths`].  # Pagination  Optionally, the root vertices can be split into pages by specifying a [`Pagination`]. The roots are then ordered by a stable sort key and at most `limit` roots are returned. If more roots are available, the returned [`Subgraph`] contains a `nextCursor`, which can be passed as `after` to read the next page:  ```json { \"limit\": 100, \"after\": \"68747470733a2f2f6578616d706c652e636f6d2f\" } ```  # Temporal axes  Entities are versioned in time. By default, the query is resolved with the decision time pinned to now. To query the graph as it was at a point in time, the [`TemporalAxes`] can be pinned. The following will return the graph as it was recorded at the given transaction time, while the decision time defaults to now:  ```json { \"transactionTime\": \"2022-12-01T12:00:00Z\" } ```  The root vertices as well as all vertices reached while following edges are resolved in the same temporal slice. Ontology types are not versioned in time and are not affected.  # Examples  Typically, a structural will be deserialized from a JSON request. The following examples assume, that the type of the request body is `StructuralQuery<Entity>`.  This will return all entities with the latest version of the `foo` type:  ```json { \"filter\": { \"all\": [ { \"equal\": [ { \"path\": [\"type\", \"baseUri\"] }, { \"parameter\": \"foo\" } ] }, { \"equal\": [ { \"path\": [\"type\", \"version\"] }, { \"parameter\": \"latest\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } ```  This query will return any entity, which was either created by or is owned by the account `12345678-90ab-cdef-1234-567890abcdef`:  ```json { \"filter\": { \"any\": [ { \"equal\": [ { \"path\": [\"updatedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] }, { \"equal\": [ { \"path\": [\"ownedById\"] }, { \"parameter\": \"12345678-90ab-cdef-1234-567890abcdef\" } ] } ] }, \"graphResolveDepths\": { \"inheritsFrom\": { \"outgoing\": 0 }, \"constrainsValuesOn\": { \"outgoing\": 0 }, \"constrainsPropertiesOn\": { \"outgoing\": 0 }, \"constrainsLinksOn\": { \"outgoing\": 0 }, \"constrainsLinkDestinationsOn\": { \"outgoing\": 0 }, \"isOfType\": { \"outgoing\": 0 }, \"hasLeftEntity\": { \"incoming\": 2, \"outgoing\": 2 }, \"hasRightEntity\": { \"incoming\": 2, \"outgoing\": 2 } } } ```  [`RecordPath`]: crate::store::query::RecordPath [`Parameter`]: crate::store::query::Parameter [`Subgraph`]: crate::subgraph::Subgraph
+ * @export
+ * @interface PropertyTypeStructuralQuery
+ */
+export interface PropertyTypeStructuralQuery {
+  /**
+   *
+   * @type {Filter}
+   * @memberof PropertyTypeStructuralQuery
+   */
+  filter: Filter;
+  /**
+   *
+   * @type {GraphResolveDepths}
+   * @memberof PropertyTypeStructuralQuery
+   */
+  graphResolveDepths: GraphResolveDepths;
+  /**
+   *
+   * @type {Pagination}
+   * @memberof PropertyTypeStructuralQuery
+   */
+  pagination?: Pagination;
+  /**
+   *
+   * @type {TemporalAxes}
+   * @memberof PropertyTypeStructuralQuery
+   */
+  temporalAxes?: TemporalAxes;
+}
+/**
+ *
+ * @export
+ * @interface PropertyTypeWithMetadata
+ */
+export interface PropertyTypeWithMetadata {
+  /**
+   *
+   * @type {OntologyElementMetadata}
+   * @memberof PropertyTypeWithMetadata
+   */
+  metadata: OntologyElementMetadata;
+  /**
+   *
+   * @type {PropertyType}
+   * @memberof PropertyTypeWithMetadata
+   */
+  schema: PropertyType;
+}
+/**
+ * @type PropertyValues
+ * @export
+ */
+export type PropertyValues =
+  | DataTypeReference
+  | PropertyArrayValue
+  | PropertyObjectValue;
+
+/**
+ * @type PropertyValuesUpdate
+ * @export
+ */
+export type PropertyValuesUpdate =
+  | DataTypeReferenceUpdate
+  | PropertyArrayValueUpdate
+  | PropertyObjectValue;
+
+/**
+ *
+ * @export
+ * @interface ProvenanceMetadata
+ */
+export interface ProvenanceMetadata {
+  /**
+   *
+   * @type {string}
+   * @memberof ProvenanceMetadata
+   */
+  updatedById: string;
+}
+/**
+ *
+ * @export
+ * @interface PurgeEntityRequest
+ */
+export interface PurgeEntityRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof PurgeEntityRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof PurgeEntityRequest
+   */
+  entityId: string;
+  /**
+   *
+   * @type {LinkPurgeBehavior}
+   * @memberof PurgeEntityRequest
+   */
+  linkBehavior?: LinkPurgeBehavior;
+}
+/**
+ *
+ * @export
+ * @interface PurgeEntityResponse
+ */
+export interface PurgeEntityResponse {
+  /**
+   *
+   * @type {Array<string>}
+   * @memberof PurgeEntityResponse
+   */
+  purgedEntityIds: Array<string>;
+}
+/**
+ *
+ * @export
+ * @interface QueryPath
+ */
+export interface QueryPath extends Array<QueryPathInner> {}
+/**
+ * @type QueryPathInner
+ * @export
+ */
+export type QueryPathInner =
+  | DataTypeQueryToken
+  | EntityQueryToken
+  | EntityTypeQueryToken
+  | PropertyTypeQueryToken
+  | Selector;
+
+/**
+ *
+ * @export
+ * @interface RestoreEntityEditionRequest
+ */
+export interface RestoreEntityEditionRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof RestoreEntityEditionRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof RestoreEntityEditionRequest
+   */
+  entityId: string;
+  /**
+   *
+   * @type {number}
+   * @memberof RestoreEntityEditionRequest
+   */
+  recordId: number;
+}
+/**
+ * A single change between two versions of an ontology type.
+ * @export
+ * @interface SchemaChange
+ */
+export interface SchemaChange {
+  /**
+   *
+   * @type {string}
+   * @memberof SchemaChange
+   */
+  description: string;
+  /**
+   *
+   * @type {CompatibilityLevel}
+   * @memberof SchemaChange
+   */
+  level: CompatibilityLevel;
+  /**
+   * The JSON pointer to the changed part of the schema.
+   * @type {string}
+   * @memberof SchemaChange
+   */
+  pointer: string;
+}
+/**
+ *
+ * @export
+ * @interface SearchFilter
+ */
+export interface SearchFilter {
+  /**
+   *
+   * @type {string}
+   * @memberof SearchFilter
+   */
+  search: string;
+}
+/**
+ *
+ * @export
+ * @interface SearchRank
+ */
+export interface SearchRank {
+  /**
+   *
+   * @type {GraphElementEditionId}
+   * @memberof SearchRank
+   */
+  editionId: GraphElementEditionId;
+  /**
+   *
+   * @type {number}
+   * @memberof SearchRank
+   */
+  rank: number;
+}
+/**
+ *
+ * @export
+ * @enum {string}
+ */
+
+export const Selector = {
+  Star: "*",
+} as const;
+
+export type Selector = typeof Selector[keyof typeof Selector];
+
+/**
+ *
+ * @export
+ * @enum {string}
+ */
+
+export const SharedEdgeKind = {
+  IsOfType: "IS_OF_TYPE",
+} as const;
+
+export type SharedEdgeKind = typeof SharedEdgeKind[keyof typeof SharedEdgeKind];
+
+/**
+ *
+ * @export
+ * @interface StartsWithFilter
+ */
+export interface StartsWithFilter {
+  /**
+   *
+   * @type {Array<FilterExpression>}
+   * @memberof StartsWithFilter
+   */
+  startsWith: Array<FilterExpression>;
+}
+/**
+ *
+ * @export
+ * @interface Subgraph
+ */
+export interface Subgraph {
+  /**
+   *
+   * @type {GraphResolveDepths}
+   * @memberof Subgraph
+   */
+  depths: GraphResolveDepths;
+  /**
+   *
+   * @type {Edges}
+   * @memberof Subgraph
+   */
+  edges: Edges;
+  /**
+   *
+   * @type {string}
+   * @memberof Subgraph
+   */
+  nextCursor?: string;
+  /**
+   *
+   * @type {Array<GraphElementEditionId>}
+   * @memberof Subgraph
+   */
+  roots: Array<GraphElementEditionId>;
+  /**
+   * The full-text search relevance of the roots, ordered from the most to the least relevant.
+   * @type {Array<SearchRank>}
+   * @memberof Subgraph
+   */
+  searchRanks: Array<SearchRank>;
+  /**
+   *
+   * @type {Vertices}
+   * @memberof Subgraph
+   */
+  vertices: Vertices;
+}
+/**
+ *
+ * @export
+ * @interface SumAggregate
+ */
+export interface SumAggregate {
+  /**
+   *
+   * @type {QueryPath}
+   * @memberof SumAggregate
+   */
+  sum: QueryPath;
+}
+/**
+ *
+ * @export
+ * @interface TemporalAxes
+ */
+export interface TemporalAxes {
+  /**
+   *
+   * @type {string}
+   * @memberof TemporalAxes
+   */
+  decisionTime?: string;
+  /**
+   *
+   * @type {string}
+   * @memberof TemporalAxes
+   */
+  transactionTime?: string;
+}
+/**
+ * The contents of a Data Type update request
+ * @export
+ * @interface UpdateDataType
+ */
+export interface UpdateDataType {
+  [key: string]: any;
+
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateDataType
+   */
+  kind: UpdateDataTypeKindEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateDataType
+   */
+  title: string;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateDataType
+   */
+  description?: string;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateDataType
+   */
+  type: string;
+}
+
+export const UpdateDataTypeKindEnum = {
+  DataType: "dataType",
+} as const;
+
+export type UpdateDataTypeKindEnum =
+  typeof UpdateDataTypeKindEnum[keyof typeof UpdateDataTypeKindEnum];
+
+/**
+ *
+ * @export
+ * @interface UpdateDataTypeRequest
+ */
+export interface UpdateDataTypeRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateDataTypeRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {CompatibilityPolicy}
+   * @memberof UpdateDataTypeRequest
+   */
+  compatibilityPolicy?: CompatibilityPolicy;
+  /**
+   *
+   * @type {UpdateDataType}
+   * @memberof UpdateDataTypeRequest
+   */
+  schema: UpdateDataType;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateDataTypeRequest
+   */
+  typeToUpdate: string;
+}
+/**
+ *
+ * @export
+ * @interface UpdateEntityRequest
+ */
+export interface UpdateEntityRequest {
+  /**
+   *
+   * @type {number}
+   * @memberof UpdateEntityRequest
+   */
+  leftToRightOrder?: number;
+  /**
+   *
+   * @type {number}
+   * @memberof UpdateEntityRequest
+   */
+  rightToLeftOrder?: number;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {boolean}
+   * @memberof UpdateEntityRequest
+   */
+  archived?: boolean;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityRequest
+   */
+  entityId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityRequest
+   */
+  entityTypeId: string;
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityRequest
+   */
+  properties: object;
+}
+/**
+ *
+ * @export
+ * @interface UpdateEntityRequestAllOf
+ */
+export interface UpdateEntityRequestAllOf {
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityRequestAllOf
+   */
+  actorId: string;
+  /**
+   *
+   * @type {boolean}
+   * @memberof UpdateEntityRequestAllOf
+   */
+  archived?: boolean;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityRequestAllOf
+   */
+  entityId: string;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityRequestAllOf
+   */
+  entityTypeId: string;
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityRequestAllOf
+   */
+  properties: object;
+}
+/**
+ * The contents of an Entity Type update request
+ * @export
+ * @interface UpdateEntityType
+ */
+export interface UpdateEntityType {
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityType
+   */
+  kind: UpdateEntityTypeKindEnum;
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityType
+   */
+  type: UpdateEntityTypeTypeEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityType
+   */
+  title: string;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityType
+   */
+  description?: string;
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityType
+   */
+  default?: object;
+  /**
+   *
+   * @type {Array<object>}
+   * @memberof UpdateEntityType
+   */
+  examples?: Array<object>;
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityType
+   */
+  properties: object;
+  /**
+   *
+   * @type {Array<string>}
+   * @memberof UpdateEntityType
+   */
+  required?: Array<string>;
+  /**
+   *
+   * @type {object}
+   * @memberof UpdateEntityType
+   */
+  links?: object;
+  /**
+   *
+   * @type {Array<string>}
+   * @memberof UpdateEntityType
+   */
+  requiredLinks?: Array<string>;
+}
+
+export const UpdateEntityTypeKindEnum = {
+  EntityType: "entityType",
+} as const;
+
+export type UpdateEntityTypeKindEnum =
+  typeof UpdateEntityTypeKindEnum[keyof typeof UpdateEntityTypeKindEnum];
+export const UpdateEntityTypeTypeEnum = {
+  Object: "object",
+} as const;
+
+export type UpdateEntityTypeTypeEnum =
+  typeof UpdateEntityTypeTypeEnum[keyof typeof UpdateEntityTypeTypeEnum];
+
+/**
+ *
+ * @export
+ * @interface UpdateEntityTypeRequest
+ */
+export interface UpdateEntityTypeRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityTypeRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {CompatibilityPolicy}
+   * @memberof UpdateEntityTypeRequest
+   */
+  compatibilityPolicy?: CompatibilityPolicy;
+  /**
+   *
+   * @type {UpdateEntityType}
+   * @memberof UpdateEntityTypeRequest
+   */
+  schema: UpdateEntityType;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdateEntityTypeRequest
+   */
+  typeToUpdate: string;
+}
+/**
+ * The contents of a Property Type update request
+ * @export
+ * @interface UpdatePropertyType
+ */
+export interface UpdatePropertyType {
+  /**
+   *
+   * @type {object}
+   * @memberof UpdatePropertyType
+   */
+  kind: UpdatePropertyTypeKindEnum;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdatePropertyType
+   */
+  title: string;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdatePropertyType
+   */
+  description?: string;
+  /**
+   *
+   * @type {Array<PropertyValuesUpdate>}
+   * @memberof UpdatePropertyType
+   */
+  oneOf: Array<PropertyValuesUpdate>;
+}
+
+export const UpdatePropertyTypeKindEnum = {
+  PropertyType: "propertyType",
+} as const;
+
+export type UpdatePropertyTypeKindEnum =
+  typeof UpdatePropertyTypeKindEnum[keyof typeof UpdatePropertyTypeKindEnum];
+
+/**
+ *
+ * @export
+ * @interface UpdatePropertyTypeRequest
+ */
+export interface UpdatePropertyTypeRequest {
+  /**
+   *
+   * @type {string}
+   * @memberof UpdatePropertyTypeRequest
+   */
+  actorId: string;
+  /**
+   *
+   * @type {CompatibilityPolicy}
+   * @memberof UpdatePropertyTypeRequest
+   */
+  compatibilityPolicy?: CompatibilityPolicy;
+  /**
+   *
+   * @type {UpdatePropertyType}
+   * @memberof UpdatePropertyTypeRequest
+   */
+  schema: UpdatePropertyType;
+  /**
+   *
+   * @type {string}
+   * @memberof UpdatePropertyTypeRequest
+   */
+  typeToUpdate: string;
+}
+/**
+ * @type Vertex
+ * @export
+ */
+export type Vertex = KnowledgeGraphVertex | OntologyVertex;
+
+/**
+ *
+ * @export
+ * @interface Vertices
+ */
+export interface Vertices {
+  [key: string]: { [key: string]: VerticesValueValue };
+}
+/**
+ * @type VerticesValueValue
+ * @export
+ */
+export type VerticesValueValue = KnowledgeGraphVertex | OntologyVertex;
+
+/**
+ * AccountApi - axios parameter creator
+ * @export
+ */
+export const AccountApiAxiosParamCreator = function (
+  configuration?: Configuration,
+) {
+  return {
+    /**
+     *
+     * @param {string} accountId The ID of the account to add the member to
+     * @param {AddAccountMemberRequest} addAccountMemberRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    addAccountMember: async (
+      accountId: string,
+      addAccountMemberRequest: AddAccountMemberRequest,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'accountId' is not null or undefined
+      assertParamExists("addAccountMember", "accountId", accountId);
+      // verify required parameter 'addAccountMemberRequest' is not null or undefined
+      assertParamExists(
+        "addAccountMember",
+        "addAccountMemberRequest",
+        addAccountMemberRequest,
+      );
+      const localVarPath = `/accounts/{accountId}/members`.replace(
+        `{${"accountId"}}`,
+        encodeURIComponent(String(accountId)),
+      );
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        addAccountMemberRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    createAccountId: async (
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      const localVarPath = `/accounts`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+  };
+};
+
+/**
+ * AccountApi - functional programming interface
+ * @export
+ */
+export const AccountApiFp = function (configuration?: Configuration) {
+  const localVarAxiosParamCreator = AccountApiAxiosParamCreator(configuration);
+  return {
+    /**
+     *
+     * @param {string} accountId The ID of the account to add the member to
+     * @param {AddAccountMemberRequest} addAccountMemberRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async addAccountMember(
+      accountId: string,
+      addAccountMemberRequest: AddAccountMemberRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.addAccountMember(
+          accountId,
+          addAccountMemberRequest,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async createAccountId(
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<string>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.createAccountId(
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+  };
+};
+
+/**
+ * AccountApi - factory interface
+ * @export
+ */
+export const AccountApiFactory = function (
+  configuration?: Configuration,
+  basePath?: string,
+  axios?: AxiosInstance,
+) {
+  const localVarFp = AccountApiFp(configuration);
+  return {
+    /**
+     *
+     * @param {string} accountId The ID of the account to add the member to
+     * @param {AddAccountMemberRequest} addAccountMemberRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    addAccountMember(
+      accountId: string,
+      addAccountMemberRequest: AddAccountMemberRequest,
+      options?: any,
+    ): AxiosPromise<void> {
+      return localVarFp
+        .addAccountMember(accountId, addAccountMemberRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    createAccountId(options?: any): AxiosPromise<string> {
+      return localVarFp
+        .createAccountId(options)
+        .then((request) => request(axios, basePath));
+    },
+  };
+};
+
+/**
+ * AccountApi - interface
+ * @export
+ * @interface AccountApi
+ */
+export interface AccountApiInterface {
+  /**
+   *
+   * @param {string} accountId The ID of the account to add the member to
+   * @param {AddAccountMemberRequest} addAccountMemberRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof AccountApiInterface
+   */
+  addAccountMember(
+    accountId: string,
+    addAccountMemberRequest: AddAccountMemberRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<void>;
+
+  /**
+   *
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof AccountApiInterface
+   */
+  createAccountId(options?: AxiosRequestConfig): AxiosPromise<string>;
+}
+
+/**
+ * AccountApi - object-oriented interface
+ * @export
+ * @class AccountApi
+ * @extends {BaseAPI}
+ */
+export class AccountApi extends BaseAPI implements AccountApiInterface {
+  /**
+   *
+   * @param {string} accountId The ID of the account to add the member to
+   * @param {AddAccountMemberRequest} addAccountMemberRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof AccountApi
+   */
+  public addAccountMember(
+    accountId: string,
+    addAccountMemberRequest: AddAccountMemberRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return AccountApiFp(this.configuration)
+      .addAccountMember(accountId, addAccountMemberRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof AccountApi
+   */
+  public createAccountId(options?: AxiosRequestConfig) {
+    return AccountApiFp(this.configuration)
+      .createAccountId(options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+}
+
+/**
+ * BatchApi - axios parameter creator
+ * @export
+ */
+export const BatchApiAxiosParamCreator = function (
+  configuration?: Configuration,
+) {
+  return {
+    /**
+     *
+     * @param {Array<BatchOperationRequest>} batchOperationRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    applyBatch: async (
+      batchOperationRequest: Array<BatchOperationRequest>,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'batchOperationRequest' is not null or undefined
+      assertParamExists(
+        "applyBatch",
+        "batchOperationRequest",
+        batchOperationRequest,
+      );
+      const localVarPath = `/batch`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        batchOperationRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+  };
+};
+
+/**
+ * BatchApi - functional programming interface
+ * @export
+ */
+export const BatchApiFp = function (configuration?: Configuration) {
+  const localVarAxiosParamCreator = BatchApiAxiosParamCreator(configuration);
+  return {
+    /**
+     *
+     * @param {Array<BatchOperationRequest>} batchOperationRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async applyBatch(
+      batchOperationRequest: Array<BatchOperationRequest>,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<BatchOperationResult>>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.applyBatch(
+        batchOperationRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+  };
+};
+
+/**
+ * BatchApi - factory interface
+ * @export
+ */
+export const BatchApiFactory = function (
+  configuration?: Configuration,
+  basePath?: string,
+  axios?: AxiosInstance,
+) {
+  const localVarFp = BatchApiFp(configuration);
+  return {
+    /**
+     *
+     * @param {Array<BatchOperationRequest>} batchOperationRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    applyBatch(
+      batchOperationRequest: Array<BatchOperationRequest>,
+      options?: any,
+    ): AxiosPromise<Array<BatchOperationResult>> {
+      return localVarFp
+        .applyBatch(batchOperationRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+  };
+};
+
+/**
+ * BatchApi - interface
+ * @export
+ * @interface BatchApi
+ */
+export interface BatchApiInterface {
+  /**
+   *
+   * @param {Array<BatchOperationRequest>} batchOperationRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof BatchApiInterface
+   */
+  applyBatch(
+    batchOperationRequest: Array<BatchOperationRequest>,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<BatchOperationResult>>;
+}
+
+/**
+ * BatchApi - object-oriented interface
+ * @export
+ * @class BatchApi
+ * @extends {BaseAPI}
+ */
+export class BatchApi extends BaseAPI implements BatchApiInterface {
+  /**
+   *
+   * @param {Array<BatchOperationRequest>} batchOperationRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof BatchApi
+   */
+  public applyBatch(
+    batchOperationRequest: Array<BatchOperationRequest>,
+    options?: AxiosRequestConfig,
+  ) {
+    return BatchApiFp(this.configuration)
+      .applyBatch(batchOperationRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+}
+
+/**
+ * ChangeFeedApi - axios parameter creator
+ * @export
+ */
+export const ChangeFeedApiAxiosParamCreator = function (
+  configuration?: Configuration,
+) {
+  return {
+    /**
+     *
+     * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+     * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+     * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getChanges: async (
+      after?: number,
+      limit?: number,
+      timeout?: number,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      const localVarPath = `/changes`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      if (after !== undefined) {
+        localVarQueryParameter["after"] = after;
+      }
+
+      if (limit !== undefined) {
+        localVarQueryParameter["limit"] = limit;
+      }
+
+      if (timeout !== undefined) {
+        localVarQueryParameter["timeout"] = timeout;
+      }
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+  };
+};
+
+/**
+ * ChangeFeedApi - functional programming interface
+ * @export
+ */
+export const ChangeFeedApiFp = function (configuration?: Configuration) {
+  const localVarAxiosParamCreator =
+    ChangeFeedApiAxiosParamCreator(configuration);
+  return {
+    /**
+     *
+     * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+     * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+     * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async getChanges(
+      after?: number,
+      limit?: number,
+      timeout?: number,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<ChangeFeedResponse>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.getChanges(
+        after,
+        limit,
+        timeout,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+  };
+};
+
+/**
+ * ChangeFeedApi - factory interface
+ * @export
+ */
+export const ChangeFeedApiFactory = function (
+  configuration?: Configuration,
+  basePath?: string,
+  axios?: AxiosInstance,
+) {
+  const localVarFp = ChangeFeedApiFp(configuration);
+  return {
+    /**
+     *
+     * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+     * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+     * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getChanges(
+      after?: number,
+      limit?: number,
+      timeout?: number,
+      options?: any,
+    ): AxiosPromise<ChangeFeedResponse> {
+      return localVarFp
+        .getChanges(after, limit, timeout, options)
+        .then((request) => request(axios, basePath));
+    },
+  };
+};
+
+/**
+ * ChangeFeedApi - interface
+ * @export
+ * @interface ChangeFeedApi
+ */
+export interface ChangeFeedApiInterface {
+  /**
+   *
+   * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+   * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+   * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof ChangeFeedApiInterface
+   */
+  getChanges(
+    after?: number,
+    limit?: number,
+    timeout?: number,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<ChangeFeedResponse>;
+}
+
+/**
+ * ChangeFeedApi - object-oriented interface
+ * @export
+ * @class ChangeFeedApi
+ * @extends {BaseAPI}
+ */
+export class ChangeFeedApi extends BaseAPI implements ChangeFeedApiInterface {
+  /**
+   *
+   * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+   * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+   * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof ChangeFeedApi
+   */
+  public getChanges(
+    after?: number,
+    limit?: number,
+    timeout?: number,
+    options?: AxiosRequestConfig,
+  ) {
+    return ChangeFeedApiFp(this.configuration)
+      .getChanges(after, limit, timeout, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+}
+
+/**
+ * DataTypeApi - axios parameter creator
+ * @export
+ */
+export const DataTypeApiAxiosParamCreator = function (
+  configuration?: Configuration,
+) {
+  return {
+    /**
+     *
+     * @param {CreateDataTypeRequest} createDataTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    createDataType: async (
+      createDataTypeRequest: CreateDataTypeRequest,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'createDataTypeRequest' is not null or undefined
+      assertParamExists(
+        "createDataType",
+        "createDataTypeRequest",
+        createDataTypeRequest,
+      );
+      const localVarPath = `/data-types`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        createDataTypeRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {string} uri The URI of the data type
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getDataType: async (
+      uri: string,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'uri' is not null or undefined
+      assertParamExists("getDataType", "uri", uri);
+      const localVarPath = `/data-types/{uri}`.replace(
         `{${"uri"}}`,
         encodeURIComponent(String(uri)),
       );
@@ -2339,10 +4317,334 @@ export const DataTypeApiAxiosParamCreator = function (
         ...options.headers,
       };
 
-      return {
-        url: toPathString(localVarUrlObj),
-        options: localVarRequestOptions,
-      };
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getDataTypesByQuery: async (
+      dataTypeStructuralQuery: DataTypeStructuralQuery,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'dataTypeStructuralQuery' is not null or undefined
+      assertParamExists(
+        "getDataTypesByQuery",
+        "dataTypeStructuralQuery",
+        dataTypeStructuralQuery,
+      );
+      const localVarPath = `/data-types/query`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        dataTypeStructuralQuery,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getLatestDataTypes: async (
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      const localVarPath = `/data-types`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {UpdateDataTypeRequest} updateDataTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    updateDataType: async (
+      updateDataTypeRequest: UpdateDataTypeRequest,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'updateDataTypeRequest' is not null or undefined
+      assertParamExists(
+        "updateDataType",
+        "updateDataTypeRequest",
+        updateDataTypeRequest,
+      );
+      const localVarPath = `/data-types`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "PUT",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        updateDataTypeRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+  };
+};
+
+/**
+ * DataTypeApi - functional programming interface
+ * @export
+ */
+export const DataTypeApiFp = function (configuration?: Configuration) {
+  const localVarAxiosParamCreator = DataTypeApiAxiosParamCreator(configuration);
+  return {
+    /**
+     *
+     * @param {CreateDataTypeRequest} createDataTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async createDataType(
+      createDataTypeRequest: CreateDataTypeRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<OntologyElementMetadata>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.createDataType(
+        createDataTypeRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {string} uri The URI of the data type
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    async getDataType(
+      uri: string,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<DataTypeWithMetadata>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.getDataType(
+        uri,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async getDataTypesByQuery(
+      dataTypeStructuralQuery: DataTypeStructuralQuery,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Subgraph>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.getDataTypesByQuery(
+          dataTypeStructuralQuery,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    async getLatestDataTypes(
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<DataTypeWithMetadata>>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.getLatestDataTypes(options);
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {UpdateDataTypeRequest} updateDataTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async updateDataType(
+      updateDataTypeRequest: UpdateDataTypeRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<OntologyUpdateMetadata>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.updateDataType(
+        updateDataTypeRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+  };
+};
+
+/**
+ * DataTypeApi - factory interface
+ * @export
+ */
+export const DataTypeApiFactory = function (
+  configuration?: Configuration,
+  basePath?: string,
+  axios?: AxiosInstance,
+) {
+  const localVarFp = DataTypeApiFp(configuration);
+  return {
+    /**
+     *
+     * @param {CreateDataTypeRequest} createDataTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    createDataType(
+      createDataTypeRequest: CreateDataTypeRequest,
+      options?: any,
+    ): AxiosPromise<OntologyElementMetadata> {
+      return localVarFp
+        .createDataType(createDataTypeRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {string} uri The URI of the data type
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getDataType(
+      uri: string,
+      options?: any,
+    ): AxiosPromise<DataTypeWithMetadata> {
+      return localVarFp
+        .getDataType(uri, options)
+        .then((request) => request(axios, basePath));
     },
     /**
      *
@@ -2350,17 +4652,219 @@ export const DataTypeApiAxiosParamCreator = function (
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    getDataTypesByQuery: async (
+    getDataTypesByQuery(
       dataTypeStructuralQuery: DataTypeStructuralQuery,
+      options?: any,
+    ): AxiosPromise<Subgraph> {
+      return localVarFp
+        .getDataTypesByQuery(dataTypeStructuralQuery, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getLatestDataTypes(
+      options?: any,
+    ): AxiosPromise<Array<DataTypeWithMetadata>> {
+      return localVarFp
+        .getLatestDataTypes(options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {UpdateDataTypeRequest} updateDataTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    updateDataType(
+      updateDataTypeRequest: UpdateDataTypeRequest,
+      options?: any,
+    ): AxiosPromise<OntologyUpdateMetadata> {
+      return localVarFp
+        .updateDataType(updateDataTypeRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+  };
+};
+
+/**
+ * DataTypeApi - interface
+ * @export
+ * @interface DataTypeApi
+ */
+export interface DataTypeApiInterface {
+  /**
+   *
+   * @param {CreateDataTypeRequest} createDataTypeRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof DataTypeApiInterface
+   */
+  createDataType(
+    createDataTypeRequest: CreateDataTypeRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<OntologyElementMetadata>;
+
+  /**
+   *
+   * @param {string} uri The URI of the data type
+   * @param {*} [options] Override http request option.
+   * @deprecated
+   * @throws {RequiredError}
+   * @memberof DataTypeApiInterface
+   */
+  getDataType(
+    uri: string,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<DataTypeWithMetadata>;
+
+  /**
+   *
+   * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof DataTypeApiInterface
+   */
+  getDataTypesByQuery(
+    dataTypeStructuralQuery: DataTypeStructuralQuery,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Subgraph>;
+
+  /**
+   *
+   * @param {*} [options] Override http request option.
+   * @deprecated
+   * @throws {RequiredError}
+   * @memberof DataTypeApiInterface
+   */
+  getLatestDataTypes(
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<DataTypeWithMetadata>>;
+
+  /**
+   *
+   * @param {UpdateDataTypeRequest} updateDataTypeRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof DataTypeApiInterface
+   */
+  updateDataType(
+    updateDataTypeRequest: UpdateDataTypeRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<OntologyUpdateMetadata>;
+}
+
+/**
+ * DataTypeApi - object-oriented interface
+ * @export
+ * @class DataTypeApi
+ * @extends {BaseAPI}
+ */
+export class DataTypeApi extends BaseAPI implements DataTypeApiInterface {
+  /**
+   *
+   * @param {CreateDataTypeRequest} createDataTypeRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof DataTypeApi
+   */
+  public createDataType(
+    createDataTypeRequest: CreateDataTypeRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return DataTypeApiFp(this.configuration)
+      .createDataType(createDataTypeRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {string} uri The URI of the data type
+   * @param {*} [options] Override http request option.
+   * @deprecated
+   * @throws {RequiredError}
+   * @memberof DataTypeApi
+   */
+  public getDataType(uri: string, options?: AxiosRequestConfig) {
+    return DataTypeApiFp(this.configuration)
+      .getDataType(uri, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof DataTypeApi
+   */
+  public getDataTypesByQuery(
+    dataTypeStructuralQuery: DataTypeStructuralQuery,
+    options?: AxiosRequestConfig,
+  ) {
+    return DataTypeApiFp(this.configuration)
+      .getDataTypesByQuery(dataTypeStructuralQuery, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {*} [options] Override http request option.
+   * @deprecated
+   * @throws {RequiredError}
+   * @memberof DataTypeApi
+   */
+  public getLatestDataTypes(options?: AxiosRequestConfig) {
+    return DataTypeApiFp(this.configuration)
+      .getLatestDataTypes(options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {UpdateDataTypeRequest} updateDataTypeRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof DataTypeApi
+   */
+  public updateDataType(
+    updateDataTypeRequest: UpdateDataTypeRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return DataTypeApiFp(this.configuration)
+      .updateDataType(updateDataTypeRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+}
+
+/**
+ * EntityApi - axios parameter creator
+ * @export
+ */
+export const EntityApiAxiosParamCreator = function (
+  configuration?: Configuration,
+) {
+  return {
+    /**
+     *
+     * @param {EntityAggregationQuery} entityAggregationQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    aggregateEntities: async (
+      entityAggregationQuery: EntityAggregationQuery,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'dataTypeStructuralQuery' is not null or undefined
+      // verify required parameter 'entityAggregationQuery' is not null or undefined
       assertParamExists(
-        "getDataTypesByQuery",
-        "dataTypeStructuralQuery",
-        dataTypeStructuralQuery,
+        "aggregateEntities",
+        "entityAggregationQuery",
+        entityAggregationQuery,
       );
-      const localVarPath = `/data-types/query`;
+      const localVarPath = `/entities/aggregate`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -2387,7 +4891,7 @@ export const DataTypeApiAxiosParamCreator = function (
         ...options.headers,
       };
       localVarRequestOptions.data = serializeDataIfNeeded(
-        dataTypeStructuralQuery,
+        entityAggregationQuery,
         localVarRequestOptions,
         configuration,
       );
@@ -2399,14 +4903,22 @@ export const DataTypeApiAxiosParamCreator = function (
     },
     /**
      *
+     * @param {ArchiveEntityRequest} archiveEntityRequest
      * @param {*} [options] Override http request option.
      * @deprecated
      * @throws {RequiredError}
      */
-    getLatestDataTypes: async (
+    archiveEntity: async (
+      archiveEntityRequest: ArchiveEntityRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      const localVarPath = `/data-types`;
+      // verify required parameter 'archiveEntityRequest' is not null or undefined
+      assertParamExists(
+        "archiveEntity",
+        "archiveEntityRequest",
+        archiveEntityRequest,
+      );
+      const localVarPath = `/entities/archive`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -2415,13 +4927,15 @@ export const DataTypeApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "GET",
+        method: "POST",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -2430,6 +4944,11 @@ export const DataTypeApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        archiveEntityRequest,
+        localVarRequestOptions,
+        configuration,
+      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -2438,21 +4957,21 @@ export const DataTypeApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {UpdateDataTypeRequest} updateDataTypeRequest
+     * @param {CreateEntityRequest} createEntityRequest
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    updateDataType: async (
-      updateDataTypeRequest: UpdateDataTypeRequest,
+    createEntity: async (
+      createEntityRequest: CreateEntityRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'updateDataTypeRequest' is not null or undefined
+      // verify required parameter 'createEntityRequest' is not null or undefined
       assertParamExists(
-        "updateDataType",
-        "updateDataTypeRequest",
-        updateDataTypeRequest,
+        "createEntity",
+        "createEntityRequest",
+        createEntityRequest,
       );
-      const localVarPath = `/data-types`;
+      const localVarPath = `/entities`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -2461,7 +4980,7 @@ export const DataTypeApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "PUT",
+        method: "POST",
         ...baseOptions,
         ...options,
       };
@@ -2479,415 +4998,192 @@ export const DataTypeApiAxiosParamCreator = function (
         ...options.headers,
       };
       localVarRequestOptions.data = serializeDataIfNeeded(
-        updateDataTypeRequest,
-        localVarRequestOptions,
-        configuration,
-      );
-
-      return {
-        url: toPathString(localVarUrlObj),
-        options: localVarRequestOptions,
-      };
-    },
-  };
-};
-
-/**
- * DataTypeApi - functional programming interface
- * @export
- */
-export const DataTypeApiFp = function (configuration?: Configuration) {
-  const localVarAxiosParamCreator = DataTypeApiAxiosParamCreator(configuration);
-  return {
-    /**
-     *
-     * @param {CreateDataTypeRequest} createDataTypeRequest
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    async createDataType(
-      createDataTypeRequest: CreateDataTypeRequest,
-      options?: AxiosRequestConfig,
-    ): Promise<
-      (
-        axios?: AxiosInstance,
-        basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
-    > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.createDataType(
-        createDataTypeRequest,
-        options,
-      );
-      return createRequestFunction(
-        localVarAxiosArgs,
-        globalAxios,
-        BASE_PATH,
-        configuration,
-      );
-    },
-    /**
-     *
-     * @param {string} uri The URI of the data type
-     * @param {*} [options] Override http request option.
-     * @deprecated
-     * @throws {RequiredError}
-     */
-    async getDataType(
-      uri: string,
-      options?: AxiosRequestConfig,
-    ): Promise<
-      (
-        axios?: AxiosInstance,
-        basePath?: string,
-      ) => AxiosPromise<DataTypeWithMetadata>
-    > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.getDataType(
-        uri,
-        options,
-      );
-      return createRequestFunction(
-        localVarAxiosArgs,
-        globalAxios,
-        BASE_PATH,
-        configuration,
-      );
-    },
-    /**
-     *
-     * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    async getDataTypesByQuery(
-      dataTypeStructuralQuery: DataTypeStructuralQuery,
-      options?: AxiosRequestConfig,
-    ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Subgraph>
-    > {
-      const localVarAxiosArgs =
-        await localVarAxiosParamCreator.getDataTypesByQuery(
-          dataTypeStructuralQuery,
-          options,
-        );
-      return createRequestFunction(
-        localVarAxiosArgs,
-        globalAxios,
-        BASE_PATH,
-        configuration,
-      );
-    },
-    /**
-     *
-     * @param {*} [options] Override http request option.
-     * @deprecated
-     * @throws {RequiredError}
-     */
-    async getLatestDataTypes(
-      options?: AxiosRequestConfig,
-    ): Promise<
-      (
-        axios?: AxiosInstance,
-        basePath?: string,
-      ) => AxiosPromise<Array<DataTypeWithMetadata>>
-    > {
-      const localVarAxiosArgs =
-        await localVarAxiosParamCreator.getLatestDataTypes(options);
-      return createRequestFunction(
-        localVarAxiosArgs,
-        globalAxios,
-        BASE_PATH,
-        configuration,
-      );
-    },
-    /**
-     *
-     * @param {UpdateDataTypeRequest} updateDataTypeRequest
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    async updateDataType(
-      updateDataTypeRequest: UpdateDataTypeRequest,
-      options?: AxiosRequestConfig,
-    ): Promise<
-      (
-        axios?: AxiosInstance,
-        basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
-    > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.updateDataType(
-        updateDataTypeRequest,
-        options,
-      );
-      return createRequestFunction(
-        localVarAxiosArgs,
-        globalAxios,
-        BASE_PATH,
-        configuration,
-      );
-    },
-  };
-};
-
-/**
- * DataTypeApi - factory interface
- * @export
- */
-export const DataTypeApiFactory = function (
-  configuration?: Configuration,
-  basePath?: string,
-  axios?: AxiosInstance,
-) {
-  const localVarFp = DataTypeApiFp(configuration);
-  return {
-    /**
-     *
-     * @param {CreateDataTypeRequest} createDataTypeRequest
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    createDataType(
-      createDataTypeRequest: CreateDataTypeRequest,
-      options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
-      return localVarFp
-        .createDataType(createDataTypeRequest, options)
-        .then((request) => request(axios, basePath));
-    },
-    /**
-     *
-     * @param {string} uri The URI of the data type
-     * @param {*} [options] Override http request option.
-     * @deprecated
-     * @throws {RequiredError}
-     */
-    getDataType(
-      uri: string,
-      options?: any,
-    ): AxiosPromise<DataTypeWithMetadata> {
-      return localVarFp
-        .getDataType(uri, options)
-        .then((request) => request(axios, basePath));
-    },
-    /**
-     *
-     * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
-     * @param {*} [options] Override http request option.
-     * @throws {RequiredError}
-     */
-    getDataTypesByQuery(
-      dataTypeStructuralQuery: DataTypeStructuralQuery,
-      options?: any,
-    ): AxiosPromise<Subgraph> {
-      return localVarFp
-        .getDataTypesByQuery(dataTypeStructuralQuery, options)
-        .then((request) => request(axios, basePath));
-    },
-    /**
-     *
-     * @param {*} [options] Override http request option.
-     * @deprecated
-     * @throws {RequiredError}
-     */
-    getLatestDataTypes(
-      options?: any,
-    ): AxiosPromise<Array<DataTypeWithMetadata>> {
-      return localVarFp
-        .getLatestDataTypes(options)
-        .then((request) => request(axios, basePath));
+        createEntityRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
     },
     /**
      *
-     * @param {UpdateDataTypeRequest} updateDataTypeRequest
+     * @param {string} entityId The EntityId
+     * @param {number} from The record ID of the edition to compare from
+     * @param {number} to The record ID of the edition to compare to
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    updateDataType(
-      updateDataTypeRequest: UpdateDataTypeRequest,
-      options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
-      return localVarFp
-        .updateDataType(updateDataTypeRequest, options)
-        .then((request) => request(axios, basePath));
-    },
-  };
-};
+    diffEntityEditions: async (
+      entityId: string,
+      from: number,
+      to: number,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'entityId' is not null or undefined
+      assertParamExists("diffEntityEditions", "entityId", entityId);
+      // verify required parameter 'from' is not null or undefined
+      assertParamExists("diffEntityEditions", "from", from);
+      // verify required parameter 'to' is not null or undefined
+      assertParamExists("diffEntityEditions", "to", to);
+      const localVarPath = `/entities/{entityId}/diff`.replace(
+        `{${"entityId"}}`,
+        encodeURIComponent(String(entityId)),
+      );
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
 
-/**
- * DataTypeApi - interface
- * @export
- * @interface DataTypeApi
- */
-export interface DataTypeApiInterface {
-  /**
-   *
-   * @param {CreateDataTypeRequest} createDataTypeRequest
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof DataTypeApiInterface
-   */
-  createDataType(
-    createDataTypeRequest: CreateDataTypeRequest,
-    options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
 
-  /**
-   *
-   * @param {string} uri The URI of the data type
-   * @param {*} [options] Override http request option.
-   * @deprecated
-   * @throws {RequiredError}
-   * @memberof DataTypeApiInterface
-   */
-  getDataType(
-    uri: string,
-    options?: AxiosRequestConfig,
-  ): AxiosPromise<DataTypeWithMetadata>;
+      if (from !== undefined) {
+        localVarQueryParameter["from"] = from;
+      }
 
-  /**
-   *
-   * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof DataTypeApiInterface
-   */
-  getDataTypesByQuery(
-    dataTypeStructuralQuery: DataTypeStructuralQuery,
-    options?: AxiosRequestConfig,
-  ): AxiosPromise<Subgraph>;
+      if (to !== undefined) {
+        localVarQueryParameter["to"] = to;
+      }
 
-  /**
-   *
-   * @param {*} [options] Override http request option.
-   * @deprecated
-   * @throws {RequiredError}
-   * @memberof DataTypeApiInterface
-   */
-  getLatestDataTypes(
-    options?: AxiosRequestConfig,
-  ): AxiosPromise<Array<DataTypeWithMetadata>>;
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
 
-  /**
-   *
-   * @param {UpdateDataTypeRequest} updateDataTypeRequest
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof DataTypeApiInterface
-   */
-  updateDataType(
-    updateDataTypeRequest: UpdateDataTypeRequest,
-    options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
-}
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {EntityStructuralQuery} entityStructuralQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getEntitiesByQuery: async (
+      entityStructuralQuery: EntityStructuralQuery,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'entityStructuralQuery' is not null or undefined
+      assertParamExists(
+        "getEntitiesByQuery",
+        "entityStructuralQuery",
+        entityStructuralQuery,
+      );
+      const localVarPath = `/entities/query`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
 
-/**
- * DataTypeApi - object-oriented interface
- * @export
- * @class DataTypeApi
- * @extends {BaseAPI}
- */
-export class DataTypeApi extends BaseAPI implements DataTypeApiInterface {
-  /**
-   *
-   * @param {CreateDataTypeRequest} createDataTypeRequest
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof DataTypeApi
-   */
-  public createDataType(
-    createDataTypeRequest: CreateDataTypeRequest,
-    options?: AxiosRequestConfig,
-  ) {
-    return DataTypeApiFp(this.configuration)
-      .createDataType(createDataTypeRequest, options)
-      .then((request) => request(this.axios, this.basePath));
-  }
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
 
-  /**
-   *
-   * @param {string} uri The URI of the data type
-   * @param {*} [options] Override http request option.
-   * @deprecated
-   * @throws {RequiredError}
-   * @memberof DataTypeApi
-   */
-  public getDataType(uri: string, options?: AxiosRequestConfig) {
-    return DataTypeApiFp(this.configuration)
-      .getDataType(uri, options)
-      .then((request) => request(this.axios, this.basePath));
-  }
+      localVarHeaderParameter["Content-Type"] = "application/json";
 
-  /**
-   *
-   * @param {DataTypeStructuralQuery} dataTypeStructuralQuery
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof DataTypeApi
-   */
-  public getDataTypesByQuery(
-    dataTypeStructuralQuery: DataTypeStructuralQuery,
-    options?: AxiosRequestConfig,
-  ) {
-    return DataTypeApiFp(this.configuration)
-      .getDataTypesByQuery(dataTypeStructuralQuery, options)
-      .then((request) => request(this.axios, this.basePath));
-  }
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        entityStructuralQuery,
+        localVarRequestOptions,
+        configuration,
+      );
 
-  /**
-   *
-   * @param {*} [options] Override http request option.
-   * @deprecated
-   * @throws {RequiredError}
-   * @memberof DataTypeApi
-   */
-  public getLatestDataTypes(options?: AxiosRequestConfig) {
-    return DataTypeApiFp(this.configuration)
-      .getLatestDataTypes(options)
-      .then((request) => request(this.axios, this.basePath));
-  }
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getEntity: async (
+      entityId: string,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'entityId' is not null or undefined
+      assertParamExists("getEntity", "entityId", entityId);
+      const localVarPath = `/entities/{entityId}`.replace(
+        `{${"entityId"}}`,
+        encodeURIComponent(String(entityId)),
+      );
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
 
-  /**
-   *
-   * @param {UpdateDataTypeRequest} updateDataTypeRequest
-   * @param {*} [options] Override http request option.
-   * @throws {RequiredError}
-   * @memberof DataTypeApi
-   */
-  public updateDataType(
-    updateDataTypeRequest: UpdateDataTypeRequest,
-    options?: AxiosRequestConfig,
-  ) {
-    return DataTypeApiFp(this.configuration)
-      .updateDataType(updateDataTypeRequest, options)
-      .then((request) => request(this.axios, this.basePath));
-  }
-}
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
 
-/**
- * EntityApi - axios parameter creator
- * @export
- */
-export const EntityApiAxiosParamCreator = function (
-  configuration?: Configuration,
-) {
-  return {
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
     /**
      *
-     * @param {ArchiveEntityRequest} archiveEntityRequest
+     * @param {string} entityId The EntityId
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    archiveEntity: async (
-      archiveEntityRequest: ArchiveEntityRequest,
+    getEntityHistory: async (
+      entityId: string,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'archiveEntityRequest' is not null or undefined
-      assertParamExists(
-        "archiveEntity",
-        "archiveEntityRequest",
-        archiveEntityRequest,
+      // verify required parameter 'entityId' is not null or undefined
+      assertParamExists("getEntityHistory", "entityId", entityId);
+      const localVarPath = `/entities/{entityId}/history`.replace(
+        `{${"entityId"}}`,
+        encodeURIComponent(String(entityId)),
       );
-      const localVarPath = `/entities/archive`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -2896,15 +5192,13 @@ export const EntityApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "POST",
+        method: "GET",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
-      localVarHeaderParameter["Content-Type"] = "application/json";
-
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -2913,11 +5207,6 @@ export const EntityApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
-      localVarRequestOptions.data = serializeDataIfNeeded(
-        archiveEntityRequest,
-        localVarRequestOptions,
-        configuration,
-      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -2926,20 +5215,13 @@ export const EntityApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {CreateEntityRequest} createEntityRequest
      * @param {*} [options] Override http request option.
+     * @deprecated
      * @throws {RequiredError}
      */
-    createEntity: async (
-      createEntityRequest: CreateEntityRequest,
+    getLatestEntities: async (
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'createEntityRequest' is not null or undefined
-      assertParamExists(
-        "createEntity",
-        "createEntityRequest",
-        createEntityRequest,
-      );
       const localVarPath = `/entities`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
@@ -2949,15 +5231,13 @@ export const EntityApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "POST",
+        method: "GET",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
-      localVarHeaderParameter["Content-Type"] = "application/json";
-
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -2966,11 +5246,6 @@ export const EntityApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
-      localVarRequestOptions.data = serializeDataIfNeeded(
-        createEntityRequest,
-        localVarRequestOptions,
-        configuration,
-      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -2979,21 +5254,21 @@ export const EntityApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {EntityStructuralQuery} entityStructuralQuery
+     * @param {MigrateEntitiesRequest} migrateEntitiesRequest
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    getEntitiesByQuery: async (
-      entityStructuralQuery: EntityStructuralQuery,
+    migrateEntities: async (
+      migrateEntitiesRequest: MigrateEntitiesRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'entityStructuralQuery' is not null or undefined
+      // verify required parameter 'migrateEntitiesRequest' is not null or undefined
       assertParamExists(
-        "getEntitiesByQuery",
-        "entityStructuralQuery",
-        entityStructuralQuery,
+        "migrateEntities",
+        "migrateEntitiesRequest",
+        migrateEntitiesRequest,
       );
-      const localVarPath = `/entities/query`;
+      const localVarPath = `/entities/migrate`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -3020,7 +5295,7 @@ export const EntityApiAxiosParamCreator = function (
         ...options.headers,
       };
       localVarRequestOptions.data = serializeDataIfNeeded(
-        entityStructuralQuery,
+        migrateEntitiesRequest,
         localVarRequestOptions,
         configuration,
       );
@@ -3032,21 +5307,21 @@ export const EntityApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {string} entityId The EntityId
+     * @param {PurgeEntityRequest} purgeEntityRequest
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    getEntity: async (
-      entityId: string,
+    purgeEntity: async (
+      purgeEntityRequest: PurgeEntityRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'entityId' is not null or undefined
-      assertParamExists("getEntity", "entityId", entityId);
-      const localVarPath = `/entities/{entityId}`.replace(
-        `{${"entityId"}}`,
-        encodeURIComponent(String(entityId)),
+      // verify required parameter 'purgeEntityRequest' is not null or undefined
+      assertParamExists(
+        "purgeEntity",
+        "purgeEntityRequest",
+        purgeEntityRequest,
       );
+      const localVarPath = `/entities/purge`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -3055,13 +5330,15 @@ export const EntityApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "GET",
+        method: "POST",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -3070,6 +5347,11 @@ export const EntityApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        purgeEntityRequest,
+        localVarRequestOptions,
+        configuration,
+      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -3078,14 +5360,21 @@ export const EntityApiAxiosParamCreator = function (
     },
     /**
      *
+     * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    getLatestEntities: async (
+    restoreEntityEdition: async (
+      restoreEntityEditionRequest: RestoreEntityEditionRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      const localVarPath = `/entities`;
+      // verify required parameter 'restoreEntityEditionRequest' is not null or undefined
+      assertParamExists(
+        "restoreEntityEdition",
+        "restoreEntityEditionRequest",
+        restoreEntityEditionRequest,
+      );
+      const localVarPath = `/entities/restore`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -3094,13 +5383,15 @@ export const EntityApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "GET",
+        method: "POST",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -3109,6 +5400,11 @@ export const EntityApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        restoreEntityEditionRequest,
+        localVarRequestOptions,
+        configuration,
+      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -3180,21 +5476,173 @@ export const EntityApiFp = function (configuration?: Configuration) {
   return {
     /**
      *
-     * @param {ArchiveEntityRequest} archiveEntityRequest
+     * @param {EntityAggregationQuery} entityAggregationQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async aggregateEntities(
+      entityAggregationQuery: EntityAggregationQuery,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<AggregationGroup>>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.aggregateEntities(
+          entityAggregationQuery,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {ArchiveEntityRequest} archiveEntityRequest
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    async archiveEntity(
+      archiveEntityRequest: ArchiveEntityRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.archiveEntity(
+        archiveEntityRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {CreateEntityRequest} createEntityRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async createEntity(
+      createEntityRequest: CreateEntityRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<EntityMetadata>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.createEntity(
+        createEntityRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {number} from The record ID of the edition to compare from
+     * @param {number} to The record ID of the edition to compare to
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async diffEntityEditions(
+      entityId: string,
+      from: number,
+      to: number,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<PropertyPatch>>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.diffEntityEditions(
+          entityId,
+          from,
+          to,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {EntityStructuralQuery} entityStructuralQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async getEntitiesByQuery(
+      entityStructuralQuery: EntityStructuralQuery,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Subgraph>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.getEntitiesByQuery(
+          entityStructuralQuery,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    async getEntity(
+      entityId: string,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Entity>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.getEntity(
+        entityId,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {string} entityId The EntityId
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    async archiveEntity(
-      archiveEntityRequest: ArchiveEntityRequest,
+    async getEntityHistory(
+      entityId: string,
       options?: AxiosRequestConfig,
     ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<Entity>>
     > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.archiveEntity(
-        archiveEntityRequest,
-        options,
-      );
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.getEntityHistory(entityId, options);
       return createRequestFunction(
         localVarAxiosArgs,
         globalAxios,
@@ -3204,20 +5652,17 @@ export const EntityApiFp = function (configuration?: Configuration) {
     },
     /**
      *
-     * @param {CreateEntityRequest} createEntityRequest
      * @param {*} [options] Override http request option.
+     * @deprecated
      * @throws {RequiredError}
      */
-    async createEntity(
-      createEntityRequest: CreateEntityRequest,
+    async getLatestEntities(
       options?: AxiosRequestConfig,
     ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<EntityMetadata>
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<Entity>>
     > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.createEntity(
-        createEntityRequest,
-        options,
-      );
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.getLatestEntities(options);
       return createRequestFunction(
         localVarAxiosArgs,
         globalAxios,
@@ -3227,21 +5672,23 @@ export const EntityApiFp = function (configuration?: Configuration) {
     },
     /**
      *
-     * @param {EntityStructuralQuery} entityStructuralQuery
+     * @param {MigrateEntitiesRequest} migrateEntitiesRequest
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    async getEntitiesByQuery(
-      entityStructuralQuery: EntityStructuralQuery,
+    async migrateEntities(
+      migrateEntitiesRequest: MigrateEntitiesRequest,
       options?: AxiosRequestConfig,
     ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Subgraph>
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<MigratedEntity>>
     > {
-      const localVarAxiosArgs =
-        await localVarAxiosParamCreator.getEntitiesByQuery(
-          entityStructuralQuery,
-          options,
-        );
+      const localVarAxiosArgs = await localVarAxiosParamCreator.migrateEntities(
+        migrateEntitiesRequest,
+        options,
+      );
       return createRequestFunction(
         localVarAxiosArgs,
         globalAxios,
@@ -3251,19 +5698,21 @@ export const EntityApiFp = function (configuration?: Configuration) {
     },
     /**
      *
-     * @param {string} entityId The EntityId
+     * @param {PurgeEntityRequest} purgeEntityRequest
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    async getEntity(
-      entityId: string,
+    async purgeEntity(
+      purgeEntityRequest: PurgeEntityRequest,
       options?: AxiosRequestConfig,
     ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Entity>
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<PurgeEntityResponse>
     > {
-      const localVarAxiosArgs = await localVarAxiosParamCreator.getEntity(
-        entityId,
+      const localVarAxiosArgs = await localVarAxiosParamCreator.purgeEntity(
+        purgeEntityRequest,
         options,
       );
       return createRequestFunction(
@@ -3275,17 +5724,21 @@ export const EntityApiFp = function (configuration?: Configuration) {
     },
     /**
      *
+     * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    async getLatestEntities(
+    async restoreEntityEdition(
+      restoreEntityEditionRequest: RestoreEntityEditionRequest,
       options?: AxiosRequestConfig,
     ): Promise<
-      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<Entity>>
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<EntityMetadata>
     > {
       const localVarAxiosArgs =
-        await localVarAxiosParamCreator.getLatestEntities(options);
+        await localVarAxiosParamCreator.restoreEntityEdition(
+          restoreEntityEditionRequest,
+          options,
+        );
       return createRequestFunction(
         localVarAxiosArgs,
         globalAxios,
@@ -3330,6 +5783,20 @@ export const EntityApiFactory = function (
 ) {
   const localVarFp = EntityApiFp(configuration);
   return {
+    /**
+     *
+     * @param {EntityAggregationQuery} entityAggregationQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    aggregateEntities(
+      entityAggregationQuery: EntityAggregationQuery,
+      options?: any,
+    ): AxiosPromise<Array<AggregationGroup>> {
+      return localVarFp
+        .aggregateEntities(entityAggregationQuery, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -3359,6 +5826,24 @@ export const EntityApiFactory = function (
         .createEntity(createEntityRequest, options)
         .then((request) => request(axios, basePath));
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {number} from The record ID of the edition to compare from
+     * @param {number} to The record ID of the edition to compare to
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    diffEntityEditions(
+      entityId: string,
+      from: number,
+      to: number,
+      options?: any,
+    ): AxiosPromise<Array<PropertyPatch>> {
+      return localVarFp
+        .diffEntityEditions(entityId, from, to, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {EntityStructuralQuery} entityStructuralQuery
@@ -3385,6 +5870,20 @@ export const EntityApiFactory = function (
         .getEntity(entityId, options)
         .then((request) => request(axios, basePath));
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getEntityHistory(
+      entityId: string,
+      options?: any,
+    ): AxiosPromise<Array<Entity>> {
+      return localVarFp
+        .getEntityHistory(entityId, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {*} [options] Override http request option.
@@ -3396,6 +5895,48 @@ export const EntityApiFactory = function (
         .getLatestEntities(options)
         .then((request) => request(axios, basePath));
     },
+    /**
+     *
+     * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    migrateEntities(
+      migrateEntitiesRequest: MigrateEntitiesRequest,
+      options?: any,
+    ): AxiosPromise<Array<MigratedEntity>> {
+      return localVarFp
+        .migrateEntities(migrateEntitiesRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {PurgeEntityRequest} purgeEntityRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    purgeEntity(
+      purgeEntityRequest: PurgeEntityRequest,
+      options?: any,
+    ): AxiosPromise<PurgeEntityResponse> {
+      return localVarFp
+        .purgeEntity(purgeEntityRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    restoreEntityEdition(
+      restoreEntityEditionRequest: RestoreEntityEditionRequest,
+      options?: any,
+    ): AxiosPromise<EntityMetadata> {
+      return localVarFp
+        .restoreEntityEdition(restoreEntityEditionRequest, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {UpdateEntityRequest} updateEntityRequest
@@ -3419,6 +5960,18 @@ export const EntityApiFactory = function (
  * @interface EntityApi
  */
 export interface EntityApiInterface {
+  /**
+   *
+   * @param {EntityAggregationQuery} entityAggregationQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApiInterface
+   */
+  aggregateEntities(
+    entityAggregationQuery: EntityAggregationQuery,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<AggregationGroup>>;
+
   /**
    *
    * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -3444,6 +5997,22 @@ export interface EntityApiInterface {
     options?: AxiosRequestConfig,
   ): AxiosPromise<EntityMetadata>;
 
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {number} from The record ID of the edition to compare from
+   * @param {number} to The record ID of the edition to compare to
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApiInterface
+   */
+  diffEntityEditions(
+    entityId: string,
+    from: number,
+    to: number,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<PropertyPatch>>;
+
   /**
    *
    * @param {EntityStructuralQuery} entityStructuralQuery
@@ -3469,6 +6038,18 @@ export interface EntityApiInterface {
     options?: AxiosRequestConfig,
   ): AxiosPromise<Entity>;
 
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApiInterface
+   */
+  getEntityHistory(
+    entityId: string,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<Entity>>;
+
   /**
    *
    * @param {*} [options] Override http request option.
@@ -3478,6 +6059,42 @@ export interface EntityApiInterface {
    */
   getLatestEntities(options?: AxiosRequestConfig): AxiosPromise<Array<Entity>>;
 
+  /**
+   *
+   * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApiInterface
+   */
+  migrateEntities(
+    migrateEntitiesRequest: MigrateEntitiesRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<MigratedEntity>>;
+
+  /**
+   *
+   * @param {PurgeEntityRequest} purgeEntityRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApiInterface
+   */
+  purgeEntity(
+    purgeEntityRequest: PurgeEntityRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<PurgeEntityResponse>;
+
+  /**
+   *
+   * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApiInterface
+   */
+  restoreEntityEdition(
+    restoreEntityEditionRequest: RestoreEntityEditionRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<EntityMetadata>;
+
   /**
    *
    * @param {UpdateEntityRequest} updateEntityRequest
@@ -3498,6 +6115,22 @@ export interface EntityApiInterface {
  * @extends {BaseAPI}
  */
 export class EntityApi extends BaseAPI implements EntityApiInterface {
+  /**
+   *
+   * @param {EntityAggregationQuery} entityAggregationQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApi
+   */
+  public aggregateEntities(
+    entityAggregationQuery: EntityAggregationQuery,
+    options?: AxiosRequestConfig,
+  ) {
+    return EntityApiFp(this.configuration)
+      .aggregateEntities(entityAggregationQuery, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
   /**
    *
    * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -3527,50 +6160,131 @@ export class EntityApi extends BaseAPI implements EntityApiInterface {
     options?: AxiosRequestConfig,
   ) {
     return EntityApiFp(this.configuration)
-      .createEntity(createEntityRequest, options)
+      .createEntity(createEntityRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {number} from The record ID of the edition to compare from
+   * @param {number} to The record ID of the edition to compare to
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApi
+   */
+  public diffEntityEditions(
+    entityId: string,
+    from: number,
+    to: number,
+    options?: AxiosRequestConfig,
+  ) {
+    return EntityApiFp(this.configuration)
+      .diffEntityEditions(entityId, from, to, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {EntityStructuralQuery} entityStructuralQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApi
+   */
+  public getEntitiesByQuery(
+    entityStructuralQuery: EntityStructuralQuery,
+    options?: AxiosRequestConfig,
+  ) {
+    return EntityApiFp(this.configuration)
+      .getEntitiesByQuery(entityStructuralQuery, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {*} [options] Override http request option.
+   * @deprecated
+   * @throws {RequiredError}
+   * @memberof EntityApi
+   */
+  public getEntity(entityId: string, options?: AxiosRequestConfig) {
+    return EntityApiFp(this.configuration)
+      .getEntity(entityId, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof EntityApi
+   */
+  public getEntityHistory(entityId: string, options?: AxiosRequestConfig) {
+    return EntityApiFp(this.configuration)
+      .getEntityHistory(entityId, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {*} [options] Override http request option.
+   * @deprecated
+   * @throws {RequiredError}
+   * @memberof EntityApi
+   */
+  public getLatestEntities(options?: AxiosRequestConfig) {
+    return EntityApiFp(this.configuration)
+      .getLatestEntities(options)
       .then((request) => request(this.axios, this.basePath));
   }
 
   /**
    *
-   * @param {EntityStructuralQuery} entityStructuralQuery
+   * @param {MigrateEntitiesRequest} migrateEntitiesRequest
    * @param {*} [options] Override http request option.
    * @throws {RequiredError}
    * @memberof EntityApi
    */
-  public getEntitiesByQuery(
-    entityStructuralQuery: EntityStructuralQuery,
+  public migrateEntities(
+    migrateEntitiesRequest: MigrateEntitiesRequest,
     options?: AxiosRequestConfig,
   ) {
     return EntityApiFp(this.configuration)
-      .getEntitiesByQuery(entityStructuralQuery, options)
+      .migrateEntities(migrateEntitiesRequest, options)
       .then((request) => request(this.axios, this.basePath));
   }
 
   /**
    *
-   * @param {string} entityId The EntityId
+   * @param {PurgeEntityRequest} purgeEntityRequest
    * @param {*} [options] Override http request option.
-   * @deprecated
    * @throws {RequiredError}
    * @memberof EntityApi
    */
-  public getEntity(entityId: string, options?: AxiosRequestConfig) {
+  public purgeEntity(
+    purgeEntityRequest: PurgeEntityRequest,
+    options?: AxiosRequestConfig,
+  ) {
     return EntityApiFp(this.configuration)
-      .getEntity(entityId, options)
+      .purgeEntity(purgeEntityRequest, options)
       .then((request) => request(this.axios, this.basePath));
   }
 
   /**
    *
+   * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
    * @param {*} [options] Override http request option.
-   * @deprecated
    * @throws {RequiredError}
    * @memberof EntityApi
    */
-  public getLatestEntities(options?: AxiosRequestConfig) {
+  public restoreEntityEdition(
+    restoreEntityEditionRequest: RestoreEntityEditionRequest,
+    options?: AxiosRequestConfig,
+  ) {
     return EntityApiFp(this.configuration)
-      .getLatestEntities(options)
+      .restoreEntityEdition(restoreEntityEditionRequest, options)
       .then((request) => request(this.axios, this.basePath));
   }
 
@@ -3968,7 +6682,7 @@ export const EntityTypeApiFp = function (configuration?: Configuration) {
       (
         axios?: AxiosInstance,
         basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
+      ) => AxiosPromise<OntologyUpdateMetadata>
     > {
       const localVarAxiosArgs =
         await localVarAxiosParamCreator.updateEntityType(
@@ -4061,7 +6775,7 @@ export const EntityTypeApiFactory = function (
     updateEntityType(
       updateEntityTypeRequest: UpdateEntityTypeRequest,
       options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
+    ): AxiosPromise<OntologyUpdateMetadata> {
       return localVarFp
         .updateEntityType(updateEntityTypeRequest, options)
         .then((request) => request(axios, basePath));
@@ -4133,7 +6847,7 @@ export interface EntityTypeApiInterface {
   updateEntityType(
     updateEntityTypeRequest: UpdateEntityTypeRequest,
     options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
+  ): AxiosPromise<OntologyUpdateMetadata>;
 }
 
 /**
@@ -4227,6 +6941,172 @@ export const GraphApiAxiosParamCreator = function (
   configuration?: Configuration,
 ) {
   return {
+    /**
+     *
+     * @param {string} accountId The ID of the account to add the member to
+     * @param {AddAccountMemberRequest} addAccountMemberRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    addAccountMember: async (
+      accountId: string,
+      addAccountMemberRequest: AddAccountMemberRequest,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'accountId' is not null or undefined
+      assertParamExists("addAccountMember", "accountId", accountId);
+      // verify required parameter 'addAccountMemberRequest' is not null or undefined
+      assertParamExists(
+        "addAccountMember",
+        "addAccountMemberRequest",
+        addAccountMemberRequest,
+      );
+      const localVarPath = `/accounts/{accountId}/members`.replace(
+        `{${"accountId"}}`,
+        encodeURIComponent(String(accountId)),
+      );
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        addAccountMemberRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {EntityAggregationQuery} entityAggregationQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    aggregateEntities: async (
+      entityAggregationQuery: EntityAggregationQuery,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'entityAggregationQuery' is not null or undefined
+      assertParamExists(
+        "aggregateEntities",
+        "entityAggregationQuery",
+        entityAggregationQuery,
+      );
+      const localVarPath = `/entities/aggregate`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        entityAggregationQuery,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {Array<BatchOperationRequest>} batchOperationRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    applyBatch: async (
+      batchOperationRequest: Array<BatchOperationRequest>,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'batchOperationRequest' is not null or undefined
+      assertParamExists(
+        "applyBatch",
+        "batchOperationRequest",
+        batchOperationRequest,
+      );
+      const localVarPath = `/batch`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        batchOperationRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
     /**
      *
      * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -4329,13 +7209,119 @@ export const GraphApiAxiosParamCreator = function (
       createDataTypeRequest: CreateDataTypeRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'createDataTypeRequest' is not null or undefined
+      // verify required parameter 'createDataTypeRequest' is not null or undefined
+      assertParamExists(
+        "createDataType",
+        "createDataTypeRequest",
+        createDataTypeRequest,
+      );
+      const localVarPath = `/data-types`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        createDataTypeRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {CreateEntityRequest} createEntityRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    createEntity: async (
+      createEntityRequest: CreateEntityRequest,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'createEntityRequest' is not null or undefined
+      assertParamExists(
+        "createEntity",
+        "createEntityRequest",
+        createEntityRequest,
+      );
+      const localVarPath = `/entities`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        createEntityRequest,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {CreateEntityTypeRequest} createEntityTypeRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    createEntityType: async (
+      createEntityTypeRequest: CreateEntityTypeRequest,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'createEntityTypeRequest' is not null or undefined
       assertParamExists(
-        "createDataType",
-        "createDataTypeRequest",
-        createDataTypeRequest,
+        "createEntityType",
+        "createEntityTypeRequest",
+        createEntityTypeRequest,
       );
-      const localVarPath = `/data-types`;
+      const localVarPath = `/entity-types`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -4362,7 +7348,7 @@ export const GraphApiAxiosParamCreator = function (
         ...options.headers,
       };
       localVarRequestOptions.data = serializeDataIfNeeded(
-        createDataTypeRequest,
+        createEntityTypeRequest,
         localVarRequestOptions,
         configuration,
       );
@@ -4374,21 +7360,21 @@ export const GraphApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {CreateEntityRequest} createEntityRequest
+     * @param {CreatePropertyTypeRequest} createPropertyTypeRequest
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    createEntity: async (
-      createEntityRequest: CreateEntityRequest,
+    createPropertyType: async (
+      createPropertyTypeRequest: CreatePropertyTypeRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'createEntityRequest' is not null or undefined
+      // verify required parameter 'createPropertyTypeRequest' is not null or undefined
       assertParamExists(
-        "createEntity",
-        "createEntityRequest",
-        createEntityRequest,
+        "createPropertyType",
+        "createPropertyTypeRequest",
+        createPropertyTypeRequest,
       );
-      const localVarPath = `/entities`;
+      const localVarPath = `/property-types`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -4415,7 +7401,7 @@ export const GraphApiAxiosParamCreator = function (
         ...options.headers,
       };
       localVarRequestOptions.data = serializeDataIfNeeded(
-        createEntityRequest,
+        createPropertyTypeRequest,
         localVarRequestOptions,
         configuration,
       );
@@ -4427,21 +7413,28 @@ export const GraphApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {CreateEntityTypeRequest} createEntityTypeRequest
+     * @param {string} entityId The EntityId
+     * @param {number} from The record ID of the edition to compare from
+     * @param {number} to The record ID of the edition to compare to
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    createEntityType: async (
-      createEntityTypeRequest: CreateEntityTypeRequest,
+    diffEntityEditions: async (
+      entityId: string,
+      from: number,
+      to: number,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'createEntityTypeRequest' is not null or undefined
-      assertParamExists(
-        "createEntityType",
-        "createEntityTypeRequest",
-        createEntityTypeRequest,
+      // verify required parameter 'entityId' is not null or undefined
+      assertParamExists("diffEntityEditions", "entityId", entityId);
+      // verify required parameter 'from' is not null or undefined
+      assertParamExists("diffEntityEditions", "from", from);
+      // verify required parameter 'to' is not null or undefined
+      assertParamExists("diffEntityEditions", "to", to);
+      const localVarPath = `/entities/{entityId}/diff`.replace(
+        `{${"entityId"}}`,
+        encodeURIComponent(String(entityId)),
       );
-      const localVarPath = `/entity-types`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -4450,14 +7443,20 @@ export const GraphApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "POST",
+        method: "GET",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
-      localVarHeaderParameter["Content-Type"] = "application/json";
+      if (from !== undefined) {
+        localVarQueryParameter["from"] = from;
+      }
+
+      if (to !== undefined) {
+        localVarQueryParameter["to"] = to;
+      }
 
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
@@ -4467,11 +7466,6 @@ export const GraphApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
-      localVarRequestOptions.data = serializeDataIfNeeded(
-        createEntityTypeRequest,
-        localVarRequestOptions,
-        configuration,
-      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -4480,21 +7474,19 @@ export const GraphApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {CreatePropertyTypeRequest} createPropertyTypeRequest
+     * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+     * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+     * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    createPropertyType: async (
-      createPropertyTypeRequest: CreatePropertyTypeRequest,
+    getChanges: async (
+      after?: number,
+      limit?: number,
+      timeout?: number,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'createPropertyTypeRequest' is not null or undefined
-      assertParamExists(
-        "createPropertyType",
-        "createPropertyTypeRequest",
-        createPropertyTypeRequest,
-      );
-      const localVarPath = `/property-types`;
+      const localVarPath = `/changes`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -4503,14 +7495,24 @@ export const GraphApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "POST",
+        method: "GET",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
-      localVarHeaderParameter["Content-Type"] = "application/json";
+      if (after !== undefined) {
+        localVarQueryParameter["after"] = after;
+      }
+
+      if (limit !== undefined) {
+        localVarQueryParameter["limit"] = limit;
+      }
+
+      if (timeout !== undefined) {
+        localVarQueryParameter["timeout"] = timeout;
+      }
 
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
@@ -4520,11 +7522,6 @@ export const GraphApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
-      localVarRequestOptions.data = serializeDataIfNeeded(
-        createPropertyTypeRequest,
-        localVarRequestOptions,
-        configuration,
-      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -4729,6 +7726,51 @@ export const GraphApiAxiosParamCreator = function (
         options: localVarRequestOptions,
       };
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getEntityHistory: async (
+      entityId: string,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'entityId' is not null or undefined
+      assertParamExists("getEntityHistory", "entityId", entityId);
+      const localVarPath = `/entities/{entityId}/history`.replace(
+        `{${"entityId"}}`,
+        encodeURIComponent(String(entityId)),
+      );
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
     /**
      *
      * @param {string} uri The URI of the entity type
@@ -4948,13 +7990,158 @@ export const GraphApiAxiosParamCreator = function (
     /**
      *
      * @param {*} [options] Override http request option.
-     * @deprecated
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getLatestPropertyTypes: async (
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      const localVarPath = `/property-types`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {string} uri The URI of the property type
+     * @param {*} [options] Override http request option.
+     * @deprecated
+     * @throws {RequiredError}
+     */
+    getPropertyType: async (
+      uri: string,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'uri' is not null or undefined
+      assertParamExists("getPropertyType", "uri", uri);
+      const localVarPath = `/property-types/{uri}`.replace(
+        `{${"uri"}}`,
+        encodeURIComponent(String(uri)),
+      );
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "GET",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {PropertyTypeStructuralQuery} propertyTypeStructuralQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getPropertyTypesByQuery: async (
+      propertyTypeStructuralQuery: PropertyTypeStructuralQuery,
+      options: AxiosRequestConfig = {},
+    ): Promise<RequestArgs> => {
+      // verify required parameter 'propertyTypeStructuralQuery' is not null or undefined
+      assertParamExists(
+        "getPropertyTypesByQuery",
+        "propertyTypeStructuralQuery",
+        propertyTypeStructuralQuery,
+      );
+      const localVarPath = `/property-types/query`;
+      // use dummy base URL string because the URL constructor only accepts absolute URLs.
+      const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+      let baseOptions;
+      if (configuration) {
+        baseOptions = configuration.baseOptions;
+      }
+
+      const localVarRequestOptions = {
+        method: "POST",
+        ...baseOptions,
+        ...options,
+      };
+      const localVarHeaderParameter = {} as any;
+      const localVarQueryParameter = {} as any;
+
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
+      setSearchParams(localVarUrlObj, localVarQueryParameter);
+      let headersFromBaseOptions =
+        baseOptions && baseOptions.headers ? baseOptions.headers : {};
+      localVarRequestOptions.headers = {
+        ...localVarHeaderParameter,
+        ...headersFromBaseOptions,
+        ...options.headers,
+      };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        propertyTypeStructuralQuery,
+        localVarRequestOptions,
+        configuration,
+      );
+
+      return {
+        url: toPathString(localVarUrlObj),
+        options: localVarRequestOptions,
+      };
+    },
+    /**
+     *
+     * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+     * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    getLatestPropertyTypes: async (
+    migrateEntities: async (
+      migrateEntitiesRequest: MigrateEntitiesRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      const localVarPath = `/property-types`;
+      // verify required parameter 'migrateEntitiesRequest' is not null or undefined
+      assertParamExists(
+        "migrateEntities",
+        "migrateEntitiesRequest",
+        migrateEntitiesRequest,
+      );
+      const localVarPath = `/entities/migrate`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -4963,13 +8150,15 @@ export const GraphApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "GET",
+        method: "POST",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -4978,6 +8167,11 @@ export const GraphApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        migrateEntitiesRequest,
+        localVarRequestOptions,
+        configuration,
+      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -4986,21 +8180,21 @@ export const GraphApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {string} uri The URI of the property type
+     * @param {PurgeEntityRequest} purgeEntityRequest
      * @param {*} [options] Override http request option.
-     * @deprecated
      * @throws {RequiredError}
      */
-    getPropertyType: async (
-      uri: string,
+    purgeEntity: async (
+      purgeEntityRequest: PurgeEntityRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'uri' is not null or undefined
-      assertParamExists("getPropertyType", "uri", uri);
-      const localVarPath = `/property-types/{uri}`.replace(
-        `{${"uri"}}`,
-        encodeURIComponent(String(uri)),
+      // verify required parameter 'purgeEntityRequest' is not null or undefined
+      assertParamExists(
+        "purgeEntity",
+        "purgeEntityRequest",
+        purgeEntityRequest,
       );
+      const localVarPath = `/entities/purge`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -5009,13 +8203,15 @@ export const GraphApiAxiosParamCreator = function (
       }
 
       const localVarRequestOptions = {
-        method: "GET",
+        method: "POST",
         ...baseOptions,
         ...options,
       };
       const localVarHeaderParameter = {} as any;
       const localVarQueryParameter = {} as any;
 
+      localVarHeaderParameter["Content-Type"] = "application/json";
+
       setSearchParams(localVarUrlObj, localVarQueryParameter);
       let headersFromBaseOptions =
         baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -5024,6 +8220,11 @@ export const GraphApiAxiosParamCreator = function (
         ...headersFromBaseOptions,
         ...options.headers,
       };
+      localVarRequestOptions.data = serializeDataIfNeeded(
+        purgeEntityRequest,
+        localVarRequestOptions,
+        configuration,
+      );
 
       return {
         url: toPathString(localVarUrlObj),
@@ -5032,21 +8233,21 @@ export const GraphApiAxiosParamCreator = function (
     },
     /**
      *
-     * @param {PropertyTypeStructuralQuery} propertyTypeStructuralQuery
+     * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
      */
-    getPropertyTypesByQuery: async (
-      propertyTypeStructuralQuery: PropertyTypeStructuralQuery,
+    restoreEntityEdition: async (
+      restoreEntityEditionRequest: RestoreEntityEditionRequest,
       options: AxiosRequestConfig = {},
     ): Promise<RequestArgs> => {
-      // verify required parameter 'propertyTypeStructuralQuery' is not null or undefined
+      // verify required parameter 'restoreEntityEditionRequest' is not null or undefined
       assertParamExists(
-        "getPropertyTypesByQuery",
-        "propertyTypeStructuralQuery",
-        propertyTypeStructuralQuery,
+        "restoreEntityEdition",
+        "restoreEntityEditionRequest",
+        restoreEntityEditionRequest,
       );
-      const localVarPath = `/property-types/query`;
+      const localVarPath = `/entities/restore`;
       // use dummy base URL string because the URL constructor only accepts absolute URLs.
       const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
       let baseOptions;
@@ -5073,7 +8274,7 @@ export const GraphApiAxiosParamCreator = function (
         ...options.headers,
       };
       localVarRequestOptions.data = serializeDataIfNeeded(
-        propertyTypeStructuralQuery,
+        restoreEntityEditionRequest,
         localVarRequestOptions,
         configuration,
       );
@@ -5305,6 +8506,86 @@ export const GraphApiAxiosParamCreator = function (
 export const GraphApiFp = function (configuration?: Configuration) {
   const localVarAxiosParamCreator = GraphApiAxiosParamCreator(configuration);
   return {
+    /**
+     *
+     * @param {string} accountId The ID of the account to add the member to
+     * @param {AddAccountMemberRequest} addAccountMemberRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async addAccountMember(
+      accountId: string,
+      addAccountMemberRequest: AddAccountMemberRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.addAccountMember(
+          accountId,
+          addAccountMemberRequest,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {EntityAggregationQuery} entityAggregationQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async aggregateEntities(
+      entityAggregationQuery: EntityAggregationQuery,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<AggregationGroup>>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.aggregateEntities(
+          entityAggregationQuery,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {Array<BatchOperationRequest>} batchOperationRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async applyBatch(
+      batchOperationRequest: Array<BatchOperationRequest>,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<BatchOperationResult>>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.applyBatch(
+        batchOperationRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
     /**
      *
      * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -5452,6 +8733,71 @@ export const GraphApiFp = function (configuration?: Configuration) {
         configuration,
       );
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {number} from The record ID of the edition to compare from
+     * @param {number} to The record ID of the edition to compare to
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async diffEntityEditions(
+      entityId: string,
+      from: number,
+      to: number,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<PropertyPatch>>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.diffEntityEditions(
+          entityId,
+          from,
+          to,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+     * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+     * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async getChanges(
+      after?: number,
+      limit?: number,
+      timeout?: number,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<ChangeFeedResponse>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.getChanges(
+        after,
+        limit,
+        timeout,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
     /**
      *
      * @param {string} uri The URI of the data type
@@ -5551,6 +8897,27 @@ export const GraphApiFp = function (configuration?: Configuration) {
         configuration,
       );
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async getEntityHistory(
+      entityId: string,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<Entity>>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.getEntityHistory(entityId, options);
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
     /**
      *
      * @param {string} uri The URI of the entity type
@@ -5742,6 +9109,82 @@ export const GraphApiFp = function (configuration?: Configuration) {
         configuration,
       );
     },
+    /**
+     *
+     * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async migrateEntities(
+      migrateEntitiesRequest: MigrateEntitiesRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<Array<MigratedEntity>>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.migrateEntities(
+        migrateEntitiesRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {PurgeEntityRequest} purgeEntityRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async purgeEntity(
+      purgeEntityRequest: PurgeEntityRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (
+        axios?: AxiosInstance,
+        basePath?: string,
+      ) => AxiosPromise<PurgeEntityResponse>
+    > {
+      const localVarAxiosArgs = await localVarAxiosParamCreator.purgeEntity(
+        purgeEntityRequest,
+        options,
+      );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
+    /**
+     *
+     * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    async restoreEntityEdition(
+      restoreEntityEditionRequest: RestoreEntityEditionRequest,
+      options?: AxiosRequestConfig,
+    ): Promise<
+      (axios?: AxiosInstance, basePath?: string) => AxiosPromise<EntityMetadata>
+    > {
+      const localVarAxiosArgs =
+        await localVarAxiosParamCreator.restoreEntityEdition(
+          restoreEntityEditionRequest,
+          options,
+        );
+      return createRequestFunction(
+        localVarAxiosArgs,
+        globalAxios,
+        BASE_PATH,
+        configuration,
+      );
+    },
     /**
      *
      * @param {UpdateDataTypeRequest} updateDataTypeRequest
@@ -5755,7 +9198,7 @@ export const GraphApiFp = function (configuration?: Configuration) {
       (
         axios?: AxiosInstance,
         basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
+      ) => AxiosPromise<OntologyUpdateMetadata>
     > {
       const localVarAxiosArgs = await localVarAxiosParamCreator.updateDataType(
         updateDataTypeRequest,
@@ -5804,7 +9247,7 @@ export const GraphApiFp = function (configuration?: Configuration) {
       (
         axios?: AxiosInstance,
         basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
+      ) => AxiosPromise<OntologyUpdateMetadata>
     > {
       const localVarAxiosArgs =
         await localVarAxiosParamCreator.updateEntityType(
@@ -5831,7 +9274,7 @@ export const GraphApiFp = function (configuration?: Configuration) {
       (
         axios?: AxiosInstance,
         basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
+      ) => AxiosPromise<OntologyUpdateMetadata>
     > {
       const localVarAxiosArgs =
         await localVarAxiosParamCreator.updatePropertyType(
@@ -5859,6 +9302,50 @@ export const GraphApiFactory = function (
 ) {
   const localVarFp = GraphApiFp(configuration);
   return {
+    /**
+     *
+     * @param {string} accountId The ID of the account to add the member to
+     * @param {AddAccountMemberRequest} addAccountMemberRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    addAccountMember(
+      accountId: string,
+      addAccountMemberRequest: AddAccountMemberRequest,
+      options?: any,
+    ): AxiosPromise<void> {
+      return localVarFp
+        .addAccountMember(accountId, addAccountMemberRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {EntityAggregationQuery} entityAggregationQuery
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    aggregateEntities(
+      entityAggregationQuery: EntityAggregationQuery,
+      options?: any,
+    ): AxiosPromise<Array<AggregationGroup>> {
+      return localVarFp
+        .aggregateEntities(entityAggregationQuery, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {Array<BatchOperationRequest>} batchOperationRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    applyBatch(
+      batchOperationRequest: Array<BatchOperationRequest>,
+      options?: any,
+    ): AxiosPromise<Array<BatchOperationResult>> {
+      return localVarFp
+        .applyBatch(batchOperationRequest, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -5940,6 +9427,42 @@ export const GraphApiFactory = function (
         .createPropertyType(createPropertyTypeRequest, options)
         .then((request) => request(axios, basePath));
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {number} from The record ID of the edition to compare from
+     * @param {number} to The record ID of the edition to compare to
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    diffEntityEditions(
+      entityId: string,
+      from: number,
+      to: number,
+      options?: any,
+    ): AxiosPromise<Array<PropertyPatch>> {
+      return localVarFp
+        .diffEntityEditions(entityId, from, to, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+     * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+     * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getChanges(
+      after?: number,
+      limit?: number,
+      timeout?: number,
+      options?: any,
+    ): AxiosPromise<ChangeFeedResponse> {
+      return localVarFp
+        .getChanges(after, limit, timeout, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {string} uri The URI of the data type
@@ -5995,6 +9518,20 @@ export const GraphApiFactory = function (
         .getEntity(entityId, options)
         .then((request) => request(axios, basePath));
     },
+    /**
+     *
+     * @param {string} entityId The EntityId
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    getEntityHistory(
+      entityId: string,
+      options?: any,
+    ): AxiosPromise<Array<Entity>> {
+      return localVarFp
+        .getEntityHistory(entityId, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {string} uri The URI of the entity type
@@ -6103,6 +9640,48 @@ export const GraphApiFactory = function (
         .getPropertyTypesByQuery(propertyTypeStructuralQuery, options)
         .then((request) => request(axios, basePath));
     },
+    /**
+     *
+     * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    migrateEntities(
+      migrateEntitiesRequest: MigrateEntitiesRequest,
+      options?: any,
+    ): AxiosPromise<Array<MigratedEntity>> {
+      return localVarFp
+        .migrateEntities(migrateEntitiesRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {PurgeEntityRequest} purgeEntityRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    purgeEntity(
+      purgeEntityRequest: PurgeEntityRequest,
+      options?: any,
+    ): AxiosPromise<PurgeEntityResponse> {
+      return localVarFp
+        .purgeEntity(purgeEntityRequest, options)
+        .then((request) => request(axios, basePath));
+    },
+    /**
+     *
+     * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     */
+    restoreEntityEdition(
+      restoreEntityEditionRequest: RestoreEntityEditionRequest,
+      options?: any,
+    ): AxiosPromise<EntityMetadata> {
+      return localVarFp
+        .restoreEntityEdition(restoreEntityEditionRequest, options)
+        .then((request) => request(axios, basePath));
+    },
     /**
      *
      * @param {UpdateDataTypeRequest} updateDataTypeRequest
@@ -6112,7 +9691,7 @@ export const GraphApiFactory = function (
     updateDataType(
       updateDataTypeRequest: UpdateDataTypeRequest,
       options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
+    ): AxiosPromise<OntologyUpdateMetadata> {
       return localVarFp
         .updateDataType(updateDataTypeRequest, options)
         .then((request) => request(axios, basePath));
@@ -6140,7 +9719,7 @@ export const GraphApiFactory = function (
     updateEntityType(
       updateEntityTypeRequest: UpdateEntityTypeRequest,
       options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
+    ): AxiosPromise<OntologyUpdateMetadata> {
       return localVarFp
         .updateEntityType(updateEntityTypeRequest, options)
         .then((request) => request(axios, basePath));
@@ -6154,7 +9733,7 @@ export const GraphApiFactory = function (
     updatePropertyType(
       updatePropertyTypeRequest: UpdatePropertyTypeRequest,
       options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
+    ): AxiosPromise<OntologyUpdateMetadata> {
       return localVarFp
         .updatePropertyType(updatePropertyTypeRequest, options)
         .then((request) => request(axios, basePath));
@@ -6168,6 +9747,44 @@ export const GraphApiFactory = function (
  * @interface GraphApi
  */
 export interface GraphApiInterface {
+  /**
+   *
+   * @param {string} accountId The ID of the account to add the member to
+   * @param {AddAccountMemberRequest} addAccountMemberRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  addAccountMember(
+    accountId: string,
+    addAccountMemberRequest: AddAccountMemberRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<void>;
+
+  /**
+   *
+   * @param {EntityAggregationQuery} entityAggregationQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  aggregateEntities(
+    entityAggregationQuery: EntityAggregationQuery,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<AggregationGroup>>;
+
+  /**
+   *
+   * @param {Array<BatchOperationRequest>} batchOperationRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  applyBatch(
+    batchOperationRequest: Array<BatchOperationRequest>,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<BatchOperationResult>>;
+
   /**
    *
    * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -6237,6 +9854,38 @@ export interface GraphApiInterface {
     options?: AxiosRequestConfig,
   ): AxiosPromise<OntologyElementMetadata>;
 
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {number} from The record ID of the edition to compare from
+   * @param {number} to The record ID of the edition to compare to
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  diffEntityEditions(
+    entityId: string,
+    from: number,
+    to: number,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<PropertyPatch>>;
+
+  /**
+   *
+   * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+   * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+   * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  getChanges(
+    after?: number,
+    limit?: number,
+    timeout?: number,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<ChangeFeedResponse>;
+
   /**
    *
    * @param {string} uri The URI of the data type
@@ -6287,6 +9936,18 @@ export interface GraphApiInterface {
     options?: AxiosRequestConfig,
   ): AxiosPromise<Entity>;
 
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  getEntityHistory(
+    entityId: string,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<Entity>>;
+
   /**
    *
    * @param {string} uri The URI of the entity type
@@ -6379,6 +10040,42 @@ export interface GraphApiInterface {
     options?: AxiosRequestConfig,
   ): AxiosPromise<Subgraph>;
 
+  /**
+   *
+   * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  migrateEntities(
+    migrateEntitiesRequest: MigrateEntitiesRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<Array<MigratedEntity>>;
+
+  /**
+   *
+   * @param {PurgeEntityRequest} purgeEntityRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  purgeEntity(
+    purgeEntityRequest: PurgeEntityRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<PurgeEntityResponse>;
+
+  /**
+   *
+   * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApiInterface
+   */
+  restoreEntityEdition(
+    restoreEntityEditionRequest: RestoreEntityEditionRequest,
+    options?: AxiosRequestConfig,
+  ): AxiosPromise<EntityMetadata>;
+
   /**
    *
    * @param {UpdateDataTypeRequest} updateDataTypeRequest
@@ -6389,7 +10086,7 @@ export interface GraphApiInterface {
   updateDataType(
     updateDataTypeRequest: UpdateDataTypeRequest,
     options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
+  ): AxiosPromise<OntologyUpdateMetadata>;
 
   /**
    *
@@ -6413,7 +10110,7 @@ export interface GraphApiInterface {
   updateEntityType(
     updateEntityTypeRequest: UpdateEntityTypeRequest,
     options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
+  ): AxiosPromise<OntologyUpdateMetadata>;
 
   /**
    *
@@ -6425,7 +10122,7 @@ export interface GraphApiInterface {
   updatePropertyType(
     updatePropertyTypeRequest: UpdatePropertyTypeRequest,
     options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
+  ): AxiosPromise<OntologyUpdateMetadata>;
 }
 
 /**
@@ -6435,6 +10132,56 @@ export interface GraphApiInterface {
  * @extends {BaseAPI}
  */
 export class GraphApi extends BaseAPI implements GraphApiInterface {
+  /**
+   *
+   * @param {string} accountId The ID of the account to add the member to
+   * @param {AddAccountMemberRequest} addAccountMemberRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public addAccountMember(
+    accountId: string,
+    addAccountMemberRequest: AddAccountMemberRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .addAccountMember(accountId, addAccountMemberRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {EntityAggregationQuery} entityAggregationQuery
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public aggregateEntities(
+    entityAggregationQuery: EntityAggregationQuery,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .aggregateEntities(entityAggregationQuery, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {Array<BatchOperationRequest>} batchOperationRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public applyBatch(
+    batchOperationRequest: Array<BatchOperationRequest>,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .applyBatch(batchOperationRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
   /**
    *
    * @param {ArchiveEntityRequest} archiveEntityRequest
@@ -6528,6 +10275,46 @@ export class GraphApi extends BaseAPI implements GraphApiInterface {
       .then((request) => request(this.axios, this.basePath));
   }
 
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {number} from The record ID of the edition to compare from
+   * @param {number} to The record ID of the edition to compare to
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public diffEntityEditions(
+    entityId: string,
+    from: number,
+    to: number,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .diffEntityEditions(entityId, from, to, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {number} [after] Only return events after the event with this offset. If not specified, the change feed is read from the beginning
+   * @param {number} [limit] The maximum number of events to return, at most 1000. Defaults to 100
+   * @param {number} [timeout] The number of seconds to wait for new events if there are none, at most 60. Defaults to 0
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public getChanges(
+    after?: number,
+    limit?: number,
+    timeout?: number,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .getChanges(after, limit, timeout, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
   /**
    *
    * @param {string} uri The URI of the data type
@@ -6588,6 +10375,19 @@ export class GraphApi extends BaseAPI implements GraphApiInterface {
       .then((request) => request(this.axios, this.basePath));
   }
 
+  /**
+   *
+   * @param {string} entityId The EntityId
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public getEntityHistory(entityId: string, options?: AxiosRequestConfig) {
+    return GraphApiFp(this.configuration)
+      .getEntityHistory(entityId, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
   /**
    *
    * @param {string} uri The URI of the entity type
@@ -6700,6 +10500,54 @@ export class GraphApi extends BaseAPI implements GraphApiInterface {
       .then((request) => request(this.axios, this.basePath));
   }
 
+  /**
+   *
+   * @param {MigrateEntitiesRequest} migrateEntitiesRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public migrateEntities(
+    migrateEntitiesRequest: MigrateEntitiesRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .migrateEntities(migrateEntitiesRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {PurgeEntityRequest} purgeEntityRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public purgeEntity(
+    purgeEntityRequest: PurgeEntityRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .purgeEntity(purgeEntityRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
+  /**
+   *
+   * @param {RestoreEntityEditionRequest} restoreEntityEditionRequest
+   * @param {*} [options] Override http request option.
+   * @throws {RequiredError}
+   * @memberof GraphApi
+   */
+  public restoreEntityEdition(
+    restoreEntityEditionRequest: RestoreEntityEditionRequest,
+    options?: AxiosRequestConfig,
+  ) {
+    return GraphApiFp(this.configuration)
+      .restoreEntityEdition(restoreEntityEditionRequest, options)
+      .then((request) => request(this.axios, this.basePath));
+  }
+
   /**
    *
    * @param {UpdateDataTypeRequest} updateDataTypeRequest
@@ -7142,7 +10990,7 @@ export const PropertyTypeApiFp = function (configuration?: Configuration) {
       (
         axios?: AxiosInstance,
         basePath?: string,
-      ) => AxiosPromise<OntologyElementMetadata>
+      ) => AxiosPromise<OntologyUpdateMetadata>
     > {
       const localVarAxiosArgs =
         await localVarAxiosParamCreator.updatePropertyType(
@@ -7235,7 +11083,7 @@ export const PropertyTypeApiFactory = function (
     updatePropertyType(
       updatePropertyTypeRequest: UpdatePropertyTypeRequest,
       options?: any,
-    ): AxiosPromise<OntologyElementMetadata> {
+    ): AxiosPromise<OntologyUpdateMetadata> {
       return localVarFp
         .updatePropertyType(updatePropertyTypeRequest, options)
         .then((request) => request(axios, basePath));
@@ -7307,7 +11155,7 @@ export interface PropertyTypeApiInterface {
   updatePropertyType(
     updatePropertyTypeRequest: UpdatePropertyTypeRequest,
     options?: AxiosRequestConfig,
-  ): AxiosPromise<OntologyElementMetadata>;
+  ): AxiosPromise<OntologyUpdateMetadata>;
 }
 
 /**
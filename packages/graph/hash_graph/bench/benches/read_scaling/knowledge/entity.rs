@@ -6,7 +6,10 @@ use graph::{
     identifier::account::AccountId,
     knowledge::{EntityMetadata, EntityProperties},
     provenance::{OwnedById, UpdatedById},
-    store::{query::Filter, AccountStore, AsClient, EntityStore, PostgresStore},
+    store::{
+        query::{Filter, TemporalAxes},
+        AccountStore, AsClient, EntityStore, PostgresStore,
+    },
     subgraph::{edges::GraphResolveDepths, query::StructuralQuery},
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
@@ -115,6 +118,8 @@ pub fn bench_get_entity_by_id(
                 .get_entity(&StructuralQuery {
                     filter: Filter::for_entity_by_entity_id(entity_edition_id.base_id()),
                     graph_resolve_depths: GraphResolveDepths::default(),
+                    pagination: None,
+                    temporal_axes: TemporalAxes::default(),
                 })
                 .await
                 .expect("failed to read entity from store");
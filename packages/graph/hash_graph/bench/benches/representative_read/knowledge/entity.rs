@@ -4,7 +4,7 @@ use criterion::{BatchSize::SmallInput, Bencher};
 use graph::{
     knowledge::{EntityQueryPath, EntityUuid},
     store::{
-        query::{Filter, FilterExpression, Parameter},
+        query::{Filter, FilterExpression, Parameter, TemporalAxes},
         EntityStore,
     },
     subgraph::{edges::GraphResolveDepths, query::StructuralQuery},
@@ -30,6 +30,8 @@ pub fn bench_get_entity_by_id(
                 .get_entity(&StructuralQuery {
                     filter: Filter::for_latest_entity_by_entity_uuid(entity_uuid),
                     graph_resolve_depths: GraphResolveDepths::default(),
+                    pagination: None,
+                    temporal_axes: TemporalAxes::default(),
                 })
                 .await
                 .expect("failed to read entity from store");
@@ -57,6 +59,8 @@ pub fn bench_get_entities_by_property(
                     )))),
                 ),
                 graph_resolve_depths,
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await
             .expect("failed to read entity from store");
@@ -84,6 +88,8 @@ pub fn bench_get_link_by_target_by_property(
                     )))),
                 ),
                 graph_resolve_depths,
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await
             .expect("failed to read entity from store");
@@ -19,7 +19,7 @@
 
 use criterion::{BenchmarkId, Criterion, SamplingMode};
 use criterion_macro::criterion;
-use graph::subgraph::edges::{EdgeResolveDepths, GraphResolveDepths, OutgoingEdgeResolveDepth};
+use graph::subgraph::edges::{EdgeResolveDepths, GraphResolveDepths};
 
 use crate::{representative_read::seed::setup_and_extract_samples, util::setup};
 
@@ -64,27 +64,27 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
     let graph_resolve_depths = [
         GraphResolveDepths::default(),
         GraphResolveDepths {
-            inherits_from: OutgoingEdgeResolveDepth {
+            inherits_from: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_values_on: OutgoingEdgeResolveDepth {
+            constrains_values_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_properties_on: OutgoingEdgeResolveDepth {
+            constrains_properties_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_links_on: OutgoingEdgeResolveDepth {
+            constrains_links_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_link_destinations_on: OutgoingEdgeResolveDepth {
+            constrains_link_destinations_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            is_of_type: OutgoingEdgeResolveDepth {
+            is_of_type: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
@@ -98,27 +98,27 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
             },
         },
         GraphResolveDepths {
-            inherits_from: OutgoingEdgeResolveDepth {
+            inherits_from: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_values_on: OutgoingEdgeResolveDepth {
+            constrains_values_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_properties_on: OutgoingEdgeResolveDepth {
+            constrains_properties_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_links_on: OutgoingEdgeResolveDepth {
+            constrains_links_on: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
-            constrains_link_destinations_on: OutgoingEdgeResolveDepth {
+            constrains_link_destinations_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            is_of_type: OutgoingEdgeResolveDepth {
+            is_of_type: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
@@ -132,27 +132,27 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
             },
         },
         GraphResolveDepths {
-            inherits_from: OutgoingEdgeResolveDepth {
+            inherits_from: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_values_on: OutgoingEdgeResolveDepth {
+            constrains_values_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_properties_on: OutgoingEdgeResolveDepth {
+            constrains_properties_on: EdgeResolveDepths {
                 outgoing: 2,
                 incoming: 0,
             },
-            constrains_links_on: OutgoingEdgeResolveDepth {
+            constrains_links_on: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
-            constrains_link_destinations_on: OutgoingEdgeResolveDepth {
+            constrains_link_destinations_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            is_of_type: OutgoingEdgeResolveDepth {
+            is_of_type: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
@@ -166,27 +166,27 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
             },
         },
         GraphResolveDepths {
-            inherits_from: OutgoingEdgeResolveDepth {
+            inherits_from: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            constrains_values_on: OutgoingEdgeResolveDepth {
+            constrains_values_on: EdgeResolveDepths {
                 outgoing: 2,
                 incoming: 0,
             },
-            constrains_properties_on: OutgoingEdgeResolveDepth {
+            constrains_properties_on: EdgeResolveDepths {
                 outgoing: 2,
                 incoming: 0,
             },
-            constrains_links_on: OutgoingEdgeResolveDepth {
+            constrains_links_on: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
-            constrains_link_destinations_on: OutgoingEdgeResolveDepth {
+            constrains_link_destinations_on: EdgeResolveDepths {
                 outgoing: 0,
                 incoming: 0,
             },
-            is_of_type: OutgoingEdgeResolveDepth {
+            is_of_type: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
@@ -200,27 +200,27 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
             },
         },
         GraphResolveDepths {
-            inherits_from: OutgoingEdgeResolveDepth {
+            inherits_from: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
-            constrains_values_on: OutgoingEdgeResolveDepth {
+            constrains_values_on: EdgeResolveDepths {
                 outgoing: 255,
                 incoming: 0,
             },
-            constrains_properties_on: OutgoingEdgeResolveDepth {
+            constrains_properties_on: EdgeResolveDepths {
                 outgoing: 255,
                 incoming: 0,
             },
-            constrains_links_on: OutgoingEdgeResolveDepth {
+            constrains_links_on: EdgeResolveDepths {
                 outgoing: 127,
                 incoming: 0,
             },
-            constrains_link_destinations_on: OutgoingEdgeResolveDepth {
+            constrains_link_destinations_on: EdgeResolveDepths {
                 outgoing: 126,
                 incoming: 0,
             },
-            is_of_type: OutgoingEdgeResolveDepth {
+            is_of_type: EdgeResolveDepths {
                 outgoing: 1,
                 incoming: 0,
             },
@@ -1,6 +1,9 @@
 use criterion::{BatchSize::SmallInput, Bencher};
 use graph::{
-    store::{query::Filter, EntityTypeStore},
+    store::{
+        query::{Filter, TemporalAxes},
+        EntityTypeStore,
+    },
     subgraph::{edges::GraphResolveDepths, query::StructuralQuery},
 };
 use rand::{prelude::IteratorRandom, thread_rng};
@@ -29,6 +32,8 @@ pub fn bench_get_entity_type_by_id(
                 .get_entity_type(&StructuralQuery {
                     filter: Filter::for_versioned_uri(&entity_type_id),
                     graph_resolve_depths: GraphResolveDepths::default(),
+                    pagination: None,
+                    temporal_axes: TemporalAxes::default(),
                 })
                 .await
                 .expect("failed to read entity type from store");
@@ -2,6 +2,7 @@ use std::mem::ManuallyDrop;
 
 use graph::{
     identifier::account::AccountId,
+    ontology::{CompatibilityAction, CompatibilityPolicy},
     provenance::{OwnedById, UpdatedById},
     store::{
         AsClient, BaseUriAlreadyExists, DataTypeStore, DatabaseConnectionInfo, DatabaseType,
@@ -192,7 +193,14 @@ pub async fn seed<D, P, E, C>(
             Err(report) => {
                 if report.contains::<BaseUriAlreadyExists>() {
                     store
-                        .update_data_type(data_type, UpdatedById::new(account_id))
+                        .update_data_type(
+                            data_type,
+                            UpdatedById::new(account_id),
+                            CompatibilityPolicy::new(
+                                CompatibilityAction::Accept,
+                                CompatibilityAction::Accept,
+                            ),
+                        )
                         .await
                         .expect("failed to update data type");
                 } else {
@@ -220,7 +228,14 @@ pub async fn seed<D, P, E, C>(
             Err(report) => {
                 if report.contains::<BaseUriAlreadyExists>() {
                     store
-                        .update_property_type(property_type, UpdatedById::new(account_id))
+                        .update_property_type(
+                            property_type,
+                            UpdatedById::new(account_id),
+                            CompatibilityPolicy::new(
+                                CompatibilityAction::Accept,
+                                CompatibilityAction::Accept,
+                            ),
+                        )
                         .await
                         .expect("failed to update property type");
                 } else {
@@ -248,7 +263,14 @@ pub async fn seed<D, P, E, C>(
             Err(report) => {
                 if report.contains::<BaseUriAlreadyExists>() {
                     store
-                        .update_entity_type(entity_type, UpdatedById::new(account_id))
+                        .update_entity_type(
+                            entity_type,
+                            UpdatedById::new(account_id),
+                            CompatibilityPolicy::new(
+                                CompatibilityAction::Accept,
+                                CompatibilityAction::Accept,
+                            ),
+                        )
                         .await
                         .expect("failed to update entity type");
                 } else {
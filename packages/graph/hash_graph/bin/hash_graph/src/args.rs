@@ -1,12 +1,18 @@
-use clap::{Args as _, Command, Parser};
+use std::path::PathBuf;
+
+use clap::{Args as _, Command, Parser, Subcommand};
 use clap_complete::Shell;
-use graph::{logging::LoggingArgs, store::DatabaseConnectionInfo};
+use graph::{logging::LoggingArgs, ontology::CompatibilityAction, store::DatabaseConnectionInfo};
 use regex::Regex;
 
 /// Arguments passed to the program.
 #[derive(Debug, Parser)]
 #[clap(version, author, about, long_about = None)]
 pub struct Args {
+    /// Runs a maintenance command instead of the REST API.
+    #[clap(subcommand)]
+    pub command: Option<GraphCommand>,
+
     #[clap(flatten)]
     pub db_info: DatabaseConnectionInfo,
 
@@ -41,11 +47,69 @@ pub struct Args {
     )]
     pub allowed_url_domain: Regex,
 
+    /// The action taken for breaking updates of ontology types, i.e. updates which may invalidate
+    /// existing values.
+    ///
+    /// Requests can only choose a stricter action.
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = CompatibilityAction::Warn,
+        env = "HASH_GRAPH_BREAKING_UPDATES"
+    )]
+    pub breaking_updates: CompatibilityAction,
+
+    /// The action taken for incompatible updates of ontology types, i.e. updates which invalidate
+    /// all existing values.
+    ///
+    /// Requests can only choose a stricter action.
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = CompatibilityAction::Reject,
+        env = "HASH_GRAPH_INCOMPATIBLE_UPDATES"
+    )]
+    pub incompatible_updates: CompatibilityAction,
+
+    /// A JSON file containing the key set used to authenticate requests to the REST API.
+    ///
+    /// The file has to contain an object mapping each bearer token to the account ID the token
+    /// was issued for. If no file is specified, requests are not authenticated and every request
+    /// can act on behalf of any account.
+    #[clap(long, env = "HASH_GRAPH_API_KEYS_FILE")]
+    pub api_keys_file: Option<PathBuf>,
+
     /// Generate a completion script for the given shell and outputs it to stdout.
     #[clap(long, value_enum, exclusive = true)]
     generate_completion: Option<Shell>,
 }
 
+/// Maintenance commands of the Graph.
+#[derive(Debug, Subcommand)]
+pub enum GraphCommand {
+    /// Exports accounts, ontology types and entities as newline-delimited JSON archive.
+    Export(ExportArgs),
+    /// Imports an archive created by `export`.
+    ///
+    /// Records already present in the database are skipped, so an import can be repeated or
+    /// resumed after a failure.
+    Import(ImportArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ExportArgs {
+    /// The file to write the archive to. Writes to stdout if not specified.
+    #[clap(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ImportArgs {
+    /// The file to read the archive from. Reads from stdin if not specified.
+    #[clap(long, short)]
+    pub input: Option<PathBuf>,
+}
+
 impl Args {
     /// Parse the arguments passed to the program.
     pub fn parse() -> Self {
@@ -2,18 +2,32 @@
 
 mod args;
 
-use std::{collections::HashMap, fmt, net::SocketAddr, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    net::SocketAddr,
+    sync::Arc,
+};
 
-use error_stack::{Context, IntoReport, Result, ResultExt};
+use error_stack::{Context, IntoReport, Report, Result, ResultExt};
 use graph::{
-    api::rest::rest_api_router,
+    api::{
+        graphql::{graphql_api_router, SchemaCache},
+        monitoring::{monitoring_router, record_request_metrics},
+        rest::{
+            authentication::{Authenticator, BearerTokenAuthenticator},
+            rest_api_router,
+        },
+    },
     identifier::account::AccountId,
     logging::init_logger,
-    ontology::domain_validator::DomainValidator,
+    ontology::{domain_validator::DomainValidator, CompatibilityPolicy},
     provenance::{OwnedById, UpdatedById},
     store::{
-        AccountStore, BaseUriAlreadyExists, DataTypeStore, EntityTypeStore, PostgresStorePool,
-        StorePool,
+        archive, AccountStore, BaseUriAlreadyExists, DataTypeStore, EntityTypeStore,
+        PostgresStorePool, StorePool,
     },
 };
 use serde_json::json;
@@ -24,7 +38,7 @@ use type_system::{
 };
 use uuid::Uuid;
 
-use crate::args::Args;
+use crate::args::{Args, ExportArgs, GraphCommand, ImportArgs};
 
 #[derive(Debug)]
 pub struct GraphError;
@@ -143,7 +157,8 @@ async fn stop_gap_setup(pool: &PostgresStorePool<NoTls>) -> Result<(), GraphErro
         Vec::default(),
     );
 
-    // TODO: Revisit once an authentication and authorization setup is in place
+    // The root account owns the primitive types. When authentication is enabled, its namespace is
+    // only writable if an API key is issued for the root account.
     let root_account_id = AccountId::new(Uuid::nil());
 
     let mut connection = pool
@@ -212,6 +227,49 @@ async fn stop_gap_setup(pool: &PostgresStorePool<NoTls>) -> Result<(), GraphErro
     Ok(())
 }
 
+/// Runs a maintenance command against the database.
+async fn run_command(
+    pool: &PostgresStorePool<NoTls>,
+    command: GraphCommand,
+) -> Result<(), GraphError> {
+    let mut store = pool.acquire().await.change_context(GraphError)?;
+
+    match command {
+        GraphCommand::Export(ExportArgs { output }) => {
+            let written = match output {
+                Some(path) => {
+                    let file = File::create(&path)
+                        .into_report()
+                        .change_context(GraphError)
+                        .attach_printable_lazy(|| path.display().to_string())?;
+                    archive::export(&store, BufWriter::new(file)).await
+                }
+                None => archive::export(&store, BufWriter::new(io::stdout().lock())).await,
+            }
+            .change_context(GraphError)?;
+
+            tracing::info!("Exported {written} records");
+        }
+        GraphCommand::Import(ImportArgs { input }) => {
+            let imported = match input {
+                Some(path) => {
+                    let file = File::open(&path)
+                        .into_report()
+                        .change_context(GraphError)
+                        .attach_printable_lazy(|| path.display().to_string())?;
+                    archive::import(&mut store, BufReader::new(file)).await
+                }
+                None => archive::import(&mut store, io::stdin().lock()).await,
+            }
+            .change_context(GraphError)?;
+
+            tracing::info!("Imported {imported} records");
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), GraphError> {
     let args = Args::parse();
@@ -220,6 +278,7 @@ async fn main() -> Result<(), GraphError> {
         args.log_config.log_folder,
         args.log_config.log_level,
         &args.log_config.log_file_prefix,
+        args.log_config.otlp_endpoint.as_deref(),
     );
 
     let pool = PostgresStorePool::new(&args.db_info, NoTls)
@@ -230,12 +289,50 @@ async fn main() -> Result<(), GraphError> {
             err
         })?;
 
+    if let Some(command) = args.command {
+        return run_command(&pool, command).await;
+    }
+
     stop_gap_setup(&pool).await?;
 
-    let rest_router = rest_api_router(
-        Arc::new(pool),
+    let authenticator = args
+        .api_keys_file
+        .map(|path| {
+            let file = File::open(&path)
+                .into_report()
+                .change_context(GraphError)
+                .attach_printable_lazy(|| path.display().to_string())?;
+            let keys: HashMap<String, AccountId> = serde_json::from_reader(BufReader::new(file))
+                .into_report()
+                .change_context(GraphError)
+                .attach_printable("could not parse the API key set")
+                .attach_printable_lazy(|| path.display().to_string())?;
+
+            tracing::info!("Authenticating requests with {} API keys", keys.len());
+            Ok::<_, Report<GraphError>>(
+                Arc::new(BearerTokenAuthenticator::new(keys)) as Arc<dyn Authenticator>
+            )
+        })
+        .transpose()?;
+
+    let pool = Arc::new(pool);
+    let schema_cache = SchemaCache::new();
+    let router = rest_api_router(
+        Arc::clone(&pool),
         DomainValidator::new(args.allowed_url_domain),
-    );
+        CompatibilityPolicy::new(args.breaking_updates, args.incompatible_updates),
+        schema_cache.clone(),
+        authenticator.clone(),
+    )
+    .merge(graphql_api_router(
+        Arc::clone(&pool),
+        schema_cache,
+        authenticator,
+    ))
+    .layer(axum::middleware::from_fn(record_request_metrics))
+    // The monitoring routes are merged after the metrics layer, so scraping the metrics or
+    // probing the health of the Graph is not recorded as a request.
+    .merge(monitoring_router(pool));
     let api_address = format!("{}:{}", args.api_host, args.api_port);
     let addr: SocketAddr = api_address
         .parse()
@@ -245,7 +342,7 @@ async fn main() -> Result<(), GraphError> {
 
     tracing::info!("Listening on {api_address}");
     axum::Server::bind(&addr)
-        .serve(rest_router.into_make_service())
+        .serve(router.into_make_service())
         .await
         .unwrap();
 
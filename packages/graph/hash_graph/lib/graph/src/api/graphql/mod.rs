@@ -0,0 +1,174 @@
+//! The GraphQL interface to the Graph.
+//!
+//! The schema is generated from the latest versions of all entity types in the store, so it
+//! changes as entity types are created or updated. The `Query` type has a field for every entity
+//! type, which returns the latest entities of that type:
+//!
+//! ```graphql
+//! query {
+//!   person {
+//!     entityId
+//!     name
+//!     friendOf {
+//!       entityId
+//!       ... on Person {
+//!         name
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Nested link selections are translated to [`GraphResolveDepths`], so every root field is
+//! resolved by a single structural query, regardless of how deeply the links are nested.
+//!
+//! Building the schema requires reading all entity types, so it is kept in a [`SchemaCache`]. The
+//! REST API invalidates the cache whenever an entity type is created or updated. Entity types
+//! written by other instances of the Graph are picked up after [`SchemaCache::MAX_AGE`].
+//!
+//! [`GraphResolveDepths`]: crate::subgraph::edges::GraphResolveDepths
+
+mod schema;
+
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use async_graphql::dynamic::Schema;
+use axum::{body::Body, http::StatusCode, routing::post, Extension, Json, Router};
+use tower_http::trace::TraceLayer;
+
+use crate::{
+    api::rest::authentication::{authenticate_request, Authenticator},
+    logging::make_request_span,
+    ontology::EntityTypeWithMetadata,
+    store::{
+        crud::Read,
+        query::{Filter, TemporalAxes},
+        StorePool,
+    },
+};
+
+#[derive(Default)]
+struct CachedSchema {
+    /// Incremented on every invalidation, so a schema built from outdated entity types is not
+    /// stored.
+    generation: u64,
+    schema: Option<(Schema, Instant)>,
+}
+
+/// Caches the GraphQL schema generated from the entity types in the store.
+///
+/// The cache is shared between the GraphQL and the REST API, which invalidates it when entity
+/// types change.
+#[derive(Clone, Default)]
+pub struct SchemaCache {
+    inner: Arc<Mutex<CachedSchema>>,
+}
+
+impl SchemaCache {
+    /// The time after which a cached schema is rebuilt, even if it was not invalidated.
+    pub const MAX_AGE: Duration = Duration::from_secs(60);
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discards the cached schema, so it's rebuilt on the next request.
+    pub fn invalidate(&self) {
+        let mut cached = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        cached.generation += 1;
+        cached.schema = None;
+    }
+
+    /// Returns the cached schema if it's not outdated, together with the current generation.
+    fn get(&self) -> (Option<Schema>, u64) {
+        let cached = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let schema = cached
+            .schema
+            .as_ref()
+            .filter(|(_, built_at)| built_at.elapsed() < Self::MAX_AGE)
+            .map(|(schema, _)| schema.clone());
+        (schema, cached.generation)
+    }
+
+    /// Stores the `schema` unless the cache was invalidated since `generation` was read.
+    fn set(&self, schema: Schema, generation: u64) {
+        let mut cached = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        if cached.generation == generation {
+            cached.schema = Some((schema, Instant::now()));
+        }
+    }
+}
+
+/// A [`Router`] serving the GraphQL interface at `/graphql`.
+///
+/// The `schema_cache` should be the same one passed to the REST API, so changes to entity types
+/// are reflected in the schema immediately.
+///
+/// If an [`Authenticator`] is passed, all requests have to be authenticated by it.
+pub fn graphql_api_router<P: StorePool + Send + Sync + 'static>(
+    store: Arc<P>,
+    schema_cache: SchemaCache,
+    authenticator: Option<Arc<dyn Authenticator>>,
+) -> Router {
+    let mut routes = Router::new()
+        .route("/graphql", post(execute_request::<P>))
+        .layer(Extension(store))
+        .layer(Extension(schema_cache));
+
+    // The authenticator is added after the authentication layer to make it available to it.
+    if let Some(authenticator) = authenticator {
+        routes = routes
+            .layer(axum::middleware::from_fn(authenticate_request))
+            .layer(Extension(authenticator));
+    }
+
+    routes.layer(TraceLayer::new_for_http().make_span_with(make_request_span::<Body>))
+}
+
+async fn execute_request<P: StorePool + Send + Sync + 'static>(
+    pool: Extension<Arc<P>>,
+    Extension(schema_cache): Extension<SchemaCache>,
+    Json(request): Json<async_graphql::Request>,
+) -> Result<Json<async_graphql::Response>, StatusCode> {
+    let schema = match schema_cache.get() {
+        (Some(schema), _) => schema,
+        (None, generation) => {
+            let schema = build_schema(&pool).await?;
+            schema_cache.set(schema.clone(), generation);
+            schema
+        }
+    };
+
+    Ok(Json(schema.execute(request).await))
+}
+
+async fn build_schema<P: StorePool + Send + Sync + 'static>(
+    pool: &Arc<P>,
+) -> Result<Schema, StatusCode> {
+    let entity_types = {
+        let store = pool.acquire().await.map_err(|report| {
+            tracing::error!(error=?report, "Could not acquire access to the store");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+        Read::<EntityTypeWithMetadata>::read(
+            &store,
+            &Filter::for_latest_version(),
+            &TemporalAxes::default(),
+        )
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read entity types");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+    };
+
+    schema::build_schema(&entity_types, Arc::clone(pool)).map_err(|error| {
+        tracing::error!(%error, "Could not build GraphQL schema");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
@@ -0,0 +1,671 @@
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_graphql::{
+    dynamic::{
+        Field, FieldFuture, FieldValue, InputValue, Interface, InterfaceField, Object,
+        ResolverContext, Scalar, Schema, SchemaError, TypeRef,
+    },
+    Error, SelectionField, Value,
+};
+use type_system::{uri::BaseUri, EntityType};
+
+use crate::{
+    identifier::{knowledge::EntityId, GraphElementEditionId},
+    knowledge::{Entity, EntityQueryPath},
+    ontology::{EntityTypeQueryPath, EntityTypeWithMetadata},
+    store::{
+        query::{Filter, FilterExpression, Parameter, TemporalAxes},
+        EntityStore, StorePool,
+    },
+    subgraph::{
+        edges::{EdgeResolveDepths, GraphResolveDepths},
+        query::StructuralQuery,
+        vertices::KnowledgeGraphVertex,
+        Subgraph,
+    },
+};
+
+const QUERY_TYPE: &str = "Query";
+const ENTITY_INTERFACE: &str = "Entity";
+const JSON_SCALAR: &str = "JSON";
+
+/// Type names, which are either built in or used by the schema itself.
+const RESERVED_TYPE_NAMES: [&str; 8] = [
+    QUERY_TYPE,
+    ENTITY_INTERFACE,
+    JSON_SCALAR,
+    "String",
+    "Int",
+    "Float",
+    "Boolean",
+    "ID",
+];
+
+/// The maximum nesting depth of a query.
+///
+/// Nested link selections are resolved by a single structural query, so the depth directly bounds
+/// the graph resolve depths requested from the store.
+const MAX_QUERY_DEPTH: usize = 16;
+
+/// The maximum complexity of a query, i.e. the number of selected fields.
+const MAX_QUERY_COMPLEXITY: usize = 1000;
+
+/// Fields of the [`ENTITY_INTERFACE`], which must not be shadowed by properties or links.
+const ENTITY_FIELDS: [&str; 4] = ["entityId", "entityTypeId", "properties", "metadata"];
+
+/// Builds the schema for the latest versions of `entity_types`.
+///
+/// The schema resolves queries against the store handed out by `pool`.
+pub(super) fn build_schema<P: StorePool + Send + Sync + 'static>(
+    entity_types: &[EntityTypeWithMetadata],
+    pool: Arc<P>,
+) -> Result<Schema, SchemaError> {
+    let mut entity_types = entity_types
+        .iter()
+        .map(EntityTypeWithMetadata::inner)
+        .collect::<Vec<_>>();
+    // Sorting the entity types keeps the generated names stable between requests.
+    entity_types.sort_by(|lhs, rhs| {
+        lhs.id()
+            .base_uri()
+            .as_str()
+            .cmp(rhs.id().base_uri().as_str())
+    });
+
+    let mut names = SchemaNames::default();
+    let mut used_type_names = RESERVED_TYPE_NAMES.map(str::to_owned).into();
+    let mut used_query_fields = HashSet::from(["entity".to_owned()]);
+    let definitions = entity_types
+        .into_iter()
+        .map(|entity_type| {
+            let definition = ObjectTypeDefinition::new(
+                entity_type,
+                &mut used_type_names,
+                &mut used_query_fields,
+            );
+            names
+                .object_types
+                .insert(definition.base_uri.clone(), definition.type_name.clone());
+            names.link_fields.extend(
+                definition
+                    .links
+                    .iter()
+                    .map(|(field_name, _)| field_name.clone()),
+            );
+            definition
+        })
+        .collect::<Vec<_>>();
+    let names = Arc::new(names);
+
+    let mut query = Object::new(QUERY_TYPE).field(
+        Field::new(
+            "entity",
+            TypeRef::named(ENTITY_INTERFACE),
+            resolve_entity::<P>(Arc::clone(&names)),
+        )
+        .argument(InputValue::new(
+            "entityId",
+            TypeRef::named_nn(TypeRef::STRING),
+        ))
+        .description("Returns the latest edition of the entity with the specified ID."),
+    );
+
+    let mut schema = Schema::build(QUERY_TYPE, None, None)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .register(Scalar::new(JSON_SCALAR).description("An arbitrary JSON value."))
+        .register(entity_interface());
+
+    for definition in definitions {
+        query = query.field(
+            Field::new(
+                &definition.query_field,
+                TypeRef::named_nn_list_nn(&definition.type_name),
+                resolve_entities_by_type::<P>(definition.base_uri.clone(), Arc::clone(&names)),
+            )
+            .argument(InputValue::new("entityId", TypeRef::named(TypeRef::STRING)))
+            .description(format!(
+                "Returns the latest entities of the entity type `{}`.",
+                definition.base_uri
+            )),
+        );
+        schema = schema.register(definition.into_object(&names));
+    }
+
+    schema.register(query).data(pool).finish()
+}
+
+/// The generated names needed to resolve values.
+#[derive(Debug, Default)]
+struct SchemaNames {
+    /// Maps the base URI of every entity type to the name of its object type.
+    object_types: HashMap<BaseUri, String>,
+    /// The names of all fields, which resolve links.
+    link_fields: HashSet<String>,
+}
+
+/// The object type generated for an entity type.
+struct ObjectTypeDefinition {
+    base_uri: BaseUri,
+    type_name: String,
+    query_field: String,
+    /// The field names of the properties of the entity type.
+    properties: Vec<(String, BaseUri)>,
+    /// The field names of the links of the entity type.
+    links: Vec<(String, BaseUri)>,
+}
+
+impl ObjectTypeDefinition {
+    fn new(
+        entity_type: &EntityType,
+        used_type_names: &mut HashSet<String>,
+        used_query_fields: &mut HashSet<String>,
+    ) -> Self {
+        let type_name = unique_name(&pascal_case(entity_type.title()), used_type_names);
+        let query_field = unique_name(&camel_case(&type_name), used_query_fields);
+
+        let mut used_fields = ENTITY_FIELDS.map(str::to_owned).into();
+        let mut property_types = entity_type
+            .property_type_references()
+            .into_iter()
+            .map(|reference| reference.uri().base_uri().clone())
+            .collect::<Vec<_>>();
+        property_types.sort_by(|lhs, rhs| lhs.as_str().cmp(rhs.as_str()));
+        let properties = property_types
+            .into_iter()
+            .map(|base_uri| {
+                let field_name = unique_name(&camel_case(slug(&base_uri)), &mut used_fields);
+                (field_name, base_uri)
+            })
+            .collect();
+
+        let mut link_types = entity_type
+            .link_mappings()
+            .into_keys()
+            .map(|reference| reference.uri().base_uri().clone())
+            .collect::<Vec<_>>();
+        link_types.sort_by(|lhs, rhs| lhs.as_str().cmp(rhs.as_str()));
+        let links = link_types
+            .into_iter()
+            .map(|base_uri| {
+                let field_name = unique_name(&camel_case(slug(&base_uri)), &mut used_fields);
+                (field_name, base_uri)
+            })
+            .collect();
+
+        Self {
+            base_uri: entity_type.id().base_uri().clone(),
+            type_name,
+            query_field,
+            properties,
+            links,
+        }
+    }
+
+    fn into_object(self, names: &Arc<SchemaNames>) -> Object {
+        let mut object = entity_fields()
+            .into_iter()
+            .fold(Object::new(&self.type_name), Object::field)
+            .implement(ENTITY_INTERFACE)
+            .description(format!("An entity of the entity type `{}`.", self.base_uri));
+
+        for (field_name, base_uri) in self.properties {
+            let description = format!("The value of the property `{base_uri}`.");
+            object = object.field(
+                Field::new(
+                    field_name,
+                    TypeRef::named(JSON_SCALAR),
+                    move |ctx: ResolverContext<'_>| {
+                        let base_uri = base_uri.clone();
+                        FieldFuture::new(async move {
+                            let entity = ctx.parent_value.try_downcast_ref::<EntityValue>()?;
+                            let value = entity
+                                .entity()
+                                .properties()
+                                .properties()
+                                .get(&base_uri)
+                                .map(|value| Value::from_json(value.clone()))
+                                .transpose()?;
+                            Ok(value.map(FieldValue::value))
+                        })
+                    },
+                )
+                .description(description),
+            );
+        }
+
+        for (field_name, base_uri) in self.links {
+            let description = format!("The entities linked by links of the type `{base_uri}`.");
+            let names = Arc::clone(names);
+            object = object.field(
+                Field::new(
+                    field_name,
+                    TypeRef::named_nn_list_nn(ENTITY_INTERFACE),
+                    move |ctx: ResolverContext<'_>| {
+                        let link_type = base_uri.clone();
+                        let names = Arc::clone(&names);
+                        FieldFuture::new(async move {
+                            let entity = ctx.parent_value.try_downcast_ref::<EntityValue>()?;
+                            Ok(Some(FieldValue::list(
+                                entity.linked_entities(&link_type, &names),
+                            )))
+                        })
+                    },
+                )
+                .description(description),
+            );
+        }
+
+        object
+    }
+}
+
+/// The interface implemented by the object types of all entity types.
+fn entity_interface() -> Interface {
+    Interface::new(ENTITY_INTERFACE)
+        .field(InterfaceField::new(
+            "entityId",
+            TypeRef::named_nn(TypeRef::STRING),
+        ))
+        .field(InterfaceField::new(
+            "entityTypeId",
+            TypeRef::named_nn(TypeRef::STRING),
+        ))
+        .field(InterfaceField::new(
+            "properties",
+            TypeRef::named_nn(JSON_SCALAR),
+        ))
+        .field(InterfaceField::new(
+            "metadata",
+            TypeRef::named_nn(JSON_SCALAR),
+        ))
+        .description("An entity in the graph.")
+}
+
+/// The fields of the [`entity_interface`].
+fn entity_fields() -> [Field; 4] {
+    [
+        entity_field("entityId", TypeRef::STRING, |entity| {
+            Value::from_json(serde_json::to_value(
+                entity.metadata().edition_id().base_id(),
+            )?)
+        }),
+        entity_field("entityTypeId", TypeRef::STRING, |entity| {
+            Ok(Value::String(
+                entity.metadata().entity_type_id().to_string(),
+            ))
+        }),
+        entity_field("properties", JSON_SCALAR, |entity| {
+            Value::from_json(serde_json::to_value(entity.properties())?)
+        }),
+        entity_field("metadata", JSON_SCALAR, |entity| {
+            Value::from_json(serde_json::to_value(entity.metadata())?)
+        }),
+    ]
+}
+
+fn entity_field(
+    name: &'static str,
+    type_name: &'static str,
+    resolve: fn(&Entity) -> Result<Value, serde_json::Error>,
+) -> Field {
+    Field::new(
+        name,
+        TypeRef::named_nn(type_name),
+        move |ctx: ResolverContext<'_>| {
+            FieldFuture::new(async move {
+                let entity = ctx.parent_value.try_downcast_ref::<EntityValue>()?;
+                Ok(Some(FieldValue::value(resolve(entity.entity())?)))
+            })
+        },
+    )
+}
+
+/// Resolves the `entity` field of the query type.
+fn resolve_entity<P: StorePool + Send + Sync + 'static>(
+    names: Arc<SchemaNames>,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext<'_>| {
+        let names = Arc::clone(&names);
+        FieldFuture::new(async move {
+            let Some(entity_id) = entity_id_argument(&ctx)? else {
+                return Ok(None);
+            };
+
+            let (entities, roots) = query_entities::<P>(
+                &ctx,
+                &names,
+                Filter::for_latest_entity_by_entity_id(entity_id),
+            )
+            .await?;
+            Ok(roots
+                .into_iter()
+                .find_map(|entity_id| entity_value(&entities, entity_id, &names)))
+        })
+    }
+}
+
+/// Resolves the field of the query type for the entity type identified by `base_uri`.
+fn resolve_entities_by_type<P: StorePool + Send + Sync + 'static>(
+    base_uri: BaseUri,
+    names: Arc<SchemaNames>,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext<'_>| {
+        let base_uri = base_uri.clone();
+        let names = Arc::clone(&names);
+        FieldFuture::new(async move {
+            let filter = Filter::All(vec![
+                Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::Type(
+                        EntityTypeQueryPath::BaseUri,
+                    ))),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Owned(
+                        base_uri.as_str().to_owned(),
+                    )))),
+                ),
+                entity_id_argument(&ctx)?.map_or_else(
+                    Filter::for_all_latest_entities,
+                    Filter::for_latest_entity_by_entity_id,
+                ),
+            ]);
+
+            let (entities, roots) = query_entities::<P>(&ctx, &names, filter).await?;
+            Ok(Some(FieldValue::list(roots.into_iter().map(|entity_id| {
+                FieldValue::owned_any(EntityValue {
+                    entities: Arc::clone(&entities),
+                    entity_id,
+                })
+            }))))
+        })
+    }
+}
+
+/// Reads the optional `entityId` argument of a field.
+fn entity_id_argument(ctx: &ResolverContext<'_>) -> Result<Option<EntityId>, Error> {
+    ctx.args
+        .get("entityId")
+        .filter(|value| !value.is_null())
+        .map(|value| {
+            let entity_id = value.string()?;
+            serde_json::from_value(serde_json::Value::String(entity_id.to_owned()))
+                .map_err(|_| Error::new(format!("`{entity_id}` is not a valid entity ID")))
+        })
+        .transpose()
+}
+
+/// Reads the entities matching `filter` together with all links selected by the current field.
+///
+/// Returns the read entities and the IDs of the entities matching `filter`.
+async fn query_entities<P: StorePool + Send + Sync + 'static>(
+    ctx: &ResolverContext<'_>,
+    names: &SchemaNames,
+    filter: Filter<'static, Entity>,
+) -> Result<(Arc<ResolvedEntities>, Vec<EntityId>), Error> {
+    let pool = ctx.data::<Arc<P>>()?;
+    let depth = link_depth(ctx.ctx.field(), &names.link_fields);
+
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire access to the store");
+        Error::new("could not acquire access to the store")
+    })?;
+
+    let subgraph = store
+        .get_entity(&StructuralQuery {
+            filter,
+            graph_resolve_depths: GraphResolveDepths {
+                has_left_entity: EdgeResolveDepths {
+                    incoming: depth,
+                    outgoing: 0,
+                },
+                has_right_entity: EdgeResolveDepths {
+                    incoming: 0,
+                    outgoing: depth,
+                },
+                ..GraphResolveDepths::default()
+            },
+            pagination: None,
+            temporal_axes: TemporalAxes::default(),
+        })
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read entities");
+            Error::new("could not read entities")
+        })?;
+
+    let mut roots = subgraph
+        .roots
+        .iter()
+        .filter_map(|root| match root {
+            GraphElementEditionId::KnowledgeGraph(edition_id) => Some(edition_id.base_id()),
+            GraphElementEditionId::Ontology(_) => None,
+        })
+        .collect::<Vec<_>>();
+    roots.sort_unstable();
+    roots.dedup();
+
+    Ok((Arc::new(ResolvedEntities::new(subgraph)), roots))
+}
+
+/// Returns how many links deep the selection of `field` reaches.
+///
+/// Following a link requires two edges, one from the entity to the link entity and one from the
+/// link entity to the linked entity, so this is the depth of both edges to be resolved.
+fn link_depth(field: SelectionField<'_>, link_fields: &HashSet<String>) -> u8 {
+    field
+        .selection_set()
+        .map(|child| {
+            if link_fields.contains(child.name()) {
+                link_depth(child, link_fields).saturating_add(1)
+            } else {
+                0
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// The entities read by a single structural query.
+struct ResolvedEntities {
+    /// The latest edition of every entity in the subgraph.
+    entities: HashMap<EntityId, Entity>,
+    /// The non-archived link entities in the subgraph, grouped by their left entity.
+    outgoing_links: HashMap<EntityId, Vec<EntityId>>,
+}
+
+impl ResolvedEntities {
+    fn new(subgraph: Subgraph) -> Self {
+        let mut entities = HashMap::<EntityId, Entity>::new();
+        for vertex in subgraph.vertices.knowledge_graph.into_values() {
+            let KnowledgeGraphVertex::Entity(entity) = vertex;
+            match entities.entry(entity.metadata().edition_id().base_id()) {
+                Entry::Occupied(mut entry) => {
+                    if transaction_time(entry.get()) < transaction_time(&entity) {
+                        entry.insert(entity);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(entity);
+                }
+            }
+        }
+
+        let mut outgoing_links = HashMap::<EntityId, Vec<EntityId>>::new();
+        for (entity_id, entity) in &entities {
+            if entity.metadata().archived() {
+                continue;
+            }
+            if let Some(link_data) = entity.link_data() {
+                outgoing_links
+                    .entry(link_data.left_entity_id())
+                    .or_default()
+                    .push(*entity_id);
+            }
+        }
+        for links in outgoing_links.values_mut() {
+            // Links without an order come after the ordered ones.
+            links.sort_by_key(|link_id| {
+                let order = entities[link_id]
+                    .link_data()
+                    .and_then(|link_data| link_data.left_to_right_order());
+                (order.is_none(), order, *link_id)
+            });
+        }
+
+        Self {
+            entities,
+            outgoing_links,
+        }
+    }
+}
+
+fn transaction_time(entity: &Entity) -> impl Ord {
+    entity
+        .metadata()
+        .edition_id()
+        .version()
+        .transaction_time()
+        .as_start_bound_timestamp()
+}
+
+/// The parent value of the fields of entity object types.
+struct EntityValue {
+    entities: Arc<ResolvedEntities>,
+    entity_id: EntityId,
+}
+
+impl EntityValue {
+    fn entity(&self) -> &Entity {
+        // An `EntityValue` is only created for entities contained in `entities`.
+        &self.entities.entities[&self.entity_id]
+    }
+
+    /// Returns the right entities of the outgoing links of the type `link_type`.
+    fn linked_entities<'v>(&self, link_type: &BaseUri, names: &SchemaNames) -> Vec<FieldValue<'v>> {
+        self.entities
+            .outgoing_links
+            .get(&self.entity_id)
+            .into_iter()
+            .flatten()
+            .map(|link_id| &self.entities.entities[link_id])
+            .filter(|link| link.metadata().entity_type_id().base_uri() == link_type)
+            .filter_map(Entity::link_data)
+            .filter_map(|link_data| {
+                entity_value(&self.entities, link_data.right_entity_id(), names)
+            })
+            .collect()
+    }
+}
+
+/// Returns the value of an entity, which is resolved as the object type of its entity type.
+///
+/// Returns `None` if the entity was not read or its entity type is not part of the schema.
+fn entity_value<'v>(
+    entities: &Arc<ResolvedEntities>,
+    entity_id: EntityId,
+    names: &SchemaNames,
+) -> Option<FieldValue<'v>> {
+    let entity = entities.entities.get(&entity_id)?;
+    let type_name = names
+        .object_types
+        .get(entity.metadata().entity_type_id().base_uri())?;
+    Some(
+        FieldValue::owned_any(EntityValue {
+            entities: Arc::clone(entities),
+            entity_id,
+        })
+        .with_type(type_name.clone()),
+    )
+}
+
+/// Returns the last path segment of `base_uri`, e.g. `first-name` for
+/// `https://example.com/@alice/types/property-type/first-name/`.
+fn slug(base_uri: &BaseUri) -> &str {
+    base_uri
+        .as_str()
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Converts `name` to a valid GraphQL name in `PascalCase`.
+///
+/// All characters except ASCII letters and digits are treated as word separators. If the result
+/// would not start with a letter, it's prefixed by an underscore.
+fn pascal_case(name: &str) -> String {
+    let name = name
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let (first, rest) = word.split_at(1);
+            format!("{}{rest}", first.to_ascii_uppercase())
+        })
+        .collect::<String>();
+
+    if name.starts_with(|character: char| character.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("_{name}")
+    }
+}
+
+/// Converts `name` to a valid GraphQL name in `camelCase`.
+fn camel_case(name: &str) -> String {
+    let mut name = pascal_case(name);
+    if let Some(first) = name.get_mut(0..1) {
+        first.make_ascii_lowercase();
+    }
+    name
+}
+
+/// Returns `name`, or `name` with the first numeric suffix not contained in `used`.
+fn unique_name(name: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.to_owned();
+    let mut suffix = 1_usize;
+    while used.contains(&candidate) {
+        suffix += 1;
+        candidate = format!("{name}{suffix}");
+    }
+    used.insert(candidate.clone());
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(pascal_case("Person"), "Person");
+        assert_eq!(pascal_case("blog post"), "BlogPost");
+        assert_eq!(pascal_case("Friend-Of (v2)"), "FriendOfV2");
+        assert_eq!(pascal_case("3D Model"), "_3DModel");
+        assert_eq!(pascal_case("???"), "_");
+
+        assert_eq!(camel_case("first-name"), "firstName");
+        assert_eq!(camel_case("Blog Post"), "blogPost");
+        assert_eq!(camel_case("3d-model"), "_3dModel");
+    }
+
+    #[test]
+    fn base_uri_slug() {
+        let base_uri = BaseUri::new(
+            "https://blockprotocol.org/@alice/types/property-type/first-name/".to_owned(),
+        )
+        .expect("invalid base uri");
+        assert_eq!(slug(&base_uri), "first-name");
+    }
+
+    #[test]
+    fn unique_names() {
+        let mut used = HashSet::from(["Query".to_owned()]);
+        assert_eq!(unique_name("Query", &mut used), "Query2");
+        assert_eq!(unique_name("Query", &mut used), "Query3");
+        assert_eq!(unique_name("Person", &mut used), "Person");
+        assert_eq!(unique_name("Person", &mut used), "Person2");
+    }
+}
@@ -1 +1,3 @@
+pub mod graphql;
+pub mod monitoring;
 pub mod rest;
@@ -0,0 +1,147 @@
+//! Routes to monitor the Graph.
+//!
+//! - `/metrics` exposes the metrics of the Graph in the Prometheus text format
+//! - `/health/live` responds with `200 OK` as long as the server is able to handle requests
+//! - `/health/ready` responds with `200 OK` if the store is reachable and with `503 Service
+//!   Unavailable` otherwise
+//!
+//! Request metrics are only recorded for routes wrapped by [`record_request_metrics`].
+
+use std::{
+    sync::{Arc, LazyLock},
+    time::Instant,
+};
+
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{header, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::get,
+    Extension, Router,
+};
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge, Encoder, HistogramVec,
+    IntCounterVec, IntGauge, TextEncoder,
+};
+
+use crate::store::StorePool;
+
+static REQUEST_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "graph_http_request_duration_seconds",
+        "The time until the response of an HTTP request is sent, by route, method and status",
+        &["route", "method", "status"]
+    )
+    .expect("could not register request duration metric")
+});
+
+static STORE_ERRORS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "graph_store_errors_total",
+        "The number of requests, which failed because of an error in the store, by route",
+        &["route"]
+    )
+    .expect("could not register store error metric")
+});
+
+static POOL_CONNECTIONS: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "graph_store_pool_connections",
+        "The number of open connections of the store pool"
+    )
+    .expect("could not register pool connection metric")
+});
+
+static POOL_IDLE_CONNECTIONS: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "graph_store_pool_idle_connections",
+        "The number of open connections of the store pool, which are currently not in use"
+    )
+    .expect("could not register idle pool connection metric")
+});
+
+/// Marks a response, which failed because of an error reported by the store.
+///
+/// The marker is added to the response extensions when a report of the store is converted to a
+/// response or the store could not be acquired from the pool, so [`record_request_metrics`] only
+/// counts these responses as store errors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StoreError;
+
+/// A [`Router`] serving the metrics and the health checks of the Graph.
+pub fn monitoring_router<P: StorePool + Send + Sync + 'static>(store: Arc<P>) -> Router {
+    Router::new()
+        .route("/metrics", get(serve_metrics::<P>))
+        .route("/health/live", get(check_liveness))
+        .route("/health/ready", get(check_readiness::<P>))
+        .layer(Extension(store))
+}
+
+/// An `axum` middleware recording the duration of every request.
+///
+/// Requests are labeled by their matched route instead of their path to keep the number of time
+/// series bounded. Responses are counted as store errors if they are marked with [`StoreError`],
+/// other server errors are only recorded by their status.
+pub async fn record_request_metrics(request: Request<Body>, next: Next<Body>) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "<unmatched>".to_owned(), |path| path.as_str().to_owned());
+    let method = request.method().clone();
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+    let status = response.status();
+
+    REQUEST_DURATION
+        .with_label_values(&[&route, method.as_str(), status.as_str()])
+        .observe(start.elapsed().as_secs_f64());
+    if response.extensions().get::<StoreError>().is_some() {
+        STORE_ERRORS.with_label_values(&[&route]).inc();
+    }
+
+    response
+}
+
+async fn serve_metrics<P: StorePool + Send + Sync>(pool: Extension<Arc<P>>) -> Response {
+    if let Some(state) = pool.state() {
+        POOL_CONNECTIONS.set(i64::from(state.connections));
+        POOL_IDLE_CONNECTIONS.set(i64::from(state.idle_connections));
+    }
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    match encoder.encode(&prometheus::gather(), &mut buffer) {
+        Ok(()) => (
+            [(header::CONTENT_TYPE, encoder.format_type().to_owned())],
+            buffer,
+        )
+            .into_response(),
+        Err(error) => {
+            tracing::error!(%error, "Could not encode metrics");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[allow(
+    clippy::unused_async,
+    reason = "This route does not need async capabilities, but axum requires it in trait bounds."
+)]
+async fn check_liveness() -> StatusCode {
+    StatusCode::OK
+}
+
+async fn check_readiness<P: StorePool + Send + Sync>(pool: Extension<Arc<P>>) -> StatusCode {
+    // Connections are validated when they are checked out of the pool, so acquiring a store
+    // fails if the database is not reachable.
+    match pool.acquire().await {
+        Ok(_) => StatusCode::OK,
+        Err(report) => {
+            tracing::warn!(error=?report, "The store is not ready");
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+    }
+}
@@ -2,13 +2,16 @@
 
 use std::sync::Arc;
 
-use axum::{http::StatusCode, routing::post, Extension, Json, Router};
-use utoipa::OpenApi;
+use axum::{extract::Path, http::StatusCode, routing::post, Extension, Json, Router};
+use serde::Deserialize;
+use utoipa::{OpenApi, ToSchema};
 use uuid::Uuid;
 
 use super::api_resource::RoutedResource;
 use crate::{
+    api::rest::{authentication::Authentication, problem::Problem},
     identifier::account::AccountId,
+    provenance::{OwnedById, UpdatedById},
     store::{AccountStore, StorePool},
 };
 
@@ -16,9 +19,10 @@ use crate::{
 #[openapi(
     paths(
         create_account_id,
+        add_account_member,
     ),
     components(
-        schemas(AccountId),
+        schemas(AccountId, AddAccountMemberRequest),
     ),
     tags(
         (name = "Account", description = "Account management API")
@@ -32,7 +36,9 @@ impl RoutedResource for AccountResource {
         // TODO: The URL format here is preliminary and will have to change.
         Router::new().nest(
             "/accounts",
-            Router::new().route("/", post(create_account_id::<P>)),
+            Router::new()
+                .route("/", post(create_account_id::<P>))
+                .route("/:account_id/members", post(add_account_member::<P>)),
         )
     }
 }
@@ -44,15 +50,17 @@ impl RoutedResource for AccountResource {
     responses(
         (status = 201, content_type = "application/json", description = "The schema of the created account", body = AccountId),
 
-        (status = 500, description = "Store error occurred"),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
 async fn create_account_id<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
-) -> Result<Json<AccountId>, StatusCode> {
+    authentication: Authentication,
+) -> Result<Json<AccountId>, Problem> {
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
     let account_id = AccountId::new(Uuid::new_v4());
@@ -62,9 +70,77 @@ async fn create_account_id<P: StorePool + Send>(
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create account id");
 
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::from_report(&report)
         })?;
 
+    // The authenticated account creating the account is allowed to write to its namespace.
+    if let Some(creator_id) = authentication.account_id() {
+        store
+            .insert_account_member(account_id, creator_id)
+            .await
+            .map_err(|report| {
+                tracing::error!(error=?report, "Could not add the creator to the account members");
+
+                Problem::from_report(&report)
+            })?;
+    }
+
     Ok(Json(account_id))
 }
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct AddAccountMemberRequest {
+    member_id: AccountId,
+    actor_id: UpdatedById,
+}
+
+#[utoipa::path(
+    post,
+    path = "/accounts/{accountId}/members",
+    tag = "Account",
+    request_body = AddAccountMemberRequest,
+    responses(
+        (status = 204, description = "The member was added to the account"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not a member of the account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+    params(
+        ("accountId" = AccountId, Path, description = "The ID of the account to add the member to"),
+    )
+)]
+async fn add_account_member<P: StorePool + Send>(
+    account_id: Path<AccountId>,
+    pool: Extension<Arc<P>>,
+    authentication: Authentication,
+    body: Json<AddAccountMemberRequest>,
+) -> Result<StatusCode, Problem> {
+    let Path(account_id) = account_id;
+    let Json(AddAccountMemberRequest {
+        member_id,
+        actor_id,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    authentication
+        .authorize_write(&store, actor_id, OwnedById::new(account_id))
+        .await?;
+
+    store
+        .insert_account_member(account_id, member_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not add account member");
+
+            Problem::from_report(&report)
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,314 @@
+//! Authentication of requests to the REST API.
+//!
+//! Authentication is pluggable: any [`Authenticator`] can be passed to [`rest_api_router`]. If an
+//! [`Authenticator`] is passed, every request has to be authenticated and the account the request
+//! is made on behalf of is used as the actor of write operations. Without an [`Authenticator`],
+//! requests are not authenticated and the actor specified in the request is trusted.
+//!
+//! [`rest_api_router`]: super::rest_api_router
+
+use std::{collections::HashMap, convert::Infallible, fmt, sync::Arc};
+
+use axum::{
+    async_trait,
+    body::Body,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap, HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use error_stack::{Context, Report};
+use sha2::{Digest, Sha256};
+use type_system::uri::VersionedUri;
+
+use super::problem::Problem;
+use crate::{
+    identifier::account::AccountId,
+    ontology::PersistedOntologyType,
+    provenance::{OwnedById, UpdatedById},
+    store::{
+        crud::Read,
+        query::{Filter, OntologyPath, QueryRecord, TemporalAxes},
+        AccountStore,
+    },
+};
+
+#[derive(Debug)]
+#[must_use]
+pub struct AuthenticationError;
+
+impl fmt::Display for AuthenticationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("the request could not be authenticated")
+    }
+}
+
+impl Context for AuthenticationError {}
+
+/// Verifies the credentials of a request.
+pub trait Authenticator: Send + Sync + 'static {
+    /// Returns the account the request with the specified `headers` is made on behalf of.
+    ///
+    /// # Errors
+    ///
+    /// - [`AuthenticationError`] if the request does not contain valid credentials
+    fn authenticate(&self, headers: &HeaderMap) -> Result<AccountId, Report<AuthenticationError>>;
+}
+
+/// Authenticates requests by a bearer token in the `Authorization` header.
+///
+/// The tokens are verified against a local key set, which maps each token to the account it was
+/// issued for. Only the SHA-256 digests of the tokens are kept and looked up, so the time a lookup
+/// takes does not reveal how much of a token matches a known token.
+#[derive(Debug, Default, Clone)]
+pub struct BearerTokenAuthenticator {
+    keys: HashMap<[u8; 32], AccountId>,
+}
+
+impl BearerTokenAuthenticator {
+    #[must_use]
+    pub fn new(keys: impl IntoIterator<Item = (String, AccountId)>) -> Self {
+        Self {
+            keys: keys
+                .into_iter()
+                .map(|(token, account_id)| (Self::digest(&token), account_id))
+                .collect(),
+        }
+    }
+
+    fn digest(token: &str) -> [u8; 32] {
+        Sha256::digest(token.as_bytes()).into()
+    }
+}
+
+impl Authenticator for BearerTokenAuthenticator {
+    fn authenticate(&self, headers: &HeaderMap) -> Result<AccountId, Report<AuthenticationError>> {
+        let header = headers.get(header::AUTHORIZATION).ok_or_else(|| {
+            Report::new(AuthenticationError).attach_printable("missing `Authorization` header")
+        })?;
+
+        let token = header
+            .to_str()
+            .ok()
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .ok_or_else(|| {
+                Report::new(AuthenticationError)
+                    .attach_printable("`Authorization` header is not a bearer token")
+            })?;
+
+        self.keys
+            .get(&Self::digest(token.trim()))
+            .copied()
+            .ok_or_else(|| {
+                Report::new(AuthenticationError).attach_printable("unknown bearer token")
+            })
+    }
+}
+
+/// The result of authenticating a request.
+///
+/// Contains the authenticated account if the API is run with an [`Authenticator`]. Can be used as
+/// an extractor in handlers and never rejects a request.
+#[derive(Debug, Copy, Clone, Default)]
+pub(super) struct Authentication {
+    account_id: Option<AccountId>,
+}
+
+impl Authentication {
+    /// Creates an `Authentication` of a request, which was authenticated as `account_id`.
+    #[cfg(test)]
+    pub(super) const fn authenticated(account_id: AccountId) -> Self {
+        Self {
+            account_id: Some(account_id),
+        }
+    }
+
+    /// Returns if the request was authenticated by an [`Authenticator`].
+    pub(super) const fn is_authenticated(self) -> bool {
+        self.account_id.is_some()
+    }
+
+    /// Returns the authenticated account if the request was authenticated.
+    pub(super) const fn account_id(self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Returns the actor of a write operation on behalf of `actor_id`.
+    ///
+    /// When the request is authenticated, the actor is derived from the request and has to match
+    /// `actor_id`.
+    pub(super) fn authorize_actor(self, actor_id: UpdatedById) -> Result<UpdatedById, StatusCode> {
+        match self.account_id {
+            Some(account_id) if account_id != actor_id.as_account_id() => {
+                tracing::error!(
+                    %account_id,
+                    %actor_id,
+                    "Authenticated account tried to act on behalf of another account"
+                );
+                Err(StatusCode::FORBIDDEN)
+            }
+            Some(account_id) => Ok(UpdatedById::new(account_id)),
+            None => Ok(actor_id),
+        }
+    }
+
+    /// Returns the actor of a write operation on behalf of `actor_id` into the namespace of
+    /// `owned_by_id`.
+    ///
+    /// In addition to [`authorize_actor`], the authenticated account has to be a member of the
+    /// account specified by `owned_by_id`.
+    ///
+    /// [`authorize_actor`]: Self::authorize_actor
+    pub(super) async fn authorize_write<S: AccountStore + Sync>(
+        self,
+        store: &S,
+        actor_id: UpdatedById,
+        owned_by_id: OwnedById,
+    ) -> Result<UpdatedById, StatusCode> {
+        let actor_id = self.authorize_actor(actor_id)?;
+
+        if self.account_id.is_none() {
+            return Ok(actor_id);
+        }
+
+        let is_member = store
+            .is_account_member(owned_by_id.as_account_id(), actor_id.as_account_id())
+            .await
+            .map_err(|report| {
+                tracing::error!(error=?report, "Could not read account members");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+
+        if is_member {
+            Ok(actor_id)
+        } else {
+            tracing::error!(
+                %actor_id,
+                %owned_by_id,
+                "Authenticated account is not a member of the owning account"
+            );
+            Err(StatusCode::FORBIDDEN)
+        }
+    }
+}
+
+/// Returns the account owning the ontology type specified by `type_id`.
+///
+/// Updates of an ontology type are written to the namespace of this account.
+pub(super) async fn ontology_type_owner<'q, S, T>(
+    store: &S,
+    type_id: &'q VersionedUri,
+) -> Result<OwnedById, StatusCode>
+where
+    S: Read<T> + Sync,
+    T: PersistedOntologyType + QueryRecord<Path<'q>: OntologyPath> + Send,
+    for<'p> T::Path<'p>: Sync,
+{
+    store
+        .read_one(
+            &Filter::for_versioned_uri(type_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .map(|ontology_type| ontology_type.metadata().owned_by_id())
+        .map_err(|report| {
+            tracing::error!(error=?report, %type_id, "Could not read the ontology type to update");
+            StatusCode::NOT_FOUND
+        })
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Authentication {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.extensions.get::<Self>().copied().unwrap_or_default())
+    }
+}
+
+/// Authenticates the request by the [`Authenticator`] installed as extension.
+///
+/// Rejects the request with `401 Unauthorized` if it could not be authenticated.
+pub(crate) async fn authenticate_request(mut request: Request<Body>, next: Next<Body>) -> Response {
+    let Some(authenticator) = request.extensions().get::<Arc<dyn Authenticator>>() else {
+        tracing::error!("Authentication is enabled but no authenticator is available");
+        return Problem::from(StatusCode::INTERNAL_SERVER_ERROR).into_response();
+    };
+
+    match authenticator.authenticate(request.headers()) {
+        Ok(account_id) => {
+            request.extensions_mut().insert(Authentication {
+                account_id: Some(account_id),
+            });
+            next.run(request).await
+        }
+        Err(report) => {
+            tracing::warn!(error=?report, "Could not authenticate request");
+            (
+                [(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"))],
+                Problem::from(StatusCode::UNAUTHORIZED),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn headers(authorization: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static(authorization),
+        );
+        headers
+    }
+
+    #[test]
+    fn bearer_token() {
+        let account_id = AccountId::new(Uuid::new_v4());
+        let authenticator = BearerTokenAuthenticator::new([("secret".to_owned(), account_id)]);
+
+        assert_eq!(
+            authenticator
+                .authenticate(&headers("Bearer secret"))
+                .expect("could not authenticate"),
+            account_id
+        );
+        assert!(authenticator.authenticate(&HeaderMap::new()).is_err());
+        assert!(authenticator.authenticate(&headers("secret")).is_err());
+        assert!(authenticator
+            .authenticate(&headers("Basic secret"))
+            .is_err());
+        assert!(authenticator
+            .authenticate(&headers("Bearer unknown"))
+            .is_err());
+    }
+
+    #[test]
+    fn authorize_actor() {
+        let account_id = AccountId::new(Uuid::new_v4());
+        let other_account_id = AccountId::new(Uuid::new_v4());
+
+        assert_eq!(
+            Authentication::default().authorize_actor(UpdatedById::new(other_account_id)),
+            Ok(UpdatedById::new(other_account_id))
+        );
+
+        let authentication = Authentication {
+            account_id: Some(account_id),
+        };
+        assert_eq!(
+            authentication.authorize_actor(UpdatedById::new(account_id)),
+            Ok(UpdatedById::new(account_id))
+        );
+        assert_eq!(
+            authentication.authorize_actor(UpdatedById::new(other_account_id)),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Web routes for applying batches of write operations.
+
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{http::StatusCode, routing::post, Extension, Json, Router};
+use serde::Deserialize;
+use type_system::uri::{BaseUri, VersionedUri};
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{
+        graphql::SchemaCache,
+        rest::{
+            api_resource::RoutedResource,
+            authentication::{ontology_type_owner, Authentication},
+            data_type::{CreateDataTypeRequest, UpdateDataTypeRequest},
+            entity_type::{CreateEntityTypeRequest, UpdateEntityTypeRequest},
+            problem::Problem,
+            property_type::{CreatePropertyTypeRequest, UpdatePropertyTypeRequest},
+        },
+    },
+    knowledge::{EntityLinkOrder, EntityProperties, EntityUuid},
+    ontology::{
+        domain_validator::DomainValidator, CompatibilityPolicy, DataTypeWithMetadata,
+        EntityTypeWithMetadata, PropertyTypeWithMetadata,
+    },
+    provenance::{OwnedById, UpdatedById},
+    store::{
+        BatchLinkData, BatchOperation, BatchOperationResult, BatchStore, EntityReference, Store,
+        StorePool,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(apply_batch),
+    components(
+        schemas(
+            BatchOperationRequest,
+            BatchCreateEntityRequest,
+            BatchUpdateEntityRequest,
+            BatchLinkData,
+            BatchOperationResult,
+            EntityReference,
+        )
+    ),
+    tags(
+        (name = "Batch", description = "Batch write API")
+    )
+)]
+pub struct BatchResource;
+
+impl RoutedResource for BatchResource {
+    /// Create routes for applying batches of operations.
+    fn routes<P: StorePool + Send + 'static>() -> Router {
+        Router::new().route("/batch", post(apply_batch::<P>))
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BatchCreateEntityRequest {
+    properties: EntityProperties,
+    #[schema(value_type = String)]
+    entity_type_id: VersionedUri,
+    owned_by_id: OwnedById,
+    entity_uuid: Option<EntityUuid>,
+    actor_id: UpdatedById,
+    #[serde(default)]
+    link_data: Option<BatchLinkData>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct BatchUpdateEntityRequest {
+    properties: EntityProperties,
+    entity: EntityReference,
+    #[schema(value_type = String)]
+    entity_type_id: VersionedUri,
+    actor_id: UpdatedById,
+    #[serde(flatten)]
+    order: EntityLinkOrder,
+    #[serde(default)]
+    archived: bool,
+}
+
+/// A single operation of a batch.
+///
+/// The operations have the same shape as the requests of the corresponding routes, except that
+/// entities are referenced by an [`EntityReference`], so operations can refer to entities created
+/// by preceding operations.
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+enum BatchOperationRequest {
+    CreateDataType(CreateDataTypeRequest),
+    UpdateDataType(UpdateDataTypeRequest),
+    CreatePropertyType(CreatePropertyTypeRequest),
+    UpdatePropertyType(UpdatePropertyTypeRequest),
+    CreateEntityType(CreateEntityTypeRequest),
+    UpdateEntityType(UpdateEntityTypeRequest),
+    CreateEntity(BatchCreateEntityRequest),
+    UpdateEntity(BatchUpdateEntityRequest),
+}
+
+impl BatchOperationRequest {
+    fn into_batch_operation(
+        self,
+        domain_validator: &DomainValidator,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<BatchOperation, Problem> {
+        match self {
+            Self::CreateDataType(request) => request.into_batch_operation(domain_validator),
+            Self::UpdateDataType(request) => request.into_batch_operation(compatibility_policy),
+            Self::CreatePropertyType(request) => request.into_batch_operation(domain_validator),
+            Self::UpdatePropertyType(request) => request.into_batch_operation(compatibility_policy),
+            Self::CreateEntityType(request) => request.into_batch_operation(domain_validator),
+            Self::UpdateEntityType(request) => request.into_batch_operation(compatibility_policy),
+            Self::CreateEntity(BatchCreateEntityRequest {
+                properties,
+                entity_type_id,
+                owned_by_id,
+                entity_uuid,
+                actor_id,
+                link_data,
+            }) => Ok(BatchOperation::CreateEntity {
+                owned_by_id,
+                entity_uuid,
+                actor_id,
+                entity_type_id,
+                properties,
+                link_data,
+            }),
+            Self::UpdateEntity(BatchUpdateEntityRequest {
+                properties,
+                entity,
+                entity_type_id,
+                actor_id,
+                order,
+                archived,
+            }) => Ok(BatchOperation::UpdateEntity {
+                entity,
+                actor_id,
+                archived,
+                entity_type_id,
+                properties,
+                link_order: order,
+            }),
+        }
+    }
+}
+
+/// Checks that the request is allowed to apply every operation of the batch.
+///
+/// Entities referenced by an [`EntityReference::Operation`] and ontology types created earlier in
+/// the batch were already authorized by the operation creating them.
+async fn authorize_batch<S: Store + Sync>(
+    store: &S,
+    authentication: Authentication,
+    operations: &[BatchOperation],
+) -> Result<(), StatusCode> {
+    let mut created_ontology_types = HashMap::<BaseUri, OwnedById>::new();
+
+    for operation in operations {
+        match operation {
+            BatchOperation::CreateDataType {
+                data_type,
+                owned_by_id,
+                actor_id,
+            } => {
+                authentication
+                    .authorize_write(store, *actor_id, *owned_by_id)
+                    .await?;
+                created_ontology_types.insert(data_type.id().base_uri().clone(), *owned_by_id);
+            }
+            BatchOperation::CreatePropertyType {
+                property_type,
+                owned_by_id,
+                actor_id,
+            } => {
+                authentication
+                    .authorize_write(store, *actor_id, *owned_by_id)
+                    .await?;
+                created_ontology_types.insert(property_type.id().base_uri().clone(), *owned_by_id);
+            }
+            BatchOperation::CreateEntityType {
+                entity_type,
+                owned_by_id,
+                actor_id,
+            } => {
+                authentication
+                    .authorize_write(store, *actor_id, *owned_by_id)
+                    .await?;
+                created_ontology_types.insert(entity_type.id().base_uri().clone(), *owned_by_id);
+            }
+            BatchOperation::UpdateDataType {
+                data_type,
+                actor_id,
+                ..
+            } => {
+                let owned_by_id = match created_ontology_types.get(data_type.id().base_uri()) {
+                    Some(owned_by_id) => *owned_by_id,
+                    None => {
+                        ontology_type_owner::<_, DataTypeWithMetadata>(
+                            store,
+                            &previous_version(data_type.id()),
+                        )
+                        .await?
+                    }
+                };
+                authentication
+                    .authorize_write(store, *actor_id, owned_by_id)
+                    .await?;
+            }
+            BatchOperation::UpdatePropertyType {
+                property_type,
+                actor_id,
+                ..
+            } => {
+                let owned_by_id = match created_ontology_types.get(property_type.id().base_uri()) {
+                    Some(owned_by_id) => *owned_by_id,
+                    None => {
+                        ontology_type_owner::<_, PropertyTypeWithMetadata>(
+                            store,
+                            &previous_version(property_type.id()),
+                        )
+                        .await?
+                    }
+                };
+                authentication
+                    .authorize_write(store, *actor_id, owned_by_id)
+                    .await?;
+            }
+            BatchOperation::UpdateEntityType {
+                entity_type,
+                actor_id,
+                ..
+            } => {
+                let owned_by_id = match created_ontology_types.get(entity_type.id().base_uri()) {
+                    Some(owned_by_id) => *owned_by_id,
+                    None => {
+                        ontology_type_owner::<_, EntityTypeWithMetadata>(
+                            store,
+                            &previous_version(entity_type.id()),
+                        )
+                        .await?
+                    }
+                };
+                authentication
+                    .authorize_write(store, *actor_id, owned_by_id)
+                    .await?;
+            }
+            BatchOperation::CreateEntity {
+                owned_by_id,
+                actor_id,
+                ..
+            } => {
+                authentication
+                    .authorize_write(store, *actor_id, *owned_by_id)
+                    .await?;
+            }
+            BatchOperation::UpdateEntity {
+                entity: EntityReference::EntityId(entity_id),
+                actor_id,
+                ..
+            } => {
+                authentication
+                    .authorize_write(store, *actor_id, entity_id.owned_by_id())
+                    .await?;
+            }
+            BatchOperation::UpdateEntity {
+                entity: EntityReference::Operation(_),
+                actor_id,
+                ..
+            } => {
+                authentication.authorize_actor(*actor_id)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn previous_version(type_id: &VersionedUri) -> VersionedUri {
+    VersionedUri::new(
+        type_id.base_uri().clone(),
+        type_id.version().saturating_sub(1),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/batch",
+    request_body = [BatchOperationRequest],
+    tag = "Batch",
+    responses(
+        (status = 200, content_type = "application/json", description = "The results of the operations in the order of the operations", body = [BatchOperationResult]),
+        (status = 400, content_type = "application/problem+json", description = "Provided entity does not conform to its entity type", body = Problem),
+        (status = 409, content_type = "application/problem+json", description = "The base URI of a created ontology type already exists or an ontology type update was rejected by its compatibility policy", body = Problem),
+        (status = 422, content_type = "application/problem+json", description = "Provided request body is invalid or an entity reference does not refer to a preceding entity operation", body = Problem),
+        (status = 423, content_type = "application/problem+json", description = "An entity that should be updated was unexpectedly updated at the same time", body = Problem),
+
+        (status = 404, content_type = "application/problem+json", description = "An updated ontology type or entity was not found", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor of an operation is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+)]
+async fn apply_batch<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    domain_validator: Extension<DomainValidator>,
+    Extension(compatibility_policy): Extension<CompatibilityPolicy>,
+    schema_cache: Extension<SchemaCache>,
+    authentication: Authentication,
+    body: Json<Vec<BatchOperationRequest>>,
+) -> Result<Json<Vec<BatchOperationResult>>, Problem> {
+    let Json(operations) = body;
+
+    let operations = operations
+        .into_iter()
+        .map(|operation| operation.into_batch_operation(&domain_validator, compatibility_policy))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let changes_entity_types = operations.iter().any(|operation| {
+        matches!(
+            operation,
+            BatchOperation::CreateEntityType { .. } | BatchOperation::UpdateEntityType { .. }
+        )
+    });
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    if authentication.is_authenticated() {
+        authorize_batch(&store, authentication, &operations).await?;
+    }
+
+    let results = store.apply_batch(operations).await.map_err(|report| {
+        tracing::error!(error=?report, "Could not apply batch");
+
+        Problem::from_report(&report)
+    })?;
+
+    if changes_entity_types {
+        schema_cache.invalidate();
+    }
+
+    Ok(Json(results))
+}
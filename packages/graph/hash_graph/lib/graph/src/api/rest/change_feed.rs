@@ -0,0 +1,117 @@
+//! Web routes for reading the change feed of the graph.
+
+use std::{sync::Arc, time::Duration};
+
+use axum::{extract::Query, routing::get, Extension, Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep, Instant};
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::rest::{api_resource::RoutedResource, problem::Problem},
+    store::{ChangeEvent, ChangeFeedStore, ChangeKind, ChangeOffset, ChangedElement, StorePool},
+};
+
+/// Number of events returned if no limit is specified.
+const DEFAULT_LIMIT: usize = 100;
+/// Maximum number of events returned by a single request.
+const MAX_LIMIT: usize = 1000;
+/// Maximum time a request waits for new events.
+const MAX_TIMEOUT: Duration = Duration::from_secs(60);
+/// Interval in which the change feed is polled while waiting for new events.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_changes,
+    ),
+    components(
+        schemas(ChangeEvent, ChangeKind, ChangeOffset, ChangedElement, ChangeFeedResponse),
+    ),
+    tags(
+        (name = "ChangeFeed", description = "Change feed API")
+    )
+)]
+pub struct ChangeFeedResource;
+
+impl RoutedResource for ChangeFeedResource {
+    /// Create routes for reading the change feed.
+    fn routes<P: StorePool + Send + 'static>() -> Router {
+        Router::new().route("/changes", get(get_changes::<P>))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangeFeedParameters {
+    after: Option<ChangeOffset>,
+    limit: Option<usize>,
+    timeout: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct ChangeFeedResponse {
+    events: Vec<ChangeEvent>,
+    /// The offset to pass as `after` to continue reading the change feed.
+    ///
+    /// This is the offset of the last returned event or the requested offset if no event was
+    /// returned.
+    next_offset: Option<ChangeOffset>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/changes",
+    tag = "ChangeFeed",
+    responses(
+        (status = 200, content_type = "application/json", description = "The events of the change feed after the event with the specified offset in the order they were recorded. An event is only returned once all writes, which started before it, have finished, so a long-running write, e.g. an import or a large batch, delays all events recorded after it was started", body = ChangeFeedResponse),
+        (status = 400, content_type = "text/plain", description = "Provided query parameters are invalid"),
+        (status = 404, content_type = "application/problem+json", description = "There is no event with the offset specified by `after`", body = Problem),
+
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+    params(
+        ("after" = Option<i64>, Query, description = "Only return events after the event with this offset. If not specified, the change feed is read from the beginning"),
+        ("limit" = Option<usize>, Query, description = "The maximum number of events to return, at most 1000. Defaults to 100"),
+        ("timeout" = Option<u64>, Query, description = "The number of seconds to wait for new events if there are none, at most 60. Defaults to 0"),
+    )
+)]
+async fn get_changes<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    parameters: Query<ChangeFeedParameters>,
+) -> Result<Json<ChangeFeedResponse>, Problem> {
+    let Query(ChangeFeedParameters {
+        after,
+        limit,
+        timeout,
+    }) = parameters;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let deadline = Instant::now() + Duration::from_secs(timeout.unwrap_or(0)).min(MAX_TIMEOUT);
+
+    loop {
+        // The store is acquired for every poll to not block a connection while waiting.
+        let store = pool.acquire().await.map_err(|report| {
+            tracing::error!(error=?report, "Could not acquire store");
+            Problem::store_unavailable()
+        })?;
+
+        let events = store.read_changes(after, limit).await.map_err(|report| {
+            tracing::error!(error=?report, "Could not read the change feed");
+            Problem::from_report(&report)
+        })?;
+        drop(store);
+
+        if !events.is_empty() || Instant::now() >= deadline {
+            let next_offset = events.last().map(ChangeEvent::offset).or(after);
+            return Ok(Json(ChangeFeedResponse {
+                events,
+                next_offset,
+            }));
+        }
+
+        sleep(POLL_INTERVAL.min(deadline - Instant::now())).await;
+    }
+}
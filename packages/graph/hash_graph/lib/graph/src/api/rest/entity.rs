@@ -1,10 +1,11 @@
-//! Web routes for CRU operations on entities.
+//! Web routes for CRUD operations on entities.
 
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use axum::{
-    extract::Path,
-    http::StatusCode,
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Extension, Json, Router,
 };
@@ -15,17 +16,22 @@ use utoipa::{OpenApi, ToSchema};
 
 use crate::{
     api::rest::{
+        accepts_ndjson,
         api_resource::RoutedResource,
-        read_from_store, report_to_status_code,
+        authentication::Authentication,
+        problem::Problem,
+        read_from_store, stream_vertices,
         utoipa_typedef::subgraph::{
             Edges, KnowledgeGraphRootedEdges, KnowledgeGraphVertices, OntologyRootedEdges,
-            OntologyVertices, Subgraph, Vertices,
+            OntologyVertices, SearchRank, Subgraph, Vertices,
         },
+        validate_pagination,
     },
     identifier::{
         knowledge::{
             EntityEditionId, EntityId, EntityIdAndTimestamp, EntityRecordId, EntityVersion,
         },
+        ontology::OntologyTypeEditionId,
         GraphElementEditionId, GraphElementId,
     },
     knowledge::{
@@ -34,17 +40,18 @@ use crate::{
     },
     provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
     store::{
-        error::{EntityDoesNotExist, RaceConditionOnUpdate},
-        query::Filter,
-        EntityStore, StorePool,
+        crud::Read,
+        diff_properties,
+        query::{Aggregate, AggregationGroup, Cursor, Filter, Pagination, TemporalAxes},
+        EntityHistoryStore, EntityMigration, EntityMigrationStore, EntityStore, LinkPurgeBehavior,
+        MigratedEntity, PropertyMapping, PropertyPatch, StorePool,
     },
     subgraph::{
         edges::{
             EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind,
-            KnowledgeGraphOutwardEdges, OntologyEdgeKind, OntologyOutwardEdges,
-            OutgoingEdgeResolveDepth, SharedEdgeKind,
+            KnowledgeGraphOutwardEdges, OntologyEdgeKind, OntologyOutwardEdges, SharedEdgeKind,
         },
-        query::{EntityStructuralQuery, StructuralQuery},
+        query::{AggregationQuery, EntityAggregationQuery, EntityStructuralQuery, StructuralQuery},
         vertices::{KnowledgeGraphVertex, OntologyVertex, Vertex},
     },
 };
@@ -54,10 +61,16 @@ use crate::{
     paths(
         create_entity,
         get_entities_by_query,
+        aggregate_entities,
         get_entity,
         get_latest_entities,
         update_entity,
-        archive_entity
+        archive_entity,
+        purge_entity,
+        migrate_entities,
+        get_entity_history,
+        diff_entity_editions,
+        restore_entity_edition
     ),
     components(
         schemas(
@@ -66,6 +79,15 @@ use crate::{
             CreateEntityRequest,
             UpdateEntityRequest,
             ArchiveEntityRequest,
+            PurgeEntityRequest,
+            PurgeEntityResponse,
+            LinkPurgeBehavior,
+            MigrateEntitiesRequest,
+            EntityMigration,
+            PropertyMapping,
+            MigratedEntity,
+            RestoreEntityEditionRequest,
+            PropertyPatch,
             EntityUuid,
             EntityId,
             EntityEditionId,
@@ -77,6 +99,11 @@ use crate::{
             EntityRecordId,
             EntityVersion,
             EntityStructuralQuery,
+            EntityAggregationQuery,
+            AggregationGroup,
+            Pagination,
+            TemporalAxes,
+            Cursor,
             EntityQueryToken,
             LinkData,
             LinkOrder,
@@ -99,8 +126,8 @@ use crate::{
             Edges,
             GraphResolveDepths,
             EdgeResolveDepths,
-            OutgoingEdgeResolveDepth,
             Subgraph,
+            SearchRank,
         )
     ),
     tags(
@@ -124,8 +151,14 @@ impl RoutedResource for EntityResource {
                         .put(update_entity::<P>),
                 )
                 .route("/archive", post(archive_entity::<P>))
+                .route("/purge", post(purge_entity::<P>))
+                .route("/migrate", post(migrate_entities::<P>))
                 .route("/query", post(get_entities_by_query::<P>))
-                .route("/:entity_uuid", get(get_entity::<P>)),
+                .route("/aggregate", post(aggregate_entities::<P>))
+                .route("/restore", post(restore_entity_edition::<P>))
+                .route("/:entity_uuid", get(get_entity::<P>))
+                .route("/:entity_uuid/history", get(get_entity_history::<P>))
+                .route("/:entity_uuid/diff", get(diff_entity_editions::<P>)),
         )
     }
 }
@@ -152,16 +185,20 @@ struct CreateEntityRequest {
     tag = "Entity",
     responses(
         (status = 201, content_type = "application/json", description = "The metadata of the created entity", body = EntityMetadata),
+        (status = 400, content_type = "application/problem+json", description = "Provided entity does not conform to its entity type", body = Problem),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
-        (status = 404, description = "Entity Type URI was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Entity Type URI was not found", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
 )]
 async fn create_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
+    authentication: Authentication,
     body: Json<CreateEntityRequest>,
-) -> Result<Json<EntityMetadata>, StatusCode> {
+) -> Result<Json<EntityMetadata>, Problem> {
     let Json(CreateEntityRequest {
         properties,
         entity_type_id,
@@ -173,9 +210,13 @@ async fn create_entity<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, owned_by_id)
+        .await?;
+
     store
         .create_entity(
             owned_by_id,
@@ -191,8 +232,7 @@ async fn create_entity<P: StorePool + Send>(
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create entity");
 
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::from_report(&report)
         })
         .map(Json)
 }
@@ -213,15 +253,18 @@ struct ArchiveEntityRequest {
         (status = 200, content_type = "application/json", description = "No response"),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
-        (status = 404, description = "Entity could not be found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Entity could not be found", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
 )]
 #[deprecated = "use `/entities/update` instead"]
 async fn archive_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
+    authentication: Authentication,
     body: Json<ArchiveEntityRequest>,
-) -> Result<(), StatusCode> {
+) -> Result<(), Problem> {
     let Json(ArchiveEntityRequest {
         entity_id,
         actor_id,
@@ -234,13 +277,21 @@ async fn archive_entity<P: StorePool + Send>(
         &Filter::for_latest_entity_by_entity_id(entity_id),
     )
     .await
-    .and_then(|mut entities| entities.pop().ok_or(StatusCode::NOT_FOUND))?;
+    .and_then(|mut entities| {
+        entities
+            .pop()
+            .ok_or_else(|| Problem::from(StatusCode::NOT_FOUND))
+    })?;
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, entity_id.owned_by_id())
+        .await?;
+
     store
         .update_entity(
             entity_id,
@@ -264,31 +315,124 @@ async fn archive_entity<P: StorePool + Send>(
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update entity");
 
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::from_report(&report)
         })?;
     Ok(())
 }
 
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct PurgeEntityRequest {
+    entity_id: EntityId,
+    actor_id: UpdatedById,
+    #[serde(default)]
+    link_behavior: LinkPurgeBehavior,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct PurgeEntityResponse {
+    purged_entity_ids: Vec<EntityId>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/purge",
+    request_body = PurgeEntityRequest,
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The IDs of all purged entities, including purged link entities", body = PurgeEntityResponse),
+        (status = 409, content_type = "application/problem+json", description = "The entity is referenced by link entities and `linkBehavior` is `refuse` or the entities to purge changed during the request", body = Problem),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 404, content_type = "application/problem+json", description = "Entity could not be found", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of an account owning a purged entity", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+)]
+async fn purge_entity<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    authentication: Authentication,
+    body: Json<PurgeEntityRequest>,
+) -> Result<Json<PurgeEntityResponse>, Problem> {
+    let Json(PurgeEntityRequest {
+        entity_id,
+        actor_id,
+        link_behavior,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    // Purging cascades to link entities, which may be owned by other accounts, so the actor has
+    // to be allowed to write to the namespaces of all entities, which would be purged. The store
+    // refuses the purge if these entities changed until the purge is executed.
+    let authorized_entity_ids = match link_behavior {
+        LinkPurgeBehavior::Refuse => vec![entity_id],
+        LinkPurgeBehavior::Cascade => {
+            store
+                .read_purged_entity_ids(entity_id)
+                .await
+                .map_err(|report| {
+                    tracing::error!(error=?report, "Could not read entities to purge");
+                    Problem::from_report(&report)
+                })?
+        }
+    };
+    let owners = authorized_entity_ids
+        .iter()
+        .map(|purged_entity_id| purged_entity_id.owned_by_id())
+        .collect::<HashSet<_>>();
+
+    let mut actor_id = authentication.authorize_actor(actor_id)?;
+    for owned_by_id in owners {
+        actor_id = authentication
+            .authorize_write(&store, actor_id, owned_by_id)
+            .await?;
+    }
+
+    store
+        .purge_entity(entity_id, actor_id, link_behavior, &authorized_entity_ids)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not purge entity");
+
+            Problem::from_report(&report)
+        })
+        .map(|purged_entity_ids| Json(PurgeEntityResponse { purged_entity_ids }))
+}
+
 #[utoipa::path(
     post,
     path = "/entities/query",
     request_body = EntityStructuralQuery,
     tag = "Entity",
     responses(
-        (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at entities that satisfy the given query, each resolved to the requested depth."),
-        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
-        (status = 500, description = "Store error occurred"),
+        (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at entities that satisfy the given query, each resolved to the requested depth. If `application/x-ndjson` is accepted, the vertices matching the filter are streamed as newline-delimited JSON instead."),
+        (status = 400, content_type = "application/problem+json", description = "Graph resolve depths were requested for a streamed response or the page size is out of range", body = Problem),
+        (status = 422, content_type = "application/problem+json", description = "Provided query is invalid", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
-async fn get_entities_by_query<P: StorePool + Send>(
+async fn get_entities_by_query<P: StorePool + Send + 'static>(
     pool: Extension<Arc<P>>,
+    headers: HeaderMap,
     Json(query): Json<serde_json::Value>,
-) -> Result<Json<Subgraph>, StatusCode> {
+) -> Result<Response, Problem> {
+    if accepts_ndjson(&headers) {
+        return stream_vertices::<_, Entity>(&**pool, query, |entity| {
+            Vertex::KnowledgeGraph(Box::new(KnowledgeGraphVertex::Entity(entity)))
+        })
+        .await;
+    }
+
     pool.acquire()
         .map_err(|error| {
             tracing::error!(?error, "Could not acquire access to the store");
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::store_unavailable()
         })
         .and_then(|store| async move {
             let mut query = StructuralQuery::deserialize(&query).map_err(|error| {
@@ -299,13 +443,61 @@ async fn get_entities_by_query<P: StorePool + Send>(
                 tracing::error!(?error, "Could not validate query");
                 StatusCode::INTERNAL_SERVER_ERROR
             })?;
+            validate_pagination(query.pagination.as_ref())?;
             store.get_entity(&query).await.map_err(|report| {
                 tracing::error!(error=?report, ?query, "Could not read entities from the store");
-                report_to_status_code(&report)
+                Problem::from_report(&report)
             })
         })
         .await
-        .map(|subgraph| Json(subgraph.into()))
+        .map(|subgraph| Json(Subgraph::from(subgraph)).into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/aggregate",
+    request_body = EntityAggregationQuery,
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", body = [AggregationGroup], description = "The aggregates computed for every group of the entities, which satisfy the given query"),
+        (status = 400, content_type = "application/problem+json", description = "An aggregate requires a path resolving to numbers, but the path resolves to another type", body = Problem),
+        (status = 422, content_type = "application/problem+json", description = "Provided query is invalid", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    )
+)]
+async fn aggregate_entities<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    Json(query): Json<serde_json::Value>,
+) -> Result<Json<Vec<AggregationGroup>>, Problem> {
+    pool.acquire()
+        .map_err(|error| {
+            tracing::error!(?error, "Could not acquire access to the store");
+            Problem::store_unavailable()
+        })
+        .and_then(|store| async move {
+            let mut query = AggregationQuery::deserialize(&query).map_err(|error| {
+                tracing::error!(?error, "Could not deserialize query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            query.filter.convert_parameters().map_err(|error| {
+                tracing::error!(?error, "Could not validate query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            query
+                .aggregates
+                .iter()
+                .try_for_each(Aggregate::validate)
+                .map_err(|report| {
+                    tracing::error!(error=?report, "Invalid aggregate");
+                    StatusCode::BAD_REQUEST
+                })?;
+            store.aggregate_entities(&query).await.map_err(|report| {
+                tracing::error!(error=?report, ?query, "Could not aggregate entities");
+                Problem::from_report(&report)
+            })
+        })
+        .await
+        .map(Json)
 }
 
 #[utoipa::path(
@@ -315,13 +507,13 @@ async fn get_entities_by_query<P: StorePool + Send>(
     responses(
         (status = 200, content_type = "application/json", description = "List of all entities", body = [Entity]),
 
-        (status = 500, description = "Store error occurred"),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
 #[deprecated = "use `/entities/query` instead"]
 async fn get_latest_entities<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
-) -> Result<Json<Vec<Entity>>, StatusCode> {
+) -> Result<Json<Vec<Entity>>, Problem> {
     read_from_store(pool.as_ref(), &Filter::for_all_latest_entities())
         .await
         .map(Json)
@@ -335,8 +527,8 @@ async fn get_latest_entities<P: StorePool + Send>(
         (status = 200, content_type = "application/json", description = "The latest version of the requested entity", body = Entity),
 
         (status = 400, content_type = "text/plain", description = "Provided entity id is invalid"),
-        (status = 404, description = "Entity was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Entity was not found", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     params(
         ("entityId" = EntityId, Path, description = "The EntityId"),
@@ -346,13 +538,17 @@ async fn get_latest_entities<P: StorePool + Send>(
 async fn get_entity<P: StorePool + Send>(
     Path(entity_id): Path<EntityId>,
     pool: Extension<Arc<P>>,
-) -> Result<Json<Entity>, StatusCode> {
+) -> Result<Json<Entity>, Problem> {
     read_from_store(
         pool.as_ref(),
         &Filter::for_latest_entity_by_entity_id(entity_id),
     )
     .await
-    .and_then(|mut entities| entities.pop().ok_or(StatusCode::NOT_FOUND))
+    .and_then(|mut entities| {
+        entities
+            .pop()
+            .ok_or_else(|| Problem::from(StatusCode::NOT_FOUND))
+    })
     .map(Json)
 }
 
@@ -378,18 +574,22 @@ struct UpdateEntityRequest {
     tag = "Entity",
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the updated entity", body = EntityMetadata),
+        (status = 400, content_type = "application/problem+json", description = "Provided entity does not conform to its entity type", body = Problem),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
-        (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
+        (status = 423, content_type = "application/problem+json", description = "The entity that should be updated was unexpectedly updated at the same time", body = Problem),
 
-        (status = 404, description = "Entity ID or Entity Type URI was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Entity ID or Entity Type URI was not found", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     request_body = UpdateEntityRequest,
 )]
 async fn update_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
+    authentication: Authentication,
     body: Json<UpdateEntityRequest>,
-) -> Result<Json<EntityMetadata>, StatusCode> {
+) -> Result<Json<EntityMetadata>, Problem> {
     let Json(UpdateEntityRequest {
         properties,
         entity_id,
@@ -401,9 +601,13 @@ async fn update_entity<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, entity_id.owned_by_id())
+        .await?;
+
     store
         .update_entity(
             entity_id,
@@ -418,14 +622,399 @@ async fn update_entity<P: StorePool + Send>(
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update entity");
 
-            if report.contains::<EntityDoesNotExist>() {
-                StatusCode::NOT_FOUND
-            } else if report.contains::<RaceConditionOnUpdate>() {
-                StatusCode::LOCKED
-            } else {
-                // Insertion/update errors are considered internal server errors.
-                StatusCode::INTERNAL_SERVER_ERROR
-            }
+            Problem::from_report(&report)
+        })
+        .map(Json)
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct MigrateEntitiesRequest {
+    migration: EntityMigration,
+    actor_id: UpdatedById,
+    /// If `true`, the migrated entities are returned without persisting them.
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/migrate",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The migrated entities", body = [MigratedEntity]),
+        (status = 400, content_type = "application/problem+json", description = "The migration does not target a newer version of the same entity type or a migrated entity does not conform to the target entity type", body = Problem),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "application/problem+json", description = "An entity that should be migrated was unexpectedly updated at the same time", body = Problem),
+
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of an account owning an affected entity", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+    request_body = MigrateEntitiesRequest,
+)]
+async fn migrate_entities<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    authentication: Authentication,
+    body: Json<MigrateEntitiesRequest>,
+) -> Result<Json<Vec<MigratedEntity>>, Problem> {
+    let Json(MigrateEntitiesRequest {
+        migration,
+        actor_id,
+        dry_run,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    // Every entity is updated in the namespace of its owning account, so the actor has to be
+    // allowed to write to the namespaces of all affected entities.
+    let source_entity_type_id = OntologyTypeEditionId::from(migration.source_entity_type_id());
+    let owners = Read::<Entity>::read(
+        &store,
+        &Filter::for_latest_entities_by_entity_type(&source_entity_type_id),
+        &TemporalAxes::default(),
+    )
+    .await
+    .map_err(|report| {
+        tracing::error!(error=?report, "Could not read entities to migrate");
+        Problem::from_report(&report)
+    })?
+    .iter()
+    .map(|entity| entity.metadata().edition_id().base_id().owned_by_id())
+    .collect::<HashSet<_>>();
+
+    let mut actor_id = authentication.authorize_actor(actor_id)?;
+    for owned_by_id in owners {
+        actor_id = authentication
+            .authorize_write(&store, actor_id, owned_by_id)
+            .await?;
+    }
+
+    store
+        .migrate_entities(&migration, actor_id, dry_run)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not migrate entities");
+
+            Problem::from_report(&report)
         })
         .map(Json)
 }
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entityId}/history",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "All editions of the requested entity ordered by their record ID", body = [Entity]),
+
+        (status = 400, content_type = "text/plain", description = "Provided entity id is invalid"),
+        (status = 404, content_type = "application/problem+json", description = "Entity was not found", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+    params(
+        ("entityId" = EntityId, Path, description = "The EntityId"),
+    )
+)]
+async fn get_entity_history<P: StorePool + Send>(
+    Path(entity_id): Path<EntityId>,
+    pool: Extension<Arc<P>>,
+) -> Result<Json<Vec<Entity>>, Problem> {
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    let editions = store
+        .read_entity_history(entity_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read entity history");
+            Problem::from_report(&report)
+        })?;
+
+    if editions.is_empty() {
+        return Err(Problem::from(StatusCode::NOT_FOUND));
+    }
+
+    Ok(Json(editions))
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiffEntityEditionsQuery {
+    from: EntityRecordId,
+    to: EntityRecordId,
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entityId}/diff",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The JSON Patch operations, which change the properties of the edition `from` into the properties of the edition `to`", body = [PropertyPatch]),
+
+        (status = 400, content_type = "text/plain", description = "Provided entity id or record IDs are invalid"),
+        (status = 404, content_type = "application/problem+json", description = "Entity or one of the editions was not found", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+    params(
+        ("entityId" = EntityId, Path, description = "The EntityId"),
+        ("from" = EntityRecordId, Query, description = "The record ID of the edition to compare from"),
+        ("to" = EntityRecordId, Query, description = "The record ID of the edition to compare to"),
+    )
+)]
+async fn diff_entity_editions<P: StorePool + Send>(
+    Path(entity_id): Path<EntityId>,
+    Query(DiffEntityEditionsQuery { from, to }): Query<DiffEntityEditionsQuery>,
+    pool: Extension<Arc<P>>,
+) -> Result<Json<Vec<PropertyPatch>>, Problem> {
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    let editions = store
+        .read_entity_history(entity_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read entity history");
+            Problem::from_report(&report)
+        })?;
+
+    let find_edition = |record_id: EntityRecordId| {
+        editions
+            .iter()
+            .find(|entity| entity.metadata().edition_id().record_id() == record_id)
+            .ok_or(StatusCode::NOT_FOUND)
+    };
+
+    Ok(Json(diff_properties(
+        find_edition(from)?.properties(),
+        find_edition(to)?.properties(),
+    )))
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RestoreEntityEditionRequest {
+    entity_id: EntityId,
+    record_id: EntityRecordId,
+    actor_id: UpdatedById,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/restore",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the new edition, which restores the requested edition", body = EntityMetadata),
+        (status = 400, content_type = "application/problem+json", description = "The restored edition does not conform to its entity type", body = Problem),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "application/problem+json", description = "The entity that should be restored was unexpectedly updated at the same time", body = Problem),
+
+        (status = 404, content_type = "application/problem+json", description = "Entity or edition was not found", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
+    ),
+    request_body = RestoreEntityEditionRequest,
+)]
+async fn restore_entity_edition<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    authentication: Authentication,
+    body: Json<RestoreEntityEditionRequest>,
+) -> Result<Json<EntityMetadata>, Problem> {
+    let Json(RestoreEntityEditionRequest {
+        entity_id,
+        record_id,
+        actor_id,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        Problem::store_unavailable()
+    })?;
+
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, entity_id.owned_by_id())
+        .await?;
+
+    store
+        .restore_entity_edition(entity_id, record_id, actor_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not restore entity edition");
+
+            Problem::from_report(&report)
+        })
+        .map(Json)
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_test_data::{data_type, entity, entity_type, property_type};
+    use type_system::{repr, uri::BaseUri, DataType, EntityType, PropertyType};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        identifier::account::AccountId,
+        store::{
+            AccountStore, DataTypeStore, EntityTypeStore, MemoryStore, MemoryStorePool,
+            PropertyTypeStore,
+        },
+    };
+
+    fn entity_type_id(name: &str) -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new(format!(
+                "https://blockprotocol.org/@alice/types/entity-type/{name}/"
+            ))
+            .expect("invalid base uri"),
+            1,
+        )
+    }
+
+    async fn seed_ontology(store: &mut MemoryStore, account_id: AccountId) {
+        let owned_by_id = OwnedById::new(account_id);
+        let actor_id = UpdatedById::new(account_id);
+
+        let data_type: repr::DataType =
+            serde_json::from_str(data_type::TEXT_V1).expect("could not parse data type");
+        store
+            .create_data_type(
+                DataType::try_from(data_type).expect("could not parse data type"),
+                owned_by_id,
+                actor_id,
+            )
+            .await
+            .expect("could not create data type");
+
+        let property_type: repr::PropertyType =
+            serde_json::from_str(property_type::NAME_V1).expect("could not parse property type");
+        store
+            .create_property_type(
+                PropertyType::try_from(property_type).expect("could not parse property type"),
+                owned_by_id,
+                actor_id,
+            )
+            .await
+            .expect("could not create property type");
+
+        for entity_type_str in [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ] {
+            let entity_type: repr::EntityType =
+                serde_json::from_str(entity_type_str).expect("could not parse entity type");
+            store
+                .create_entity_type(
+                    EntityType::try_from(entity_type).expect("could not parse entity type"),
+                    owned_by_id,
+                    actor_id,
+                )
+                .await
+                .expect("could not create entity type");
+        }
+    }
+
+    async fn create_person(store: &mut MemoryStore, account_id: AccountId) -> EntityId {
+        let properties: EntityProperties =
+            serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+        store
+            .create_entity(
+                OwnedById::new(account_id),
+                None,
+                None,
+                UpdatedById::new(account_id),
+                false,
+                entity_type_id("person"),
+                properties,
+                None,
+            )
+            .await
+            .expect("could not create entity")
+            .edition_id()
+            .base_id()
+    }
+
+    #[tokio::test]
+    async fn purge_cascade_requires_write_access_to_all_owners() {
+        let pool = Arc::new(MemoryStorePool::new());
+        let mut store = pool.acquire().await.expect("could not acquire store");
+
+        let alice = AccountId::new(Uuid::new_v4());
+        let bob = AccountId::new(Uuid::new_v4());
+        for account_id in [alice, bob] {
+            store
+                .insert_account_id(account_id)
+                .await
+                .expect("could not insert account id");
+        }
+        seed_ontology(&mut store, alice).await;
+
+        // Bob links to an entity of Alice with a link entity owned by Bob.
+        let left_entity_id = create_person(&mut store, alice).await;
+        let right_entity_id = create_person(&mut store, bob).await;
+        let link_entity_id = store
+            .create_entity(
+                OwnedById::new(bob),
+                None,
+                None,
+                UpdatedById::new(bob),
+                false,
+                entity_type_id("friend-of"),
+                EntityProperties::empty(),
+                Some(LinkData::new(left_entity_id, right_entity_id, None, None)),
+            )
+            .await
+            .expect("could not create link entity")
+            .edition_id()
+            .base_id();
+
+        let purge = |account_id: AccountId| {
+            purge_entity(
+                Extension(Arc::clone(&pool)),
+                Authentication::authenticated(account_id),
+                Json(PurgeEntityRequest {
+                    entity_id: left_entity_id,
+                    actor_id: UpdatedById::new(account_id),
+                    link_behavior: LinkPurgeBehavior::Cascade,
+                }),
+            )
+        };
+
+        let Err(problem) = purge(alice).await else {
+            panic!("could purge a link entity of another account");
+        };
+        assert_eq!(problem.into_response().status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            store
+                .read_purged_entity_ids(left_entity_id)
+                .await
+                .expect("entity was purged although the purge was forbidden"),
+            [left_entity_id, link_entity_id]
+        );
+
+        store
+            .insert_account_member(bob, alice)
+            .await
+            .expect("could not insert account member");
+
+        let Ok(Json(PurgeEntityResponse {
+            mut purged_entity_ids,
+        })) = purge(alice).await
+        else {
+            panic!("could not purge entity");
+        };
+        purged_entity_ids.sort();
+        let mut expected_entity_ids = vec![left_entity_id, link_entity_id];
+        expected_entity_ids.sort();
+        assert_eq!(purged_entity_ids, expected_entity_ids);
+    }
+}
@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use axum::{
     extract::Path,
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Extension, Json, Router,
 };
@@ -15,31 +16,39 @@ use type_system::{repr, uri::VersionedUri, EntityType};
 use utoipa::{OpenApi, ToSchema};
 
 use crate::{
-    api::rest::{
-        api_resource::RoutedResource,
-        read_from_store, report_to_status_code,
-        utoipa_typedef::subgraph::{
-            Edges, OntologyRootedEdges, OntologyVertices, Subgraph, Vertices,
+    api::{
+        graphql::SchemaCache,
+        rest::{
+            accepts_ndjson,
+            api_resource::RoutedResource,
+            authentication::{ontology_type_owner, Authentication},
+            problem::Problem,
+            read_from_store, stream_vertices,
+            utoipa_typedef::subgraph::{
+                Edges, OntologyRootedEdges, OntologyVertices, SearchRank, Subgraph, Vertices,
+            },
+            validate_pagination,
         },
     },
     identifier::{ontology::OntologyTypeEditionId, GraphElementEditionId, GraphElementId},
     ontology::{
         domain_validator::{DomainValidator, ValidateOntologyType},
-        patch_id_and_parse, EntityTypeQueryToken, EntityTypeWithMetadata, OntologyElementMetadata,
+        patch_id_and_parse, CompatibilityAction, CompatibilityLevel, CompatibilityPolicy,
+        CompatibilityReport, EntityTypeQueryToken, EntityTypeWithMetadata, OntologyElementMetadata,
+        OntologyUpdateMetadata, SchemaChange,
     },
     provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
     store::{
-        error::{BaseUriAlreadyExists, BaseUriDoesNotExist},
-        query::Filter,
-        EntityTypeStore, StorePool,
+        query::{Cursor, Filter, Pagination, TemporalAxes},
+        BatchOperation, EntityTypeStore, StorePool,
     },
     subgraph::{
         edges::{
             EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
-            OutgoingEdgeResolveDepth, SharedEdgeKind,
+            SharedEdgeKind,
         },
         query::{EntityTypeStructuralQuery, StructuralQuery},
-        vertices::Vertex,
+        vertices::{OntologyVertex, Vertex},
     },
 };
 
@@ -60,8 +69,17 @@ use crate::{
             UpdatedById,
             OntologyTypeEditionId,
             OntologyElementMetadata,
+            OntologyUpdateMetadata,
+            CompatibilityPolicy,
+            CompatibilityAction,
+            CompatibilityReport,
+            CompatibilityLevel,
+            SchemaChange,
             EntityTypeWithMetadata,
             EntityTypeStructuralQuery,
+            Pagination,
+            TemporalAxes,
+            Cursor,
             EntityTypeQueryToken,
             GraphElementId,
             GraphElementEditionId,
@@ -76,8 +94,8 @@ use crate::{
             Edges,
             GraphResolveDepths,
             EdgeResolveDepths,
-            OutgoingEdgeResolveDepth,
             Subgraph,
+            SearchRank,
         )
     ),
     tags(
@@ -108,13 +126,37 @@ impl RoutedResource for EntityTypeResource {
 
 #[derive(Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct CreateEntityTypeRequest {
+pub(super) struct CreateEntityTypeRequest {
     #[schema(value_type = VAR_ENTITY_TYPE)]
     schema: repr::EntityType,
     owned_by_id: OwnedById,
     actor_id: UpdatedById,
 }
 
+impl CreateEntityTypeRequest {
+    /// Converts the request into a [`BatchOperation`] to be applied as part of a batch.
+    pub(super) fn into_batch_operation(
+        self,
+        domain_validator: &DomainValidator,
+    ) -> Result<BatchOperation, Problem> {
+        let entity_type: EntityType = self.schema.try_into().into_report().map_err(|report| {
+            tracing::error!(error=?report, "Couldn't convert schema to Entity Type");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+        domain_validator.validate(&entity_type).map_err(|report| {
+            tracing::error!(error=?report, id=entity_type.id().to_string(), "Entity Type ID failed to validate");
+            Problem::from_report(&report)
+        })?;
+
+        Ok(BatchOperation::CreateEntityType {
+            entity_type,
+            owned_by_id: self.owned_by_id,
+            actor_id: self.actor_id,
+        })
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entity-types",
@@ -124,16 +166,20 @@ struct CreateEntityTypeRequest {
         (status = 201, content_type = "application/json", description = "The metadata of the created entity type", body = OntologyElementMetadata),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
-        (status = 409, description = "Unable to create entity type in the datastore as the base entity type ID already exists"),
-        (status = 500, description = "Store error occurred"),
+        (status = 409, content_type = "application/problem+json", description = "Unable to create entity type in the datastore as the base entity type ID already exists", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     request_body = CreateEntityTypeRequest,
 )]
 async fn create_entity_type<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
     domain_validator: Extension<DomainValidator>,
+    schema_cache: Extension<SchemaCache>,
+    authentication: Authentication,
     body: Json<CreateEntityTypeRequest>,
-) -> Result<Json<OntologyElementMetadata>, StatusCode> {
+) -> Result<Json<OntologyElementMetadata>, Problem> {
     let Json(CreateEntityTypeRequest {
         schema,
         owned_by_id,
@@ -150,28 +196,29 @@ async fn create_entity_type<P: StorePool + Send>(
 
     domain_validator.validate(&entity_type).map_err(|report| {
         tracing::error!(error=?report, id=entity_type.id().to_string(), "Entity Type ID failed to validate");
-        StatusCode::UNPROCESSABLE_ENTITY
+        Problem::from_report(&report)
     })?;
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
-    store
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, owned_by_id)
+        .await?;
+
+    let metadata = store
         .create_entity_type(entity_type, owned_by_id, actor_id)
         .await
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create entity type");
 
-            if report.contains::<BaseUriAlreadyExists>() {
-                return StatusCode::CONFLICT;
-            }
+            Problem::from_report(&report)
+        })?;
 
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
-        })
-        .map(Json)
+    schema_cache.invalidate();
+    Ok(Json(metadata))
 }
 
 #[utoipa::path(
@@ -180,20 +227,29 @@ async fn create_entity_type<P: StorePool + Send>(
     request_body = EntityTypeStructuralQuery,
     tag = "EntityType",
     responses(
-        (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at entity types that satisfy the given query, each resolved to the requested depth."),
+        (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at entity types that satisfy the given query, each resolved to the requested depth. If `application/x-ndjson` is accepted, the vertices matching the filter are streamed as newline-delimited JSON instead."),
 
-        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
-        (status = 500, description = "Store error occurred"),
+        (status = 400, content_type = "application/problem+json", description = "Graph resolve depths were requested for a streamed response or the page size is out of range", body = Problem),
+        (status = 422, content_type = "application/problem+json", description = "Provided query is invalid", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
-async fn get_entity_types_by_query<P: StorePool + Send>(
+async fn get_entity_types_by_query<P: StorePool + Send + 'static>(
     pool: Extension<Arc<P>>,
+    headers: HeaderMap,
     Json(query): Json<serde_json::Value>,
-) -> Result<Json<Subgraph>, StatusCode> {
+) -> Result<Response, Problem> {
+    if accepts_ndjson(&headers) {
+        return stream_vertices::<_, EntityTypeWithMetadata>(&**pool, query, |entity_type| {
+            Vertex::Ontology(Box::new(OntologyVertex::EntityType(Box::new(entity_type))))
+        })
+        .await;
+    }
+
     pool.acquire()
         .map_err(|error| {
             tracing::error!(?error, "Could not acquire access to the store");
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::store_unavailable()
         })
         .and_then(|store| async move {
             let mut query = StructuralQuery::deserialize(&query).map_err(|error| {
@@ -204,16 +260,17 @@ async fn get_entity_types_by_query<P: StorePool + Send>(
                 tracing::error!(?error, "Could not validate query");
                 StatusCode::INTERNAL_SERVER_ERROR
             })?;
+            validate_pagination(query.pagination.as_ref())?;
             store
                 .get_entity_type(&query)
                 .await
                 .map_err(|report| {
                     tracing::error!(error=?report, ?query, "Could not read entity types from the store");
-                    report_to_status_code(&report)
+                    Problem::from_report(&report)
                 })
         })
         .await
-        .map(|subgraph| Json(subgraph.into()))
+        .map(|subgraph| Json(Subgraph::from(subgraph)).into_response())
 }
 
 #[utoipa::path(
@@ -223,13 +280,13 @@ async fn get_entity_types_by_query<P: StorePool + Send>(
     responses(
         (status = 200, content_type = "application/json", description = "List of all entity types at their latest versions", body = [EntityTypeWithMetadata]),
 
-        (status = 500, description = "Store error occurred"),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
 #[deprecated = "use `/entity-types/query` instead"]
 async fn get_latest_entity_types<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
-) -> Result<Json<Vec<EntityTypeWithMetadata>>, StatusCode> {
+) -> Result<Json<Vec<EntityTypeWithMetadata>>, Problem> {
     read_from_store(pool.as_ref(), &Filter::for_latest_version())
         .await
         .map(Json)
@@ -243,8 +300,8 @@ async fn get_latest_entity_types<P: StorePool + Send>(
         (status = 200, content_type = "application/json", description = "The schema of the requested entity type", body = EntityTypeWithMetadata),
         (status = 422, content_type = "text/plain", description = "Provided URI is invalid"),
 
-        (status = 404, description = "Entity type was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Entity type was not found", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     params(
         ("uri" = String, Path, description = "The URI of the entity type"),
@@ -254,21 +311,59 @@ async fn get_latest_entity_types<P: StorePool + Send>(
 async fn get_entity_type<P: StorePool + Send>(
     uri: Path<VersionedUri>,
     pool: Extension<Arc<P>>,
-) -> Result<Json<EntityTypeWithMetadata>, StatusCode> {
+) -> Result<Json<EntityTypeWithMetadata>, Problem> {
     read_from_store(pool.as_ref(), &Filter::for_versioned_uri(&uri.0))
         .await
-        .and_then(|mut entity_types| entity_types.pop().ok_or(StatusCode::NOT_FOUND))
+        .and_then(|mut entity_types| {
+            entity_types
+                .pop()
+                .ok_or_else(|| Problem::from(StatusCode::NOT_FOUND))
+        })
         .map(Json)
 }
 
 #[derive(ToSchema, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct UpdateEntityTypeRequest {
+pub(super) struct UpdateEntityTypeRequest {
     #[schema(value_type = VAR_UPDATE_ENTITY_TYPE)]
     schema: serde_json::Value,
     #[schema(value_type = String)]
     type_to_update: VersionedUri,
     actor_id: UpdatedById,
+    /// Overrides the compatibility policy of the Graph for this update.
+    ///
+    /// The policy can only be made stricter, so an update rejected by the Graph's policy is
+    /// rejected regardless of this policy.
+    #[serde(default)]
+    compatibility_policy: Option<CompatibilityPolicy>,
+}
+
+impl UpdateEntityTypeRequest {
+    /// Converts the request into a [`BatchOperation`] to be applied as part of a batch.
+    pub(super) fn into_batch_operation(
+        self,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<BatchOperation, Problem> {
+        let new_type_id = VersionedUri::new(
+            self.type_to_update.base_uri().clone(),
+            self.type_to_update.version() + 1,
+        );
+
+        let entity_type = patch_id_and_parse(&new_type_id, self.schema).map_err(|report| {
+            tracing::error!(error=?report, "Couldn't patch schema and convert to Entity Type");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+        Ok(BatchOperation::UpdateEntityType {
+            entity_type,
+            actor_id: self.actor_id,
+            compatibility_policy: self
+                .compatibility_policy
+                .map_or(compatibility_policy, |policy| {
+                    policy.restrict(compatibility_policy)
+                }),
+        })
+    }
 }
 
 #[utoipa::path(
@@ -276,22 +371,29 @@ struct UpdateEntityTypeRequest {
     path = "/entity-types",
     tag = "EntityType",
     responses(
-        (status = 200, content_type = "application/json", description = "The metadata of the updated entity type", body = OntologyElementMetadata),
+        (status = 200, content_type = "application/json", description = "The metadata of the updated entity type and the changes to the previous version", body = OntologyUpdateMetadata),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
-        (status = 404, description = "Base entity type ID was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Base entity type ID was not found", body = Problem),
+        (status = 409, content_type = "application/problem+json", description = "The changes to the previous version were rejected by the compatibility policy", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     request_body = UpdateEntityTypeRequest,
 )]
 async fn update_entity_type<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
+    Extension(server_policy): Extension<CompatibilityPolicy>,
+    schema_cache: Extension<SchemaCache>,
+    authentication: Authentication,
     body: Json<UpdateEntityTypeRequest>,
-) -> Result<Json<OntologyElementMetadata>, StatusCode> {
+) -> Result<Json<OntologyUpdateMetadata>, Problem> {
     let Json(UpdateEntityTypeRequest {
         schema,
         type_to_update,
         actor_id,
+        compatibility_policy,
     }) = body;
 
     let new_type_id = VersionedUri::new(
@@ -309,21 +411,28 @@ async fn update_entity_type<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
-    store
-        .update_entity_type(entity_type, actor_id)
+    let owned_by_id =
+        ontology_type_owner::<_, EntityTypeWithMetadata>(&store, &type_to_update).await?;
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, owned_by_id)
+        .await?;
+
+    let metadata = store
+        .update_entity_type(
+            entity_type,
+            actor_id,
+            compatibility_policy.map_or(server_policy, |policy| policy.restrict(server_policy)),
+        )
         .await
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update entity type");
 
-            if report.contains::<BaseUriDoesNotExist>() {
-                return StatusCode::NOT_FOUND;
-            }
+            Problem::from_report(&report)
+        })?;
 
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
-        })
-        .map(Json)
+    schema_cache.invalidate();
+    Ok(Json(metadata))
 }
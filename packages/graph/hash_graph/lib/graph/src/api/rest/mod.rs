@@ -3,27 +3,36 @@
 //! Handler methods are grouped by routes that make up the REST API.
 
 mod api_resource;
+pub mod authentication;
 mod middleware;
 
 mod account;
+mod batch;
+mod change_feed;
 mod data_type;
 mod entity;
 mod entity_type;
+mod problem;
 mod property_type;
 mod utoipa_typedef;
 
-use std::{fmt::Debug, sync::Arc};
+use std::{
+    fmt::{Debug, Display},
+    io,
+    sync::Arc,
+};
 
 use axum::{
+    body::{Body, StreamBody},
     extract::Path,
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
     routing::get,
     Extension, Json, Router,
 };
-use error_stack::Report;
-use futures::TryFutureExt;
+use futures::{channel::mpsc, stream, StreamExt, TryFutureExt};
 use include_dir::{include_dir, Dir};
+use serde::Deserialize;
 use tower::ServiceBuilder;
 use tower_http::trace::TraceLayer;
 use utoipa::{
@@ -34,15 +43,21 @@ use utoipa::{
     Modify, OpenApi,
 };
 
-use self::api_resource::RoutedResource;
+use self::{
+    api_resource::RoutedResource,
+    authentication::{authenticate_request, Authenticator},
+    problem::{ErrorCode, Problem},
+};
 use crate::{
-    api::rest::middleware::log_request_and_response,
-    ontology::{domain_validator::DomainValidator, Selector},
+    api::{graphql::SchemaCache, rest::middleware::log_request_and_response},
+    logging::make_request_span,
+    ontology::{domain_validator::DomainValidator, CompatibilityPolicy, Selector},
     store::{
         crud::Read,
-        query::{Filter, QueryRecord},
-        QueryError, StorePool,
+        query::{Filter, Pagination, QueryRecord, TemporalAxes},
+        StorePool,
     },
+    subgraph::{edges::GraphResolveDepths, query::StructuralQuery, vertices::Vertex},
 };
 
 static STATIC_SCHEMAS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/api/rest/json_schemas");
@@ -54,6 +69,8 @@ fn api_resources<P: StorePool + Send + 'static>() -> Vec<Router> {
         property_type::PropertyTypeResource::routes::<P>(),
         entity_type::EntityTypeResource::routes::<P>(),
         entity::EntityResource::routes::<P>(),
+        batch::BatchResource::routes::<P>(),
+        change_feed::ChangeFeedResource::routes::<P>(),
     ]
 }
 
@@ -64,23 +81,15 @@ fn api_documentation() -> Vec<openapi::OpenApi> {
         property_type::PropertyTypeResource::documentation(),
         entity_type::EntityTypeResource::documentation(),
         entity::EntityResource::documentation(),
+        batch::BatchResource::documentation(),
+        change_feed::ChangeFeedResource::documentation(),
     ]
 }
 
-fn report_to_status_code<C>(report: &Report<C>) -> StatusCode {
-    let mut status_code = StatusCode::INTERNAL_SERVER_ERROR;
-
-    if let Some(error) = report.downcast_ref::<QueryError>() {
-        tracing::error!(%error, "Unable to query from data store");
-        status_code = StatusCode::UNPROCESSABLE_ENTITY;
-    }
-    status_code
-}
-
 async fn read_from_store<'pool, 'q, P, T>(
     pool: &'pool P,
     query: &'q Filter<'q, T>,
-) -> Result<Vec<T>, StatusCode>
+) -> Result<Vec<T>, Problem>
 where
     P: StorePool<Store<'pool>: Read<T>>,
     T: QueryRecord<Path<'q>: Sync + Debug> + Send,
@@ -88,27 +97,169 @@ where
     pool.acquire()
         .map_err(|report| {
             tracing::error!(error=?report, "Could not acquire access to the store");
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::store_unavailable()
         })
         .and_then(|store| async move {
             // TODO: Closure is taking reference of `store` to `read()`, so the read operation
             //       needs to be awaited on. By passing through a stream we could avoid awaiting and
             //       remove the `async move` closure
             //   see https://app.asana.com/0/1202805690238892/1202923536131158/f
-            Read::read(&store, query)
+            Read::read(&store, query, &TemporalAxes::default())
                 .map_err(|report| {
                     // TODO: Implement `Valuable` for queries and print them here
                     tracing::error!(error=?report, ?query, "Could not read from the store");
-                    report_to_status_code(&report)
+                    Problem::from_report(&report)
                 })
                 .await
         })
         .await
 }
 
+/// The media type of newline-delimited JSON.
+const NDJSON_MEDIA_TYPE: &str = "application/x-ndjson";
+
+/// The number of lines buffered for a newline-delimited JSON response before reading from the
+/// store is paused.
+const NDJSON_BUFFER_SIZE: usize = 64;
+
+/// Returns if the client accepts newline-delimited JSON as response.
+fn accepts_ndjson(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media_type| media_type.trim().starts_with(NDJSON_MEDIA_TYPE))
+}
+
+/// Rejects a [`Pagination`], whose page size is out of range.
+fn validate_pagination(pagination: Option<&Pagination>) -> Result<(), StatusCode> {
+    if let Some(pagination) = pagination {
+        pagination.validate().map_err(|report| {
+            tracing::error!(error=?report, "Invalid pagination");
+            StatusCode::BAD_REQUEST
+        })?;
+    }
+    Ok(())
+}
+
+/// Deserializes a [`StructuralQuery`], which is answered by a stream of vertices.
+///
+/// The vertices are written as they are read from the store, so the graph is not traversed and
+/// the query must not specify any resolve depth.
+fn deserialize_streamed_query<'q, T>(
+    query: &'q serde_json::Value,
+) -> Result<StructuralQuery<'q, T>, StatusCode>
+where
+    T: QueryRecord<Path<'q>: Display>,
+    StructuralQuery<'q, T>: Deserialize<'q>,
+{
+    let mut query = StructuralQuery::deserialize(query).map_err(|error| {
+        tracing::error!(?error, "Could not deserialize query");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    query.filter.convert_parameters().map_err(|error| {
+        tracing::error!(?error, "Could not validate query");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if query.graph_resolve_depths != GraphResolveDepths::default() {
+        tracing::error!(
+            graph_resolve_depths=?query.graph_resolve_depths,
+            "Streamed responses can't resolve the graph"
+        );
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    Ok(query)
+}
+
+/// Responds with the vertices of the records matching the structural `query` as newline-delimited
+/// JSON.
+///
+/// The records are read from the store in a separate task and written to the response as they are
+/// fetched, so in contrast to a [`Subgraph`] they are never collected in memory. Reading stops as
+/// soon as the client disconnects.
+///
+/// [`Subgraph`]: crate::subgraph::Subgraph
+async fn stream_vertices<P, T>(
+    pool: &P,
+    query: serde_json::Value,
+    to_vertex: fn(T) -> Vertex,
+) -> Result<Response, Problem>
+where
+    P: StorePool<Store<'static>: Read<T>> + Send + 'static,
+    T: QueryRecord + Send + 'static,
+    for<'q> T::Path<'q>: Display + Debug + Send + Sync,
+    for<'q> StructuralQuery<'q, T>: Deserialize<'q>,
+{
+    // The query is validated upfront, as the status code can't be changed once streaming started.
+    deserialize_streamed_query::<T>(&query)?;
+
+    let store = pool.acquire_owned().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire access to the store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let (sender, receiver) = mpsc::channel(NDJSON_BUFFER_SIZE);
+    tokio::spawn(write_vertices(store, query, to_vertex, sender));
+
+    Ok((
+        [(header::CONTENT_TYPE, NDJSON_MEDIA_TYPE)],
+        StreamBody::new(receiver),
+    )
+        .into_response())
+}
+
+/// Sends the vertices of the records matching `query` as lines to `sender`.
+///
+/// If a record can't be read, an error is sent instead, which aborts the response.
+async fn write_vertices<S, T>(
+    mut store: S,
+    query: serde_json::Value,
+    to_vertex: fn(T) -> Vertex,
+    sender: mpsc::Sender<io::Result<String>>,
+) where
+    S: Read<T> + Send,
+    T: QueryRecord + Send,
+    for<'q> T::Path<'q>: Display + Debug + Send + Sync,
+    for<'q> StructuralQuery<'q, T>: Deserialize<'q>,
+{
+    let Ok(query) = deserialize_streamed_query::<T>(&query) else {
+        return;
+    };
+
+    let records = match store.read_stream(&query.filter, &query.temporal_axes).await {
+        Ok(records) => records,
+        Err(report) => stream::once(async move { Err(report) }).boxed(),
+    };
+
+    let lines = records.map(|record| -> io::Result<String> {
+        let record = record.map_err(|report| {
+            tracing::error!(error=?report, "Could not read from the store");
+            io::Error::new(io::ErrorKind::Other, "could not read from the store")
+        })?;
+        let mut line = serde_json::to_string(&to_vertex(record))?;
+        line.push('\n');
+        Ok(line)
+    });
+
+    // Forwarding fails if the client disconnected, which stops reading from the store.
+    if lines.map(Ok).forward(sender).await.is_err() {
+        tracing::debug!("Client disconnected while streaming the response");
+    }
+}
+
+/// Creates the router for the REST API.
+///
+/// Updates of ontology types are checked against the `compatibility_policy`, which requests can
+/// only make stricter. Writes to entity types invalidate the `schema_cache` of the GraphQL API.
+///
+/// If an [`Authenticator`] is passed, all API requests have to be authenticated by it.
 pub fn rest_api_router<P: StorePool + Send + 'static>(
     store: Arc<P>,
     domain_regex: DomainValidator,
+    compatibility_policy: CompatibilityPolicy,
+    schema_cache: SchemaCache,
+    authenticator: Option<Arc<dyn Authenticator>>,
 ) -> Router {
     // All api resources are merged together into a super-router.
     let merged_routes = api_resources::<P>()
@@ -121,11 +272,25 @@ pub fn rest_api_router<P: StorePool + Send + 'static>(
     // super-router can then be used as any other router.
     // Make sure extensions are added at the end so they are made available to merged routers.
     // The `/api-doc` endpoints are nested as we don't want any layers or handlers for the api-doc
-    merged_routes
+    let mut routes = merged_routes
         .layer(Extension(store))
         .layer(Extension(domain_regex))
+        .layer(Extension(compatibility_policy))
+        .layer(Extension(schema_cache));
+
+    // The authenticator is added after the authentication layer to make it available to it.
+    if let Some(authenticator) = authenticator {
+        routes = routes
+            .layer(axum::middleware::from_fn(authenticate_request))
+            .layer(Extension(authenticator));
+    }
+
+    routes
         .layer(axum::middleware::from_fn(log_request_and_response))
-        .layer(ServiceBuilder::new().layer(TraceLayer::new_for_http()))
+        .layer(
+            ServiceBuilder::new()
+                .layer(TraceLayer::new_for_http().make_span_with(make_request_span::<Body>)),
+        )
         .nest(
             "/api-doc",
             Router::new()
@@ -170,6 +335,8 @@ async fn serve_static_schema(Path(path): Path<String>) -> Result<Response, Statu
     components(
         schemas(
             Selector,
+            Problem,
+            ErrorCode,
         )
     ),
 )]
@@ -366,6 +533,123 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("notEqual"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LessFilter"))
+                                .property(
+                                    "less",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("less"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LessOrEqualFilter"))
+                                .property(
+                                    "lessOrEqual",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("lessOrEqual"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("GreaterFilter"))
+                                .property(
+                                    "greater",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("greater"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("GreaterOrEqualFilter"))
+                                .property(
+                                    "greaterOrEqual",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("greaterOrEqual"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("InFilter"))
+                                .property(
+                                    "in",
+                                    ArrayBuilder::new()
+                                        .items(
+                                            OneOfBuilder::new()
+                                                .item(Ref::from_schema_name("FilterExpression"))
+                                                .item(ArrayBuilder::new().items(
+                                                    Ref::from_schema_name("FilterExpression"),
+                                                )),
+                                        )
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("in"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("StartsWithFilter"))
+                                .property(
+                                    "startsWith",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("startsWith"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("EndsWithFilter"))
+                                .property(
+                                    "endsWith",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("endsWith"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ContainsSegmentFilter"))
+                                .property(
+                                    "containsSegment",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("containsSegment"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ExistsFilter"))
+                                .property("exists", Ref::from_schema_name("FilterExpression"))
+                                .required("exists"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("SearchFilter"))
+                                .property(
+                                    "search",
+                                    ObjectBuilder::new().schema_type(SchemaType::String),
+                                )
+                                .required("search"),
+                        )
                         .build(),
                 )
                 .into(),
@@ -412,6 +696,54 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "QueryPath".to_owned(),
+                schema::Schema::Array(
+                    ArrayBuilder::new()
+                        .items(
+                            OneOfBuilder::new()
+                                .item(Ref::from_schema_name("DataTypeQueryToken"))
+                                .item(Ref::from_schema_name("PropertyTypeQueryToken"))
+                                .item(Ref::from_schema_name("EntityTypeQueryToken"))
+                                .item(Ref::from_schema_name("EntityQueryToken"))
+                                .item(Ref::from_schema_name("Selector")),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
+            components.schemas.insert(
+                "Aggregate".to_owned(),
+                schema::Schema::OneOf(
+                    OneOfBuilder::new()
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("CountAggregate"))
+                                .schema_type(SchemaType::String)
+                                .enum_values(Some(["count"])),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("MinAggregate"))
+                                .property("min", Ref::from_schema_name("QueryPath"))
+                                .required("min"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("MaxAggregate"))
+                                .property("max", Ref::from_schema_name("QueryPath"))
+                                .required("max"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("SumAggregate"))
+                                .property("sum", Ref::from_schema_name("QueryPath"))
+                                .required("sum"),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
         }
     }
 }
@@ -0,0 +1,350 @@
+//! Error responses of the REST API following [RFC 7807].
+//!
+//! [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use error_stack::{AttachmentKind, Context, Frame, FrameKind, Report};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    api::monitoring::StoreError,
+    knowledge::EntityValidationError,
+    ontology::{domain_validator::DomainValidationError, IncompatibleOntologyTypeUpdate},
+    store::{
+        error::{
+            ChangeOffsetDoesNotExist, EntityDoesNotExist, EntityIsReferenced,
+            PurgedEntitiesChanged, RaceConditionOnUpdate, VersionedUriAlreadyExists,
+        },
+        BaseUriAlreadyExists, BaseUriDoesNotExist, DeletionError, InsertionError,
+        InvalidEntityMigration, InvalidEntityReference, QueryError, UpdateError,
+    },
+};
+
+/// The media type of a [`Problem`].
+const PROBLEM_MEDIA_TYPE: &str = "application/problem+json";
+
+/// A stable, machine-readable identifier of the kind of a [`Problem`].
+///
+/// In contrast to the status code, the code distinguishes between different problems with the
+/// same status, e.g. a base URI, which already exists, and an incompatible update.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub(super) enum ErrorCode {
+    BaseUriAlreadyExists,
+    BaseUriDoesNotExist,
+    VersionedUriAlreadyExists,
+    IncompatibleUpdate,
+    InvalidDomain,
+    EntityDoesNotExist,
+    EntityIsReferenced,
+    PurgedEntitiesChanged,
+    ChangeOffsetDoesNotExist,
+    InvalidEntity,
+    InvalidMigration,
+    InvalidReference,
+    ConcurrentUpdate,
+    InvalidQuery,
+    InsertionFailed,
+    UpdateFailed,
+    BadRequest,
+    Unauthenticated,
+    Forbidden,
+    NotFound,
+    Conflict,
+    UnprocessableEntity,
+    InternalError,
+}
+
+impl ErrorCode {
+    /// Returns the status code and the error code of the most specific context of `report`.
+    ///
+    /// The contexts are checked from the most specific one to the most general one, so e.g. an
+    /// [`UpdateError`] caused by a [`BaseUriDoesNotExist`] is reported as the latter. A plain
+    /// [`InsertionError`] or [`UpdateError`] is not caused by the client and therefore reported as
+    /// `500 Internal Server Error`.
+    fn of_report<C>(report: &Report<C>) -> (StatusCode, Self) {
+        if report.contains::<BaseUriAlreadyExists>() {
+            (StatusCode::CONFLICT, Self::BaseUriAlreadyExists)
+        } else if report.contains::<VersionedUriAlreadyExists>() {
+            (StatusCode::CONFLICT, Self::VersionedUriAlreadyExists)
+        } else if report.contains::<BaseUriDoesNotExist>() {
+            (StatusCode::NOT_FOUND, Self::BaseUriDoesNotExist)
+        } else if report.contains::<IncompatibleOntologyTypeUpdate>() {
+            (StatusCode::CONFLICT, Self::IncompatibleUpdate)
+        } else if report.contains::<DomainValidationError>() {
+            (StatusCode::UNPROCESSABLE_ENTITY, Self::InvalidDomain)
+        } else if report.contains::<EntityDoesNotExist>() {
+            (StatusCode::NOT_FOUND, Self::EntityDoesNotExist)
+        } else if report.contains::<EntityIsReferenced>() {
+            (StatusCode::CONFLICT, Self::EntityIsReferenced)
+        } else if report.contains::<PurgedEntitiesChanged>() {
+            (StatusCode::CONFLICT, Self::PurgedEntitiesChanged)
+        } else if report.contains::<ChangeOffsetDoesNotExist>() {
+            (StatusCode::NOT_FOUND, Self::ChangeOffsetDoesNotExist)
+        } else if report.contains::<EntityValidationError>() {
+            (StatusCode::BAD_REQUEST, Self::InvalidEntity)
+        } else if report.contains::<InvalidEntityMigration>() {
+            (StatusCode::BAD_REQUEST, Self::InvalidMigration)
+        } else if report.contains::<InvalidEntityReference>() {
+            (StatusCode::UNPROCESSABLE_ENTITY, Self::InvalidReference)
+        } else if report.contains::<RaceConditionOnUpdate>() {
+            (StatusCode::LOCKED, Self::ConcurrentUpdate)
+        } else if report.contains::<QueryError>() {
+            (StatusCode::UNPROCESSABLE_ENTITY, Self::InvalidQuery)
+        } else if report.contains::<InsertionError>() {
+            (StatusCode::INTERNAL_SERVER_ERROR, Self::InsertionFailed)
+        } else if report.contains::<UpdateError>() {
+            (StatusCode::INTERNAL_SERVER_ERROR, Self::UpdateFailed)
+        } else {
+            (StatusCode::INTERNAL_SERVER_ERROR, Self::InternalError)
+        }
+    }
+
+    const fn of_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::BAD_REQUEST => Self::BadRequest,
+            StatusCode::UNAUTHORIZED => Self::Unauthenticated,
+            StatusCode::FORBIDDEN => Self::Forbidden,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::UNPROCESSABLE_ENTITY => Self::UnprocessableEntity,
+            StatusCode::LOCKED => Self::ConcurrentUpdate,
+            _ => Self::InternalError,
+        }
+    }
+}
+
+/// An error response following [RFC 7807].
+///
+/// The problem `type` is omitted, so it defaults to `about:blank` and the `title` is the reason
+/// phrase of the status code. The kind of the problem is identified by its `code` instead.
+///
+/// [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Problem {
+    /// The reason phrase of the status code.
+    title: String,
+    /// The HTTP status code of the response.
+    #[schema(value_type = u16)]
+    status: u16,
+    code: ErrorCode,
+    /// A human-readable explanation of this occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    /// The chain of errors, which caused the problem, starting with the outermost error.
+    ///
+    /// Only errors defined by the graph are listed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    contexts: Vec<String>,
+    /// Additional information attached to the errors, starting with the outermost error.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<String>,
+    /// Whether the problem was caused by an error reported by the store.
+    #[serde(skip)]
+    store_error: bool,
+}
+
+impl Problem {
+    fn new(status: StatusCode, code: ErrorCode) -> Self {
+        Self {
+            title: status
+                .canonical_reason()
+                .unwrap_or_else(|| status.as_str())
+                .to_owned(),
+            status: status.as_u16(),
+            code,
+            detail: None,
+            contexts: Vec::new(),
+            attachments: Vec::new(),
+            store_error: false,
+        }
+    }
+
+    /// Creates a `Problem` from the contexts and the printable attachments of `report`.
+    ///
+    /// The status and the code are determined by the most specific context of the report, e.g.
+    /// [`BaseUriAlreadyExists`] results in `409 Conflict`.
+    ///
+    /// Only contexts defined by the graph are exposed, as other contexts, e.g. errors of the
+    /// database driver, may contain internals like SQL statements. For the same reason, the
+    /// attachments are omitted if the problem is a server error.
+    ///
+    /// Reports containing an error of the store, e.g. a [`QueryError`], are recorded as store
+    /// errors by [`record_request_metrics`].
+    ///
+    /// [`record_request_metrics`]: crate::api::monitoring::record_request_metrics
+    pub(super) fn from_report<C: Context>(report: &Report<C>) -> Self {
+        let (status, code) = ErrorCode::of_report(report);
+        let mut problem = Self::new(status, code);
+
+        for frame in report.frames() {
+            match frame.kind() {
+                FrameKind::Context(context) if is_graph_context(frame) => {
+                    problem.contexts.push(context.to_string());
+                }
+                FrameKind::Attachment(AttachmentKind::Printable(attachment))
+                    if !status.is_server_error() =>
+                {
+                    problem.attachments.push(attachment.to_string());
+                }
+                // Foreign contexts and opaque attachments are not rendered for the client.
+                FrameKind::Context(_) | FrameKind::Attachment(_) => {}
+            }
+        }
+        problem.detail = problem.contexts.first().cloned();
+        problem.store_error = is_store_error(report);
+
+        problem
+    }
+
+    /// Creates a `Problem` for a store, which could not be acquired from the pool.
+    ///
+    /// This is recorded as store error by [`record_request_metrics`].
+    ///
+    /// [`record_request_metrics`]: crate::api::monitoring::record_request_metrics
+    pub(super) fn store_unavailable() -> Self {
+        Self {
+            store_error: true,
+            ..Self::from(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Returns if `report` was reported by the store.
+fn is_store_error<C>(report: &Report<C>) -> bool {
+    report.contains::<QueryError>()
+        || report.contains::<InsertionError>()
+        || report.contains::<UpdateError>()
+        || report.contains::<DeletionError>()
+}
+
+/// Returns if `frame` is a context, which is defined by the graph.
+fn is_graph_context(frame: &Frame) -> bool {
+    frame.is::<BaseUriAlreadyExists>()
+        || frame.is::<BaseUriDoesNotExist>()
+        || frame.is::<VersionedUriAlreadyExists>()
+        || frame.is::<IncompatibleOntologyTypeUpdate>()
+        || frame.is::<DomainValidationError>()
+        || frame.is::<EntityDoesNotExist>()
+        || frame.is::<EntityIsReferenced>()
+        || frame.is::<PurgedEntitiesChanged>()
+        || frame.is::<ChangeOffsetDoesNotExist>()
+        || frame.is::<EntityValidationError>()
+        || frame.is::<InvalidEntityMigration>()
+        || frame.is::<InvalidEntityReference>()
+        || frame.is::<RaceConditionOnUpdate>()
+        || frame.is::<QueryError>()
+        || frame.is::<InsertionError>()
+        || frame.is::<UpdateError>()
+        || frame.is::<DeletionError>()
+}
+
+impl From<StatusCode> for Problem {
+    fn from(status: StatusCode) -> Self {
+        Self::new(status, ErrorCode::of_status(status))
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let store_error = self.store_error;
+        let mut response = (status, Json(self)).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(PROBLEM_MEDIA_TYPE),
+        );
+        if store_error {
+            response.extensions_mut().insert(StoreError);
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn problem_from_report() {
+        let report = Report::new(BaseUriAlreadyExists)
+            .attach_printable("https://example.com/data-type/text/")
+            .change_context(InsertionError);
+
+        assert_eq!(
+            serde_json::to_value(Problem::from_report(&report)).expect("could not serialize"),
+            json!({
+                "title": "Conflict",
+                "status": 409,
+                "code": "base-uri-already-exists",
+                "detail": InsertionError.to_string(),
+                "contexts": [InsertionError.to_string(), BaseUriAlreadyExists.to_string()],
+                "attachments": ["https://example.com/data-type/text/"],
+            })
+        );
+    }
+
+    #[test]
+    fn problem_from_foreign_report() {
+        let report = Report::new(io::Error::new(
+            io::ErrorKind::Other,
+            "relation \"entities\" does not exist",
+        ))
+        .attach_printable("host=localhost user=postgres")
+        .change_context(InsertionError);
+
+        assert_eq!(
+            serde_json::to_value(Problem::from_report(&report)).expect("could not serialize"),
+            json!({
+                "title": "Internal Server Error",
+                "status": 500,
+                "code": "insertion-failed",
+                "detail": InsertionError.to_string(),
+                "contexts": [InsertionError.to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn problem_from_status() {
+        let response = Problem::from(StatusCode::NOT_FOUND).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_MEDIA_TYPE);
+        assert!(response.extensions().get::<StoreError>().is_none());
+    }
+
+    #[test]
+    fn store_errors_are_marked() {
+        let response = Problem::from_report(&Report::new(QueryError)).into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(response.extensions().get::<StoreError>().is_some());
+
+        let response = Problem::from_report(&Report::new(InsertionError)).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.extensions().get::<StoreError>().is_some());
+
+        let response = Problem::store_unavailable().into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.extensions().get::<StoreError>().is_some());
+
+        let response = Problem::from_report(&Report::new(io::Error::new(
+            io::ErrorKind::Other,
+            "not an error of the store",
+        )))
+        .into_response();
+        assert!(response.extensions().get::<StoreError>().is_none());
+
+        let response = Problem::from(StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        assert!(response.extensions().get::<StoreError>().is_none());
+    }
+}
@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use axum::{
     extract::Path,
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Extension, Json, Router,
 };
@@ -17,26 +18,29 @@ use utoipa::{OpenApi, ToSchema};
 use super::api_resource::RoutedResource;
 use crate::{
     api::rest::{
-        read_from_store, report_to_status_code,
-        utoipa_typedef::subgraph::{Edges, Subgraph, Vertices},
+        accepts_ndjson,
+        authentication::{ontology_type_owner, Authentication},
+        problem::Problem,
+        read_from_store, stream_vertices,
+        utoipa_typedef::subgraph::{Edges, SearchRank, Subgraph, Vertices},
+        validate_pagination,
     },
     identifier::{ontology::OntologyTypeEditionId, GraphElementEditionId, GraphElementId},
     ontology::{
         domain_validator::{DomainValidator, ValidateOntologyType},
-        patch_id_and_parse, OntologyElementMetadata, PropertyTypeQueryToken,
-        PropertyTypeWithMetadata,
+        patch_id_and_parse, CompatibilityAction, CompatibilityLevel, CompatibilityPolicy,
+        CompatibilityReport, OntologyElementMetadata, OntologyUpdateMetadata,
+        PropertyTypeQueryToken, PropertyTypeWithMetadata, SchemaChange,
     },
     provenance::{OwnedById, UpdatedById},
     store::{
-        query::Filter, BaseUriAlreadyExists, BaseUriDoesNotExist, PropertyTypeStore, StorePool,
+        query::{Cursor, Filter, Pagination, TemporalAxes},
+        BatchOperation, PropertyTypeStore, StorePool,
     },
     subgraph::{
-        edges::{
-            EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, OutgoingEdgeResolveDepth,
-            SharedEdgeKind,
-        },
+        edges::{EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, SharedEdgeKind},
         query::{PropertyTypeStructuralQuery, StructuralQuery},
-        vertices::Vertex,
+        vertices::{OntologyVertex, Vertex},
     },
 };
 
@@ -57,8 +61,17 @@ use crate::{
             UpdatedById,
             OntologyTypeEditionId,
             OntologyElementMetadata,
+            OntologyUpdateMetadata,
+            CompatibilityPolicy,
+            CompatibilityAction,
+            CompatibilityReport,
+            CompatibilityLevel,
+            SchemaChange,
             PropertyTypeWithMetadata,
             PropertyTypeStructuralQuery,
+            Pagination,
+            TemporalAxes,
+            Cursor,
             PropertyTypeQueryToken,
             GraphElementId,
             GraphElementEditionId,
@@ -68,9 +81,9 @@ use crate::{
             SharedEdgeKind,
             GraphResolveDepths,
             EdgeResolveDepths,
-            OutgoingEdgeResolveDepth,
             Edges,
             Subgraph,
+            SearchRank,
         )
     ),
     tags(
@@ -101,13 +114,38 @@ impl RoutedResource for PropertyTypeResource {
 
 #[derive(Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct CreatePropertyTypeRequest {
+pub(super) struct CreatePropertyTypeRequest {
     #[schema(value_type = VAR_PROPERTY_TYPE)]
     schema: repr::PropertyType,
     owned_by_id: OwnedById,
     actor_id: UpdatedById,
 }
 
+impl CreatePropertyTypeRequest {
+    /// Converts the request into a [`BatchOperation`] to be applied as part of a batch.
+    pub(super) fn into_batch_operation(
+        self,
+        domain_validator: &DomainValidator,
+    ) -> Result<BatchOperation, Problem> {
+        let property_type: PropertyType =
+            self.schema.try_into().into_report().map_err(|report| {
+                tracing::error!(error=?report, "Couldn't convert schema to Property Type");
+                StatusCode::UNPROCESSABLE_ENTITY
+            })?;
+
+        domain_validator.validate(&property_type).map_err(|report| {
+            tracing::error!(error=?report, id=property_type.id().to_string(), "Property Type ID failed to validate");
+            Problem::from_report(&report)
+        })?;
+
+        Ok(BatchOperation::CreatePropertyType {
+            property_type,
+            owned_by_id: self.owned_by_id,
+            actor_id: self.actor_id,
+        })
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/property-types",
@@ -117,16 +155,19 @@ struct CreatePropertyTypeRequest {
         (status = 201, content_type = "application/json", description = "The metadata of the created property type", body = OntologyElementMetadata),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
-        (status = 409, description = "Unable to create property type in the store as the base property type ID already exists"),
-        (status = 500, description = "Store error occurred"),
+        (status = 409, content_type = "application/problem+json", description = "Unable to create property type in the store as the base property type ID already exists", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     request_body = CreatePropertyTypeRequest,
 )]
 async fn create_property_type<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
     domain_validator: Extension<DomainValidator>,
+    authentication: Authentication,
     body: Json<CreatePropertyTypeRequest>,
-) -> Result<Json<OntologyElementMetadata>, StatusCode> {
+) -> Result<Json<OntologyElementMetadata>, Problem> {
     let Json(CreatePropertyTypeRequest {
         schema,
         owned_by_id,
@@ -144,26 +185,25 @@ async fn create_property_type<P: StorePool + Send>(
         .validate(&property_type)
         .map_err(|report| {
             tracing::error!(error=?report, id=property_type.id().to_string(), "Property Type ID failed to validate");
-            StatusCode::UNPROCESSABLE_ENTITY
+            Problem::from_report(&report)
         })?;
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, owned_by_id)
+        .await?;
+
     store
         .create_property_type(property_type, owned_by_id, actor_id)
         .await
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create property type");
 
-            if report.contains::<BaseUriAlreadyExists>() {
-                return StatusCode::CONFLICT;
-            }
-
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::from_report(&report)
         })
         .map(Json)
 }
@@ -174,20 +214,31 @@ async fn create_property_type<P: StorePool + Send>(
     request_body = PropertyTypeStructuralQuery,
     tag = "PropertyType",
     responses(
-        (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at property types that satisfy the given query, each resolved to the requested depth."),
+        (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at property types that satisfy the given query, each resolved to the requested depth. If `application/x-ndjson` is accepted, the vertices matching the filter are streamed as newline-delimited JSON instead."),
 
-        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
-        (status = 500, description = "Store error occurred"),
+        (status = 400, content_type = "application/problem+json", description = "Graph resolve depths were requested for a streamed response or the page size is out of range", body = Problem),
+        (status = 422, content_type = "application/problem+json", description = "Provided query is invalid", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
-async fn get_property_types_by_query<P: StorePool + Send>(
+async fn get_property_types_by_query<P: StorePool + Send + 'static>(
     pool: Extension<Arc<P>>,
+    headers: HeaderMap,
     Json(query): Json<serde_json::Value>,
-) -> Result<Json<Subgraph>, StatusCode> {
+) -> Result<Response, Problem> {
+    if accepts_ndjson(&headers) {
+        return stream_vertices::<_, PropertyTypeWithMetadata>(&**pool, query, |property_type| {
+            Vertex::Ontology(Box::new(OntologyVertex::PropertyType(Box::new(
+                property_type,
+            ))))
+        })
+        .await;
+    }
+
     pool.acquire()
         .map_err(|error| {
             tracing::error!(?error, "Could not acquire access to the store");
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::store_unavailable()
         })
         .and_then(|store| async move {
             let mut query = StructuralQuery::deserialize(&query).map_err(|error| {
@@ -198,16 +249,17 @@ async fn get_property_types_by_query<P: StorePool + Send>(
                 tracing::error!(?error, "Could not validate query");
                 StatusCode::INTERNAL_SERVER_ERROR
             })?;
+            validate_pagination(query.pagination.as_ref())?;
             store
                 .get_property_type(&query)
                 .await
                 .map_err(|report| {
                     tracing::error!(error=?report, ?query, "Could not read property types from the store");
-                    report_to_status_code(&report)
+                    Problem::from_report(&report)
                 })
         })
         .await
-        .map(|subgraph| Json(subgraph.into()))
+        .map(|subgraph| Json(Subgraph::from(subgraph)).into_response())
 }
 
 #[utoipa::path(
@@ -217,13 +269,13 @@ async fn get_property_types_by_query<P: StorePool + Send>(
     responses(
         (status = 200, content_type = "application/json", description = "List of all property types at their latest versions", body = [PropertyTypeWithMetadata]),
 
-        (status = 500, description = "Store error occurred"),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     )
 )]
 #[deprecated = "use `/property-types/query` instead"]
 async fn get_latest_property_types<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
-) -> Result<Json<Vec<PropertyTypeWithMetadata>>, StatusCode> {
+) -> Result<Json<Vec<PropertyTypeWithMetadata>>, Problem> {
     read_from_store(pool.as_ref(), &Filter::for_latest_version())
         .await
         .map(Json)
@@ -237,8 +289,8 @@ async fn get_latest_property_types<P: StorePool + Send>(
         (status = 200, content_type = "application/json", description = "The schema of the requested property type", body = PropertyTypeWithMetadata),
         (status = 422, content_type = "text/plain", description = "Provided URI is invalid"),
 
-        (status = 404, description = "Property type was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Property type was not found", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     params(
         ("uri" = String, Path, description = "The URI of the property type"),
@@ -248,21 +300,59 @@ async fn get_latest_property_types<P: StorePool + Send>(
 async fn get_property_type<P: StorePool + Send>(
     uri: Path<VersionedUri>,
     pool: Extension<Arc<P>>,
-) -> Result<Json<PropertyTypeWithMetadata>, StatusCode> {
+) -> Result<Json<PropertyTypeWithMetadata>, Problem> {
     read_from_store(pool.as_ref(), &Filter::for_versioned_uri(&uri.0))
         .await
-        .and_then(|mut property_types| property_types.pop().ok_or(StatusCode::NOT_FOUND))
+        .and_then(|mut property_types| {
+            property_types
+                .pop()
+                .ok_or_else(|| Problem::from(StatusCode::NOT_FOUND))
+        })
         .map(Json)
 }
 
 #[derive(ToSchema, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct UpdatePropertyTypeRequest {
+pub(super) struct UpdatePropertyTypeRequest {
     #[schema(value_type = VAR_UPDATE_PROPERTY_TYPE)]
     schema: serde_json::Value,
     #[schema(value_type = String)]
     type_to_update: VersionedUri,
     actor_id: UpdatedById,
+    /// Overrides the compatibility policy of the Graph for this update.
+    ///
+    /// The policy can only be made stricter, so an update rejected by the Graph's policy is
+    /// rejected regardless of this policy.
+    #[serde(default)]
+    compatibility_policy: Option<CompatibilityPolicy>,
+}
+
+impl UpdatePropertyTypeRequest {
+    /// Converts the request into a [`BatchOperation`] to be applied as part of a batch.
+    pub(super) fn into_batch_operation(
+        self,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<BatchOperation, Problem> {
+        let new_type_id = VersionedUri::new(
+            self.type_to_update.base_uri().clone(),
+            self.type_to_update.version() + 1,
+        );
+
+        let property_type = patch_id_and_parse(&new_type_id, self.schema).map_err(|report| {
+            tracing::error!(error=?report, "Couldn't patch schema and convert to Property Type");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+        Ok(BatchOperation::UpdatePropertyType {
+            property_type,
+            actor_id: self.actor_id,
+            compatibility_policy: self
+                .compatibility_policy
+                .map_or(compatibility_policy, |policy| {
+                    policy.restrict(compatibility_policy)
+                }),
+        })
+    }
 }
 
 #[utoipa::path(
@@ -270,22 +360,28 @@ struct UpdatePropertyTypeRequest {
     path = "/property-types",
     tag = "PropertyType",
     responses(
-        (status = 200, content_type = "application/json", description = "The metadata of the updated property type", body = OntologyElementMetadata),
+        (status = 200, content_type = "application/json", description = "The metadata of the updated property type and the changes to the previous version", body = OntologyUpdateMetadata),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
-        (status = 404, description = "Base property type ID was not found"),
-        (status = 500, description = "Store error occurred"),
+        (status = 404, content_type = "application/problem+json", description = "Base property type ID was not found", body = Problem),
+        (status = 409, content_type = "application/problem+json", description = "The changes to the previous version were rejected by the compatibility policy", body = Problem),
+        (status = 401, content_type = "application/problem+json", description = "The request could not be authenticated", body = Problem),
+        (status = 403, content_type = "application/problem+json", description = "The actor is not allowed to write to the namespace of the owning account", body = Problem),
+        (status = 500, content_type = "application/problem+json", description = "Store error occurred", body = Problem),
     ),
     request_body = UpdatePropertyTypeRequest,
 )]
 async fn update_property_type<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
+    Extension(server_policy): Extension<CompatibilityPolicy>,
+    authentication: Authentication,
     body: Json<UpdatePropertyTypeRequest>,
-) -> Result<Json<OntologyElementMetadata>, StatusCode> {
+) -> Result<Json<OntologyUpdateMetadata>, Problem> {
     let Json(UpdatePropertyTypeRequest {
         schema,
         type_to_update,
         actor_id,
+        compatibility_policy,
     }) = body;
 
     let new_type_id = VersionedUri::new(
@@ -302,21 +398,26 @@ async fn update_property_type<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        Problem::store_unavailable()
     })?;
 
+    let owned_by_id =
+        ontology_type_owner::<_, PropertyTypeWithMetadata>(&store, &type_to_update).await?;
+    let actor_id = authentication
+        .authorize_write(&store, actor_id, owned_by_id)
+        .await?;
+
     store
-        .update_property_type(property_type, actor_id)
+        .update_property_type(
+            property_type,
+            actor_id,
+            compatibility_policy.map_or(server_policy, |policy| policy.restrict(server_policy)),
+        )
         .await
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update property type");
 
-            if report.contains::<BaseUriDoesNotExist>() {
-                return StatusCode::NOT_FOUND;
-            }
-
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            Problem::from_report(&report)
         })
         .map(Json)
 }
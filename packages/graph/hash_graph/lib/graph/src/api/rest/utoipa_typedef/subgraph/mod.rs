@@ -8,7 +8,9 @@ pub use self::{
     edges::{Edges, KnowledgeGraphRootedEdges, OntologyRootedEdges},
     vertices::{KnowledgeGraphVertices, OntologyVertices, Vertices},
 };
-use crate::{identifier::GraphElementEditionId, subgraph::edges::GraphResolveDepths};
+use crate::{
+    identifier::GraphElementEditionId, store::query::Cursor, subgraph::edges::GraphResolveDepths,
+};
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -17,15 +19,36 @@ pub struct Subgraph {
     vertices: Vertices,
     edges: Edges,
     depths: GraphResolveDepths,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<Cursor>,
+    /// The full-text search relevance of the roots, ordered from the most to the least relevant.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    search_ranks: Vec<SearchRank>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchRank {
+    edition_id: GraphElementEditionId,
+    rank: f32,
 }
 
 impl From<crate::subgraph::Subgraph> for Subgraph {
     fn from(subgraph: crate::subgraph::Subgraph) -> Self {
+        let mut search_ranks = subgraph
+            .search_ranks
+            .into_iter()
+            .map(|(edition_id, rank)| SearchRank { edition_id, rank })
+            .collect::<Vec<_>>();
+        search_ranks.sort_by(|lhs, rhs| rhs.rank.total_cmp(&lhs.rank));
+
         Self {
             roots: subgraph.roots.into_iter().collect(),
             vertices: subgraph.vertices.into(),
             edges: subgraph.edges.into(),
             depths: subgraph.depths,
+            next_cursor: subgraph.next_cursor,
+            search_ranks,
         }
     }
 }
@@ -1,4 +1,5 @@
 mod query;
+mod validation;
 
 use std::{collections::HashMap, fmt};
 
@@ -8,7 +9,13 @@ use type_system::uri::{BaseUri, VersionedUri};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-pub use self::query::{EntityQueryPath, EntityQueryPathVisitor, EntityQueryToken};
+pub use self::{
+    query::{EntityQueryPath, EntityQueryPathVisitor, EntityQueryToken},
+    validation::{
+        EntityValidationError, EntityValidator, LinkDataViolation, PropertyPath,
+        PropertyPathElement, PropertyViolation, PropertyViolationReason,
+    },
+};
 use crate::{
     identifier::knowledge::{EntityEditionId, EntityId},
     provenance::ProvenanceMetadata,
@@ -51,7 +58,19 @@ impl fmt::Display for EntityUuid {
 }
 
 #[derive(
-    Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, FromSql, ToSql,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    FromSql,
+    ToSql,
 )]
 #[repr(transparent)]
 #[postgres(transparent)]
@@ -83,6 +102,10 @@ impl EntityProperties {
     pub const fn properties(&self) -> &HashMap<BaseUri, serde_json::Value> {
         &self.0
     }
+
+    pub(crate) fn properties_mut(&mut self) -> &mut HashMap<BaseUri, serde_json::Value> {
+        &mut self.0
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
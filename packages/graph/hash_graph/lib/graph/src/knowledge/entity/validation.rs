@@ -0,0 +1,806 @@
+//! Validation of [`EntityProperties`] and [`LinkData`] against an [`EntityType`].
+//!
+//! The Block Protocol type system describes entity types as JSON schemas, which reference property
+//! types and data types by their [`VersionedUri`]. To validate an entity, the entity type, its
+//! parents, and every ontology type reachable from them are resolved once into an
+//! [`EntityValidator`], which can then check any number of entities.
+//!
+//! [`EntityType`]: type_system::EntityType
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use error_stack::{Context, IntoReport, Report, Result, ResultExt};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use type_system::{repr, uri::VersionedUri};
+
+use crate::{
+    knowledge::{EntityProperties, LinkData},
+    ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
+    store::{
+        crud::Read,
+        query::{Filter, TemporalAxes},
+        QueryError,
+    },
+};
+
+const LINK_ENTITY_TYPE_BASE_URI: &str =
+    "https://blockprotocol.org/@blockprotocol/types/entity-type/link/";
+
+#[derive(Debug)]
+#[must_use]
+pub struct EntityValidationError;
+
+impl fmt::Display for EntityValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("entity does not conform to its entity type")
+    }
+}
+
+impl Context for EntityValidationError {}
+
+/// A single step in a [`PropertyPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum PropertyPathElement {
+    /// The property identified by its base URI.
+    Property(String),
+    /// The element at the specified position of an array.
+    Index(usize),
+}
+
+/// The location of a value inside of [`EntityProperties`].
+///
+/// The path is displayed in a JSONPath-like notation, e.g.
+/// `$["https://example.com/property-type/name/"][0]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct PropertyPath(Vec<PropertyPathElement>);
+
+impl PropertyPath {
+    #[must_use]
+    pub fn elements(&self) -> &[PropertyPathElement] {
+        &self.0
+    }
+}
+
+impl fmt::Display for PropertyPath {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("$")?;
+        for element in &self.0 {
+            match element {
+                PropertyPathElement::Property(base_uri) => write!(fmt, r#"["{base_uri}"]"#)?,
+                PropertyPathElement::Index(index) => write!(fmt, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The reason why a value does not conform to its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PropertyViolationReason {
+    /// A required property was not specified.
+    MissingRequired,
+    /// A property was specified, which is not allowed by the schema.
+    Unexpected,
+    /// The JSON type of the value does not match the expected type.
+    InvalidType {
+        expected: String,
+        actual: &'static str,
+    },
+    /// The value does not match the constant value required by the data type.
+    InvalidConstant,
+    /// An array contains fewer items than required.
+    TooFewItems { min: usize, actual: usize },
+    /// An array contains more items than allowed.
+    TooManyItems { max: usize, actual: usize },
+    /// The value does not match any of the possible values of the property type.
+    NoMatchingValue,
+    /// A referenced ontology type could not be resolved.
+    UnresolvedReference { uri: String },
+}
+
+impl fmt::Display for PropertyViolationReason {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRequired => fmt.write_str("required property is missing"),
+            Self::Unexpected => fmt.write_str("property is not allowed by the schema"),
+            Self::InvalidType { expected, actual } => {
+                write!(
+                    fmt,
+                    "expected value of type `{expected}` but found `{actual}`"
+                )
+            }
+            Self::InvalidConstant => fmt.write_str("value does not match the constant value"),
+            Self::TooFewItems { min, actual } => {
+                write!(fmt, "expected at least {min} items but found {actual}")
+            }
+            Self::TooManyItems { max, actual } => {
+                write!(fmt, "expected at most {max} items but found {actual}")
+            }
+            Self::NoMatchingValue => {
+                fmt.write_str("value does not match any of the possible values")
+            }
+            Self::UnresolvedReference { uri } => write!(fmt, "could not resolve `{uri}`"),
+        }
+    }
+}
+
+/// A value at [`path`] which does not conform to the schema.
+///
+/// [`path`]: Self::path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PropertyViolation {
+    path: PropertyPath,
+    reason: PropertyViolationReason,
+}
+
+impl PropertyViolation {
+    #[must_use]
+    pub const fn path(&self) -> &PropertyPath {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn reason(&self) -> &PropertyViolationReason {
+        &self.reason
+    }
+}
+
+impl fmt::Display for PropertyViolation {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}: {}", self.path, self.reason)
+    }
+}
+
+/// The [`LinkData`] of an entity does not match its entity type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkDataViolation {
+    /// The entity type is a link entity type but no link data was provided.
+    MissingLinkData,
+    /// Link data was provided but the entity type is not a link entity type.
+    UnexpectedLinkData,
+}
+
+impl fmt::Display for LinkDataViolation {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLinkData => {
+                fmt.write_str("the entity type is a link entity type but no link data was provided")
+            }
+            Self::UnexpectedLinkData => fmt
+                .write_str("link data was provided but the entity type is not a link entity type"),
+        }
+    }
+}
+
+/// An entity type with all of its parents, property types, and data types resolved.
+#[derive(Debug, Default)]
+pub struct EntityValidator {
+    is_link: bool,
+    properties: Map<String, Value>,
+    required: HashSet<String>,
+    property_types: HashMap<String, Value>,
+    data_types: HashMap<String, Value>,
+}
+
+/// Returns the `"$ref"` of a property type reference, which may be wrapped in an array.
+fn property_type_reference(schema: &Value) -> Option<&str> {
+    schema.get("items").unwrap_or(schema).get("$ref")?.as_str()
+}
+
+/// Collects the references inside of a `"oneOf"` of a property type.
+///
+/// A `"$ref"` at this level refers to a data type while the values of a property type object
+/// refer to property types.
+fn collect_one_of_references(
+    one_of: Option<&Value>,
+    data_types: &mut Vec<String>,
+    property_types: &mut Vec<String>,
+) {
+    for value in one_of.and_then(Value::as_array).into_iter().flatten() {
+        if let Some(data_type) = value.get("$ref").and_then(Value::as_str) {
+            data_types.push(data_type.to_owned());
+        } else if let Some(items) = value.get("items") {
+            collect_one_of_references(items.get("oneOf"), data_types, property_types);
+        } else if let Some(properties) = value.get("properties").and_then(Value::as_object) {
+            property_types.extend(
+                properties
+                    .values()
+                    .filter_map(property_type_reference)
+                    .map(ToOwned::to_owned),
+            );
+        }
+    }
+}
+
+const fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn parse_uri(uri: &str) -> Result<VersionedUri, QueryError> {
+    VersionedUri::from_str(uri)
+        .into_report()
+        .change_context(QueryError)
+        .attach_printable_lazy(|| uri.to_owned())
+}
+
+impl EntityValidator {
+    /// Resolves the entity type identified by `entity_type_id` from the `store`.
+    ///
+    /// # Errors
+    ///
+    /// - if the entity type or any of its referenced ontology types could not be read
+    pub async fn resolve<S>(store: &S, entity_type_id: &VersionedUri) -> Result<Self, QueryError>
+    where
+        S: Read<EntityTypeWithMetadata>
+            + Read<PropertyTypeWithMetadata>
+            + Read<DataTypeWithMetadata>
+            + Sync,
+    {
+        let mut validator = Self::default();
+
+        let mut visited = HashSet::new();
+        let mut entity_types = vec![entity_type_id.to_string()];
+        while let Some(uri) = entity_types.pop() {
+            if !visited.insert(uri.clone()) {
+                continue;
+            }
+            let uri = parse_uri(&uri)?;
+            let entity_type = Read::<EntityTypeWithMetadata>::read_one(
+                store,
+                &Filter::for_versioned_uri(&uri),
+                &TemporalAxes::default(),
+            )
+            .await?;
+            let schema = serde_json::to_value(repr::EntityType::from(entity_type.inner().clone()))
+                .into_report()
+                .change_context(QueryError)?;
+            entity_types.extend(validator.add_entity_type(&schema));
+        }
+
+        let mut data_types = Vec::new();
+        let mut property_types: Vec<_> = validator
+            .properties
+            .values()
+            .filter_map(property_type_reference)
+            .map(ToOwned::to_owned)
+            .collect();
+        while let Some(uri) = property_types.pop() {
+            if validator.property_types.contains_key(&uri) {
+                continue;
+            }
+            let property_type = Read::<PropertyTypeWithMetadata>::read_one(
+                store,
+                &Filter::for_versioned_uri(&parse_uri(&uri)?),
+                &TemporalAxes::default(),
+            )
+            .await?;
+            let schema =
+                serde_json::to_value(repr::PropertyType::from(property_type.inner().clone()))
+                    .into_report()
+                    .change_context(QueryError)?;
+            collect_one_of_references(schema.get("oneOf"), &mut data_types, &mut property_types);
+            validator.property_types.insert(uri, schema);
+        }
+
+        while let Some(uri) = data_types.pop() {
+            if validator.data_types.contains_key(&uri) {
+                continue;
+            }
+            let data_type = Read::<DataTypeWithMetadata>::read_one(
+                store,
+                &Filter::for_versioned_uri(&parse_uri(&uri)?),
+                &TemporalAxes::default(),
+            )
+            .await?;
+            let schema = serde_json::to_value(repr::DataType::from(data_type.inner().clone()))
+                .into_report()
+                .change_context(QueryError)?;
+            validator.data_types.insert(uri, schema);
+        }
+
+        Ok(validator)
+    }
+
+    /// Merges the properties of an entity type schema into the validator and returns the parent
+    /// entity types, which have to be added as well.
+    fn add_entity_type(&mut self, schema: &Value) -> Vec<String> {
+        if schema
+            .get("$id")
+            .and_then(Value::as_str)
+            .map_or(false, |id| id.starts_with(LINK_ENTITY_TYPE_BASE_URI))
+        {
+            self.is_link = true;
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (base_uri, property) in properties {
+                self.properties
+                    .entry(base_uri.clone())
+                    .or_insert_with(|| property.clone());
+            }
+        }
+        self.required.extend(
+            schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(ToOwned::to_owned),
+        );
+
+        schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|parent| parent.get("$ref")?.as_str())
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+
+    /// Returns if the resolved entity type is a link entity type.
+    #[must_use]
+    pub const fn is_link(&self) -> bool {
+        self.is_link
+    }
+
+    /// Validates the `properties` and `link_data` of an entity.
+    ///
+    /// # Errors
+    ///
+    /// - [`EntityValidationError`] if the entity does not conform to the entity type. Every
+    ///   [`PropertyViolation`] and [`LinkDataViolation`] is attached to the returned report.
+    pub fn validate(
+        &self,
+        properties: &EntityProperties,
+        link_data: Option<&LinkData>,
+    ) -> Result<(), EntityValidationError> {
+        let mut validation = Validation {
+            validator: self,
+            path: Vec::new(),
+            violations: Vec::new(),
+        };
+        let properties: Map<String, Value> = properties
+            .properties()
+            .iter()
+            .map(|(base_uri, value)| (base_uri.as_str().to_owned(), value.clone()))
+            .collect();
+        validation.validate_object(&self.properties, self.required.iter(), &properties);
+
+        let link_data_violation = match (self.is_link, link_data) {
+            (true, None) => Some(LinkDataViolation::MissingLinkData),
+            (false, Some(_)) => Some(LinkDataViolation::UnexpectedLinkData),
+            _ => None,
+        };
+
+        if validation.violations.is_empty() && link_data_violation.is_none() {
+            return Ok(());
+        }
+
+        let mut report = Report::new(EntityValidationError);
+        for violation in validation.violations {
+            report = report.attach_printable(violation);
+        }
+        if let Some(violation) = link_data_violation {
+            report = report.attach_printable(violation);
+        }
+        Err(report)
+    }
+}
+
+struct Validation<'v> {
+    validator: &'v EntityValidator,
+    path: Vec<PropertyPathElement>,
+    violations: Vec<PropertyViolation>,
+}
+
+impl Validation<'_> {
+    fn violation(&mut self, reason: PropertyViolationReason) {
+        self.violations.push(PropertyViolation {
+            path: PropertyPath(self.path.clone()),
+            reason,
+        });
+    }
+
+    fn with_element(&mut self, element: PropertyPathElement, validate: impl FnOnce(&mut Self)) {
+        self.path.push(element);
+        validate(self);
+        self.path.pop();
+    }
+
+    /// Validates an object of properties, where each property is either a reference to a property
+    /// type or an array of these.
+    fn validate_object<'r>(
+        &mut self,
+        schema: &Map<String, Value>,
+        required: impl IntoIterator<Item = &'r String>,
+        object: &Map<String, Value>,
+    ) {
+        for (base_uri, value) in object {
+            self.with_element(
+                PropertyPathElement::Property(base_uri.clone()),
+                |this| match schema.get(base_uri) {
+                    Some(property_schema) => this.validate_property(property_schema, value),
+                    None => this.violation(PropertyViolationReason::Unexpected),
+                },
+            );
+        }
+
+        for base_uri in required {
+            if !object.contains_key(base_uri) {
+                self.with_element(PropertyPathElement::Property(base_uri.clone()), |this| {
+                    this.violation(PropertyViolationReason::MissingRequired);
+                });
+            }
+        }
+    }
+
+    fn validate_array_length(&mut self, schema: &Value, length: usize) {
+        let bound = |key: &str| {
+            schema
+                .get(key)
+                .and_then(Value::as_u64)
+                .and_then(|bound| usize::try_from(bound).ok())
+        };
+        if let Some(min) = bound("minItems").filter(|min| length < *min) {
+            self.violation(PropertyViolationReason::TooFewItems {
+                min,
+                actual: length,
+            });
+        }
+        if let Some(max) = bound("maxItems").filter(|max| length > *max) {
+            self.violation(PropertyViolationReason::TooManyItems {
+                max,
+                actual: length,
+            });
+        }
+    }
+
+    /// Validates a value against `{ "$ref": <property type> }` or an array of it.
+    fn validate_property(&mut self, schema: &Value, value: &Value) {
+        if let Some(items) = schema.get("items") {
+            let Value::Array(values) = value else {
+                self.violation(PropertyViolationReason::InvalidType {
+                    expected: "array".to_owned(),
+                    actual: json_type_name(value),
+                });
+                return;
+            };
+            self.validate_array_length(schema, values.len());
+            for (index, value) in values.iter().enumerate() {
+                self.with_element(PropertyPathElement::Index(index), |this| {
+                    this.validate_property(items, value);
+                });
+            }
+        } else if let Some(uri) = schema.get("$ref").and_then(Value::as_str) {
+            let validator = self.validator;
+            let Some(property_type) = validator.property_types.get(uri) else {
+                self.violation(PropertyViolationReason::UnresolvedReference {
+                    uri: uri.to_owned(),
+                });
+                return;
+            };
+            self.validate_one_of(property_type.get("oneOf"), value);
+        }
+    }
+
+    /// Validates a value against a list of possible values of a property type.
+    ///
+    /// If the value does not match any of the possibilities and there is only one possibility, the
+    /// violations of that possibility are reported, otherwise only a single violation is reported.
+    fn validate_one_of(&mut self, one_of: Option<&Value>, value: &Value) {
+        let possibilities = one_of
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice);
+
+        let mut candidate_violations = Vec::new();
+        for possibility in possibilities {
+            let mut validation = Validation {
+                validator: self.validator,
+                path: self.path.clone(),
+                violations: Vec::new(),
+            };
+            validation.validate_property_values(possibility, value);
+            if validation.violations.is_empty() {
+                return;
+            }
+            candidate_violations = validation.violations;
+        }
+
+        if possibilities.len() == 1 {
+            self.violations.extend(candidate_violations);
+        } else {
+            self.violation(PropertyViolationReason::NoMatchingValue);
+        }
+    }
+
+    /// Validates a value against a single possibility of a property type, which is either a
+    /// reference to a data type, an object of properties, or an array of possible values.
+    fn validate_property_values(&mut self, schema: &Value, value: &Value) {
+        if let Some(uri) = schema.get("$ref").and_then(Value::as_str) {
+            self.validate_data_type(uri, value);
+        } else if let Some(items) = schema.get("items") {
+            let Value::Array(values) = value else {
+                self.violation(PropertyViolationReason::InvalidType {
+                    expected: "array".to_owned(),
+                    actual: json_type_name(value),
+                });
+                return;
+            };
+            self.validate_array_length(schema, values.len());
+            for (index, value) in values.iter().enumerate() {
+                self.with_element(PropertyPathElement::Index(index), |this| {
+                    this.validate_one_of(items.get("oneOf"), value);
+                });
+            }
+        } else if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            let Value::Object(object) = value else {
+                self.violation(PropertyViolationReason::InvalidType {
+                    expected: "object".to_owned(),
+                    actual: json_type_name(value),
+                });
+                return;
+            };
+            let required: Vec<_> = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(ToOwned::to_owned)
+                .collect();
+            self.validate_object(properties, &required, object);
+        }
+    }
+
+    fn validate_data_type(&mut self, uri: &str, value: &Value) {
+        let validator = self.validator;
+        let Some(data_type) = validator.data_types.get(uri) else {
+            self.violation(PropertyViolationReason::UnresolvedReference {
+                uri: uri.to_owned(),
+            });
+            return;
+        };
+
+        if let Some(expected) = data_type.get("type").and_then(Value::as_str) {
+            let actual = json_type_name(value);
+            if expected != actual {
+                self.violation(PropertyViolationReason::InvalidType {
+                    expected: expected.to_owned(),
+                    actual,
+                });
+                return;
+            }
+        }
+
+        if data_type
+            .get("const")
+            .map_or(false, |constant| constant != value)
+        {
+            self.violation(PropertyViolationReason::InvalidConstant);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(
+        entity_types: &[&str],
+        property_types: &[&str],
+        data_types: &[&str],
+    ) -> EntityValidator {
+        let mut validator = EntityValidator::default();
+        for entity_type in entity_types {
+            validator.add_entity_type(&serde_json::from_str(entity_type).expect("invalid JSON"));
+        }
+        for (types, schemas) in [
+            (&mut validator.property_types, property_types),
+            (&mut validator.data_types, data_types),
+        ] {
+            for schema in schemas {
+                let schema: Value = serde_json::from_str(schema).expect("invalid JSON");
+                let id = schema["$id"].as_str().expect("missing `$id`").to_owned();
+                types.insert(id, schema);
+            }
+        }
+        validator
+    }
+
+    fn violations(
+        validator: &EntityValidator,
+        properties: serde_json::Value,
+    ) -> Vec<PropertyViolation> {
+        let properties: EntityProperties =
+            serde_json::from_value(properties).expect("invalid entity properties");
+        validator
+            .validate(&properties, None)
+            .err()
+            .map(|report| {
+                report
+                    .frames()
+                    .filter_map(|frame| frame.downcast_ref::<PropertyViolation>())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn path(elements: impl IntoIterator<Item = PropertyPathElement>) -> PropertyPath {
+        PropertyPath(elements.into_iter().collect())
+    }
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const BLURB: &str = "https://blockprotocol.org/@alice/types/property-type/blurb/";
+
+    fn book() -> EntityValidator {
+        validator(
+            &[graph_test_data::entity_type::BOOK_V1],
+            &[
+                graph_test_data::property_type::NAME_V1,
+                graph_test_data::property_type::BLURB_V1,
+                graph_test_data::property_type::PUBLISHED_ON_V1,
+            ],
+            &[graph_test_data::data_type::TEXT_V1],
+        )
+    }
+
+    #[test]
+    fn valid_entity() {
+        let properties: Value =
+            serde_json::from_str(graph_test_data::entity::BOOK_V1).expect("invalid JSON");
+        assert!(violations(&book(), properties).is_empty());
+    }
+
+    #[test]
+    fn missing_required_property() {
+        assert_eq!(
+            violations(&book(), serde_json::json!({ BLURB: "A book" })),
+            [PropertyViolation {
+                path: path([PropertyPathElement::Property(NAME.to_owned())]),
+                reason: PropertyViolationReason::MissingRequired,
+            }]
+        );
+    }
+
+    #[test]
+    fn unexpected_property() {
+        let unknown = "https://blockprotocol.org/@alice/types/property-type/unknown/";
+        assert_eq!(
+            violations(&book(), serde_json::json!({ NAME: ["Book"], unknown: 5 })),
+            [PropertyViolation {
+                path: path([PropertyPathElement::Property(unknown.to_owned())]),
+                reason: PropertyViolationReason::Unexpected,
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_type_in_array() {
+        assert_eq!(
+            violations(&book(), serde_json::json!({ NAME: ["Book", 5] })),
+            [PropertyViolation {
+                path: path([
+                    PropertyPathElement::Property(NAME.to_owned()),
+                    PropertyPathElement::Index(1)
+                ]),
+                reason: PropertyViolationReason::InvalidType {
+                    expected: "string".to_owned(),
+                    actual: "number",
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn nested_object() {
+        let validator = validator(
+            &[r#"{
+                "$id": "https://example.com/entity-type/contact/v/1",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/contact-information/": {
+                        "$ref": "https://blockprotocol.org/@alice/types/property-type/contact-information/v/1"
+                    }
+                }
+            }"#],
+            &[
+                graph_test_data::property_type::CONTACT_INFORMATION_V1,
+                graph_test_data::property_type::EMAIL_V1,
+                graph_test_data::property_type::PHONE_NUMBER_V1,
+            ],
+            &[graph_test_data::data_type::TEXT_V1],
+        );
+        let contact_information =
+            "https://blockprotocol.org/@alice/types/property-type/contact-information/";
+        let email = "https://blockprotocol.org/@alice/types/property-type/email/";
+        let phone_number = "https://blockprotocol.org/@alice/types/property-type/phone-number/";
+
+        assert!(violations(
+            &validator,
+            serde_json::json!({ contact_information: { email: "alice@example.com" } })
+        )
+        .is_empty());
+        assert_eq!(
+            violations(
+                &validator,
+                serde_json::json!({ contact_information: { phone_number: "0123" } })
+            ),
+            [PropertyViolation {
+                path: path([
+                    PropertyPathElement::Property(contact_information.to_owned()),
+                    PropertyPathElement::Property(email.to_owned()),
+                ]),
+                reason: PropertyViolationReason::MissingRequired,
+            }]
+        );
+    }
+
+    #[test]
+    fn array_bounds() {
+        let validator = validator(
+            &[r#"{
+                "$id": "https://example.com/entity-type/contrived/v/1",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/contrived-property/": {
+                        "$ref": "https://blockprotocol.org/@alice/types/property-type/contrived-property/v/1"
+                    }
+                }
+            }"#],
+            &[graph_test_data::property_type::CONTRIVED_PROPERTY_V1],
+            &[graph_test_data::data_type::NUMBER_V1],
+        );
+        let contrived = "https://blockprotocol.org/@alice/types/property-type/contrived-property/";
+
+        assert!(violations(&validator, serde_json::json!({ contrived: 5 })).is_empty());
+        assert!(violations(&validator, serde_json::json!({ contrived: [1, 2, 3, 4] })).is_empty());
+        assert_eq!(
+            violations(
+                &validator,
+                serde_json::json!({ contrived: [1, 2, 3, 4, 5] })
+            ),
+            [PropertyViolation {
+                path: path([PropertyPathElement::Property(contrived.to_owned())]),
+                reason: PropertyViolationReason::NoMatchingValue,
+            }]
+        );
+    }
+
+    #[test]
+    fn link_data() {
+        let validator = validator(&[graph_test_data::entity_type::LINK_V1], &[], &[]);
+        assert!(validator.is_link());
+        assert!(validator
+            .validate(&EntityProperties::empty(), None)
+            .is_err());
+        assert!(!book().is_link());
+    }
+
+    #[test]
+    fn path_display() {
+        assert_eq!(
+            path([
+                PropertyPathElement::Property(NAME.to_owned()),
+                PropertyPathElement::Index(1)
+            ])
+            .to_string(),
+            format!("$[\"{NAME}\"][1]")
+        );
+    }
+}
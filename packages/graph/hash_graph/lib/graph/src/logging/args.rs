@@ -104,4 +104,9 @@ pub struct LoggingArgs {
     /// Logging output file prefix.
     #[cfg_attr(feature = "clap", clap(short, long, default_value = "out"))]
     pub log_file_prefix: String,
+
+    /// The OpenTelemetry collector endpoint to export traces to using OTLP over gRPC, e.g.
+    /// `http://localhost:4317`. If not set, traces are not exported.
+    #[cfg_attr(feature = "clap", clap(long, env = "HASH_GRAPH_OTLP_ENDPOINT"))]
+    pub otlp_endpoint: Option<String>,
 }
@@ -1,6 +1,8 @@
 use std::{io, path::Path};
 
+use opentelemetry::global;
 use tracing::{Event, Subscriber};
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
     filter::{Directive, LevelFilter},
     fmt::{
@@ -16,7 +18,10 @@ use tracing_subscriber::{
     EnvFilter,
 };
 
-use crate::logging::args::{LogFormat, LogLevel};
+use crate::logging::{
+    args::{LogFormat, LogLevel},
+    otlp,
+};
 
 enum OutputFormatter<T> {
     Full(Format<fmt::format::Full, T>),
@@ -46,8 +51,25 @@ where
     }
 }
 
+/// Flushes the log file and the exported traces when dropped.
+struct LoggingGuard {
+    _json_file_guard: WorkerGuard,
+    exports_traces: bool,
+}
+
+impl Drop for LoggingGuard {
+    fn drop(&mut self) {
+        if self.exports_traces {
+            global::shutdown_tracer_provider();
+        }
+    }
+}
+
 /// Initialize the `tracing` logging setup.
 ///
+/// If `otlp_endpoint` is specified, spans are exported to the OpenTelemetry collector at this
+/// endpoint. Failing to set up the exporter is logged, but does not prevent logging.
+///
 /// # Errors
 ///
 /// - [`TryInitError`], if initializing the [`tracing_subscriber::Registry`] fails.
@@ -56,6 +78,7 @@ pub fn init_logger<P: AsRef<Path>>(
     log_folder: P,
     log_level: Option<LogLevel>,
     log_file_name: &str,
+    otlp_endpoint: Option<&str>,
 ) -> Result<impl Drop, TryInitError> {
     let log_folder = log_folder.as_ref();
 
@@ -122,13 +145,30 @@ pub fn init_logger<P: AsRef<Path>>(
         .fmt_fields(JsonFields::new())
         .with_writer(non_blocking);
 
+    let (otlp_layer, otlp_error) = match otlp_endpoint.map(otlp::install_tracer).transpose() {
+        Ok(tracer) => (
+            tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer)),
+            None,
+        ),
+        Err(error) => (None, Some(error)),
+    };
+    let exports_traces = otlp_layer.is_some();
+
     tracing_subscriber::registry()
         .with(filter)
         .with(output_layer)
         .with(json_output_layer)
         .with(json_file_layer)
+        .with(otlp_layer)
         .with(error_layer)
         .try_init()?;
 
-    Ok(json_file_guard)
+    if let Some(error) = otlp_error {
+        tracing::error!(%error, "Could not set up the OpenTelemetry exporter");
+    }
+
+    Ok(LoggingGuard {
+        _json_file_guard: json_file_guard,
+        exports_traces,
+    })
 }
@@ -1,5 +1,7 @@
 mod args;
 mod init;
+mod otlp;
 
 pub use args::LoggingArgs;
 pub use init::init_logger;
+pub use otlp::make_request_span;
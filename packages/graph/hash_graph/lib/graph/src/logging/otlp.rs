@@ -0,0 +1,68 @@
+use axum::http::{HeaderMap, Request};
+use opentelemetry::{
+    global,
+    propagation::Extractor,
+    sdk::{
+        propagation::TraceContextPropagator,
+        trace::{self, Tracer},
+        Resource,
+    },
+    trace::TraceError,
+    KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Installs a batching OTLP exporter sending traces to the collector at `endpoint`.
+///
+/// The W3C trace context propagator is installed as well, so parents of spans can be read from
+/// `traceparent` headers by [`make_request_span`].
+pub(super) fn install_tracer(endpoint: &str) -> Result<Tracer, TraceError> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(
+            trace::config().with_resource(Resource::new([KeyValue::new("service.name", "graph")])),
+        )
+        .install_batch(opentelemetry::runtime::Tokio)
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/// Creates the span in which `request` is handled.
+///
+/// If the request has a `traceparent` header, the span is a child of the referenced remote span,
+/// so the request is part of the trace of the caller. Without an installed propagator, e.g. if no
+/// OTLP endpoint is configured, the headers are ignored.
+pub fn make_request_span<B>(request: &Request<B>) -> Span {
+    let span = tracing::info_span!(
+        "request",
+        otel.kind = "server",
+        http.method = %request.method(),
+        http.target = %request.uri(),
+    );
+
+    let parent_context = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    span.set_parent(parent_context);
+
+    span
+}
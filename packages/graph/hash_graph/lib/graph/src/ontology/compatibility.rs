@@ -0,0 +1,703 @@
+//! Compatibility analysis between two versions of an ontology type.
+//!
+//! The analysis compares the JSON representation of both versions. A change is classified by its
+//! effect on values, which were valid with respect to the previous version:
+//!
+//! - [`CompatibilityLevel::Additive`]: all of them stay valid
+//! - [`CompatibilityLevel::Breaking`]: some of them may become invalid
+//! - [`CompatibilityLevel::Incompatible`]: none of them can be valid anymore
+//!
+//! References to other ontology types are compared by their [`VersionedUri`], so pointing a
+//! reference to a new version of the referenced type is considered a breaking change.
+
+use std::{collections::BTreeSet, fmt};
+
+use error_stack::{Context, Report, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use type_system::{uri::VersionedUri, DataType, EntityType, PropertyType};
+use utoipa::ToSchema;
+
+use crate::ontology::OntologyType;
+
+/// The effect of a change to an ontology type on values, which were valid before.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum CompatibilityLevel {
+    /// All values valid with respect to the previous version stay valid.
+    Additive,
+    /// Some values valid with respect to the previous version may become invalid.
+    Breaking,
+    /// No value valid with respect to the previous version can be valid anymore.
+    Incompatible,
+}
+
+/// A single change between two versions of an ontology type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaChange {
+    /// The JSON pointer to the changed part of the schema.
+    pointer: String,
+    level: CompatibilityLevel,
+    description: String,
+}
+
+impl SchemaChange {
+    #[must_use]
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    #[must_use]
+    pub const fn level(&self) -> CompatibilityLevel {
+        self.level
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// The changes between two versions of an ontology type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityReport {
+    /// The highest [`CompatibilityLevel`] of all changes.
+    level: CompatibilityLevel,
+    changes: Vec<SchemaChange>,
+}
+
+impl CompatibilityReport {
+    fn new(changes: Vec<SchemaChange>) -> Self {
+        Self {
+            level: changes
+                .iter()
+                .map(SchemaChange::level)
+                .max()
+                .unwrap_or(CompatibilityLevel::Additive),
+            changes,
+        }
+    }
+
+    #[must_use]
+    pub const fn level(&self) -> CompatibilityLevel {
+        self.level
+    }
+
+    #[must_use]
+    pub fn changes(&self) -> &[SchemaChange] {
+        &self.changes
+    }
+}
+
+/// The action taken for an update of an ontology type.
+///
+/// Actions are ordered by their strictness, i.e. `Accept < Warn < Reject`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "camelCase")]
+pub enum CompatibilityAction {
+    /// The update is applied.
+    Accept,
+    /// The update is applied and a warning is logged.
+    Warn,
+    /// The update is rejected.
+    Reject,
+}
+
+/// Specifies how updates of ontology types are handled depending on their [`CompatibilityLevel`].
+///
+/// Additive updates are always accepted. By default, breaking updates are accepted with a warning
+/// and incompatible updates are rejected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CompatibilityPolicy {
+    breaking: CompatibilityAction,
+    incompatible: CompatibilityAction,
+}
+
+impl Default for CompatibilityPolicy {
+    fn default() -> Self {
+        Self {
+            breaking: CompatibilityAction::Warn,
+            incompatible: CompatibilityAction::Reject,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct IncompatibleOntologyTypeUpdate;
+
+impl Context for IncompatibleOntologyTypeUpdate {}
+
+impl fmt::Display for IncompatibleOntologyTypeUpdate {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("the update of the ontology type was rejected by the compatibility policy")
+    }
+}
+
+impl CompatibilityPolicy {
+    #[must_use]
+    pub const fn new(breaking: CompatibilityAction, incompatible: CompatibilityAction) -> Self {
+        Self {
+            breaking,
+            incompatible,
+        }
+    }
+
+    /// Returns a policy, which is at least as strict as `self` and `other` for every
+    /// [`CompatibilityLevel`].
+    #[must_use]
+    pub fn restrict(self, other: Self) -> Self {
+        Self {
+            breaking: self.breaking.max(other.breaking),
+            incompatible: self.incompatible.max(other.incompatible),
+        }
+    }
+
+    /// Returns the action taken for an update with the specified [`CompatibilityLevel`].
+    #[must_use]
+    pub const fn action(&self, level: CompatibilityLevel) -> CompatibilityAction {
+        match level {
+            CompatibilityLevel::Additive => CompatibilityAction::Accept,
+            CompatibilityLevel::Breaking => self.breaking,
+            CompatibilityLevel::Incompatible => self.incompatible,
+        }
+    }
+
+    /// Applies the policy to the update of the ontology type identified by `uri`.
+    ///
+    /// # Errors
+    ///
+    /// - [`IncompatibleOntologyTypeUpdate`] if the policy rejects the update
+    pub fn enforce(
+        &self,
+        uri: &VersionedUri,
+        report: &CompatibilityReport,
+    ) -> Result<(), IncompatibleOntologyTypeUpdate> {
+        match self.action(report.level()) {
+            CompatibilityAction::Accept => Ok(()),
+            CompatibilityAction::Warn => {
+                tracing::warn!(
+                    %uri,
+                    level=?report.level(),
+                    changes=?report.changes(),
+                    "Ontology type update is not backwards compatible"
+                );
+                Ok(())
+            }
+            CompatibilityAction::Reject => {
+                let mut error = Report::new(IncompatibleOntologyTypeUpdate)
+                    .attach_printable(uri.clone())
+                    .attach(report.level());
+                for change in report.changes() {
+                    error = error.attach_printable(format!(
+                        "{}: {}",
+                        change.pointer(),
+                        change.description()
+                    ));
+                }
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Compares two versions of an ontology type.
+pub trait CheckCompatibility: OntologyType + Clone {
+    /// Returns the changes from `previous` to `current`.
+    fn check_compatibility(previous: &Self, current: &Self) -> CompatibilityReport;
+}
+
+fn to_json<T: OntologyType + Clone>(ontology_type: &T) -> JsonValue {
+    serde_json::to_value(T::Representation::from(ontology_type.clone()))
+        .expect("the representation of an ontology type is valid JSON")
+}
+
+/// Escapes `segment` to be used in a JSON pointer as specified in RFC 6901.
+pub(crate) fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Returns a short description of a schema, which is the referenced URI if it's a reference.
+fn describe(value: &JsonValue) -> String {
+    value
+        .get("$ref")
+        .and_then(JsonValue::as_str)
+        .map_or_else(|| value.to_string(), ToOwned::to_owned)
+}
+
+fn object<'v>(value: &'v JsonValue, key: &str) -> Option<&'v Map<String, JsonValue>> {
+    value.get(key)?.as_object()
+}
+
+fn string_set<'v>(value: &'v JsonValue, key: &str) -> BTreeSet<&'v str> {
+    value
+        .get(key)
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(JsonValue::as_str)
+        .collect()
+}
+
+/// The keys, which are compared by the dedicated rules of every kind of ontology type.
+const COMMON_KEYS: [&str; 3] = ["$id", "kind", "title"];
+
+#[derive(Default)]
+struct Differ {
+    changes: Vec<SchemaChange>,
+}
+
+impl Differ {
+    fn push(&mut self, pointer: String, level: CompatibilityLevel, description: String) {
+        self.changes.push(SchemaChange {
+            pointer,
+            level,
+            description,
+        });
+    }
+
+    /// Compares annotations, which don't affect validation.
+    fn annotations(&mut self, previous: &JsonValue, current: &JsonValue, keys: &[&str]) {
+        for key in keys {
+            if previous.get(key) != current.get(key) {
+                self.push(
+                    format!("/{key}"),
+                    CompatibilityLevel::Additive,
+                    format!("`{key}` was changed"),
+                );
+            }
+        }
+    }
+
+    /// Compares all top-level keys, which are not in `known_keys`.
+    ///
+    /// Adding or changing a keyword may restrict valid values, while removing one can only allow
+    /// more values.
+    fn remaining_keywords(
+        &mut self,
+        previous: &JsonValue,
+        current: &JsonValue,
+        known_keys: &[&str],
+    ) {
+        let empty = Map::new();
+        let previous = previous.as_object().unwrap_or(&empty);
+        let current = current.as_object().unwrap_or(&empty);
+
+        let keys = previous
+            .keys()
+            .chain(current.keys())
+            .filter(|key| {
+                !COMMON_KEYS.contains(&key.as_str()) && !known_keys.contains(&key.as_str())
+            })
+            .collect::<BTreeSet<_>>();
+
+        for key in keys {
+            let pointer = format!("/{}", escape_pointer_segment(key));
+            match (previous.get(key), current.get(key)) {
+                (Some(_), None) => self.push(
+                    pointer,
+                    CompatibilityLevel::Additive,
+                    format!("`{key}` was removed"),
+                ),
+                (None, Some(_)) => self.push(
+                    pointer,
+                    CompatibilityLevel::Breaking,
+                    format!("`{key}` was added"),
+                ),
+                (Some(previous), Some(current)) if previous != current => self.push(
+                    pointer,
+                    CompatibilityLevel::Breaking,
+                    format!("`{key}` was changed"),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    /// Compares the entries of the object at `key`, e.g. the properties of an entity type.
+    ///
+    /// Values can only contain the entries specified in the schema, so removing an entry is a
+    /// breaking change.
+    fn entries(&mut self, previous: &JsonValue, current: &JsonValue, key: &str, name: &str) {
+        let empty = Map::new();
+        let previous = object(previous, key).unwrap_or(&empty);
+        let current = object(current, key).unwrap_or(&empty);
+
+        for entry in previous
+            .keys()
+            .chain(current.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            let pointer = format!("/{key}/{}", escape_pointer_segment(entry));
+            match (previous.get(entry), current.get(entry)) {
+                (Some(_), None) => self.push(
+                    pointer,
+                    CompatibilityLevel::Breaking,
+                    format!("{name} `{entry}` was removed"),
+                ),
+                (None, Some(_)) => self.push(
+                    pointer,
+                    CompatibilityLevel::Additive,
+                    format!("{name} `{entry}` was added"),
+                ),
+                (Some(previous), Some(current)) if previous != current => self.push(
+                    pointer,
+                    CompatibilityLevel::Breaking,
+                    format!(
+                        "{name} `{entry}` was changed from `{}` to `{}`",
+                        describe(previous),
+                        describe(current)
+                    ),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    /// Compares the list of required entries at `required_key` of the object at `key`.
+    ///
+    /// Requiring an entry, which was not allowed before, invalidates all previously valid values.
+    fn required_entries(
+        &mut self,
+        previous: &JsonValue,
+        current: &JsonValue,
+        key: &str,
+        required_key: &str,
+        name: &str,
+    ) {
+        let allowed = object(previous, key);
+        let previous_required = string_set(previous, required_key);
+        let current_required = string_set(current, required_key);
+
+        for entry in current_required.difference(&previous_required) {
+            if allowed.map_or(false, |allowed| allowed.contains_key(*entry)) {
+                self.push(
+                    format!("/{required_key}"),
+                    CompatibilityLevel::Breaking,
+                    format!("{name} `{entry}` is required"),
+                );
+            } else {
+                self.push(
+                    format!("/{required_key}"),
+                    CompatibilityLevel::Incompatible,
+                    format!("{name} `{entry}` is required but was not allowed before"),
+                );
+            }
+        }
+        for entry in previous_required.difference(&current_required) {
+            self.push(
+                format!("/{required_key}"),
+                CompatibilityLevel::Additive,
+                format!("{name} `{entry}` is not required anymore"),
+            );
+        }
+    }
+
+    fn finish(self) -> CompatibilityReport {
+        CompatibilityReport::new(self.changes)
+    }
+}
+
+impl CheckCompatibility for DataType {
+    fn check_compatibility(previous: &Self, current: &Self) -> CompatibilityReport {
+        let previous = to_json(previous);
+        let current = to_json(current);
+
+        let mut differ = Differ::default();
+        differ.annotations(&previous, &current, &["title", "description"]);
+        if previous.get("type") != current.get("type") {
+            differ.push(
+                "/type".to_owned(),
+                CompatibilityLevel::Incompatible,
+                format!(
+                    "`type` was changed from {} to {}",
+                    previous.get("type").unwrap_or(&JsonValue::Null),
+                    current.get("type").unwrap_or(&JsonValue::Null)
+                ),
+            );
+        }
+        differ.remaining_keywords(&previous, &current, &["description", "type"]);
+        differ.finish()
+    }
+}
+
+impl CheckCompatibility for PropertyType {
+    fn check_compatibility(previous: &Self, current: &Self) -> CompatibilityReport {
+        let previous = to_json(previous);
+        let current = to_json(current);
+
+        let mut differ = Differ::default();
+        differ.annotations(&previous, &current, &["title", "description"]);
+
+        let empty = Vec::new();
+        let previous_values = previous
+            .get("oneOf")
+            .and_then(JsonValue::as_array)
+            .unwrap_or(&empty);
+        let current_values = current
+            .get("oneOf")
+            .and_then(JsonValue::as_array)
+            .unwrap_or(&empty);
+
+        for value in previous_values {
+            if !current_values.contains(value) {
+                differ.push(
+                    "/oneOf".to_owned(),
+                    CompatibilityLevel::Breaking,
+                    format!("`{}` is not accepted anymore", describe(value)),
+                );
+            }
+        }
+        for value in current_values {
+            if !previous_values.contains(value) {
+                differ.push(
+                    "/oneOf".to_owned(),
+                    CompatibilityLevel::Additive,
+                    format!("`{}` is accepted", describe(value)),
+                );
+            }
+        }
+        if !previous_values.is_empty()
+            && previous_values
+                .iter()
+                .all(|value| !current_values.contains(value))
+        {
+            differ.push(
+                "/oneOf".to_owned(),
+                CompatibilityLevel::Incompatible,
+                "none of the previously accepted values are accepted anymore".to_owned(),
+            );
+        }
+
+        differ.remaining_keywords(&previous, &current, &["description", "oneOf"]);
+        differ.finish()
+    }
+}
+
+impl CheckCompatibility for EntityType {
+    fn check_compatibility(previous: &Self, current: &Self) -> CompatibilityReport {
+        let previous = to_json(previous);
+        let current = to_json(current);
+
+        let mut differ = Differ::default();
+        differ.annotations(&previous, &current, &[
+            "title",
+            "description",
+            "examples",
+            "default",
+        ]);
+        differ.entries(&previous, &current, "properties", "property");
+        differ.required_entries(&previous, &current, "properties", "required", "property");
+        differ.entries(&previous, &current, "links", "link");
+        differ.required_entries(&previous, &current, "links", "requiredLinks", "link");
+        if previous.get("allOf") != current.get("allOf") {
+            differ.push(
+                "/allOf".to_owned(),
+                CompatibilityLevel::Breaking,
+                "the inherited entity types were changed".to_owned(),
+            );
+        }
+        differ.remaining_keywords(&previous, &current, &[
+            "description",
+            "examples",
+            "default",
+            "properties",
+            "required",
+            "links",
+            "requiredLinks",
+            "allOf",
+        ]);
+        differ.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_test_data::{data_type, entity_type, property_type};
+    use serde_json::json;
+    use type_system::repr;
+
+    use super::*;
+
+    fn parse<T: OntologyType>(value: &str) -> T {
+        let representation: T::Representation =
+            serde_json::from_str(value).expect("could not parse ontology type representation");
+        T::try_from(representation).expect("could not parse ontology type")
+    }
+
+    fn entity_type(properties: JsonValue, required: JsonValue) -> EntityType {
+        let representation: repr::EntityType = serde_json::from_value(json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "properties": properties,
+            "required": required,
+        }))
+        .expect("could not parse entity type representation");
+        EntityType::try_from(representation).expect("could not parse entity type")
+    }
+
+    fn levels(report: &CompatibilityReport) -> Vec<CompatibilityLevel> {
+        report.changes().iter().map(SchemaChange::level).collect()
+    }
+
+    #[test]
+    fn changed_description_is_additive() {
+        let report = DataType::check_compatibility(
+            &parse(data_type::OBJECT_V1),
+            &parse(data_type::OBJECT_V2),
+        );
+
+        assert_eq!(report.level(), CompatibilityLevel::Additive);
+        assert_eq!(report.changes().len(), 1);
+        assert_eq!(report.changes()[0].pointer(), "/description");
+    }
+
+    #[test]
+    fn changed_data_type_is_incompatible() {
+        let text: DataType = parse(data_type::TEXT_V1);
+        let number: DataType = parse(data_type::NUMBER_V1);
+
+        assert_eq!(
+            DataType::check_compatibility(&text, &number).level(),
+            CompatibilityLevel::Incompatible
+        );
+    }
+
+    #[test]
+    fn accepting_more_values_is_additive() {
+        let v1: PropertyType = parse(property_type::USER_ID_V1);
+        let v2: PropertyType = parse(property_type::USER_ID_V2);
+
+        let report = PropertyType::check_compatibility(&v1, &v2);
+        assert_eq!(report.level(), CompatibilityLevel::Additive);
+        assert_eq!(levels(&report), [CompatibilityLevel::Additive]);
+
+        let report = PropertyType::check_compatibility(&v2, &v1);
+        assert_eq!(report.level(), CompatibilityLevel::Breaking);
+        assert_eq!(levels(&report), [CompatibilityLevel::Breaking]);
+    }
+
+    #[test]
+    fn adding_links_is_additive() {
+        let report = EntityType::check_compatibility(
+            &parse(entity_type::PAGE_V1),
+            &parse(entity_type::PAGE_V2),
+        );
+
+        assert_eq!(report.level(), CompatibilityLevel::Additive);
+        assert_eq!(levels(&report), [
+            CompatibilityLevel::Additive,
+            CompatibilityLevel::Additive
+        ]);
+    }
+
+    #[test]
+    fn removing_properties_is_breaking() {
+        let name = "https://blockprotocol.org/@alice/types/property-type/name/";
+        let age = "https://blockprotocol.org/@alice/types/property-type/age/";
+        let previous = entity_type(
+            json!({
+                name: { "$ref": format!("{name}v/1") },
+                age: { "$ref": format!("{age}v/1") },
+            }),
+            json!([name]),
+        );
+        let current = entity_type(
+            json!({ name: { "$ref": format!("{name}v/1") } }),
+            json!([name]),
+        );
+
+        let report = EntityType::check_compatibility(&previous, &current);
+        assert_eq!(report.level(), CompatibilityLevel::Breaking);
+        assert_eq!(
+            report.changes()[0].pointer(),
+            "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1age~1"
+        );
+    }
+
+    #[test]
+    fn requiring_new_properties_is_incompatible() {
+        let name = "https://blockprotocol.org/@alice/types/property-type/name/";
+        let age = "https://blockprotocol.org/@alice/types/property-type/age/";
+        let previous = entity_type(
+            json!({ name: { "$ref": format!("{name}v/1") } }),
+            json!([name]),
+        );
+        let current = entity_type(
+            json!({
+                name: { "$ref": format!("{name}v/1") },
+                age: { "$ref": format!("{age}v/1") },
+            }),
+            json!([name, age]),
+        );
+
+        let report = EntityType::check_compatibility(&previous, &current);
+        assert_eq!(report.level(), CompatibilityLevel::Incompatible);
+        assert_eq!(levels(&report), [
+            CompatibilityLevel::Additive,
+            CompatibilityLevel::Incompatible
+        ]);
+
+        // Requiring a property, which was optional before, only invalidates some entities
+        let report = EntityType::check_compatibility(
+            &current,
+            &entity_type(
+                json!({
+                    name: { "$ref": format!("{name}v/1") },
+                    age: { "$ref": format!("{age}v/1") },
+                }),
+                json!([name]),
+            ),
+        );
+        assert_eq!(report.level(), CompatibilityLevel::Additive);
+    }
+
+    #[test]
+    fn policy() {
+        let uri = VersionedUri::new(
+            type_system::uri::BaseUri::new(
+                "https://blockprotocol.org/@alice/types/data-type/text/".to_owned(),
+            )
+            .expect("invalid base uri"),
+            2,
+        );
+        let text: DataType = parse(data_type::TEXT_V1);
+        let number: DataType = parse(data_type::NUMBER_V1);
+        let incompatible = DataType::check_compatibility(&text, &number);
+
+        let report = CompatibilityPolicy::default()
+            .enforce(&uri, &incompatible)
+            .expect_err("incompatible updates should be rejected by default");
+        assert!(report.contains::<IncompatibleOntologyTypeUpdate>());
+
+        CompatibilityPolicy::new(CompatibilityAction::Reject, CompatibilityAction::Accept)
+            .enforce(&uri, &incompatible)
+            .expect("incompatible updates should be accepted");
+        CompatibilityPolicy::new(CompatibilityAction::Reject, CompatibilityAction::Reject)
+            .enforce(&uri, &DataType::check_compatibility(&text, &text))
+            .expect("additive updates should always be accepted");
+
+        let relaxed =
+            CompatibilityPolicy::new(CompatibilityAction::Accept, CompatibilityAction::Accept);
+        relaxed
+            .restrict(CompatibilityPolicy::default())
+            .enforce(&uri, &incompatible)
+            .expect_err("restricting a policy should not relax the other policy");
+        assert_eq!(
+            relaxed.restrict(CompatibilityPolicy::default()),
+            CompatibilityPolicy::default()
+        );
+    }
+
+    #[test]
+    fn pointer_segments_are_escaped() {
+        assert_eq!(escape_pointer_segment("a~b/c"), "a~0b~1c");
+    }
+}
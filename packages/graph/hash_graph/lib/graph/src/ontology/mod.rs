@@ -1,5 +1,6 @@
 //! TODO: DOC
 
+mod compatibility;
 mod data_type;
 pub mod domain_validator;
 mod entity_type;
@@ -16,7 +17,12 @@ use type_system::{
 };
 use utoipa::ToSchema;
 
+pub(crate) use self::compatibility::escape_pointer_segment;
 pub use self::{
+    compatibility::{
+        CheckCompatibility, CompatibilityAction, CompatibilityLevel, CompatibilityPolicy,
+        CompatibilityReport, IncompatibleOntologyTypeUpdate, SchemaChange,
+    },
     data_type::{DataTypeQueryPath, DataTypeQueryPathVisitor, DataTypeQueryToken},
     entity_type::{EntityTypeQueryPath, EntityTypeQueryPathVisitor, EntityTypeQueryToken},
     property_type::{PropertyTypeQueryPath, PropertyTypeQueryPathVisitor, PropertyTypeQueryToken},
@@ -177,6 +183,43 @@ impl OntologyElementMetadata {
     }
 }
 
+/// The metadata of an updated ontology type alongside the changes to the previous version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OntologyUpdateMetadata {
+    #[serde(flatten)]
+    metadata: OntologyElementMetadata,
+    compatibility: CompatibilityReport,
+}
+
+impl OntologyUpdateMetadata {
+    #[must_use]
+    pub const fn new(
+        metadata: OntologyElementMetadata,
+        compatibility: CompatibilityReport,
+    ) -> Self {
+        Self {
+            metadata,
+            compatibility,
+        }
+    }
+
+    #[must_use]
+    pub const fn metadata(&self) -> &OntologyElementMetadata {
+        &self.metadata
+    }
+
+    #[must_use]
+    pub const fn compatibility(&self) -> &CompatibilityReport {
+        &self.compatibility
+    }
+
+    #[must_use]
+    pub fn into_metadata(self) -> OntologyElementMetadata {
+        self.metadata
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, ToSchema)]
 pub struct EntityTypeWithMetadata {
     #[schema(value_type = VAR_ENTITY_TYPE)]
@@ -223,6 +266,8 @@ pub trait PersistedOntologyType {
     type OntologyType: OntologyType;
 
     fn new(record: Self::OntologyType, metadata: OntologyElementMetadata) -> Self;
+
+    fn metadata(&self) -> &OntologyElementMetadata;
 }
 
 impl PersistedOntologyType for DataTypeWithMetadata {
@@ -234,6 +279,10 @@ impl PersistedOntologyType for DataTypeWithMetadata {
             metadata,
         }
     }
+
+    fn metadata(&self) -> &OntologyElementMetadata {
+        &self.metadata
+    }
 }
 
 impl PersistedOntologyType for PropertyTypeWithMetadata {
@@ -245,6 +294,10 @@ impl PersistedOntologyType for PropertyTypeWithMetadata {
             metadata,
         }
     }
+
+    fn metadata(&self) -> &OntologyElementMetadata {
+        &self.metadata
+    }
 }
 
 impl PersistedOntologyType for EntityTypeWithMetadata {
@@ -256,4 +309,8 @@ impl PersistedOntologyType for EntityTypeWithMetadata {
             metadata,
         }
     }
+
+    fn metadata(&self) -> &OntologyElementMetadata {
+        &self.metadata
+    }
 }
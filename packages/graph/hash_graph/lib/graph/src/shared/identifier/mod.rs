@@ -29,6 +29,13 @@ pub mod ontology;
 #[postgres(transparent)]
 pub struct DecisionTimestamp(DateTime<Utc>);
 
+impl DecisionTimestamp {
+    #[must_use]
+    pub const fn as_date_time(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
 impl fmt::Display for DecisionTimestamp {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         fmt::Display::fmt(&self.0, fmt)
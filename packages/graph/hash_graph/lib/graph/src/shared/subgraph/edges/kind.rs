@@ -55,8 +55,17 @@ pub enum SharedEdgeKind {
     IsOfType,
 }
 
+/// The number of edges of a kind to follow in each direction.
+///
+/// Following an edge in `outgoing` direction resolves the vertex the edge points to, e.g. the
+/// [`PropertyType`]s an [`EntityType`] constrains its properties on. Following it in `incoming`
+/// direction resolves the vertices pointing to the current vertex, e.g. the [`EntityType`]s
+/// constraining their properties on a [`PropertyType`]. Omitted directions default to `0`.
+///
+/// [`PropertyType`]: type_system::PropertyType
+/// [`EntityType`]: type_system::EntityType
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
-#[serde(deny_unknown_fields)]
+#[serde(default, deny_unknown_fields)]
 pub struct EdgeResolveDepths {
     pub incoming: u8,
     pub outgoing: u8,
@@ -81,43 +90,16 @@ impl EdgeResolveDepths {
     }
 }
 
-// TODO: Replace with `EdgeResolveDepths`
-//   see https://app.asana.com/0/1201095311341924/1203399511264512/f
-#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
-#[serde(deny_unknown_fields)]
-pub struct OutgoingEdgeResolveDepth {
-    pub outgoing: u8,
-    #[serde(default, skip)]
-    #[doc(hidden)]
-    /// This is not used yet, but will be used in the future to support incoming edges.
-    pub incoming: u8,
-}
-
-impl OutgoingEdgeResolveDepth {
-    #[expect(
-        clippy::useless_let_if_seq,
-        reason = "Be consistent with `EdgeResolveDepths`"
-    )]
-    pub fn update(&mut self, other: Self) -> bool {
-        let mut changed = false;
-        if other.outgoing > self.outgoing {
-            self.outgoing = other.outgoing;
-            changed = true;
-        }
-        changed
-    }
-}
-
 /// TODO: DOC - <https://app.asana.com/0/0/1203438518991188/f>
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct GraphResolveDepths {
-    pub inherits_from: OutgoingEdgeResolveDepth,
-    pub constrains_values_on: OutgoingEdgeResolveDepth,
-    pub constrains_properties_on: OutgoingEdgeResolveDepth,
-    pub constrains_links_on: OutgoingEdgeResolveDepth,
-    pub constrains_link_destinations_on: OutgoingEdgeResolveDepth,
-    pub is_of_type: OutgoingEdgeResolveDepth,
+    pub inherits_from: EdgeResolveDepths,
+    pub constrains_values_on: EdgeResolveDepths,
+    pub constrains_properties_on: EdgeResolveDepths,
+    pub constrains_links_on: EdgeResolveDepths,
+    pub constrains_link_destinations_on: EdgeResolveDepths,
+    pub is_of_type: EdgeResolveDepths,
     pub has_left_entity: EdgeResolveDepths,
     pub has_right_entity: EdgeResolveDepths,
 }
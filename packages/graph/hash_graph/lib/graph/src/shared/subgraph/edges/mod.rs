@@ -9,7 +9,7 @@ pub use self::{
     edge::{KnowledgeGraphOutwardEdges, OntologyOutwardEdges, OutwardEdge},
     kind::{
         EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind, OntologyEdgeKind,
-        OutgoingEdgeResolveDepth, SharedEdgeKind,
+        SharedEdgeKind,
     },
 };
 
@@ -1,9 +1,13 @@
-use std::{collections::HashSet, fmt::Debug};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
 
 use edges::Edges;
 
 use crate::{
     shared::identifier::GraphElementEditionId,
+    store::query::Cursor,
     subgraph::{edges::GraphResolveDepths, vertices::Vertices},
 };
 
@@ -17,6 +21,9 @@ pub struct Subgraph {
     pub vertices: Vertices,
     pub edges: Edges,
     pub depths: GraphResolveDepths,
+    pub next_cursor: Option<Cursor>,
+    /// The full-text search relevance of the roots, if the query contained a search.
+    pub search_ranks: HashMap<GraphElementEditionId, f32>,
 }
 
 impl Subgraph {
@@ -27,6 +34,8 @@ impl Subgraph {
             vertices: Vertices::default(),
             edges: Edges::default(),
             depths,
+            next_cursor: None,
+            search_ranks: HashMap::new(),
         }
     }
 }
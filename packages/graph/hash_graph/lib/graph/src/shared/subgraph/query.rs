@@ -6,7 +6,7 @@ use utoipa::ToSchema;
 use crate::{
     knowledge::Entity,
     ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
-    store::query::{Filter, QueryRecord},
+    store::query::{Aggregate, Filter, Pagination, QueryRecord, TemporalAxes},
     subgraph::edges::GraphResolveDepths,
 };
 
@@ -41,6 +41,36 @@ use crate::{
 /// an in-depth explanation of the depth of a query, please see the documentation on
 /// [`GraphResolveDepths`].
 ///
+/// # Pagination
+///
+/// Optionally, the root vertices can be split into pages by specifying a [`Pagination`]. The roots
+/// are then ordered by a stable sort key and at most `limit` roots are returned. If more roots are
+/// available, the returned [`Subgraph`] contains a `nextCursor`, which can be passed as `after` to
+/// read the next page:
+///
+/// ```json
+/// {
+///   "limit": 100,
+///   "after": "68747470733a2f2f6578616d706c652e636f6d2f"
+/// }
+/// ```
+///
+/// # Temporal axes
+///
+/// Entities are versioned in time. By default, the query is resolved with the decision time pinned
+/// to now. To query the graph as it was at a point in time, the [`TemporalAxes`] can be pinned. The
+/// following will return the graph as it was recorded at the given transaction time, while the
+/// decision time defaults to now:
+///
+/// ```json
+/// {
+///   "transactionTime": "2022-12-01T12:00:00Z"
+/// }
+/// ```
+///
+/// The root vertices as well as all vertices reached while following edges are resolved in the
+/// same temporal slice. Ontology types are not versioned in time and are not affected.
+///
 /// # Examples
 ///
 /// Typically, a structural will be deserialized from a JSON request. The following examples assume,
@@ -150,6 +180,7 @@ use crate::{
 ///
 /// [`RecordPath`]: crate::store::query::RecordPath
 /// [`Parameter`]: crate::store::query::Parameter
+/// [`Subgraph`]: crate::subgraph::Subgraph
 #[derive(Deserialize, ToSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 #[aliases(
@@ -162,6 +193,10 @@ pub struct StructuralQuery<'q, T: QueryRecord> {
     #[serde(bound = "'de: 'q, T::Path<'q>: Deserialize<'de>")]
     pub filter: Filter<'q, T>,
     pub graph_resolve_depths: GraphResolveDepths,
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub temporal_axes: TemporalAxes,
 }
 
 // TODO: Derive traits when bounds are generated correctly
@@ -174,6 +209,69 @@ where
         f.debug_struct("StructuralQuery")
             .field("filter", &self.filter)
             .field("graph_resolve_depths", &self.graph_resolve_depths)
+            .field("pagination", &self.pagination)
+            .field("temporal_axes", &self.temporal_axes)
+            .finish()
+    }
+}
+
+/// Aggregation queries compute aggregates over groups of records instead of returning the records.
+///
+/// The records are selected by a [`Filter`] and resolved in the [`TemporalAxes`] as for a
+/// [`StructuralQuery`]. They are then grouped by the values of the `groupBy` paths and every
+/// [`Aggregate`] is computed per group. Without any `groupBy` paths, all records form a single
+/// group.
+///
+/// # Examples
+///
+/// The following `AggregationQuery<Entity>` counts the latest entities per entity type and sums up
+/// the `age` property of them:
+///
+/// ```json
+/// {
+///   "filter": {
+///     "equal": [
+///       { "path": ["version"] },
+///       { "parameter": "latest" }
+///     ]
+///   },
+///   "groupBy": [
+///     ["type", "versionedUri"]
+///   ],
+///   "aggregates": [
+///     "count",
+///     { "sum": ["properties", "https://example.com/property-type/age/"] }
+///   ]
+/// }
+/// ```
+#[derive(Deserialize, ToSchema)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "camelCase",
+    bound = "'de: 'q, T::Path<'q>: Deserialize<'de>"
+)]
+#[aliases(EntityAggregationQuery = AggregationQuery<'static, Entity>)]
+pub struct AggregationQuery<'q, T: QueryRecord> {
+    pub filter: Filter<'q, T>,
+    #[serde(default)]
+    #[schema(value_type = Vec<QueryPath>)]
+    pub group_by: Vec<T::Path<'q>>,
+    #[schema(value_type = Vec<Aggregate>)]
+    pub aggregates: Vec<Aggregate<'q, T>>,
+    #[serde(default)]
+    pub temporal_axes: TemporalAxes,
+}
+
+impl<'q, T> Debug for AggregationQuery<'q, T>
+where
+    T: QueryRecord<Path<'q>: Debug>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AggregationQuery")
+            .field("filter", &self.filter)
+            .field("group_by", &self.group_by)
+            .field("aggregates", &self.aggregates)
+            .field("temporal_axes", &self.temporal_axes)
             .finish()
     }
 }
@@ -0,0 +1,889 @@
+//! Logical backups of a whole graph.
+//!
+//! An archive is a newline-delimited JSON stream of [`ArchiveRecord`]s. Every record only depends
+//! on records preceding it, so an archive can be imported in a single pass:
+//!
+//! 1. accounts and their members
+//! 2. all versions of data types, property types and entity types, every version preceded by the
+//!    types it references and its previous version
+//! 3. all editions of every entity ordered by their transaction time, every link entity preceded by
+//!    the entities it links
+//!
+//! Importing an archive preserves the [`EntityId`]s and [`OntologyTypeEditionId`]s of the exported
+//! graph as well as the decision times and transaction times of the entity editions. As the store
+//! can't backdate an edition before an already stored edition of the same entity, an archive should
+//! be imported into an empty graph or into a graph it was imported into before.
+//!
+//! [`OntologyTypeEditionId`]: crate::identifier::ontology::OntologyTypeEditionId
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+    io::{BufRead, Write},
+    ops::{Bound, RangeBounds},
+};
+
+use error_stack::{Context, IntoReport, Report, ResultExt};
+use serde::{Deserialize, Serialize};
+use type_system::{
+    repr, uri::VersionedUri, DataType, EntityType, EntityTypeReference, PropertyType,
+    PropertyTypeReference,
+};
+
+use crate::{
+    identifier::{account::AccountId, knowledge::EntityId, DecisionTimespan, TransactionTimespan},
+    knowledge::{Entity, EntityProperties, LinkData},
+    ontology::{
+        CompatibilityAction, CompatibilityPolicy, DataTypeWithMetadata, EntityTypeWithMetadata,
+        PersistedOntologyType, PropertyTypeWithMetadata,
+    },
+    provenance::{OwnedById, UpdatedById},
+    store::{
+        crud::Read,
+        query::{Filter, OntologyPath, QueryRecord, TemporalAxes},
+        BaseUriAlreadyExists, QueryError, Store,
+    },
+};
+
+#[derive(Debug)]
+#[must_use]
+pub struct ExportError;
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("could not export the graph")
+    }
+}
+
+impl Context for ExportError {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct ImportError;
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("could not import the graph")
+    }
+}
+
+impl Context for ImportError {}
+
+/// A single line of an archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ArchiveRecord {
+    #[serde(rename_all = "camelCase")]
+    Account { account_id: AccountId },
+    #[serde(rename_all = "camelCase")]
+    AccountMember {
+        account_id: AccountId,
+        member_id: AccountId,
+    },
+    #[serde(rename_all = "camelCase")]
+    DataType {
+        schema: repr::DataType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    },
+    #[serde(rename_all = "camelCase")]
+    PropertyType {
+        schema: repr::PropertyType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    },
+    #[serde(rename_all = "camelCase")]
+    EntityType {
+        schema: repr::EntityType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    },
+    /// A single edition of an entity.
+    #[serde(rename_all = "camelCase")]
+    Entity {
+        entity_id: EntityId,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        link_data: Option<LinkData>,
+        decision_time: DecisionTimespan,
+        transaction_time: TransactionTimespan,
+        updated_by_id: UpdatedById,
+        archived: bool,
+    },
+}
+
+impl ArchiveRecord {
+    fn from_entity(entity: &Entity) -> Self {
+        let metadata = entity.metadata();
+        Self::Entity {
+            entity_id: metadata.edition_id().base_id(),
+            entity_type_id: metadata.entity_type_id().clone(),
+            properties: entity.properties().clone(),
+            link_data: entity.link_data(),
+            decision_time: metadata.edition_id().version().decision_time(),
+            transaction_time: metadata.edition_id().version().transaction_time(),
+            updated_by_id: metadata.provenance_metadata().updated_by_id(),
+            archived: metadata.archived(),
+        }
+    }
+}
+
+/// Orders `records` so that every record is preceded by the records it depends on.
+///
+/// Dependencies, which are not part of `records`, are ignored. The relative order of independent
+/// records is kept.
+fn order_by_dependencies<T, K>(
+    records: Vec<T>,
+    key: impl Fn(&T) -> K,
+    dependencies: impl Fn(&T) -> Vec<K>,
+) -> Vec<T>
+where
+    K: Hash + Eq,
+{
+    let indices = records
+        .iter()
+        .enumerate()
+        .map(|(index, record)| (key(record), index))
+        .collect::<HashMap<_, _>>();
+
+    let mut visited = vec![false; records.len()];
+    let mut order = Vec::with_capacity(records.len());
+    for root in 0..records.len() {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+
+        let mut stack = vec![(root, dependencies(&records[root]).into_iter())];
+        while let Some((index, remaining_dependencies)) = stack.last_mut() {
+            let index = *index;
+            match remaining_dependencies.next() {
+                Some(dependency) => {
+                    if let Some(&dependency_index) = indices.get(&dependency) {
+                        if !visited[dependency_index] {
+                            visited[dependency_index] = true;
+                            stack.push((
+                                dependency_index,
+                                dependencies(&records[dependency_index]).into_iter(),
+                            ));
+                        }
+                    }
+                }
+                None => {
+                    order.push(index);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    let mut records = records.into_iter().map(Some).collect::<Vec<_>>();
+    order
+        .into_iter()
+        .filter_map(|index| records[index].take())
+        .collect()
+}
+
+fn previous_version(uri: &VersionedUri) -> Option<VersionedUri> {
+    (uri.version() > 1).then(|| VersionedUri::new(uri.base_uri().clone(), uri.version() - 1))
+}
+
+fn sort_by_uri<T>(ontology_types: &mut [T], uri: impl Fn(&T) -> &VersionedUri) {
+    ontology_types.sort_by(|lhs, rhs| {
+        let (lhs, rhs) = (uri(lhs), uri(rhs));
+        lhs.base_uri()
+            .as_str()
+            .cmp(rhs.base_uri().as_str())
+            .then(lhs.version().cmp(&rhs.version()))
+    });
+}
+
+fn write_record<W: Write>(
+    writer: &mut W,
+    record: &ArchiveRecord,
+) -> Result<(), Report<ExportError>> {
+    serde_json::to_writer(&mut *writer, record)
+        .into_report()
+        .change_context(ExportError)?;
+    writer
+        .write_all(b"\n")
+        .into_report()
+        .change_context(ExportError)
+}
+
+/// Writes the whole graph stored in `store` as archive to `writer`.
+///
+/// Returns the number of written records.
+///
+/// # Errors
+///
+/// - [`ExportError`] if reading from the store failed
+/// - [`ExportError`] if writing to `writer` failed
+pub async fn export<S, W>(store: &S, mut writer: W) -> Result<usize, Report<ExportError>>
+where
+    S: Store + Sync,
+    W: Write,
+{
+    let mut written = 0;
+
+    for account_id in store.read_accounts().await.change_context(ExportError)? {
+        write_record(&mut writer, &ArchiveRecord::Account { account_id })?;
+        written += 1;
+    }
+    for (account_id, member_id) in store
+        .read_account_members()
+        .await
+        .change_context(ExportError)?
+    {
+        write_record(&mut writer, &ArchiveRecord::AccountMember {
+            account_id,
+            member_id,
+        })?;
+        written += 1;
+    }
+
+    let mut data_types = Read::<DataTypeWithMetadata>::read(
+        store,
+        &Filter::All(Vec::new()),
+        &TemporalAxes::default(),
+    )
+    .await
+    .change_context(ExportError)?;
+    sort_by_uri(&mut data_types, |data_type| data_type.inner().id());
+    for data_type in data_types {
+        write_record(&mut writer, &ArchiveRecord::DataType {
+            schema: repr::DataType::from(data_type.inner().clone()),
+            owned_by_id: data_type.metadata().owned_by_id(),
+            updated_by_id: data_type.metadata().provenance_metadata().updated_by_id(),
+        })?;
+        written += 1;
+    }
+
+    let mut property_types = Read::<PropertyTypeWithMetadata>::read(
+        store,
+        &Filter::All(Vec::new()),
+        &TemporalAxes::default(),
+    )
+    .await
+    .change_context(ExportError)?;
+    sort_by_uri(&mut property_types, |property_type| {
+        property_type.inner().id()
+    });
+    let property_types = order_by_dependencies(
+        property_types,
+        |property_type| property_type.inner().id().clone(),
+        |property_type| {
+            property_type
+                .inner()
+                .property_type_references()
+                .into_iter()
+                .map(PropertyTypeReference::uri)
+                .cloned()
+                .chain(previous_version(property_type.inner().id()))
+                .collect()
+        },
+    );
+    for property_type in property_types {
+        write_record(&mut writer, &ArchiveRecord::PropertyType {
+            schema: repr::PropertyType::from(property_type.inner().clone()),
+            owned_by_id: property_type.metadata().owned_by_id(),
+            updated_by_id: property_type
+                .metadata()
+                .provenance_metadata()
+                .updated_by_id(),
+        })?;
+        written += 1;
+    }
+
+    let mut entity_types = Read::<EntityTypeWithMetadata>::read(
+        store,
+        &Filter::All(Vec::new()),
+        &TemporalAxes::default(),
+    )
+    .await
+    .change_context(ExportError)?;
+    sort_by_uri(&mut entity_types, |entity_type| entity_type.inner().id());
+    let entity_types = order_by_dependencies(
+        entity_types,
+        |entity_type| entity_type.inner().id().clone(),
+        |entity_type| {
+            let entity_type = entity_type.inner();
+            entity_type
+                .link_mappings()
+                .into_keys()
+                .chain(
+                    entity_type
+                        .link_mappings()
+                        .into_values()
+                        .flatten()
+                        .flatten(),
+                )
+                .chain(entity_type.inherits_from().all_of())
+                .map(EntityTypeReference::uri)
+                .cloned()
+                .chain(previous_version(entity_type.id()))
+                .collect()
+        },
+    );
+    for entity_type in entity_types {
+        write_record(&mut writer, &ArchiveRecord::EntityType {
+            schema: repr::EntityType::from(entity_type.inner().clone()),
+            owned_by_id: entity_type.metadata().owned_by_id(),
+            updated_by_id: entity_type.metadata().provenance_metadata().updated_by_id(),
+        })?;
+        written += 1;
+    }
+
+    let mut editions = HashMap::<EntityId, Vec<Entity>>::new();
+    for entity in Read::<Entity>::read(store, &Filter::All(Vec::new()), &TemporalAxes::default())
+        .await
+        .change_context(ExportError)?
+    {
+        editions
+            .entry(entity.metadata().edition_id().base_id())
+            .or_default()
+            .push(entity);
+    }
+    let mut editions = editions.into_values().collect::<Vec<_>>();
+    for entity_editions in &mut editions {
+        entity_editions.sort_by_key(|entity| {
+            entity
+                .metadata()
+                .edition_id()
+                .version()
+                .transaction_time()
+                .as_start_bound_timestamp()
+        });
+    }
+    editions.sort_by_key(|entity_editions| entity_editions[0].metadata().edition_id().base_id());
+    let editions = order_by_dependencies(
+        editions,
+        |entity_editions| entity_editions[0].metadata().edition_id().base_id(),
+        |entity_editions| {
+            entity_editions[0]
+                .link_data()
+                .map_or_else(Vec::new, |link_data| {
+                    vec![link_data.left_entity_id(), link_data.right_entity_id()]
+                })
+        },
+    );
+    for entity in editions.iter().flatten() {
+        write_record(&mut writer, &ArchiveRecord::from_entity(entity))?;
+        written += 1;
+    }
+
+    writer.flush().into_report().change_context(ExportError)?;
+
+    Ok(written)
+}
+
+async fn ontology_type_exists<'q, S, T>(
+    store: &S,
+    type_id: &'q VersionedUri,
+) -> Result<bool, Report<QueryError>>
+where
+    S: Read<T> + Sync,
+    T: PersistedOntologyType + QueryRecord<Path<'q>: OntologyPath> + Send,
+    for<'p> T::Path<'p>: Sync,
+{
+    store
+        .read(
+            &Filter::for_versioned_uri(type_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .map(|ontology_types| !ontology_types.is_empty())
+}
+
+/// Tracks the editions of the entity currently being imported.
+struct ImportedEntity {
+    entity_id: EntityId,
+    existing_editions: usize,
+    editions: usize,
+}
+
+/// The outcome of importing a single [`ArchiveRecord`].
+enum Imported {
+    Inserted,
+    Skipped,
+}
+
+/// Writes the archive read from `reader` into `store`.
+///
+/// Records, which already exist in the store, are skipped, so importing the same archive multiple
+/// times results in the same graph. This also allows to resume an import, which failed midway. For
+/// entities, the editions already present in the store are assumed to be the leading editions of
+/// the archive.
+///
+/// Returns the number of imported records, which does not include skipped records.
+///
+/// # Errors
+///
+/// - [`ImportError`] if reading from `reader` failed or a line is not a valid [`ArchiveRecord`]
+/// - [`ImportError`] if writing a record to the store failed
+pub async fn import<S, R>(store: &mut S, reader: R) -> Result<usize, Report<ImportError>>
+where
+    S: Store + Send + Sync,
+    R: BufRead,
+{
+    // The archive might have been created with a stricter policy, but the updates were already
+    // accepted by the exported graph.
+    let compatibility_policy =
+        CompatibilityPolicy::new(CompatibilityAction::Accept, CompatibilityAction::Accept);
+
+    let existing_accounts = store
+        .read_accounts()
+        .await
+        .change_context(ImportError)?
+        .into_iter()
+        .collect::<HashSet<_>>();
+
+    let mut imported = 0;
+    let mut current_entity: Option<ImportedEntity> = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.into_report().change_context(ImportError)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: ArchiveRecord = serde_json::from_str(&line)
+            .into_report()
+            .change_context(ImportError)
+            .attach_printable_lazy(|| format!("line {line_number} is not a valid record"))?;
+
+        let outcome = match record {
+            ArchiveRecord::Account { account_id } => {
+                if existing_accounts.contains(&account_id) {
+                    Imported::Skipped
+                } else {
+                    store
+                        .insert_account_id(account_id)
+                        .await
+                        .change_context(ImportError)?;
+                    Imported::Inserted
+                }
+            }
+            ArchiveRecord::AccountMember {
+                account_id,
+                member_id,
+            } => {
+                if store
+                    .is_account_member(account_id, member_id)
+                    .await
+                    .change_context(ImportError)?
+                {
+                    Imported::Skipped
+                } else {
+                    store
+                        .insert_account_member(account_id, member_id)
+                        .await
+                        .change_context(ImportError)?;
+                    Imported::Inserted
+                }
+            }
+            ArchiveRecord::DataType {
+                schema,
+                owned_by_id,
+                updated_by_id,
+            } => {
+                let data_type = DataType::try_from(schema)
+                    .into_report()
+                    .change_context(ImportError)?;
+                if ontology_type_exists::<_, DataTypeWithMetadata>(store, data_type.id())
+                    .await
+                    .change_context(ImportError)?
+                {
+                    Imported::Skipped
+                } else {
+                    match store
+                        .create_data_type(data_type.clone(), owned_by_id, updated_by_id)
+                        .await
+                    {
+                        Err(report) if report.contains::<BaseUriAlreadyExists>() => {
+                            store
+                                .update_data_type(data_type, updated_by_id, compatibility_policy)
+                                .await
+                                .change_context(ImportError)?;
+                        }
+                        result => {
+                            result.change_context(ImportError)?;
+                        }
+                    }
+                    Imported::Inserted
+                }
+            }
+            ArchiveRecord::PropertyType {
+                schema,
+                owned_by_id,
+                updated_by_id,
+            } => {
+                let property_type = PropertyType::try_from(schema)
+                    .into_report()
+                    .change_context(ImportError)?;
+                if ontology_type_exists::<_, PropertyTypeWithMetadata>(store, property_type.id())
+                    .await
+                    .change_context(ImportError)?
+                {
+                    Imported::Skipped
+                } else {
+                    match store
+                        .create_property_type(property_type.clone(), owned_by_id, updated_by_id)
+                        .await
+                    {
+                        Err(report) if report.contains::<BaseUriAlreadyExists>() => {
+                            store
+                                .update_property_type(
+                                    property_type,
+                                    updated_by_id,
+                                    compatibility_policy,
+                                )
+                                .await
+                                .change_context(ImportError)?;
+                        }
+                        result => {
+                            result.change_context(ImportError)?;
+                        }
+                    }
+                    Imported::Inserted
+                }
+            }
+            ArchiveRecord::EntityType {
+                schema,
+                owned_by_id,
+                updated_by_id,
+            } => {
+                let entity_type = EntityType::try_from(schema)
+                    .into_report()
+                    .change_context(ImportError)?;
+                if ontology_type_exists::<_, EntityTypeWithMetadata>(store, entity_type.id())
+                    .await
+                    .change_context(ImportError)?
+                {
+                    Imported::Skipped
+                } else {
+                    match store
+                        .create_entity_type(entity_type.clone(), owned_by_id, updated_by_id)
+                        .await
+                    {
+                        Err(report) if report.contains::<BaseUriAlreadyExists>() => {
+                            store
+                                .update_entity_type(
+                                    entity_type,
+                                    updated_by_id,
+                                    compatibility_policy,
+                                )
+                                .await
+                                .change_context(ImportError)?;
+                        }
+                        result => {
+                            result.change_context(ImportError)?;
+                        }
+                    }
+                    Imported::Inserted
+                }
+            }
+            ArchiveRecord::Entity {
+                entity_id,
+                entity_type_id,
+                properties,
+                link_data,
+                decision_time,
+                transaction_time,
+                updated_by_id,
+                archived,
+            } => {
+                let mut entity = match current_entity.take() {
+                    Some(entity) if entity.entity_id == entity_id => entity,
+                    _ => ImportedEntity {
+                        entity_id,
+                        existing_editions: Read::<Entity>::read(
+                            store,
+                            &Filter::for_entity_by_entity_id(entity_id),
+                            &TemporalAxes::default(),
+                        )
+                        .await
+                        .change_context(ImportError)?
+                        .len(),
+                        editions: 0,
+                    },
+                };
+                entity.editions += 1;
+                let (editions, existing_editions) = (entity.editions, entity.existing_editions);
+                current_entity = Some(entity);
+
+                let (
+                    Bound::Included(decision_time) | Bound::Excluded(decision_time),
+                    Bound::Included(transaction_time) | Bound::Excluded(transaction_time),
+                ) = (decision_time.start_bound(), transaction_time.start_bound())
+                else {
+                    return Err(Report::new(ImportError).attach_printable(format!(
+                        "line {line_number}: the edition of entity {} has no start time",
+                        entity_id.entity_uuid()
+                    )));
+                };
+
+                if editions <= existing_editions {
+                    Imported::Skipped
+                } else {
+                    store
+                        .import_entity_edition(
+                            entity_id,
+                            *decision_time,
+                            *transaction_time,
+                            updated_by_id,
+                            archived,
+                            entity_type_id,
+                            properties,
+                            link_data,
+                        )
+                        .await
+                        .change_context(ImportError)?;
+                    Imported::Inserted
+                }
+            }
+        };
+
+        match outcome {
+            Imported::Inserted => imported += 1,
+            Imported::Skipped => {
+                tracing::debug!(line_number, "skipped record, which already exists");
+            }
+        }
+    }
+
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_test_data::{data_type, entity, entity_type, property_type};
+    use type_system::uri::BaseUri;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        identifier::knowledge::EntityVersion,
+        knowledge::EntityLinkOrder,
+        store::{
+            AccountStore, DataTypeStore, EntityStore, EntityTypeStore, MemoryStore,
+            PropertyTypeStore,
+        },
+    };
+
+    fn entity_type_id(name: &str) -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new(format!(
+                "https://blockprotocol.org/@alice/types/entity-type/{name}/"
+            ))
+            .expect("invalid base uri"),
+            1,
+        )
+    }
+
+    async fn seed() -> MemoryStore {
+        let mut store = MemoryStore::new();
+        let account_id = AccountId::new(Uuid::new_v4());
+        let owned_by_id = OwnedById::new(account_id);
+        let actor_id = UpdatedById::new(account_id);
+        store
+            .insert_account_id(account_id)
+            .await
+            .expect("could not insert account id");
+
+        let data_type: repr::DataType =
+            serde_json::from_str(data_type::TEXT_V1).expect("could not parse data type");
+        store
+            .create_data_type(
+                DataType::try_from(data_type).expect("could not parse data type"),
+                owned_by_id,
+                actor_id,
+            )
+            .await
+            .expect("could not create data type");
+
+        let property_type: repr::PropertyType =
+            serde_json::from_str(property_type::NAME_V1).expect("could not parse property type");
+        store
+            .create_property_type(
+                PropertyType::try_from(property_type).expect("could not parse property type"),
+                owned_by_id,
+                actor_id,
+            )
+            .await
+            .expect("could not create property type");
+
+        for entity_type_str in [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ] {
+            let entity_type: repr::EntityType =
+                serde_json::from_str(entity_type_str).expect("could not parse entity type");
+            store
+                .create_entity_type(
+                    EntityType::try_from(entity_type).expect("could not parse entity type"),
+                    owned_by_id,
+                    actor_id,
+                )
+                .await
+                .expect("could not create entity type");
+        }
+
+        let person_a: EntityProperties =
+            serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+        let person_b: EntityProperties =
+            serde_json::from_str(entity::PERSON_B_V1).expect("could not parse entity");
+
+        let alice = store
+            .create_entity(
+                owned_by_id,
+                None,
+                None,
+                actor_id,
+                false,
+                entity_type_id("person"),
+                person_a,
+                None,
+            )
+            .await
+            .expect("could not create entity")
+            .edition_id()
+            .base_id();
+        let bob = store
+            .create_entity(
+                owned_by_id,
+                None,
+                None,
+                actor_id,
+                false,
+                entity_type_id("person"),
+                person_b.clone(),
+                None,
+            )
+            .await
+            .expect("could not create entity")
+            .edition_id()
+            .base_id();
+        store
+            .update_entity(
+                alice,
+                None,
+                actor_id,
+                false,
+                entity_type_id("person"),
+                person_b,
+                EntityLinkOrder::new(None, None),
+            )
+            .await
+            .expect("could not update entity");
+        store
+            .create_entity(
+                owned_by_id,
+                None,
+                None,
+                actor_id,
+                false,
+                entity_type_id("friend-of"),
+                EntityProperties::empty(),
+                Some(LinkData::new(alice, bob, None, None)),
+            )
+            .await
+            .expect("could not create link entity");
+
+        store
+    }
+
+    async fn latest_entities(
+        store: &MemoryStore,
+    ) -> Vec<(EntityId, EntityVersion, EntityProperties)> {
+        let mut entities = Read::<Entity>::read(
+            store,
+            &Filter::for_all_latest_entities(),
+            &TemporalAxes::default(),
+        )
+        .await
+        .expect("could not read entities")
+        .into_iter()
+        .map(|entity| {
+            (
+                entity.metadata().edition_id().base_id(),
+                entity.metadata().edition_id().version(),
+                entity.properties().clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+        entities.sort_by_key(|(entity_id, ..)| *entity_id);
+        entities
+    }
+
+    #[tokio::test]
+    async fn round_trip() {
+        let store = seed().await;
+
+        let mut archive = Vec::new();
+        let written = export(&store, &mut archive)
+            .await
+            .expect("could not export graph");
+        // 1 account, 1 data type, 1 property type, 3 entity types and 4 entity editions
+        assert_eq!(written, 10);
+
+        let mut imported_store = MemoryStore::new();
+        assert_eq!(
+            import(&mut imported_store, archive.as_slice())
+                .await
+                .expect("could not import graph"),
+            written
+        );
+        assert_eq!(
+            latest_entities(&imported_store).await,
+            latest_entities(&store).await
+        );
+
+        // Importing the same archive again does not change the graph.
+        assert_eq!(
+            import(&mut imported_store, archive.as_slice())
+                .await
+                .expect("could not import graph"),
+            0
+        );
+        assert_eq!(
+            latest_entities(&imported_store).await,
+            latest_entities(&store).await
+        );
+    }
+
+    #[test]
+    fn order_by_dependencies_places_dependencies_first() {
+        let records = vec![
+            ("a", vec!["c"]),
+            ("b", vec![]),
+            ("c", vec!["b", "x"]),
+            ("d", vec!["d"]),
+        ];
+
+        let ordered = order_by_dependencies(records, |record| record.0, |record| record.1.clone());
+
+        assert_eq!(ordered.iter().map(|record| record.0).collect::<Vec<_>>(), [
+            "b", "c", "a", "d"
+        ]);
+    }
+
+    #[test]
+    fn previous_version_of_first_version() {
+        let base_uri =
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+                .expect("invalid base uri");
+
+        assert_eq!(
+            previous_version(&VersionedUri::new(base_uri.clone(), 1)),
+            None
+        );
+        assert_eq!(
+            previous_version(&VersionedUri::new(base_uri.clone(), 3)),
+            Some(VersionedUri::new(base_uri, 2))
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use std::fmt;
+
+use error_stack::{Context, Report, ResultExt};
+use serde::{Deserialize, Serialize};
+use type_system::{uri::VersionedUri, DataType, EntityType, PropertyType};
+use utoipa::ToSchema;
+
+use crate::{
+    identifier::knowledge::EntityId,
+    knowledge::{EntityLinkOrder, EntityMetadata, EntityProperties, EntityUuid, LinkData},
+    ontology::{CompatibilityPolicy, OntologyElementMetadata, OntologyUpdateMetadata},
+    provenance::{OwnedById, UpdatedById},
+    store::{DataTypeStore, EntityStore, EntityTypeStore, InsertionError, PropertyTypeStore},
+};
+
+#[derive(Debug)]
+#[must_use]
+pub struct InvalidEntityReference;
+
+impl fmt::Display for InvalidEntityReference {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("the entity reference does not refer to a preceding entity operation")
+    }
+}
+
+impl Context for InvalidEntityReference {}
+
+/// References an entity from inside of a batch of [`BatchOperation`]s.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum EntityReference {
+    /// An entity, which already exists in the store.
+    EntityId(EntityId),
+    /// The entity created or updated by the operation at this index of the batch.
+    ///
+    /// The referenced operation has to precede the operation referencing it.
+    Operation(usize),
+}
+
+impl EntityReference {
+    /// Returns the [`EntityId`] this reference refers to.
+    ///
+    /// `results` are the results of the operations preceding the operation holding this reference.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidEntityReference`] if the referenced operation does not precede the current
+    ///   operation or if it's not an entity operation
+    pub fn resolve(
+        self,
+        results: &[BatchOperationResult],
+    ) -> Result<EntityId, Report<InvalidEntityReference>> {
+        match self {
+            Self::EntityId(entity_id) => Ok(entity_id),
+            Self::Operation(index) => match results.get(index) {
+                Some(BatchOperationResult::Entity(metadata)) => Ok(metadata.edition_id().base_id()),
+                Some(
+                    BatchOperationResult::Ontology(_) | BatchOperationResult::OntologyUpdate(_),
+                ) => Err(Report::new(InvalidEntityReference)
+                    .attach_printable(format!("operation {index} is not an entity operation"))),
+                None => Err(Report::new(InvalidEntityReference)
+                    .attach_printable(format!("operation {index} does not precede the reference"))),
+            },
+        }
+    }
+}
+
+/// The link data of an entity created inside of a batch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct BatchLinkData {
+    pub left_entity: EntityReference,
+    pub right_entity: EntityReference,
+    #[serde(flatten)]
+    pub order: EntityLinkOrder,
+}
+
+/// A single write operation inside of a batch.
+///
+/// The operations are equivalent to the corresponding methods on the stores, e.g.
+/// [`BatchOperation::CreateDataType`] behaves like [`DataTypeStore::create_data_type`].
+///
+/// [`DataTypeStore::create_data_type`]: crate::store::DataTypeStore::create_data_type
+#[derive(Debug)]
+pub enum BatchOperation {
+    CreateDataType {
+        data_type: DataType,
+        owned_by_id: OwnedById,
+        actor_id: UpdatedById,
+    },
+    UpdateDataType {
+        data_type: DataType,
+        actor_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    },
+    CreatePropertyType {
+        property_type: PropertyType,
+        owned_by_id: OwnedById,
+        actor_id: UpdatedById,
+    },
+    UpdatePropertyType {
+        property_type: PropertyType,
+        actor_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    },
+    CreateEntityType {
+        entity_type: EntityType,
+        owned_by_id: OwnedById,
+        actor_id: UpdatedById,
+    },
+    UpdateEntityType {
+        entity_type: EntityType,
+        actor_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    },
+    CreateEntity {
+        owned_by_id: OwnedById,
+        entity_uuid: Option<EntityUuid>,
+        actor_id: UpdatedById,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<BatchLinkData>,
+    },
+    UpdateEntity {
+        entity: EntityReference,
+        actor_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_order: EntityLinkOrder,
+    },
+}
+
+/// The result of a single [`BatchOperation`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BatchOperationResult {
+    Ontology(OntologyElementMetadata),
+    OntologyUpdate(OntologyUpdateMetadata),
+    Entity(EntityMetadata),
+}
+
+/// Applies the [`BatchOperation`]s in order to `store`.
+///
+/// This does not open a transaction, so the caller is responsible for only persisting the changes
+/// if all operations succeeded.
+pub(super) async fn apply_operations<S>(
+    store: &mut S,
+    operations: Vec<BatchOperation>,
+) -> Result<Vec<BatchOperationResult>, Report<InsertionError>>
+where
+    S: DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore + Send,
+{
+    let mut results = Vec::with_capacity(operations.len());
+    for (index, operation) in operations.into_iter().enumerate() {
+        let result = match operation {
+            BatchOperation::CreateDataType {
+                data_type,
+                owned_by_id,
+                actor_id,
+            } => store
+                .create_data_type(data_type, owned_by_id, actor_id)
+                .await
+                .map(BatchOperationResult::Ontology),
+            BatchOperation::UpdateDataType {
+                data_type,
+                actor_id,
+                compatibility_policy,
+            } => store
+                .update_data_type(data_type, actor_id, compatibility_policy)
+                .await
+                .change_context(InsertionError)
+                .map(BatchOperationResult::OntologyUpdate),
+            BatchOperation::CreatePropertyType {
+                property_type,
+                owned_by_id,
+                actor_id,
+            } => store
+                .create_property_type(property_type, owned_by_id, actor_id)
+                .await
+                .map(BatchOperationResult::Ontology),
+            BatchOperation::UpdatePropertyType {
+                property_type,
+                actor_id,
+                compatibility_policy,
+            } => store
+                .update_property_type(property_type, actor_id, compatibility_policy)
+                .await
+                .change_context(InsertionError)
+                .map(BatchOperationResult::OntologyUpdate),
+            BatchOperation::CreateEntityType {
+                entity_type,
+                owned_by_id,
+                actor_id,
+            } => store
+                .create_entity_type(entity_type, owned_by_id, actor_id)
+                .await
+                .map(BatchOperationResult::Ontology),
+            BatchOperation::UpdateEntityType {
+                entity_type,
+                actor_id,
+                compatibility_policy,
+            } => store
+                .update_entity_type(entity_type, actor_id, compatibility_policy)
+                .await
+                .change_context(InsertionError)
+                .map(BatchOperationResult::OntologyUpdate),
+            BatchOperation::CreateEntity {
+                owned_by_id,
+                entity_uuid,
+                actor_id,
+                entity_type_id,
+                properties,
+                link_data,
+            } => {
+                let link_data = match link_data {
+                    Some(link_data) => Some(LinkData::new(
+                        link_data
+                            .left_entity
+                            .resolve(&results)
+                            .change_context(InsertionError)?,
+                        link_data
+                            .right_entity
+                            .resolve(&results)
+                            .change_context(InsertionError)?,
+                        link_data.order.left_to_right(),
+                        link_data.order.right_to_left(),
+                    )),
+                    None => None,
+                };
+
+                store
+                    .create_entity(
+                        owned_by_id,
+                        entity_uuid,
+                        None,
+                        actor_id,
+                        false,
+                        entity_type_id,
+                        properties,
+                        link_data,
+                    )
+                    .await
+                    .map(BatchOperationResult::Entity)
+            }
+            BatchOperation::UpdateEntity {
+                entity,
+                actor_id,
+                archived,
+                entity_type_id,
+                properties,
+                link_order,
+            } => {
+                let entity_id = entity.resolve(&results).change_context(InsertionError)?;
+
+                store
+                    .update_entity(
+                        entity_id,
+                        None,
+                        actor_id,
+                        archived,
+                        entity_type_id,
+                        properties,
+                        link_order,
+                    )
+                    .await
+                    .change_context(InsertionError)
+                    .map(BatchOperationResult::Entity)
+            }
+        }
+        .attach_printable_lazy(|| format!("could not apply operation {index} of the batch"))?;
+
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        identifier::{
+            account::AccountId,
+            knowledge::{EntityEditionId, EntityRecordId, EntityVersion},
+            ontology::{OntologyTypeEditionId, OntologyTypeVersion},
+            DecisionTimespan, TransactionTimespan,
+        },
+        provenance::ProvenanceMetadata,
+    };
+
+    fn entity_id() -> EntityId {
+        EntityId::new(
+            OwnedById::new(AccountId::new(Uuid::new_v4())),
+            EntityUuid::new(Uuid::new_v4()),
+        )
+    }
+
+    fn entity_result(entity_id: EntityId) -> BatchOperationResult {
+        BatchOperationResult::Entity(EntityMetadata::new(
+            EntityEditionId::new(
+                entity_id,
+                EntityRecordId::new(0),
+                EntityVersion::new(
+                    DecisionTimespan::from(chrono::DateTime::default()..),
+                    TransactionTimespan::from(chrono::DateTime::default()..),
+                ),
+            ),
+            VersionedUri::new(
+                type_system::uri::BaseUri::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("invalid base uri"),
+                1,
+            ),
+            ProvenanceMetadata::new(UpdatedById::new(AccountId::new(Uuid::new_v4()))),
+            false,
+        ))
+    }
+
+    #[test]
+    fn deserialize_entity_reference() {
+        let entity_id = entity_id();
+
+        assert_eq!(
+            serde_json::from_value::<EntityReference>(json!({ "entityId": entity_id }))
+                .expect("could not deserialize entity reference"),
+            EntityReference::EntityId(entity_id)
+        );
+        assert_eq!(
+            serde_json::from_value::<EntityReference>(json!({ "operation": 2 }))
+                .expect("could not deserialize entity reference"),
+            EntityReference::Operation(2)
+        );
+    }
+
+    #[test]
+    fn resolve_entity_reference() {
+        let existing_entity_id = entity_id();
+        let created_entity_id = entity_id();
+
+        let results = [
+            BatchOperationResult::Ontology(OntologyElementMetadata::new(
+                OntologyTypeEditionId::new(
+                    type_system::uri::BaseUri::new(
+                        "https://blockprotocol.org/@blockprotocol/types/data-type/text/".to_owned(),
+                    )
+                    .expect("invalid base uri"),
+                    OntologyTypeVersion::new(1),
+                ),
+                ProvenanceMetadata::new(UpdatedById::new(AccountId::new(Uuid::new_v4()))),
+                existing_entity_id.owned_by_id(),
+            )),
+            entity_result(created_entity_id),
+        ];
+
+        assert_eq!(
+            EntityReference::EntityId(existing_entity_id)
+                .resolve(&results)
+                .expect("could not resolve reference"),
+            existing_entity_id
+        );
+        assert_eq!(
+            EntityReference::Operation(1)
+                .resolve(&results)
+                .expect("could not resolve reference"),
+            created_entity_id
+        );
+        assert!(EntityReference::Operation(0).resolve(&results).is_err());
+        assert!(EntityReference::Operation(2).resolve(&results).is_err());
+    }
+}
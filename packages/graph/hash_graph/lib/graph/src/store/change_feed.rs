@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{knowledge::EntityMetadata, ontology::OntologyElementMetadata};
+
+/// The position of a [`ChangeEvent`] in the change feed.
+///
+/// Offsets uniquely identify an event and increase within the changes of a single transaction.
+/// Events of concurrent transactions are not necessarily ordered by their offset, so reading
+/// should be resumed after the offset of the last processed event instead of comparing offsets.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(transparent)]
+pub struct ChangeOffset(i64);
+
+impl ChangeOffset {
+    #[must_use]
+    pub const fn new(offset: i64) -> Self {
+        Self(offset)
+    }
+
+    #[must_use]
+    pub const fn as_i64(self) -> i64 {
+        self.0
+    }
+}
+
+/// The kind of a change to an element of the graph.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Create,
+    Update,
+    Archive,
+}
+
+/// The element of the graph affected by a change with the metadata of the written edition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangedElement {
+    DataType(OntologyElementMetadata),
+    PropertyType(OntologyElementMetadata),
+    EntityType(OntologyElementMetadata),
+    Entity(EntityMetadata),
+}
+
+/// A single change of the graph as recorded in the change feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeEvent {
+    offset: ChangeOffset,
+    kind: ChangeKind,
+    element: ChangedElement,
+    #[schema(value_type = String)]
+    recorded_at: DateTime<Utc>,
+}
+
+impl ChangeEvent {
+    #[must_use]
+    pub const fn new(
+        offset: ChangeOffset,
+        kind: ChangeKind,
+        element: ChangedElement,
+        recorded_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            offset,
+            kind,
+            element,
+            recorded_at,
+        }
+    }
+
+    #[must_use]
+    pub const fn offset(&self) -> ChangeOffset {
+        self.offset
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> ChangeKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub const fn element(&self) -> &ChangedElement {
+        &self.element
+    }
+
+    #[must_use]
+    pub const fn recorded_at(&self) -> DateTime<Utc> {
+        self.recorded_at
+    }
+}
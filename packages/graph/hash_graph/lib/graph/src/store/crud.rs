@@ -8,9 +8,10 @@
 
 use async_trait::async_trait;
 use error_stack::{ensure, Report, Result};
+use futures::stream::BoxStream;
 
 use crate::store::{
-    query::{Filter, QueryRecord},
+    query::{Filter, Page, Pagination, QueryRecord, TemporalAxes},
     QueryError,
 };
 
@@ -25,14 +26,53 @@ pub trait Read<T: QueryRecord + Send>: Sync {
     //   see https://app.asana.com/0/1202805690238892/1202923536131158/f
     /// Returns a value from the [`Store`] specified by the passed `query`.
     ///
+    /// Records, which are versioned in time, are read in the slice specified by `temporal_axes`.
+    ///
+    /// [`Store`]: crate::store::Store
+    async fn read(
+        &self,
+        query: &Filter<T>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<Vec<T>, QueryError>;
+
+    /// Returns a [`Stream`] of the values from the [`Store`] specified by the passed `query`.
+    ///
+    /// In contrast to [`read`], the values are yielded as they are fetched, so they don't have to
+    /// be held in memory at once. The [`Store`] is borrowed mutably as long as the stream is alive,
+    /// as it may keep a transaction open to fetch the values.
+    ///
+    /// [`Store`]: crate::store::Store
+    /// [`Stream`]: futures::Stream
+    /// [`read`]: Self::read
+    async fn read_stream<'s>(
+        &'s mut self,
+        query: &Filter<T>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<BoxStream<'s, Result<T, QueryError>>, QueryError>;
+
+    /// Returns a [`Page`] of values from the [`Store`] specified by the passed `query`.
+    ///
+    /// The values are ordered by a stable sort key of `T`. If more values are available, the
+    /// returned [`Page`] contains a [`Cursor`] to continue reading after the last returned value.
+    ///
     /// [`Store`]: crate::store::Store
-    async fn read(&self, query: &Filter<T>) -> Result<Vec<T>, QueryError>;
+    /// [`Cursor`]: crate::store::query::Cursor
+    async fn read_page(
+        &self,
+        query: &Filter<T>,
+        temporal_axes: &TemporalAxes,
+        pagination: &Pagination,
+    ) -> Result<Page<T>, QueryError>;
 
-    async fn read_one(&self, query: &Filter<T>) -> Result<T, QueryError>
+    async fn read_one(
+        &self,
+        query: &Filter<T>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<T, QueryError>
     where
         for<'p> T::Path<'p>: Sync,
     {
-        let mut records = self.read(query).await?;
+        let mut records = self.read(query, temporal_axes).await?;
         ensure!(
             records.len() <= 1,
             Report::new(QueryError).attach_printable(format!(
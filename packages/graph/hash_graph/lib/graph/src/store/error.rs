@@ -38,6 +38,18 @@ impl fmt::Display for UpdateError {
 
 impl Context for UpdateError {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct DeletionError;
+
+impl fmt::Display for DeletionError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Could not delete from store")
+    }
+}
+
+impl Context for DeletionError {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct BaseUriAlreadyExists;
@@ -74,6 +86,42 @@ impl fmt::Display for EntityDoesNotExist {
 
 impl Context for EntityDoesNotExist {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct ChangeOffsetDoesNotExist;
+
+impl fmt::Display for ChangeOffsetDoesNotExist {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The change feed has no event with the specified offset")
+    }
+}
+
+impl Context for ChangeOffsetDoesNotExist {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct EntityIsReferenced;
+
+impl fmt::Display for EntityIsReferenced {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entity is referenced by link entities")
+    }
+}
+
+impl Context for EntityIsReferenced {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct PurgedEntitiesChanged;
+
+impl fmt::Display for PurgedEntitiesChanged {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entities to purge changed after the purge was authorized")
+    }
+}
+
+impl Context for PurgedEntitiesChanged {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct RaceConditionOnUpdate;
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use error_stack::{Report, Result, ResultExt};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+
+use crate::{
+    identifier::knowledge::{EntityId, EntityRecordId},
+    knowledge::{Entity, EntityLinkOrder, EntityMetadata, EntityProperties},
+    ontology::escape_pointer_segment,
+    provenance::UpdatedById,
+    store::{
+        crud::Read,
+        error::EntityDoesNotExist,
+        query::{Filter, TemporalAxes},
+        EntityStore, QueryError, UpdateError,
+    },
+};
+
+/// A single operation of a [JSON Patch], which changes the properties of an entity.
+///
+/// The `path` is a JSON Pointer into the properties, so the base URI of a property is its first
+/// segment, e.g. `/https:~1~1example.com~1property-type~1name~1`.
+///
+/// [JSON Patch]: https://www.rfc-editor.org/rfc/rfc6902
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum PropertyPatch {
+    Add {
+        path: String,
+        #[schema(value_type = Object)]
+        value: JsonValue,
+    },
+    Remove {
+        path: String,
+    },
+    Replace {
+        path: String,
+        #[schema(value_type = Object)]
+        value: JsonValue,
+    },
+}
+
+/// Returns the [`PropertyPatch`]es, which change the properties `from` into the properties `to`.
+///
+/// Nested objects are compared recursively, all other values, including arrays, are replaced as a
+/// whole.
+#[must_use]
+pub fn diff_properties(from: &EntityProperties, to: &EntityProperties) -> Vec<PropertyPatch> {
+    let mut patches = Vec::new();
+    diff_objects(
+        "",
+        &from
+            .properties()
+            .iter()
+            .map(|(base_uri, value)| (base_uri.as_str(), value))
+            .collect(),
+        &to.properties()
+            .iter()
+            .map(|(base_uri, value)| (base_uri.as_str(), value))
+            .collect(),
+        &mut patches,
+    );
+    patches
+}
+
+fn diff_objects(
+    path: &str,
+    from: &BTreeMap<&str, &JsonValue>,
+    to: &BTreeMap<&str, &JsonValue>,
+    patches: &mut Vec<PropertyPatch>,
+) {
+    for (key, from_value) in from {
+        let path = format!("{path}/{}", escape_pointer_segment(key));
+        match to.get(key) {
+            Some(to_value) => diff_values(path, from_value, to_value, patches),
+            None => patches.push(PropertyPatch::Remove { path }),
+        }
+    }
+
+    for (key, to_value) in to {
+        if !from.contains_key(key) {
+            patches.push(PropertyPatch::Add {
+                path: format!("{path}/{}", escape_pointer_segment(key)),
+                value: (*to_value).clone(),
+            });
+        }
+    }
+}
+
+fn diff_values(path: String, from: &JsonValue, to: &JsonValue, patches: &mut Vec<PropertyPatch>) {
+    match (from, to) {
+        (JsonValue::Object(from), JsonValue::Object(to)) => diff_objects(
+            &path,
+            &from
+                .iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect(),
+            &to.iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect(),
+            patches,
+        ),
+        _ if from != to => patches.push(PropertyPatch::Replace {
+            path,
+            value: to.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Reads all editions of the entity specified by `entity_id` ordered by their [`EntityRecordId`].
+///
+/// The editions are resolved at the current decision time, so every edition is a transaction of
+/// the entity.
+pub(super) async fn read_entity_history<S>(
+    store: &S,
+    entity_id: EntityId,
+) -> Result<Vec<Entity>, QueryError>
+where
+    S: EntityStore,
+{
+    let mut editions = Read::<Entity>::read(
+        store,
+        &Filter::for_entity_by_entity_id(entity_id),
+        &TemporalAxes::default(),
+    )
+    .await?;
+    editions.sort_by_key(|entity| entity.metadata().edition_id().record_id());
+    Ok(editions)
+}
+
+/// Creates a new edition of the entity specified by `entity_id` with the properties, the entity
+/// type and the link order of the edition specified by `record_id`.
+pub(super) async fn restore_entity_edition<S>(
+    store: &mut S,
+    entity_id: EntityId,
+    record_id: EntityRecordId,
+    actor_id: UpdatedById,
+) -> Result<EntityMetadata, UpdateError>
+where
+    S: EntityStore + Send,
+{
+    let edition = read_entity_history(store, entity_id)
+        .await
+        .change_context(UpdateError)?
+        .into_iter()
+        .find(|entity| entity.metadata().edition_id().record_id() == record_id)
+        .ok_or_else(|| {
+            Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .attach(record_id)
+                .change_context(UpdateError)
+        })?;
+
+    let link_order = edition
+        .link_data()
+        .map_or(EntityLinkOrder::new(None, None), |link_data| {
+            EntityLinkOrder::new(
+                link_data.left_to_right_order(),
+                link_data.right_to_left_order(),
+            )
+        });
+
+    store
+        .update_entity(
+            entity_id,
+            None,
+            actor_id,
+            edition.metadata().archived(),
+            edition.metadata().entity_type_id().clone(),
+            edition.properties().clone(),
+            link_order,
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn properties(value: JsonValue) -> EntityProperties {
+        serde_json::from_value(value).expect("invalid properties")
+    }
+
+    #[test]
+    fn diff() {
+        let from = properties(json!({
+            "https://example.com/property-type/name/": "Alice",
+            "https://example.com/property-type/age/": 42,
+            "https://example.com/property-type/address/": {
+                "https://example.com/property-type/city/": "Berlin",
+                "https://example.com/property-type/street/": "Main Street",
+            },
+        }));
+        let to = properties(json!({
+            "https://example.com/property-type/name/": "Alice",
+            "https://example.com/property-type/address/": {
+                "https://example.com/property-type/city/": "Paris",
+                "https://example.com/property-type/street/": "Main Street",
+            },
+            "https://example.com/property-type/email/": "alice@example.com",
+        }));
+
+        assert_eq!(diff_properties(&from, &to), [
+            PropertyPatch::Replace {
+                path: "/https:~1~1example.com~1property-type~1address~1/https:~1~1example.\
+                       com~1property-type~1city~1"
+                    .to_owned(),
+                value: json!("Paris"),
+            },
+            PropertyPatch::Remove {
+                path: "/https:~1~1example.com~1property-type~1age~1".to_owned(),
+            },
+            PropertyPatch::Add {
+                path: "/https:~1~1example.com~1property-type~1email~1".to_owned(),
+                value: json!("alice@example.com"),
+            },
+        ]);
+        assert!(diff_properties(&from, &from).is_empty());
+    }
+
+    #[test]
+    fn serialize_patch() {
+        assert_eq!(
+            serde_json::to_value(PropertyPatch::Replace {
+                path: "/a~0b".to_owned(),
+                value: json!([1, 2]),
+            })
+            .expect("could not serialize patch"),
+            json!({ "op": "replace", "path": "/a~0b", "value": [1, 2] })
+        );
+    }
+}
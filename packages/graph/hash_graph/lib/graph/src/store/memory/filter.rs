@@ -0,0 +1,436 @@
+//! Evaluation of [`Filter`]s on records held in memory.
+//!
+//! The semantics follow the queries compiled for the Postgres store: a path traversing a relation,
+//! e.g. the outgoing links of an entity, may resolve to multiple values and a condition matches if
+//! it's fulfilled by any of them. Comparisons with `null` never match. Unlike in Postgres, every
+//! condition is evaluated on its own, so two conditions on the same relation may be fulfilled by
+//! different related records.
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value as JsonValue;
+
+use crate::store::query::{Filter, FilterExpression, Parameter, QueryRecord};
+
+/// A value a path of a record resolves to.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Value {
+    Json(JsonValue),
+    Timestamp(DateTime<Utc>),
+    /// The version of an ontology type, which is equal to `"latest"` if there is no newer
+    /// version of the type.
+    Version {
+        version: u32,
+        is_latest: bool,
+    },
+    /// The lower bound of the transaction time of an entity, which is equal to `"latest"` if the
+    /// entity is the latest version at the transaction time of the query.
+    LowerTransactionTime {
+        timestamp: DateTime<Utc>,
+        is_latest: bool,
+    },
+}
+
+impl Value {
+    pub(super) const NULL: Self = Self::Json(JsonValue::Null);
+
+    pub(super) fn text(text: impl Into<String>) -> Self {
+        Self::Json(JsonValue::String(text.into()))
+    }
+
+    /// Converts the value to JSON, e.g. to return it as the group of an aggregation.
+    pub(super) fn into_json(self) -> JsonValue {
+        match self {
+            Self::Json(value) => value,
+            Self::Timestamp(timestamp) | Self::LowerTransactionTime { timestamp, .. } => {
+                JsonValue::String(timestamp.to_rfc3339())
+            }
+            Self::Version { version, .. } => JsonValue::from(version),
+        }
+    }
+
+    const fn is_null(&self) -> bool {
+        matches!(self, Self::Json(JsonValue::Null))
+    }
+
+    fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Json(JsonValue::String(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns if both values are equal or `None` if they can't be compared.
+    fn equals(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            _ if self.is_null() || other.is_null() => None,
+            (
+                Self::Version { is_latest, .. } | Self::LowerTransactionTime { is_latest, .. },
+                Self::Json(JsonValue::String(text)),
+            )
+            | (
+                Self::Json(JsonValue::String(text)),
+                Self::Version { is_latest, .. } | Self::LowerTransactionTime { is_latest, .. },
+            ) if text == "latest" => Some(*is_latest),
+            (Self::Json(lhs), Self::Json(rhs)) => {
+                Some(compare_json(lhs, rhs).map_or_else(|| lhs == rhs, Ordering::is_eq))
+            }
+            _ => self.compare(other).map(Ordering::is_eq),
+        }
+    }
+
+    /// Returns the ordering of both values or `None` if they can't be ordered.
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Version { version, .. }, _) => {
+                Self::Json(JsonValue::from(*version)).compare(other)
+            }
+            (_, Self::Version { version, .. }) => {
+                self.compare(&Self::Json(JsonValue::from(*version)))
+            }
+            (Self::LowerTransactionTime { timestamp, .. }, _) => {
+                Self::Timestamp(*timestamp).compare(other)
+            }
+            (_, Self::LowerTransactionTime { timestamp, .. }) => {
+                self.compare(&Self::Timestamp(*timestamp))
+            }
+            (Self::Timestamp(lhs), Self::Timestamp(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Timestamp(lhs), Self::Json(JsonValue::String(rhs))) => {
+                rhs.parse::<DateTime<Utc>>().ok().map(|rhs| lhs.cmp(&rhs))
+            }
+            (Self::Json(JsonValue::String(lhs)), Self::Timestamp(rhs)) => {
+                lhs.parse::<DateTime<Utc>>().ok().map(|lhs| lhs.cmp(rhs))
+            }
+            (Self::Json(lhs), Self::Json(rhs)) => compare_json(lhs, rhs),
+            _ => None,
+        }
+    }
+}
+
+fn compare_json(lhs: &JsonValue, rhs: &JsonValue) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (JsonValue::Number(lhs), JsonValue::Number(rhs)) => {
+            lhs.as_f64()?.partial_cmp(&rhs.as_f64()?)
+        }
+        (JsonValue::String(lhs), JsonValue::String(rhs)) => Some(lhs.cmp(rhs)),
+        (JsonValue::Bool(lhs), JsonValue::Bool(rhs)) => Some(lhs.cmp(rhs)),
+        // Like in Postgres, values of different types are not compared
+        _ => None,
+    }
+}
+
+impl From<&Parameter<'_>> for Value {
+    fn from(parameter: &Parameter<'_>) -> Self {
+        match parameter {
+            Parameter::Boolean(boolean) => Self::Json(JsonValue::Bool(*boolean)),
+            Parameter::Number(number) => Self::Json(JsonValue::from(*number)),
+            Parameter::Text(text) => Self::Json(JsonValue::String(text.to_string())),
+            Parameter::Uuid(uuid) => Self::Json(JsonValue::String(uuid.to_string())),
+            Parameter::SignedInteger(integer) => Self::Json(JsonValue::from(*integer)),
+            Parameter::Timestamp(timestamp) => Self::Timestamp(*timestamp),
+        }
+    }
+}
+
+/// A record, on which a [`Filter`] can be evaluated.
+pub(super) trait Resolve<T: QueryRecord> {
+    /// Returns the values `path` resolves to.
+    ///
+    /// Returns an empty list if the path traverses a relation without related records.
+    fn resolve(&self, path: &T::Path<'_>) -> Vec<Value>;
+
+    /// Returns the text, which is matched by [`Filter::Search`].
+    fn search_text(&self) -> String;
+}
+
+fn evaluate<T: QueryRecord>(
+    record: &impl Resolve<T>,
+    expression: &FilterExpression<'_, T>,
+) -> Vec<Value> {
+    match expression {
+        FilterExpression::Path(path) => record.resolve(path),
+        FilterExpression::Parameter(parameter) => vec![Value::from(parameter)],
+    }
+}
+
+/// Returns if `expression` is `null`, which is also the case if a relation is empty.
+fn is_null<T: QueryRecord>(record: &impl Resolve<T>, expression: &FilterExpression<'_, T>) -> bool {
+    let values = evaluate(record, expression);
+    values.is_empty() || values.iter().any(Value::is_null)
+}
+
+fn is_not_null<T: QueryRecord>(
+    record: &impl Resolve<T>,
+    expression: &FilterExpression<'_, T>,
+) -> bool {
+    evaluate(record, expression)
+        .iter()
+        .any(|value| !value.is_null())
+}
+
+fn compare_any<T: QueryRecord>(
+    record: &impl Resolve<T>,
+    lhs: &FilterExpression<'_, T>,
+    rhs: &FilterExpression<'_, T>,
+    compare: impl Fn(&Value, &Value) -> Option<bool>,
+) -> bool {
+    let lhs = evaluate(record, lhs);
+    let rhs = evaluate(record, rhs);
+    lhs.iter()
+        .any(|lhs| rhs.iter().any(|rhs| compare(lhs, rhs) == Some(true)))
+}
+
+/// Returns if `record` matches `filter`.
+pub(super) fn matches<T: QueryRecord>(filter: &Filter<'_, T>, record: &impl Resolve<T>) -> bool {
+    match filter {
+        Filter::All(filters) => filters.iter().all(|filter| matches(filter, record)),
+        Filter::Any(filters) => filters.iter().any(|filter| matches(filter, record)),
+        Filter::Not(filter) => !matches(filter, record),
+        Filter::Equal(lhs, rhs) => match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => compare_any(record, lhs, rhs, Value::equals),
+            (Some(expression), None) | (None, Some(expression)) => is_null(record, expression),
+            (None, None) => true,
+        },
+        Filter::NotEqual(lhs, rhs) => match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => compare_any(record, lhs, rhs, |lhs, rhs| {
+                lhs.equals(rhs).map(|equal| !equal)
+            }),
+            (Some(expression), None) | (None, Some(expression)) => is_not_null(record, expression),
+            (None, None) => false,
+        },
+        Filter::Less(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            lhs.compare(rhs).map(Ordering::is_lt)
+        }),
+        Filter::LessOrEqual(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            lhs.compare(rhs).map(Ordering::is_le)
+        }),
+        Filter::Greater(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            lhs.compare(rhs).map(Ordering::is_gt)
+        }),
+        Filter::GreaterOrEqual(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            lhs.compare(rhs).map(Ordering::is_ge)
+        }),
+        Filter::In(lhs, rhs) => rhs
+            .iter()
+            .any(|rhs| compare_any(record, lhs, rhs, Value::equals)),
+        Filter::StartsWith(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            Some(lhs.as_text()?.starts_with(rhs.as_text()?))
+        }),
+        Filter::EndsWith(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            Some(lhs.as_text()?.ends_with(rhs.as_text()?))
+        }),
+        Filter::ContainsSegment(lhs, rhs) => compare_any(record, lhs, rhs, |lhs, rhs| {
+            Some(lhs.as_text()?.contains(rhs.as_text()?))
+        }),
+        Filter::Exists(expression) => is_not_null(record, expression),
+        Filter::Search(query) => SearchQuery::parse(query)
+            .rank(&record.search_text())
+            .is_some(),
+    }
+}
+
+/// A term of a [`SearchQuery`], which is either a single word or a quoted phrase.
+#[derive(Debug, PartialEq, Eq)]
+struct SearchTerm {
+    words: Vec<String>,
+    excluded: bool,
+}
+
+/// A full-text search query in the web search syntax supported by [`Filter::Search`].
+///
+/// Words are matched case-insensitively. In contrast to Postgres, words are not stemmed, so
+/// e.g. `runs` does not match `running`.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct SearchQuery {
+    /// The alternatives separated by `or`, each of them requires all of its terms to match.
+    alternatives: Vec<Vec<SearchTerm>>,
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+impl SearchQuery {
+    pub(super) fn parse(query: &str) -> Self {
+        let mut alternatives = vec![Vec::new()];
+        let mut characters = query.chars().peekable();
+
+        loop {
+            while characters
+                .next_if(|character| character.is_whitespace())
+                .is_some()
+            {}
+            if characters.peek().is_none() {
+                break;
+            }
+
+            let excluded = characters.next_if_eq(&'-').is_some();
+            let quoted = characters.next_if_eq(&'"').is_some();
+            let term: String = if quoted {
+                characters
+                    .by_ref()
+                    .take_while(|character| *character != '"')
+                    .collect()
+            } else {
+                let mut term = String::new();
+                while let Some(character) =
+                    characters.next_if(|character| !character.is_whitespace())
+                {
+                    term.push(character);
+                }
+                term
+            };
+
+            if !excluded && !quoted && term.eq_ignore_ascii_case("or") {
+                alternatives.push(Vec::new());
+                continue;
+            }
+
+            let words = words(&term);
+            if !words.is_empty() {
+                if let Some(terms) = alternatives.last_mut() {
+                    terms.push(SearchTerm { words, excluded });
+                }
+            }
+        }
+
+        alternatives.retain(|terms| !terms.is_empty());
+        Self { alternatives }
+    }
+
+    /// Returns the rank of `text` or `None` if it does not match the query.
+    ///
+    /// The rank is the relative frequency of the searched terms in `text`.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "ranks are only compared relative to each other"
+    )]
+    pub(super) fn rank(&self, text: &str) -> Option<f32> {
+        let words = words(text);
+        let occurrences = |phrase: &[String]| {
+            words
+                .windows(phrase.len())
+                .filter(|window| *window == phrase)
+                .count()
+        };
+
+        let mut is_match = false;
+        let mut matched_occurrences = 0;
+        for terms in &self.alternatives {
+            let counts = terms
+                .iter()
+                .map(|term| (term.excluded, occurrences(&term.words)))
+                .collect::<Vec<_>>();
+
+            if counts
+                .iter()
+                .all(|(excluded, count)| (*count > 0) != *excluded)
+            {
+                is_match = true;
+                matched_occurrences += counts
+                    .iter()
+                    .filter(|(excluded, _)| !excluded)
+                    .map(|(_, count)| count)
+                    .sum::<usize>();
+            }
+        }
+
+        is_match.then(|| matched_occurrences as f32 / words.len().max(1) as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_search_query() {
+        assert_eq!(
+            SearchQuery::parse(r#"Alice "New York" or -bob"#),
+            SearchQuery {
+                alternatives: vec![
+                    vec![
+                        SearchTerm {
+                            words: vec!["alice".to_owned()],
+                            excluded: false,
+                        },
+                        SearchTerm {
+                            words: vec!["new".to_owned(), "york".to_owned()],
+                            excluded: false,
+                        },
+                    ],
+                    vec![SearchTerm {
+                        words: vec!["bob".to_owned()],
+                        excluded: true,
+                    }],
+                ],
+            }
+        );
+        assert_eq!(SearchQuery::parse("  "), SearchQuery {
+            alternatives: Vec::new()
+        });
+    }
+
+    #[test]
+    fn search_rank() {
+        let query = SearchQuery::parse(r#""new york" -boston"#);
+        assert!(query.rank("Alice lives in New York").is_some());
+        assert!(query.rank("Alice lives in York").is_none());
+        assert!(query.rank("New York and Boston").is_none());
+
+        let query = SearchQuery::parse("alice or bob");
+        let alice = query.rank("Alice").expect("query should match");
+        let alice_and_carol = query.rank("Alice Carol").expect("query should match");
+        assert!(alice > alice_and_carol);
+        assert!(query.rank("Carol").is_none());
+        assert!(SearchQuery::parse("").rank("Alice").is_none());
+    }
+
+    #[test]
+    fn compare_values() {
+        let text = |text: &str| Value::Json(JsonValue::String(text.to_owned()));
+
+        assert_eq!(
+            Value::Json(JsonValue::from(1)).equals(&Value::Json(JsonValue::from(1.0))),
+            Some(true)
+        );
+        assert_eq!(Value::NULL.equals(&Value::NULL), None);
+        assert_eq!(
+            Value::Json(JsonValue::from(100)).compare(&Value::Json(JsonValue::from(9.5))),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Value::Json(JsonValue::from(100)).compare(&text("9.5")),
+            None
+        );
+        assert_eq!(
+            Value::Version {
+                version: 2,
+                is_latest: true
+            }
+            .equals(&text("latest")),
+            Some(true)
+        );
+        assert_eq!(
+            Value::Version {
+                version: 2,
+                is_latest: false
+            }
+            .compare(&Value::Json(JsonValue::from(3))),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Value::Timestamp(
+                "2022-12-01T12:00:00Z"
+                    .parse()
+                    .expect("could not parse timestamp")
+            )
+            .compare(&text("2022-12-02T12:00:00Z")),
+            Some(Ordering::Less)
+        );
+    }
+}
@@ -0,0 +1,1682 @@
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap},
+    ops::Bound,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use error_stack::{ensure, IntoReport, Report, Result, ResultExt};
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use serde_json::Value as JsonValue;
+use type_system::uri::VersionedUri;
+use uuid::Uuid;
+
+use crate::{
+    identifier::{
+        knowledge::{
+            EntityEditionId, EntityId, EntityIdAndTimestamp, EntityRecordId, EntityVersion,
+        },
+        ontology::OntologyTypeEditionId,
+        DecisionTimespan, DecisionTimestamp, GraphElementEditionId, TransactionTimespan,
+        TransactionTimestamp,
+    },
+    knowledge::{
+        Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityQueryPath, EntityUuid,
+        EntityValidator, LinkData, LinkOrder,
+    },
+    ontology::EntityTypeWithMetadata,
+    provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
+    store::{
+        crud::Read,
+        ensure_purge_is_authorized,
+        error::{EntityDoesNotExist, EntityIsReferenced, RaceConditionOnUpdate},
+        memory::{
+            filter::{self, Resolve, SearchQuery, Value},
+            MemoryState, MemoryStore,
+        },
+        postgres::{DependencyContext, DependencyStatus},
+        query::{Aggregate, AggregationGroup, Filter, Page, Pagination, TemporalAxes},
+        ChangeKind, ChangedElement, DeletionError, EntityStore, InsertionError, LinkPurgeBehavior,
+        QueryError, UpdateError,
+    },
+    subgraph::{
+        edges::{
+            Edge, EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind,
+            KnowledgeGraphOutwardEdges, OutwardEdge, SharedEdgeKind,
+        },
+        query::{AggregationQuery, StructuralQuery},
+        vertices::KnowledgeGraphVertex,
+        Subgraph,
+    },
+};
+
+/// A half-open interval `[start, end)` on one of the time axes, which is unbounded if `end` is
+/// `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Interval {
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+}
+
+impl Interval {
+    const fn new(start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> Self {
+        Self { start, end }
+    }
+
+    fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        self.start <= timestamp && self.end.map_or(true, |end| timestamp < end)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.end.map_or(false, |end| end <= self.start)
+    }
+
+    fn bounds(&self) -> (Bound<DateTime<Utc>>, Bound<DateTime<Utc>>) {
+        (
+            Bound::Included(self.start),
+            self.end.map_or(Bound::Unbounded, Bound::Excluded),
+        )
+    }
+}
+
+/// A version of an entity, which refers to the edition valid in the specified intervals.
+#[derive(Debug, Clone, Copy)]
+struct EntityVersionRecord {
+    record_id: EntityRecordId,
+    decision_time: Interval,
+    transaction_time: Interval,
+}
+
+impl EntityVersionRecord {
+    fn version(&self) -> EntityVersion {
+        EntityVersion::new(
+            DecisionTimespan::from(self.decision_time.bounds()),
+            TransactionTimespan::from(self.transaction_time.bounds()),
+        )
+    }
+}
+
+#[derive(Clone)]
+struct EntityEdition {
+    entity_type_id: VersionedUri,
+    properties: EntityProperties,
+    link_order: EntityLinkOrder,
+    updated_by_id: UpdatedById,
+    archived: bool,
+}
+
+#[derive(Clone)]
+struct StoredEntity {
+    /// The left and right entity, if the entity is a link entity.
+    link: Option<(EntityId, EntityId)>,
+    versions: Vec<EntityVersionRecord>,
+}
+
+/// All entities alongside their versions and editions.
+#[derive(Clone, Default)]
+pub(super) struct Entities {
+    entities: HashMap<EntityId, StoredEntity>,
+    editions: HashMap<EntityRecordId, EntityEdition>,
+    last_record_id: i64,
+}
+
+fn insert_edition(
+    editions: &mut HashMap<EntityRecordId, EntityEdition>,
+    last_record_id: &mut i64,
+    edition: EntityEdition,
+) -> EntityRecordId {
+    *last_record_id += 1;
+    let record_id = EntityRecordId::new(*last_record_id);
+    editions.insert(record_id, edition);
+    record_id
+}
+
+impl Entities {
+    fn contains(&self, entity_id: EntityId) -> bool {
+        self.entities.contains_key(&entity_id)
+    }
+
+    fn insert(
+        &mut self,
+        entity_id: EntityId,
+        link: Option<(EntityId, EntityId)>,
+        decision_time: DateTime<Utc>,
+        transaction_time: DateTime<Utc>,
+        edition: EntityEdition,
+    ) -> EntityVersionRecord {
+        let version = EntityVersionRecord {
+            record_id: insert_edition(&mut self.editions, &mut self.last_record_id, edition),
+            decision_time: Interval::new(decision_time, None),
+            transaction_time: Interval::new(transaction_time, None),
+        };
+        self.entities.insert(entity_id, StoredEntity {
+            link,
+            versions: vec![version],
+        });
+        version
+    }
+
+    /// Replaces the version of `entity_id`, which is valid at `decision_time` and
+    /// `transaction_time`, by `edition` from `decision_time` onwards.
+    ///
+    /// The replaced version stays visible at earlier transaction times and, if it started before
+    /// `decision_time`, at earlier decision times.
+    fn update(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: DateTime<Utc>,
+        transaction_time: DateTime<Utc>,
+        edition: EntityEdition,
+    ) -> Result<EntityVersionRecord, UpdateError> {
+        let Self {
+            entities,
+            editions,
+            last_record_id,
+        } = self;
+
+        let stored = entities.get_mut(&entity_id).ok_or_else(|| {
+            Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .change_context(UpdateError)
+        })?;
+        let index = stored
+            .versions
+            .iter()
+            .position(|version| {
+                version.decision_time.contains(decision_time)
+                    && version.transaction_time.contains(transaction_time)
+            })
+            .ok_or_else(|| {
+                Report::new(RaceConditionOnUpdate)
+                    .attach(entity_id)
+                    .change_context(UpdateError)
+            })?;
+        let replaced = stored.versions.swap_remove(index);
+
+        let version = EntityVersionRecord {
+            record_id: insert_edition(editions, last_record_id, edition),
+            decision_time: Interval::new(decision_time, replaced.decision_time.end),
+            transaction_time: Interval::new(transaction_time, None),
+        };
+        stored.versions.push(version);
+        stored.versions.push(EntityVersionRecord {
+            transaction_time: Interval::new(
+                replaced.transaction_time.start,
+                Some(transaction_time),
+            ),
+            ..replaced
+        });
+
+        let preceding = EntityVersionRecord {
+            decision_time: Interval::new(replaced.decision_time.start, Some(decision_time)),
+            transaction_time: Interval::new(transaction_time, None),
+            ..replaced
+        };
+        if !preceding.decision_time.is_empty() {
+            stored.versions.push(preceding);
+        }
+
+        Ok(version)
+    }
+
+    /// Returns the versions of `entity_id`, which are visible in `temporal_slice`.
+    fn entity_rows<'s>(
+        &'s self,
+        state: &'s MemoryState,
+        temporal_slice: &'s TemporalSlice,
+        entity_id: EntityId,
+    ) -> impl Iterator<Item = EntityRow<'s>> {
+        self.entities
+            .get_key_value(&entity_id)
+            .into_iter()
+            .flat_map(move |(entity_id, stored)| {
+                self.versions(state, temporal_slice, *entity_id, stored)
+            })
+    }
+
+    /// Returns the versions of all entities, which are visible in `temporal_slice`.
+    fn rows<'s>(
+        &'s self,
+        state: &'s MemoryState,
+        temporal_slice: &'s TemporalSlice,
+    ) -> impl Iterator<Item = EntityRow<'s>> {
+        self.entities.iter().flat_map(move |(entity_id, stored)| {
+            self.versions(state, temporal_slice, *entity_id, stored)
+        })
+    }
+
+    fn versions<'s>(
+        &'s self,
+        state: &'s MemoryState,
+        temporal_slice: &'s TemporalSlice,
+        entity_id: EntityId,
+        stored: &'s StoredEntity,
+    ) -> impl Iterator<Item = EntityRow<'s>> {
+        stored
+            .versions
+            .iter()
+            .filter(move |version| temporal_slice.contains(version))
+            .filter_map(move |version| {
+                Some(EntityRow {
+                    state,
+                    temporal_slice,
+                    entity_id,
+                    link: stored.link,
+                    version,
+                    edition: self.editions.get(&version.record_id)?,
+                })
+            })
+    }
+
+    /// Returns the earliest transaction time of the versions of `entity_id`, which are visible in
+    /// `temporal_slice`.
+    fn earliest_transaction_time(
+        &self,
+        entity_id: EntityId,
+        temporal_slice: &TemporalSlice,
+    ) -> Option<TransactionTimestamp> {
+        self.entities
+            .get(&entity_id)?
+            .versions
+            .iter()
+            .filter(|version| temporal_slice.contains(version))
+            .map(|version| {
+                version
+                    .version()
+                    .transaction_time()
+                    .as_start_bound_timestamp()
+            })
+            .min()
+    }
+}
+
+/// The temporal slice, in which entities are read.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct TemporalSlice {
+    decision_time: DateTime<Utc>,
+    /// The pinned transaction time, or `None` if the transaction time is not restricted.
+    transaction_time: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+}
+
+impl TemporalSlice {
+    pub(super) fn new(temporal_axes: &TemporalAxes, now: DateTime<Utc>) -> Self {
+        Self {
+            decision_time: temporal_axes
+                .decision_time
+                .map_or(now, |decision_time| decision_time.as_date_time()),
+            transaction_time: temporal_axes
+                .transaction_time
+                .map(|transaction_time| transaction_time.as_date_time()),
+            now,
+        }
+    }
+
+    fn contains(&self, version: &EntityVersionRecord) -> bool {
+        version.decision_time.contains(self.decision_time)
+            && self.transaction_time.map_or(true, |transaction_time| {
+                version.transaction_time.contains(transaction_time)
+            })
+    }
+
+    /// Returns if `version` is the latest version at the transaction time of the slice, which
+    /// defaults to now.
+    fn is_latest(&self, version: &EntityVersionRecord) -> bool {
+        version
+            .transaction_time
+            .contains(self.transaction_time.unwrap_or(self.now))
+    }
+}
+
+/// A single version of an entity, on which a [`Filter`] can be evaluated.
+struct EntityRow<'s> {
+    state: &'s MemoryState,
+    temporal_slice: &'s TemporalSlice,
+    entity_id: EntityId,
+    link: Option<(EntityId, EntityId)>,
+    version: &'s EntityVersionRecord,
+    edition: &'s EntityEdition,
+}
+
+fn uuid_value(uuid: Uuid) -> Value {
+    Value::text(uuid.to_string())
+}
+
+fn link_order_value(link_order: Option<LinkOrder>) -> Value {
+    link_order
+        .and_then(|link_order| serde_json::to_value(link_order).ok())
+        .map_or(Value::NULL, Value::Json)
+}
+
+/// Collects the strings and numbers in `value`, which are matched by a full-text search.
+fn collect_search_text(value: &JsonValue, text: &mut Vec<String>) {
+    match value {
+        JsonValue::String(string) => text.push(string.clone()),
+        JsonValue::Number(number) => text.push(number.to_string()),
+        JsonValue::Array(values) => {
+            for value in values {
+                collect_search_text(value, text);
+            }
+        }
+        JsonValue::Object(object) => {
+            for value in object.values() {
+                collect_search_text(value, text);
+            }
+        }
+        JsonValue::Null | JsonValue::Bool(_) => {}
+    }
+}
+
+/// Returns the numbers `path` resolves to on `rows`.
+///
+/// Like in Postgres, values, which are not numbers, are ignored.
+fn numeric_values(rows: &[EntityRow], path: &EntityQueryPath) -> Vec<f64> {
+    rows.iter()
+        .filter_map(|row| row.resolve(path).into_iter().next())
+        .filter_map(|value| match value {
+            Value::Json(JsonValue::Number(number)) => number.as_f64(),
+            _ => None,
+        })
+        .collect()
+}
+
+impl EntityRow<'_> {
+    fn edition_id(&self) -> EntityEditionId {
+        EntityEditionId::new(
+            self.entity_id,
+            self.version.record_id,
+            self.version.version(),
+        )
+    }
+
+    fn entity(&self) -> Entity {
+        Entity::new(
+            self.edition.properties.clone(),
+            self.link.map(|(left_entity_id, right_entity_id)| {
+                LinkData::new(
+                    left_entity_id,
+                    right_entity_id,
+                    self.edition.link_order.left_to_right(),
+                    self.edition.link_order.right_to_left(),
+                )
+            }),
+            self.edition_id(),
+            self.edition.entity_type_id.clone(),
+            ProvenanceMetadata::new(self.edition.updated_by_id),
+            self.edition.archived,
+        )
+    }
+
+    /// Resolves `path` on the left or right entity of the link.
+    ///
+    /// The [`EntityId`] of the entity is stored on the link itself, so it's resolved even if the
+    /// entity is not visible in the temporal slice.
+    fn resolve_endpoint(&self, entity_id: Option<EntityId>, path: &EntityQueryPath) -> Vec<Value> {
+        let Some(entity_id) = entity_id else {
+            return Vec::new();
+        };
+
+        match path {
+            EntityQueryPath::Uuid => vec![uuid_value(entity_id.entity_uuid().as_uuid())],
+            EntityQueryPath::OwnedById => vec![uuid_value(entity_id.owned_by_id().as_uuid())],
+            _ => self
+                .state
+                .entities
+                .entity_rows(self.state, self.temporal_slice, entity_id)
+                .flat_map(|row| row.resolve(path))
+                .collect(),
+        }
+    }
+
+    /// Resolves `path` on the link entities, which have `endpoint` on the side selected by
+    /// `select`.
+    fn resolve_links(
+        &self,
+        select: impl Fn((EntityId, EntityId)) -> EntityId,
+        path: &EntityQueryPath,
+    ) -> Vec<Value> {
+        self.state
+            .entities
+            .rows(self.state, self.temporal_slice)
+            .filter(|row| {
+                row.link
+                    .map_or(false, |link| select(link) == self.entity_id)
+            })
+            .flat_map(|row| row.resolve(path))
+            .collect()
+    }
+}
+
+impl Resolve<Entity> for EntityRow<'_> {
+    fn resolve(&self, path: &EntityQueryPath) -> Vec<Value> {
+        let properties = self.edition.properties.properties();
+
+        match path {
+            EntityQueryPath::Uuid => vec![uuid_value(self.entity_id.entity_uuid().as_uuid())],
+            EntityQueryPath::OwnedById => vec![uuid_value(self.entity_id.owned_by_id().as_uuid())],
+            EntityQueryPath::RecordId => {
+                vec![Value::Json(JsonValue::from(
+                    self.version.record_id.as_i64(),
+                ))]
+            }
+            EntityQueryPath::DecisionTime => {
+                vec![Value::Timestamp(self.version.decision_time.start)]
+            }
+            EntityQueryPath::TransactionTime => {
+                vec![Value::Timestamp(self.version.transaction_time.start)]
+            }
+            EntityQueryPath::LowerTransactionTime => vec![Value::LowerTransactionTime {
+                timestamp: self.version.transaction_time.start,
+                is_latest: self.temporal_slice.is_latest(self.version),
+            }],
+            EntityQueryPath::Archived => vec![Value::Json(JsonValue::Bool(self.edition.archived))],
+            EntityQueryPath::UpdatedById => vec![uuid_value(self.edition.updated_by_id.as_uuid())],
+            EntityQueryPath::Type(path) => self
+                .state
+                .entity_types
+                .row(self.state, &self.edition.entity_type_id)
+                .map(|row| Resolve::<EntityTypeWithMetadata>::resolve(&row, path))
+                .unwrap_or_default(),
+            EntityQueryPath::LeftEntity(path) => {
+                self.resolve_endpoint(self.link.map(|(left_entity_id, _)| left_entity_id), path)
+            }
+            EntityQueryPath::RightEntity(path) => {
+                self.resolve_endpoint(self.link.map(|(_, right_entity_id)| right_entity_id), path)
+            }
+            EntityQueryPath::OutgoingLinks(path) => {
+                self.resolve_links(|(left_entity_id, _)| left_entity_id, path)
+            }
+            EntityQueryPath::IncomingLinks(path) => {
+                self.resolve_links(|(_, right_entity_id)| right_entity_id, path)
+            }
+            EntityQueryPath::LeftToRightOrder => {
+                vec![link_order_value(self.edition.link_order.left_to_right())]
+            }
+            EntityQueryPath::RightToLeftOrder => {
+                vec![link_order_value(self.edition.link_order.right_to_left())]
+            }
+            EntityQueryPath::Properties(None) => vec![Value::Json(JsonValue::Object(
+                properties
+                    .iter()
+                    .map(|(base_uri, value)| (base_uri.as_str().to_owned(), value.clone()))
+                    .collect(),
+            ))],
+            EntityQueryPath::Properties(Some(key)) => vec![properties
+                .iter()
+                .find(|(base_uri, _)| base_uri.as_str() == key.as_ref())
+                .map_or(Value::NULL, |(_, value)| Value::Json(value.clone()))],
+        }
+    }
+
+    fn search_text(&self) -> String {
+        let mut text = Vec::new();
+        for value in self.edition.properties.properties().values() {
+            collect_search_text(value, &mut text);
+        }
+        text.join(" ")
+    }
+}
+
+impl MemoryState {
+    /// Returns the rows of the entities matching `filter` in `temporal_slice` by their
+    /// [`EntityRecordId`].
+    ///
+    /// If `cursor` is specified, only the entities after this record are returned.
+    fn matching_entity_rows<'s>(
+        &'s self,
+        filter: &Filter<'_, Entity>,
+        temporal_slice: &'s TemporalSlice,
+        cursor: Option<EntityRecordId>,
+    ) -> BTreeMap<EntityRecordId, EntityRow<'s>> {
+        // An edition can be visible in multiple versions, e.g. if the transaction time is not
+        // restricted, so the version, which was not superseded yet, is preferred.
+        let mut rows = BTreeMap::<EntityRecordId, EntityRow>::new();
+        for row in self
+            .entities
+            .rows(self, temporal_slice)
+            .filter(|row| cursor.map_or(true, |cursor| row.version.record_id > cursor))
+            .filter(|row| filter::matches(filter, row))
+        {
+            match rows.entry(row.version.record_id) {
+                std::collections::btree_map::Entry::Vacant(entry) => {
+                    entry.insert(row);
+                }
+                std::collections::btree_map::Entry::Occupied(mut entry) => {
+                    if row.version.transaction_time.end.is_none() {
+                        entry.insert(row);
+                    }
+                }
+            }
+        }
+
+        rows
+    }
+
+    /// Reads the entities matching `filter` in `temporal_slice` ordered by their
+    /// [`EntityRecordId`].
+    ///
+    /// If `cursor` is specified, only the entities after this record are returned.
+    pub(super) fn read_entities(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_slice: &TemporalSlice,
+        cursor: Option<EntityRecordId>,
+    ) -> Vec<Entity> {
+        self.matching_entity_rows(filter, temporal_slice, cursor)
+            .values()
+            .map(EntityRow::entity)
+            .collect()
+    }
+
+    /// Computes the aggregates of `query` for every group of the entities matching its filter.
+    ///
+    /// Like in Postgres, all entities form a single group if no group-by paths are specified, even
+    /// if no entity matches the filter.
+    fn aggregate_entities(&self, query: &AggregationQuery<'_, Entity>) -> Vec<AggregationGroup> {
+        let AggregationQuery {
+            ref filter,
+            ref group_by,
+            ref aggregates,
+            ref temporal_axes,
+        } = *query;
+
+        let temporal_slice = TemporalSlice::new(temporal_axes, self.now());
+
+        let mut groups = Vec::<(Vec<JsonValue>, Vec<EntityRow>)>::new();
+        if group_by.is_empty() {
+            groups.push((Vec::new(), Vec::new()));
+        }
+        for row in self
+            .matching_entity_rows(filter, &temporal_slice, None)
+            .into_values()
+        {
+            let group = group_by
+                .iter()
+                .map(|path| {
+                    row.resolve(path)
+                        .into_iter()
+                        .next()
+                        .map_or(JsonValue::Null, Value::into_json)
+                })
+                .collect::<Vec<_>>();
+            match groups.iter_mut().find(|(key, _)| *key == group) {
+                Some((_, rows)) => rows.push(row),
+                None => groups.push((group, vec![row])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(group, rows)| {
+                let values = aggregates
+                    .iter()
+                    .map(|aggregate| match aggregate {
+                        Aggregate::Count => JsonValue::from(rows.len()),
+                        Aggregate::Min(path) => JsonValue::from(
+                            numeric_values(&rows, path).into_iter().reduce(f64::min),
+                        ),
+                        Aggregate::Max(path) => JsonValue::from(
+                            numeric_values(&rows, path).into_iter().reduce(f64::max),
+                        ),
+                        Aggregate::Sum(path) => JsonValue::from(
+                            numeric_values(&rows, path)
+                                .into_iter()
+                                .reduce(|lhs, rhs| lhs + rhs),
+                        ),
+                    })
+                    .collect();
+                AggregationGroup { group, values }
+            })
+            .collect()
+    }
+
+    fn read_entity_page(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_slice: &TemporalSlice,
+        pagination: &Pagination,
+    ) -> Result<Page<Entity>, QueryError> {
+        let cursor = pagination
+            .after
+            .as_ref()
+            .map(|cursor| {
+                cursor
+                    .decode()
+                    .change_context(QueryError)?
+                    .parse::<i64>()
+                    .into_report()
+                    .change_context(QueryError)
+            })
+            .transpose()?;
+
+        let mut records =
+            self.read_entities(filter, temporal_slice, cursor.map(EntityRecordId::new));
+        records.truncate(pagination.limit.saturating_add(1));
+        let next_cursor = pagination.next_cursor(&mut records, |entity| {
+            entity
+                .metadata()
+                .edition_id()
+                .record_id()
+                .as_i64()
+                .to_string()
+        });
+
+        Ok(Page {
+            records,
+            next_cursor,
+        })
+    }
+
+    /// Returns the full-text search relevance of the entities matching `filter`.
+    ///
+    /// Returns an empty map if `filter` does not contain a [`Filter::Search`].
+    fn entity_search_ranks(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_slice: &TemporalSlice,
+    ) -> HashMap<EntityEditionId, f32> {
+        let Some(query) = filter.search_query() else {
+            return HashMap::new();
+        };
+        let query = SearchQuery::parse(query);
+
+        self.entities
+            .rows(self, temporal_slice)
+            .filter(|row| filter::matches(filter, row))
+            .map(|row| {
+                // Records matching another alternative of a `Filter::Any` have no relevance
+                let rank = query.rank(&row.search_text()).unwrap_or_default();
+                (row.edition_id(), rank)
+            })
+            .collect()
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    fn create_entity(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: Option<DecisionTimestamp>,
+        transaction_time: Option<TransactionTimestamp>,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<LinkData>,
+    ) -> Result<EntityMetadata, InsertionError> {
+        ensure!(
+            !self.entities.contains(entity_id),
+            Report::new(InsertionError).attach_printable(format!(
+                "entity with uuid {} already exists",
+                entity_id.entity_uuid()
+            ))
+        );
+        self.ensure_account_exists(entity_id.owned_by_id().as_account_id())?;
+        self.ensure_account_exists(updated_by_id.as_account_id())?;
+        if let Some(link_data) = link_data {
+            for linked_entity_id in [link_data.left_entity_id(), link_data.right_entity_id()] {
+                ensure!(
+                    self.entities.contains(linked_entity_id),
+                    Report::new(InsertionError).attach_printable(format!(
+                        "linked entity with uuid {} does not exist",
+                        linked_entity_id.entity_uuid()
+                    ))
+                );
+            }
+        }
+
+        let (transaction_time, recorded_at) = self.resolve_transaction_time(transaction_time)?;
+        let decision_time = decision_time.map_or(transaction_time, |decision_time| {
+            decision_time.as_date_time()
+        });
+        ensure!(
+            decision_time <= transaction_time,
+            Report::new(InsertionError)
+                .attach_printable("the decision time must not be after the transaction time")
+        );
+
+        let version = self.entities.insert(
+            entity_id,
+            link_data.map(|link_data| (link_data.left_entity_id(), link_data.right_entity_id())),
+            decision_time,
+            transaction_time,
+            EntityEdition {
+                entity_type_id: entity_type_id.clone(),
+                properties,
+                link_order: EntityLinkOrder::new(
+                    link_data.and_then(|link_data| link_data.left_to_right_order()),
+                    link_data.and_then(|link_data| link_data.right_to_left_order()),
+                ),
+                updated_by_id,
+                archived,
+            },
+        );
+
+        let metadata = EntityMetadata::new(
+            EntityEditionId::new(entity_id, version.record_id, version.version()),
+            entity_type_id,
+            ProvenanceMetadata::new(updated_by_id),
+            archived,
+        );
+
+        self.insert_change_event(
+            ChangeKind::Create,
+            ChangedElement::Entity(metadata.clone()),
+            recorded_at,
+        );
+
+        Ok(metadata)
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    fn update_entity(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: Option<DecisionTimestamp>,
+        transaction_time: Option<TransactionTimestamp>,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_order: EntityLinkOrder,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.ensure_account_exists(updated_by_id.as_account_id())
+            .change_context(UpdateError)?;
+
+        let (transaction_time, recorded_at) = self
+            .resolve_transaction_time(transaction_time)
+            .change_context(UpdateError)?;
+        let decision_time = decision_time.map_or(transaction_time, |decision_time| {
+            decision_time.as_date_time()
+        });
+
+        let version =
+            self.entities
+                .update(entity_id, decision_time, transaction_time, EntityEdition {
+                    entity_type_id: entity_type_id.clone(),
+                    properties,
+                    link_order,
+                    updated_by_id,
+                    archived,
+                })?;
+
+        let metadata = EntityMetadata::new(
+            EntityEditionId::new(entity_id, version.record_id, version.version()),
+            entity_type_id,
+            ProvenanceMetadata::new(updated_by_id),
+            archived,
+        );
+
+        self.insert_change_event(
+            if archived {
+                ChangeKind::Archive
+            } else {
+                ChangeKind::Update
+            },
+            ChangedElement::Entity(metadata.clone()),
+            recorded_at,
+        );
+
+        Ok(metadata)
+    }
+
+    fn purged_entity_ids(&self, entity_id: EntityId) -> Result<Vec<EntityId>, QueryError> {
+        if !self.entities.contains(entity_id) {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .change_context(QueryError));
+        }
+
+        // Link entities can be the left or right entity of other link entities as well, so all
+        // link entities transitively referencing the entity are collected.
+        let mut entity_ids = vec![entity_id];
+        let mut next = 0;
+        while let Some(purged_entity_id) = entity_ids.get(next).copied() {
+            next += 1;
+            for (link_entity_id, stored) in &self.entities.entities {
+                let is_referencing = stored.link.map_or(false, |(left, right)| {
+                    left == purged_entity_id || right == purged_entity_id
+                });
+                if is_referencing && !entity_ids.contains(link_entity_id) {
+                    entity_ids.push(*link_entity_id);
+                }
+            }
+        }
+
+        Ok(entity_ids)
+    }
+
+    fn purge_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: UpdatedById,
+        link_behavior: LinkPurgeBehavior,
+        authorized_entity_ids: &[EntityId],
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        let entity_ids = self
+            .purged_entity_ids(entity_id)
+            .change_context(DeletionError)?;
+        self.ensure_account_exists(actor_id.as_account_id())
+            .change_context(DeletionError)?;
+
+        if link_behavior == LinkPurgeBehavior::Refuse && entity_ids.len() > 1 {
+            let mut report = Report::new(EntityIsReferenced).attach(entity_id);
+            for link_entity_id in entity_ids.into_iter().filter(|id| *id != entity_id) {
+                report = report.attach_printable(format!(
+                    "referenced by link entity {}",
+                    link_entity_id.entity_uuid().as_uuid()
+                ));
+            }
+            return Err(report.change_context(DeletionError));
+        }
+        ensure_purge_is_authorized(&entity_ids, authorized_entity_ids)?;
+
+        for purged_entity_id in &entity_ids {
+            if let Some(stored) = self.entities.entities.remove(purged_entity_id) {
+                for version in stored.versions {
+                    self.entities.editions.remove(&version.record_id);
+                }
+            }
+        }
+
+        Ok(entity_ids)
+    }
+
+    fn get_entity_subgraph(
+        &self,
+        query: &StructuralQuery<'_, Entity>,
+    ) -> Result<Subgraph, QueryError> {
+        let StructuralQuery {
+            ref filter,
+            graph_resolve_depths,
+            ref pagination,
+            ref temporal_axes,
+        } = *query;
+
+        let temporal_slice = TemporalSlice::new(temporal_axes, self.now());
+        let mut subgraph = Subgraph::new(graph_resolve_depths);
+        let mut dependency_context = DependencyContext::default();
+
+        let entities = match pagination {
+            Some(pagination) => {
+                let page = self.read_entity_page(filter, &temporal_slice, pagination)?;
+                subgraph.next_cursor = page.next_cursor;
+                page.records
+            }
+            None => self.read_entities(filter, &temporal_slice, None),
+        };
+
+        for entity in entities {
+            let entity_edition_id = entity.metadata().edition_id();
+
+            // Insert the vertex into the subgraph to avoid another lookup when traversing it
+            subgraph
+                .vertices
+                .knowledge_graph
+                .insert(entity_edition_id, KnowledgeGraphVertex::Entity(entity));
+
+            self.traverse_entity(
+                entity_edition_id,
+                &mut dependency_context,
+                &mut subgraph,
+                graph_resolve_depths,
+                &temporal_slice,
+            )?;
+
+            subgraph
+                .roots
+                .insert(GraphElementEditionId::KnowledgeGraph(entity_edition_id));
+        }
+
+        subgraph.search_ranks.extend(
+            self.entity_search_ranks(filter, &temporal_slice)
+                .into_iter()
+                .map(|(edition_id, rank)| (GraphElementEditionId::KnowledgeGraph(edition_id), rank))
+                .filter(|(root, _)| subgraph.roots.contains(root)),
+        );
+
+        Ok(subgraph)
+    }
+
+    /// Reads the entity specified by `entity_edition_id` and its dependencies into the subgraph.
+    ///
+    /// All entities are resolved in `temporal_slice`.
+    pub(super) fn traverse_entity(
+        &self,
+        entity_edition_id: EntityEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        let dependency_status = dependency_context
+            .knowledge_dependency_map
+            .insert(&entity_edition_id, current_resolve_depth);
+        if matches!(dependency_status, DependencyStatus::Resolved) {
+            return Ok(());
+        }
+
+        let entity_type_id = match subgraph.vertices.knowledge_graph.entry(entity_edition_id) {
+            Entry::Occupied(entry) => {
+                let KnowledgeGraphVertex::Entity(entity) = entry.get();
+                OntologyTypeEditionId::from(entity.metadata().entity_type_id())
+            }
+            Entry::Vacant(entry) => {
+                let mut entities = self.read_entities(
+                    &Filter::for_entity_by_edition_id(entity_edition_id),
+                    temporal_slice,
+                    None,
+                );
+                ensure!(
+                    entities.len() == 1,
+                    Report::new(QueryError).attach_printable(format!(
+                        "Expected exactly one entity to be returned from the query but {} were \
+                         returned",
+                        entities.len(),
+                    ))
+                );
+                let Some(entity) = entities.pop() else {
+                    unreachable!("the number of entities was checked before")
+                };
+                let entity_type_id =
+                    OntologyTypeEditionId::from(entity.metadata().entity_type_id());
+                entry.insert(KnowledgeGraphVertex::Entity(entity));
+                entity_type_id
+            }
+        };
+
+        if current_resolve_depth.is_of_type.outgoing > 0 {
+            subgraph.edges.insert(Edge::KnowledgeGraph {
+                edition_id: entity_edition_id,
+                outward_edge: KnowledgeGraphOutwardEdges::ToOntology(OutwardEdge {
+                    kind: SharedEdgeKind::IsOfType,
+                    reversed: false,
+                    right_endpoint: entity_type_id.clone(),
+                }),
+            });
+
+            self.traverse_entity_type(
+                &entity_type_id,
+                dependency_context,
+                subgraph,
+                GraphResolveDepths {
+                    is_of_type: EdgeResolveDepths {
+                        outgoing: current_resolve_depth.is_of_type.outgoing - 1,
+                        ..current_resolve_depth.is_of_type
+                    },
+                    ..current_resolve_depth
+                },
+                temporal_slice,
+            )?;
+        }
+
+        // (HasLeftEntity, reversed=true) is equivalent to an outgoing link entity and
+        // (HasRightEntity, reversed=true) to an incoming link entity.
+        let link_edges = [
+            (
+                current_resolve_depth.has_left_entity.incoming > 0,
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                true,
+                Filter::for_outgoing_link_by_source_entity_edition_id(entity_edition_id),
+                GraphResolveDepths {
+                    has_left_entity: EdgeResolveDepths {
+                        incoming: current_resolve_depth
+                            .has_left_entity
+                            .incoming
+                            .saturating_sub(1),
+                        ..current_resolve_depth.has_left_entity
+                    },
+                    ..current_resolve_depth
+                },
+            ),
+            (
+                current_resolve_depth.has_right_entity.incoming > 0,
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                true,
+                Filter::for_incoming_link_by_source_entity_edition_id(entity_edition_id),
+                GraphResolveDepths {
+                    has_right_entity: EdgeResolveDepths {
+                        incoming: current_resolve_depth
+                            .has_right_entity
+                            .incoming
+                            .saturating_sub(1),
+                        ..current_resolve_depth.has_right_entity
+                    },
+                    ..current_resolve_depth
+                },
+            ),
+            (
+                current_resolve_depth.has_left_entity.outgoing > 0,
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                false,
+                Filter::for_left_entity_by_entity_edition_id(entity_edition_id),
+                GraphResolveDepths {
+                    has_left_entity: EdgeResolveDepths {
+                        outgoing: current_resolve_depth
+                            .has_left_entity
+                            .outgoing
+                            .saturating_sub(1),
+                        ..current_resolve_depth.has_left_entity
+                    },
+                    ..current_resolve_depth
+                },
+            ),
+            (
+                current_resolve_depth.has_right_entity.outgoing > 0,
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                false,
+                Filter::for_right_entity_by_entity_edition_id(entity_edition_id),
+                GraphResolveDepths {
+                    has_right_entity: EdgeResolveDepths {
+                        outgoing: current_resolve_depth
+                            .has_right_entity
+                            .outgoing
+                            .saturating_sub(1),
+                        ..current_resolve_depth.has_right_entity
+                    },
+                    ..current_resolve_depth
+                },
+            ),
+        ];
+
+        for (_, kind, reversed, filter, resolve_depth) in link_edges
+            .into_iter()
+            .filter(|(is_resolved, ..)| *is_resolved)
+        {
+            for entity in self.read_entities(&filter, temporal_slice, None) {
+                let edition_id = entity.metadata().edition_id();
+
+                // We want to log the time the link entity was *first* added, so the earliest
+                // transaction time of the link entity is used.
+                let link_entity_id = if reversed {
+                    edition_id.base_id()
+                } else {
+                    entity_edition_id.base_id()
+                };
+                let earliest_timestamp = self
+                    .entities
+                    .earliest_transaction_time(link_entity_id, temporal_slice)
+                    .expect(
+                        "we got the edition id from the entity in the first place, there must be \
+                         at least one version",
+                    );
+
+                subgraph.edges.insert(Edge::KnowledgeGraph {
+                    edition_id: entity_edition_id,
+                    outward_edge: KnowledgeGraphOutwardEdges::ToKnowledgeGraph(OutwardEdge {
+                        kind,
+                        reversed,
+                        right_endpoint: EntityIdAndTimestamp::new(
+                            edition_id.base_id(),
+                            earliest_timestamp,
+                        ),
+                    }),
+                });
+
+                // Insert the vertex into the subgraph to avoid another lookup when traversing it
+                subgraph
+                    .vertices
+                    .knowledge_graph
+                    .entry(edition_id)
+                    .or_insert(KnowledgeGraphVertex::Entity(entity));
+
+                self.traverse_entity(
+                    edition_id,
+                    dependency_context,
+                    subgraph,
+                    resolve_depth,
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Read<Entity> for MemoryStore {
+    async fn read(
+        &self,
+        filter: &Filter<Entity>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<Vec<Entity>, QueryError> {
+        let state = self.state();
+        let temporal_slice = TemporalSlice::new(temporal_axes, state.now());
+        Ok(state.read_entities(filter, &temporal_slice, None))
+    }
+
+    async fn read_stream<'s>(
+        &'s mut self,
+        filter: &Filter<Entity>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<BoxStream<'s, Result<Entity, QueryError>>, QueryError> {
+        // The entities are held in memory anyway, so they are read at once.
+        let entities = Read::<Entity>::read(self, filter, temporal_axes).await?;
+        Ok(stream::iter(entities.into_iter().map(Ok)).boxed())
+    }
+
+    async fn read_page(
+        &self,
+        filter: &Filter<Entity>,
+        temporal_axes: &TemporalAxes,
+        pagination: &Pagination,
+    ) -> Result<Page<Entity>, QueryError> {
+        let state = self.state();
+        let temporal_slice = TemporalSlice::new(temporal_axes, state.now());
+        state.read_entity_page(filter, &temporal_slice, pagination)
+    }
+}
+
+#[async_trait]
+impl EntityStore for MemoryStore {
+    async fn create_entity(
+        &mut self,
+        owned_by_id: OwnedById,
+        entity_uuid: Option<EntityUuid>,
+        decision_time: Option<DecisionTimestamp>,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<LinkData>,
+    ) -> Result<EntityMetadata, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        EntityValidator::resolve(&*self, &entity_type_id)
+            .await
+            .change_context(InsertionError)?
+            .validate(&properties, link_data.as_ref())
+            .change_context(InsertionError)?;
+
+        self.state_mut().create_entity(
+            EntityId::new(
+                owned_by_id,
+                entity_uuid.unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+            ),
+            decision_time,
+            None,
+            updated_by_id,
+            archived,
+            entity_type_id,
+            properties,
+            link_data,
+        )
+    }
+
+    #[doc(hidden)]
+    #[cfg(feature = "__internal_bench")]
+    async fn insert_entities_batched_by_type(
+        &mut self,
+        entities: impl IntoIterator<
+                Item = (
+                    OwnedById,
+                    Option<EntityUuid>,
+                    EntityProperties,
+                    Option<LinkData>,
+                    Option<DecisionTimestamp>,
+                ),
+                IntoIter: Send,
+            > + Send,
+        actor_id: UpdatedById,
+        entity_type_id: &VersionedUri,
+    ) -> Result<Vec<EntityMetadata>, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        let validator = EntityValidator::resolve(&*self, entity_type_id)
+            .await
+            .change_context(InsertionError)?;
+
+        // The entities are inserted into a copy of the state, so either all or none of the
+        // entities are inserted.
+        let mut state = self.state().clone();
+        let mut entity_metadata = Vec::new();
+        for (owned_by_id, entity_uuid, properties, link_data, decision_time) in entities {
+            validator
+                .validate(&properties, link_data.as_ref())
+                .change_context(InsertionError)?;
+
+            entity_metadata.push(state.create_entity(
+                EntityId::new(
+                    owned_by_id,
+                    entity_uuid.unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+                ),
+                decision_time,
+                None,
+                actor_id,
+                false,
+                entity_type_id.clone(),
+                properties,
+                link_data,
+            )?);
+        }
+
+        *self.state_mut() = state;
+
+        Ok(entity_metadata)
+    }
+
+    async fn get_entity<'f: 'q, 'q>(
+        &self,
+        query: &'f StructuralQuery<'q, Entity>,
+    ) -> Result<Subgraph, QueryError> {
+        self.state().get_entity_subgraph(query)
+    }
+
+    async fn aggregate_entities<'f: 'q, 'q>(
+        &self,
+        query: &'f AggregationQuery<'q, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        Ok(self.state().aggregate_entities(query))
+    }
+
+    async fn update_entity(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: Option<DecisionTimestamp>,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_order: EntityLinkOrder,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        let entity_exists = self.state().entities.contains(entity_id);
+        if !entity_exists {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .change_context(UpdateError));
+        }
+
+        // Whether an entity is a link is fixed on creation, so the link data of the latest
+        // edition is validated against the new entity type.
+        let link_data = Read::<Entity>::read(
+            &*self,
+            &Filter::for_latest_entity_by_entity_id(entity_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .change_context(UpdateError)?
+        .pop()
+        .and_then(|entity| entity.link_data());
+
+        EntityValidator::resolve(&*self, &entity_type_id)
+            .await
+            .change_context(UpdateError)?
+            .validate(&properties, link_data.as_ref())
+            .change_context(UpdateError)?;
+
+        self.state_mut().update_entity(
+            entity_id,
+            decision_time,
+            None,
+            updated_by_id,
+            archived,
+            entity_type_id,
+            properties,
+            link_order,
+        )
+    }
+
+    async fn import_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: DecisionTimestamp,
+        transaction_time: TransactionTimestamp,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<LinkData>,
+    ) -> Result<EntityMetadata, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        let stored_link_data = Read::<Entity>::read(
+            &*self,
+            &Filter::for_latest_entity_by_entity_id(entity_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .change_context(InsertionError)?
+        .pop()
+        .map(|entity| entity.link_data());
+
+        // Like for updates, the link data of an existing entity is fixed.
+        EntityValidator::resolve(&*self, &entity_type_id)
+            .await
+            .change_context(InsertionError)?
+            .validate(&properties, stored_link_data.unwrap_or(link_data).as_ref())
+            .change_context(InsertionError)?;
+
+        if stored_link_data.is_some() {
+            self.state_mut()
+                .update_entity(
+                    entity_id,
+                    Some(decision_time),
+                    Some(transaction_time),
+                    updated_by_id,
+                    archived,
+                    entity_type_id,
+                    properties,
+                    link_data.map_or(EntityLinkOrder::new(None, None), |link_data| {
+                        EntityLinkOrder::new(
+                            link_data.left_to_right_order(),
+                            link_data.right_to_left_order(),
+                        )
+                    }),
+                )
+                .change_context(InsertionError)
+        } else {
+            self.state_mut().create_entity(
+                entity_id,
+                Some(decision_time),
+                Some(transaction_time),
+                updated_by_id,
+                archived,
+                entity_type_id,
+                properties,
+                link_data,
+            )
+        }
+    }
+
+    async fn purge_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: UpdatedById,
+        link_behavior: LinkPurgeBehavior,
+        authorized_entity_ids: &[EntityId],
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .purge_entity(entity_id, actor_id, link_behavior, authorized_entity_ids)
+    }
+
+    async fn read_purged_entity_ids(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityId>, QueryError> {
+        self.state().purged_entity_ids(entity_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, collections::HashSet};
+
+    use graph_test_data::entity;
+    use type_system::uri::BaseUri;
+
+    use super::*;
+    use crate::{
+        identifier::account::AccountId,
+        store::{
+            error::PurgedEntitiesChanged,
+            memory::tests::{person_type_id, seed},
+            query::{FilterExpression, Parameter},
+        },
+    };
+
+    const NAME_PROPERTY: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+
+    fn friend_of_type_id() -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new(
+                "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+            )
+            .expect("couldn't construct Base URI"),
+            1,
+        )
+    }
+
+    async fn create_entity(
+        store: &mut MemoryStore,
+        account_id: AccountId,
+        entity_type_id: VersionedUri,
+        properties: &str,
+        link_data: Option<LinkData>,
+    ) -> EntityMetadata {
+        store
+            .create_entity(
+                OwnedById::new(account_id),
+                None,
+                None,
+                UpdatedById::new(account_id),
+                false,
+                entity_type_id,
+                serde_json::from_str(properties).expect("could not parse entity"),
+                link_data,
+            )
+            .await
+            .expect("could not create entity")
+    }
+
+    /// Creates the persons Alice and Bob and a link entity stating that Alice is a friend of Bob.
+    async fn seed_friends() -> (MemoryStore, [EntityId; 3]) {
+        let (mut store, account_id) = seed().await;
+
+        let alice = create_entity(
+            &mut store,
+            account_id,
+            person_type_id(),
+            entity::PERSON_A_V1,
+            None,
+        )
+        .await
+        .edition_id()
+        .base_id();
+        let bob = create_entity(
+            &mut store,
+            account_id,
+            person_type_id(),
+            entity::PERSON_B_V1,
+            None,
+        )
+        .await
+        .edition_id()
+        .base_id();
+        let friend_of = create_entity(
+            &mut store,
+            account_id,
+            friend_of_type_id(),
+            "{}",
+            Some(LinkData::new(alice, bob, None, None)),
+        )
+        .await
+        .edition_id()
+        .base_id();
+
+        (store, [alice, bob, friend_of])
+    }
+
+    async fn read_entity_ids(
+        store: &MemoryStore,
+        filter: &Filter<'_, Entity>,
+    ) -> HashSet<EntityId> {
+        Read::<Entity>::read(store, filter, &TemporalAxes::default())
+            .await
+            .expect("could not read entities")
+            .into_iter()
+            .map(|entity| entity.metadata().edition_id().base_id())
+            .collect()
+    }
+
+    async fn read_subgraph(
+        store: &MemoryStore,
+        entity_id: EntityId,
+        graph_resolve_depths: GraphResolveDepths,
+    ) -> Subgraph {
+        store
+            .get_entity(&StructuralQuery {
+                filter: Filter::for_latest_entity_by_entity_id(entity_id),
+                graph_resolve_depths,
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+            .expect("could not read subgraph")
+    }
+
+    fn vertex_ids(subgraph: &Subgraph) -> HashSet<EntityId> {
+        subgraph
+            .vertices
+            .knowledge_graph
+            .keys()
+            .map(EntityEditionId::base_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn traverse_links() {
+        let (store, [alice, bob, friend_of]) = seed_friends().await;
+
+        let subgraph = read_subgraph(&store, alice, GraphResolveDepths::default()).await;
+        assert_eq!(subgraph.roots.len(), 1);
+        assert_eq!(vertex_ids(&subgraph), HashSet::from([alice]));
+        assert!(subgraph.edges.knowledge_graph.is_empty());
+
+        let subgraph = read_subgraph(&store, alice, GraphResolveDepths {
+            has_left_entity: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 0,
+            },
+            has_right_entity: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await;
+        assert_eq!(subgraph.roots.len(), 1);
+        assert_eq!(
+            vertex_ids(&subgraph),
+            HashSet::from([alice, bob, friend_of])
+        );
+        assert!(subgraph.vertices.ontology.is_empty());
+
+        let alice_edges = subgraph
+            .edges
+            .knowledge_graph
+            .iter()
+            .find(|(edition_id, _)| edition_id.base_id() == alice)
+            .map(|(_, edges)| edges)
+            .expect("no edges for the root entity");
+        assert!(alice_edges.iter().any(|edge| matches!(
+            edge,
+            KnowledgeGraphOutwardEdges::ToKnowledgeGraph(OutwardEdge {
+                kind: KnowledgeGraphEdgeKind::HasLeftEntity,
+                reversed: true,
+                right_endpoint,
+            }) if right_endpoint.base_id() == friend_of
+        )));
+
+        // Bob is only the right entity of the link, so it's not reachable by the left entity edge.
+        let subgraph = read_subgraph(&store, bob, GraphResolveDepths {
+            has_left_entity: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 1,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await;
+        assert_eq!(vertex_ids(&subgraph), HashSet::from([bob]));
+    }
+
+    #[tokio::test]
+    async fn traverse_entity_type() {
+        let (store, [alice, ..]) = seed_friends().await;
+
+        let subgraph = read_subgraph(&store, alice, GraphResolveDepths {
+            is_of_type: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await;
+        assert_eq!(vertex_ids(&subgraph), HashSet::from([alice]));
+        assert_eq!(subgraph.vertices.ontology.keys().collect::<Vec<_>>(), [
+            &OntologyTypeEditionId::from(&person_type_id())
+        ]);
+    }
+
+    #[tokio::test]
+    async fn filter_entities() {
+        let (store, [alice, bob, friend_of]) = seed_friends().await;
+
+        assert_eq!(
+            read_entity_ids(&store, &Filter::for_all_latest_entities()).await,
+            HashSet::from([alice, bob, friend_of])
+        );
+        assert_eq!(
+            read_entity_ids(
+                &store,
+                &Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::Properties(Some(
+                        Cow::Borrowed(NAME_PROPERTY)
+                    )))),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                        "Alice"
+                    )))),
+                )
+            )
+            .await,
+            HashSet::from([alice])
+        );
+        assert_eq!(
+            read_entity_ids(&store, &Filter::Search(Cow::Borrowed("bob"))).await,
+            HashSet::from([bob])
+        );
+
+        let link_filter = |entity_id: EntityId| {
+            Filter::All(vec![
+                Filter::for_all_latest_entities(),
+                Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::LeftEntity(
+                        Box::new(EntityQueryPath::Uuid),
+                    ))),
+                    Some(FilterExpression::Parameter(Parameter::Uuid(
+                        entity_id.entity_uuid().as_uuid(),
+                    ))),
+                ),
+            ])
+        };
+        assert_eq!(
+            read_entity_ids(&store, &link_filter(alice)).await,
+            HashSet::from([friend_of])
+        );
+        assert!(read_entity_ids(&store, &link_filter(bob)).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn purge_refuses_unauthorized_entities() {
+        let (mut store, [alice, bob, friend_of]) = seed_friends().await;
+        let actor_id = UpdatedById::new(alice.owned_by_id().as_account_id());
+
+        // The link entity was created after the purge of Bob was authorized.
+        let report = store
+            .purge_entity(bob, actor_id, LinkPurgeBehavior::Cascade, &[bob])
+            .await
+            .expect_err("could purge an entity, which was not authorized");
+        assert!(report.contains::<PurgedEntitiesChanged>());
+        assert_eq!(
+            store
+                .read_purged_entity_ids(bob)
+                .await
+                .expect("entities were purged although the purge was refused"),
+            [bob, friend_of]
+        );
+
+        let mut purged_entity_ids = store
+            .purge_entity(bob, actor_id, LinkPurgeBehavior::Cascade, &[friend_of, bob])
+            .await
+            .expect("could not purge entity");
+        purged_entity_ids.sort();
+        let mut expected_entity_ids = vec![bob, friend_of];
+        expected_entity_ids.sort();
+        assert_eq!(purged_entity_ids, expected_entity_ids);
+    }
+}
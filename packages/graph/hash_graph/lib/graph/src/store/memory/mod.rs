@@ -0,0 +1,566 @@
+//! An in-memory [`Store`] for tests and local development.
+//!
+//! The [`MemoryStore`] behaves like the [`PostgresStore`] with a few exceptions:
+//!
+//! - full-text search does not stem words, so only exact words are matched
+//! - conditions on the same relation, e.g. two conditions on the outgoing links of an entity, are
+//!   evaluated independently of each other
+//! - purging entities does not keep audit records
+//!
+//! [`Store`]: crate::store::Store
+//! [`PostgresStore`]: crate::store::PostgresStore
+
+mod filter;
+mod knowledge;
+mod ontology;
+mod pool;
+
+use std::{
+    collections::HashSet,
+    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use error_stack::{ensure, Report, Result};
+use type_system::{DataType, EntityType, PropertyType};
+
+pub use self::pool::MemoryStorePool;
+use self::{knowledge::Entities, ontology::OntologyTypes};
+use crate::{
+    identifier::{
+        account::AccountId,
+        knowledge::{EntityId, EntityRecordId},
+        TransactionTimestamp,
+    },
+    knowledge::{Entity, EntityMetadata},
+    provenance::UpdatedById,
+    store::{
+        batch::apply_operations,
+        error::ChangeOffsetDoesNotExist,
+        history::{read_entity_history, restore_entity_edition},
+        migration::migrate_entities,
+        AccountStore, BatchOperation, BatchOperationResult, BatchStore, ChangeEvent,
+        ChangeFeedStore, ChangeKind, ChangeOffset, ChangedElement, EntityHistoryStore,
+        EntityMigration, EntityMigrationStore, InsertionError, MigratedEntity, QueryError,
+        UpdateError,
+    },
+};
+
+#[derive(Clone, Default)]
+struct MemoryState {
+    accounts: HashSet<AccountId>,
+    /// Pairs of `(account_id, member_id)`.
+    account_members: HashSet<(AccountId, AccountId)>,
+    data_types: OntologyTypes<DataType>,
+    property_types: OntologyTypes<PropertyType>,
+    entity_types: OntologyTypes<EntityType>,
+    entities: Entities,
+    change_events: Vec<ChangeEvent>,
+    last_transaction_time: Option<DateTime<Utc>>,
+}
+
+impl MemoryState {
+    /// Returns the transaction time for a new write.
+    ///
+    /// Transaction times are strictly increasing, so two writes are never recorded at the same
+    /// time.
+    fn transaction_time(&mut self) -> DateTime<Utc> {
+        let now = Utc::now();
+        let transaction_time = match self.last_transaction_time {
+            Some(last_transaction_time) if now <= last_transaction_time => {
+                last_transaction_time + Duration::microseconds(1)
+            }
+            _ => now,
+        };
+        self.last_transaction_time = Some(transaction_time);
+        transaction_time
+    }
+
+    /// Returns the transaction time of a write and the time it's recorded at.
+    ///
+    /// If `transaction_time` is specified, the write is backdated to it, otherwise both are a new
+    /// transaction time.
+    fn resolve_transaction_time(
+        &mut self,
+        transaction_time: Option<TransactionTimestamp>,
+    ) -> Result<(DateTime<Utc>, DateTime<Utc>), InsertionError> {
+        let recorded_at = self.transaction_time();
+        let transaction_time = transaction_time.map_or(recorded_at, |transaction_time| {
+            transaction_time.as_date_time()
+        });
+        ensure!(
+            transaction_time <= recorded_at,
+            Report::new(InsertionError).attach_printable(format!(
+                "the transaction time {transaction_time} is in the future"
+            ))
+        );
+        Ok((transaction_time, recorded_at))
+    }
+
+    /// Returns the current time, which is never before the last transaction time.
+    fn now(&self) -> DateTime<Utc> {
+        let now = Utc::now();
+        self.last_transaction_time
+            .map_or(now, |last_transaction_time| now.max(last_transaction_time))
+    }
+
+    fn ensure_account_exists(&self, account_id: AccountId) -> Result<(), InsertionError> {
+        ensure!(
+            self.accounts.contains(&account_id),
+            Report::new(InsertionError)
+                .attach_printable(format!("account {account_id} does not exist"))
+        );
+        Ok(())
+    }
+
+    fn insert_change_event(
+        &mut self,
+        kind: ChangeKind,
+        element: ChangedElement,
+        recorded_at: DateTime<Utc>,
+    ) {
+        let offset = self
+            .change_events
+            .last()
+            .map_or(1, |event| event.offset().as_i64() + 1);
+        self.change_events.push(ChangeEvent::new(
+            ChangeOffset::new(offset),
+            kind,
+            element,
+            recorded_at,
+        ));
+    }
+}
+
+/// A [`Store`] keeping all data in memory.
+///
+/// Clones of the store share the same data. Writes are serialized, so every write operates on the
+/// data written by all preceding writes.
+///
+/// [`Store`]: crate::store::Store
+#[derive(Clone, Default)]
+pub struct MemoryStore {
+    state: Arc<RwLock<MemoryState>>,
+    write_lock: Arc<futures::lock::Mutex<()>>,
+}
+
+impl MemoryStore {
+    /// Creates a new, empty `MemoryStore`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the store, which is not shared with `self`.
+    ///
+    /// The copy has its own write lock, so it can be written to while the write lock of `self` is
+    /// held.
+    fn transaction(&self) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(self.state().clone())),
+            write_lock: Arc::default(),
+        }
+    }
+
+    // The guards must not be held across an `.await` point, so they are only used for
+    // synchronous operations on the state.
+    fn state(&self) -> RwLockReadGuard<'_, MemoryState> {
+        self.state.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn state_mut(&self) -> RwLockWriteGuard<'_, MemoryState> {
+        self.state.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl AccountStore for MemoryStore {
+    async fn insert_account_id(&mut self, account_id: AccountId) -> Result<(), InsertionError> {
+        ensure!(
+            self.state_mut().accounts.insert(account_id),
+            Report::new(InsertionError)
+                .attach_printable(format!("account {account_id} already exists"))
+        );
+        Ok(())
+    }
+
+    async fn insert_account_member(
+        &mut self,
+        account_id: AccountId,
+        member_id: AccountId,
+    ) -> Result<(), InsertionError> {
+        let mut state = self.state_mut();
+        state.ensure_account_exists(account_id)?;
+        state.ensure_account_exists(member_id)?;
+        ensure!(
+            state.account_members.insert((account_id, member_id)),
+            Report::new(InsertionError).attach_printable(format!(
+                "account {member_id} is already a member of account {account_id}"
+            ))
+        );
+        Ok(())
+    }
+
+    async fn is_account_member(
+        &self,
+        account_id: AccountId,
+        member_id: AccountId,
+    ) -> Result<bool, QueryError> {
+        Ok(account_id == member_id
+            || self
+                .state()
+                .account_members
+                .contains(&(account_id, member_id)))
+    }
+
+    async fn read_accounts(&self) -> Result<Vec<AccountId>, QueryError> {
+        let mut accounts = self.state().accounts.iter().copied().collect::<Vec<_>>();
+        accounts.sort();
+        Ok(accounts)
+    }
+
+    async fn read_account_members(&self) -> Result<Vec<(AccountId, AccountId)>, QueryError> {
+        let mut account_members = self
+            .state()
+            .account_members
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        account_members.sort();
+        Ok(account_members)
+    }
+}
+
+#[async_trait]
+impl BatchStore for MemoryStore {
+    async fn apply_batch(
+        &mut self,
+        operations: Vec<BatchOperation>,
+    ) -> Result<Vec<BatchOperationResult>, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        // The operations are applied to a copy of the state, which only replaces the state if all
+        // operations succeeded.
+        let mut transaction = self.transaction();
+
+        let results = apply_operations(&mut transaction, operations).await?;
+
+        let state = transaction.state().clone();
+        *self.state_mut() = state;
+
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl EntityMigrationStore for MemoryStore {
+    async fn migrate_entities(
+        &mut self,
+        migration: &EntityMigration,
+        actor_id: UpdatedById,
+        dry_run: bool,
+    ) -> Result<Vec<MigratedEntity>, UpdateError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        let mut transaction = self.transaction();
+
+        let migrated_entities = migrate_entities(&mut transaction, migration, actor_id).await?;
+
+        if !dry_run {
+            let state = transaction.state().clone();
+            *self.state_mut() = state;
+        }
+
+        Ok(migrated_entities)
+    }
+}
+
+#[async_trait]
+impl EntityHistoryStore for MemoryStore {
+    async fn read_entity_history(&self, entity_id: EntityId) -> Result<Vec<Entity>, QueryError> {
+        read_entity_history(self, entity_id).await
+    }
+
+    async fn restore_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        record_id: EntityRecordId,
+        actor_id: UpdatedById,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let _write_guard = self.write_lock.lock().await;
+
+        let mut transaction = self.transaction();
+
+        let metadata =
+            restore_entity_edition(&mut transaction, entity_id, record_id, actor_id).await?;
+
+        let state = transaction.state().clone();
+        *self.state_mut() = state;
+
+        Ok(metadata)
+    }
+}
+
+#[async_trait]
+impl ChangeFeedStore for MemoryStore {
+    async fn read_changes(
+        &self,
+        after: Option<ChangeOffset>,
+        limit: usize,
+    ) -> Result<Vec<ChangeEvent>, QueryError> {
+        let state = self.state();
+        if let Some(after) = after {
+            ensure!(
+                state
+                    .change_events
+                    .iter()
+                    .any(|event| event.offset() == after),
+                Report::new(ChangeOffsetDoesNotExist)
+                    .attach_printable(after.as_i64())
+                    .change_context(QueryError)
+            );
+        }
+
+        Ok(state
+            .change_events
+            .iter()
+            .filter(|event| after.map_or(true, |after| event.offset() > after))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_test_data::{data_type, entity, entity_type, property_type};
+    use type_system::{
+        repr,
+        uri::{BaseUri, VersionedUri},
+    };
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        knowledge::{Entity, EntityLinkOrder, EntityProperties},
+        provenance::{OwnedById, UpdatedById},
+        store::{
+            crud::Read,
+            query::{Filter, TemporalAxes},
+            DataTypeStore, EntityStore, EntityTypeStore, PropertyTypeStore,
+        },
+    };
+
+    pub(super) fn person_type_id() -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+                .expect("couldn't construct Base URI"),
+            1,
+        )
+    }
+
+    pub(super) async fn seed() -> (MemoryStore, AccountId) {
+        let mut store = MemoryStore::new();
+        let account_id = AccountId::new(Uuid::new_v4());
+        store
+            .insert_account_id(account_id)
+            .await
+            .expect("could not insert account id");
+
+        let data_type: repr::DataType =
+            serde_json::from_str(data_type::TEXT_V1).expect("could not parse data type");
+        store
+            .create_data_type(
+                DataType::try_from(data_type).expect("could not parse data type"),
+                OwnedById::new(account_id),
+                UpdatedById::new(account_id),
+            )
+            .await
+            .expect("could not create data type");
+
+        let property_type: repr::PropertyType =
+            serde_json::from_str(property_type::NAME_V1).expect("could not parse property type");
+        store
+            .create_property_type(
+                PropertyType::try_from(property_type).expect("could not parse property type"),
+                OwnedById::new(account_id),
+                UpdatedById::new(account_id),
+            )
+            .await
+            .expect("could not create property type");
+
+        for entity_type_str in [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ] {
+            let entity_type: repr::EntityType =
+                serde_json::from_str(entity_type_str).expect("could not parse entity type");
+            store
+                .create_entity_type(
+                    EntityType::try_from(entity_type).expect("could not parse entity type"),
+                    OwnedById::new(account_id),
+                    UpdatedById::new(account_id),
+                )
+                .await
+                .expect("could not create entity type");
+        }
+
+        (store, account_id)
+    }
+
+    #[tokio::test]
+    async fn account_members() {
+        let mut store = MemoryStore::new();
+        let account_id = AccountId::new(Uuid::new_v4());
+        let member_id = AccountId::new(Uuid::new_v4());
+
+        store
+            .insert_account_id(account_id)
+            .await
+            .expect("could not insert account id");
+        assert!(store.insert_account_id(account_id).await.is_err());
+        assert!(store
+            .insert_account_member(account_id, member_id)
+            .await
+            .is_err());
+
+        store
+            .insert_account_id(member_id)
+            .await
+            .expect("could not insert account id");
+        store
+            .insert_account_member(account_id, member_id)
+            .await
+            .expect("could not insert account member");
+
+        assert!(store
+            .is_account_member(account_id, member_id)
+            .await
+            .expect("could not read account members"));
+        assert!(!store
+            .is_account_member(member_id, account_id)
+            .await
+            .expect("could not read account members"));
+    }
+
+    #[tokio::test]
+    async fn update_entity() {
+        let (mut store, account_id) = seed().await;
+
+        let person_a: EntityProperties =
+            serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+        let person_b: EntityProperties =
+            serde_json::from_str(entity::PERSON_B_V1).expect("could not parse entity");
+
+        let created = store
+            .create_entity(
+                OwnedById::new(account_id),
+                None,
+                None,
+                UpdatedById::new(account_id),
+                false,
+                person_type_id(),
+                person_a.clone(),
+                None,
+            )
+            .await
+            .expect("could not create entity");
+        let entity_id = created.edition_id().base_id();
+
+        store
+            .update_entity(
+                entity_id,
+                None,
+                UpdatedById::new(account_id),
+                false,
+                person_type_id(),
+                person_b.clone(),
+                EntityLinkOrder::new(None, None),
+            )
+            .await
+            .expect("could not update entity");
+
+        let latest = Read::<Entity>::read(
+            &store,
+            &Filter::for_latest_entity_by_entity_id(entity_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .expect("could not read entity");
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].properties(), &person_b);
+
+        let created_edition = Read::<Entity>::read(
+            &store,
+            &Filter::for_entity_by_edition_id(created.edition_id()),
+            &TemporalAxes {
+                transaction_time: Some(
+                    created
+                        .edition_id()
+                        .version()
+                        .transaction_time()
+                        .as_start_bound_timestamp(),
+                ),
+                decision_time: None,
+            },
+        )
+        .await
+        .expect("could not read entity");
+        assert_eq!(created_edition.len(), 1);
+        assert_eq!(created_edition[0].properties(), &person_a);
+
+        let changes = store
+            .read_changes(None, 10)
+            .await
+            .expect("could not read changes");
+        assert_eq!(changes.len(), 7);
+        assert_eq!(changes[5].kind(), ChangeKind::Create);
+        assert_eq!(changes[6].kind(), ChangeKind::Update);
+
+        assert_eq!(
+            store
+                .read_changes(Some(changes[5].offset()), 10)
+                .await
+                .expect("could not read changes"),
+            changes[6..]
+        );
+        let report = store
+            .read_changes(Some(ChangeOffset::new(1000)), 10)
+            .await
+            .expect_err("could read changes after an unknown offset");
+        assert!(report.contains::<ChangeOffsetDoesNotExist>());
+    }
+
+    #[tokio::test]
+    async fn failed_batch_is_not_applied() {
+        let (mut store, account_id) = seed().await;
+
+        let person: EntityProperties =
+            serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+
+        let operation = |owned_by_id| BatchOperation::CreateEntity {
+            owned_by_id,
+            entity_uuid: None,
+            actor_id: UpdatedById::new(account_id),
+            entity_type_id: person_type_id(),
+            properties: person.clone(),
+            link_data: None,
+        };
+
+        assert!(store
+            .apply_batch(vec![
+                operation(OwnedById::new(account_id)),
+                operation(OwnedById::new(AccountId::new(Uuid::new_v4()))),
+            ])
+            .await
+            .is_err());
+
+        let entities =
+            Read::<Entity>::read(&store, &Filter::All(Vec::new()), &TemporalAxes::default())
+                .await
+                .expect("could not read entities");
+        assert!(entities.is_empty());
+    }
+}
@@ -0,0 +1,1703 @@
+use std::collections::{BTreeMap, HashMap};
+
+use async_trait::async_trait;
+use error_stack::{IntoReport, Report, Result, ResultExt};
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use serde_json::Value as JsonValue;
+use type_system::{
+    uri::{BaseUri, VersionedUri},
+    DataType, DataTypeReference, EntityType, EntityTypeReference, PropertyType,
+    PropertyTypeReference,
+};
+use uuid::Uuid;
+
+use crate::{
+    identifier::{
+        ontology::{OntologyTypeEditionId, OntologyTypeVersion},
+        GraphElementEditionId,
+    },
+    ontology::{
+        CheckCompatibility, CompatibilityPolicy, DataTypeQueryPath, DataTypeWithMetadata,
+        EntityTypeQueryPath, EntityTypeWithMetadata, OntologyElementMetadata, OntologyType,
+        OntologyUpdateMetadata, PersistedOntologyType, PropertyTypeQueryPath,
+        PropertyTypeWithMetadata,
+    },
+    provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
+    store::{
+        crud::Read,
+        error::VersionedUriAlreadyExists,
+        memory::{
+            filter::{self, Resolve, SearchQuery, Value},
+            knowledge::TemporalSlice,
+            MemoryState, MemoryStore,
+        },
+        postgres::{DependencyContext, DependencyStatus},
+        query::{Filter, Page, Pagination, QueryRecord, TemporalAxes},
+        BaseUriAlreadyExists, BaseUriDoesNotExist, ChangeKind, ChangedElement, DataTypeStore,
+        EntityTypeStore, InsertionError, PropertyTypeStore, QueryError, UpdateError,
+    },
+    subgraph::{
+        edges::{
+            Edge, EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
+            OutwardEdge, SharedEdgeKind,
+        },
+        query::StructuralQuery,
+        vertices::{KnowledgeGraphVertex, OntologyVertex},
+        Subgraph,
+    },
+};
+
+/// A single version of an ontology type as it's kept in the [`MemoryStore`].
+#[derive(Clone)]
+pub(super) struct StoredOntologyType<T> {
+    ontology_type: T,
+    /// The JSON representation of the ontology type, which is used to resolve schema paths.
+    schema: JsonValue,
+    version_id: Uuid,
+    owned_by_id: OwnedById,
+    updated_by_id: UpdatedById,
+}
+
+/// All versions of the ontology types of one kind, indexed by their [`BaseUri`] and version.
+#[derive(Clone)]
+pub(super) struct OntologyTypes<T> {
+    types: HashMap<BaseUri, BTreeMap<u32, StoredOntologyType<T>>>,
+}
+
+impl<T> Default for OntologyTypes<T> {
+    fn default() -> Self {
+        Self {
+            types: HashMap::new(),
+        }
+    }
+}
+
+impl<T> OntologyTypes<T> {
+    fn contains_base_uri(&self, base_uri: &BaseUri) -> bool {
+        self.types.contains_key(base_uri)
+    }
+
+    fn contains_uri(&self, uri: &VersionedUri) -> bool {
+        self.types
+            .get(uri.base_uri())
+            .map_or(false, |versions| versions.contains_key(&uri.version()))
+    }
+
+    fn latest(&self, base_uri: &BaseUri) -> Option<&StoredOntologyType<T>> {
+        self.types.get(base_uri)?.values().next_back()
+    }
+
+    /// Returns all versions of all ontology types.
+    pub(super) fn rows<'s>(
+        &'s self,
+        state: &'s MemoryState,
+    ) -> impl Iterator<Item = OntologyRow<'s, T>> {
+        self.types.iter().flat_map(move |(base_uri, versions)| {
+            let latest_version = versions.keys().next_back().copied();
+            versions.iter().map(move |(version, stored)| OntologyRow {
+                state,
+                base_uri,
+                version: *version,
+                is_latest: latest_version == Some(*version),
+                stored,
+            })
+        })
+    }
+
+    /// Returns the ontology type specified by `uri`.
+    pub(super) fn row<'s>(
+        &'s self,
+        state: &'s MemoryState,
+        uri: &VersionedUri,
+    ) -> Option<OntologyRow<'s, T>> {
+        let (base_uri, versions) = self.types.get_key_value(uri.base_uri())?;
+        let stored = versions.get(&uri.version())?;
+        Some(OntologyRow {
+            state,
+            base_uri,
+            version: uri.version(),
+            is_latest: versions.keys().next_back() == Some(&uri.version()),
+            stored,
+        })
+    }
+}
+
+/// A single version of an ontology type, on which a [`Filter`] can be evaluated.
+pub(super) struct OntologyRow<'s, T> {
+    state: &'s MemoryState,
+    base_uri: &'s BaseUri,
+    version: u32,
+    is_latest: bool,
+    stored: &'s StoredOntologyType<T>,
+}
+
+impl<'s, T> OntologyRow<'s, T> {
+    pub(super) fn edition_id(&self) -> OntologyTypeEditionId {
+        OntologyTypeEditionId::new(
+            self.base_uri.clone(),
+            OntologyTypeVersion::new(self.version),
+        )
+    }
+
+    fn versioned_uri(&self) -> VersionedUri {
+        VersionedUri::new(self.base_uri.clone(), self.version)
+    }
+
+    pub(super) const fn ontology_type(&self) -> &'s T {
+        &self.stored.ontology_type
+    }
+
+    fn metadata(&self) -> OntologyElementMetadata {
+        OntologyElementMetadata::new(
+            self.edition_id(),
+            ProvenanceMetadata::new(self.stored.updated_by_id),
+            self.stored.owned_by_id,
+        )
+    }
+
+    pub(super) fn record<P>(&self) -> P
+    where
+        P: PersistedOntologyType<OntologyType = T>,
+        T: Clone,
+    {
+        P::new(self.stored.ontology_type.clone(), self.metadata())
+    }
+
+    fn base_uri_value(&self) -> Value {
+        Value::text(self.base_uri.as_str())
+    }
+
+    const fn version_value(&self) -> Value {
+        Value::Version {
+            version: self.version,
+            is_latest: self.is_latest,
+        }
+    }
+
+    fn owned_by_id_value(&self) -> Value {
+        Value::text(self.stored.owned_by_id.as_uuid().to_string())
+    }
+
+    fn updated_by_id_value(&self) -> Value {
+        Value::text(self.stored.updated_by_id.as_uuid().to_string())
+    }
+
+    fn version_id_value(&self) -> Value {
+        Value::text(self.stored.version_id.to_string())
+    }
+
+    fn schema_value(&self) -> Value {
+        Value::Json(self.stored.schema.clone())
+    }
+
+    /// Returns the top-level field `key` of the schema, or `null` if it's not specified.
+    fn schema_field(&self, key: &str) -> Value {
+        self.stored
+            .schema
+            .get(key)
+            .map_or(Value::NULL, |value| Value::Json(value.clone()))
+    }
+
+    fn title_and_description(&self) -> String {
+        ["title", "description"]
+            .into_iter()
+            .filter_map(|key| self.stored.schema.get(key)?.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Resolve<DataTypeWithMetadata> for OntologyRow<'_, DataType> {
+    fn resolve(&self, path: &DataTypeQueryPath) -> Vec<Value> {
+        vec![match path {
+            DataTypeQueryPath::BaseUri => self.base_uri_value(),
+            DataTypeQueryPath::Version => self.version_value(),
+            DataTypeQueryPath::VersionedUri => self.schema_field("$id"),
+            DataTypeQueryPath::OwnedById => self.owned_by_id_value(),
+            DataTypeQueryPath::UpdatedById => self.updated_by_id_value(),
+            DataTypeQueryPath::Title => self.schema_field("title"),
+            DataTypeQueryPath::Description => self.schema_field("description"),
+            DataTypeQueryPath::Type => self.schema_field("type"),
+            DataTypeQueryPath::VersionId => self.version_id_value(),
+            DataTypeQueryPath::Schema => self.schema_value(),
+        }]
+    }
+
+    fn search_text(&self) -> String {
+        self.title_and_description()
+    }
+}
+
+impl Resolve<PropertyTypeWithMetadata> for OntologyRow<'_, PropertyType> {
+    fn resolve(&self, path: &PropertyTypeQueryPath) -> Vec<Value> {
+        match path {
+            PropertyTypeQueryPath::DataTypes(path) => self
+                .stored
+                .ontology_type
+                .data_type_references()
+                .into_iter()
+                .filter_map(|reference| self.state.data_types.row(self.state, reference.uri()))
+                .flat_map(|row| Resolve::<DataTypeWithMetadata>::resolve(&row, path))
+                .collect(),
+            PropertyTypeQueryPath::PropertyTypes(path) => self
+                .stored
+                .ontology_type
+                .property_type_references()
+                .into_iter()
+                .filter_map(|reference| self.state.property_types.row(self.state, reference.uri()))
+                .flat_map(|row| Resolve::<PropertyTypeWithMetadata>::resolve(&row, path))
+                .collect(),
+            PropertyTypeQueryPath::BaseUri => vec![self.base_uri_value()],
+            PropertyTypeQueryPath::Version => vec![self.version_value()],
+            PropertyTypeQueryPath::VersionedUri => vec![self.schema_field("$id")],
+            PropertyTypeQueryPath::OwnedById => vec![self.owned_by_id_value()],
+            PropertyTypeQueryPath::UpdatedById => vec![self.updated_by_id_value()],
+            PropertyTypeQueryPath::Title => vec![self.schema_field("title")],
+            PropertyTypeQueryPath::Description => vec![self.schema_field("description")],
+            PropertyTypeQueryPath::VersionId => vec![self.version_id_value()],
+            PropertyTypeQueryPath::Schema => vec![self.schema_value()],
+        }
+    }
+
+    fn search_text(&self) -> String {
+        self.title_and_description()
+    }
+}
+
+impl Resolve<EntityTypeWithMetadata> for OntologyRow<'_, EntityType> {
+    fn resolve(&self, path: &EntityTypeQueryPath) -> Vec<Value> {
+        let entity_type_rows = |references: Vec<&EntityTypeReference>| {
+            references
+                .into_iter()
+                .filter_map(|reference| self.state.entity_types.row(self.state, reference.uri()))
+                .collect::<Vec<_>>()
+        };
+
+        match path {
+            EntityTypeQueryPath::Properties(path) => self
+                .stored
+                .ontology_type
+                .property_type_references()
+                .into_iter()
+                .filter_map(|reference| self.state.property_types.row(self.state, reference.uri()))
+                .flat_map(|row| Resolve::<PropertyTypeWithMetadata>::resolve(&row, path))
+                .collect(),
+            EntityTypeQueryPath::Links(path) => entity_type_rows(
+                self.stored
+                    .ontology_type
+                    .link_mappings()
+                    .into_keys()
+                    .collect(),
+            )
+            .iter()
+            .flat_map(|row| Resolve::<EntityTypeWithMetadata>::resolve(row, path))
+            .collect(),
+            EntityTypeQueryPath::InheritsFrom(path) => entity_type_rows(
+                self.stored
+                    .ontology_type
+                    .inherits_from()
+                    .all_of()
+                    .iter()
+                    .collect(),
+            )
+            .iter()
+            .flat_map(|row| Resolve::<EntityTypeWithMetadata>::resolve(row, path))
+            .collect(),
+            EntityTypeQueryPath::BaseUri => vec![self.base_uri_value()],
+            EntityTypeQueryPath::Version => vec![self.version_value()],
+            EntityTypeQueryPath::VersionedUri => vec![self.schema_field("$id")],
+            EntityTypeQueryPath::OwnedById => vec![self.owned_by_id_value()],
+            EntityTypeQueryPath::UpdatedById => vec![self.updated_by_id_value()],
+            EntityTypeQueryPath::Title => vec![self.schema_field("title")],
+            EntityTypeQueryPath::Description => vec![self.schema_field("description")],
+            EntityTypeQueryPath::Default => vec![self.schema_field("default")],
+            EntityTypeQueryPath::Examples => vec![self.schema_field("examples")],
+            EntityTypeQueryPath::Required => vec![self.schema_field("required")],
+            EntityTypeQueryPath::RequiredLinks => vec![self.schema_field("requiredLinks")],
+            EntityTypeQueryPath::VersionId => vec![self.version_id_value()],
+            EntityTypeQueryPath::Schema => vec![self.schema_value()],
+        }
+    }
+
+    fn search_text(&self) -> String {
+        self.title_and_description()
+    }
+}
+
+/// Checks, that every URI in `uris` refers to an ontology type in `ontology_types`.
+///
+/// References to `own_uri` are allowed as the ontology type is inserted alongside.
+fn ensure_references_exist<'u, T>(
+    ontology_types: &OntologyTypes<T>,
+    mut uris: impl Iterator<Item = &'u VersionedUri>,
+    own_uri: &VersionedUri,
+) -> Result<(), InsertionError> {
+    match uris.find(|uri| *uri != own_uri && !ontology_types.contains_uri(uri)) {
+        Some(uri) => Err(Report::new(InsertionError).attach_printable(uri.clone())),
+        None => Ok(()),
+    }
+}
+
+/// An ontology type, which can be stored in the [`MemoryStore`].
+pub(super) trait MemoryOntologyType:
+    PersistedOntologyType<OntologyType: CheckCompatibility + Send + Sync> + QueryRecord + Send
+{
+    fn ontology_types(state: &MemoryState) -> &OntologyTypes<Self::OntologyType>;
+
+    fn ontology_types_mut(state: &mut MemoryState) -> &mut OntologyTypes<Self::OntologyType>;
+
+    fn versioned_uri(ontology_type: &Self::OntologyType) -> &VersionedUri;
+
+    /// Checks, that all ontology types referenced by `ontology_type` exist in `state`.
+    fn ensure_references_exist(
+        state: &MemoryState,
+        ontology_type: &Self::OntologyType,
+    ) -> Result<(), InsertionError>;
+
+    fn changed_element(metadata: OntologyElementMetadata) -> ChangedElement;
+
+    fn into_vertex(self) -> OntologyVertex;
+
+    fn traverse(
+        state: &MemoryState,
+        edition_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError>;
+}
+
+impl MemoryOntologyType for DataTypeWithMetadata {
+    fn ontology_types(state: &MemoryState) -> &OntologyTypes<DataType> {
+        &state.data_types
+    }
+
+    fn ontology_types_mut(state: &mut MemoryState) -> &mut OntologyTypes<DataType> {
+        &mut state.data_types
+    }
+
+    fn versioned_uri(data_type: &DataType) -> &VersionedUri {
+        data_type.id()
+    }
+
+    fn ensure_references_exist(_: &MemoryState, _: &DataType) -> Result<(), InsertionError> {
+        Ok(())
+    }
+
+    fn changed_element(metadata: OntologyElementMetadata) -> ChangedElement {
+        ChangedElement::DataType(metadata)
+    }
+
+    fn into_vertex(self) -> OntologyVertex {
+        OntologyVertex::DataType(Box::new(self))
+    }
+
+    fn traverse(
+        state: &MemoryState,
+        edition_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        state.traverse_data_type(
+            edition_id,
+            dependency_context,
+            subgraph,
+            current_resolve_depth,
+            temporal_slice,
+        )
+    }
+}
+
+impl MemoryOntologyType for PropertyTypeWithMetadata {
+    fn ontology_types(state: &MemoryState) -> &OntologyTypes<PropertyType> {
+        &state.property_types
+    }
+
+    fn ontology_types_mut(state: &mut MemoryState) -> &mut OntologyTypes<PropertyType> {
+        &mut state.property_types
+    }
+
+    fn versioned_uri(property_type: &PropertyType) -> &VersionedUri {
+        property_type.id()
+    }
+
+    fn ensure_references_exist(
+        state: &MemoryState,
+        property_type: &PropertyType,
+    ) -> Result<(), InsertionError> {
+        ensure_references_exist(
+            &state.property_types,
+            property_type
+                .property_type_references()
+                .into_iter()
+                .map(PropertyTypeReference::uri),
+            property_type.id(),
+        )
+        .attach_printable("Could not find referenced property types")?;
+
+        ensure_references_exist(
+            &state.data_types,
+            property_type
+                .data_type_references()
+                .into_iter()
+                .map(DataTypeReference::uri),
+            property_type.id(),
+        )
+        .attach_printable("Could not find referenced data types")
+    }
+
+    fn changed_element(metadata: OntologyElementMetadata) -> ChangedElement {
+        ChangedElement::PropertyType(metadata)
+    }
+
+    fn into_vertex(self) -> OntologyVertex {
+        OntologyVertex::PropertyType(Box::new(self))
+    }
+
+    fn traverse(
+        state: &MemoryState,
+        edition_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        state.traverse_property_type(
+            edition_id,
+            dependency_context,
+            subgraph,
+            current_resolve_depth,
+            temporal_slice,
+        )
+    }
+}
+
+impl MemoryOntologyType for EntityTypeWithMetadata {
+    fn ontology_types(state: &MemoryState) -> &OntologyTypes<EntityType> {
+        &state.entity_types
+    }
+
+    fn ontology_types_mut(state: &mut MemoryState) -> &mut OntologyTypes<EntityType> {
+        &mut state.entity_types
+    }
+
+    fn versioned_uri(entity_type: &EntityType) -> &VersionedUri {
+        entity_type.id()
+    }
+
+    fn ensure_references_exist(
+        state: &MemoryState,
+        entity_type: &EntityType,
+    ) -> Result<(), InsertionError> {
+        ensure_references_exist(
+            &state.property_types,
+            entity_type
+                .property_type_references()
+                .into_iter()
+                .map(PropertyTypeReference::uri),
+            entity_type.id(),
+        )
+        .attach_printable("Could not find referenced property types")?;
+
+        ensure_references_exist(
+            &state.entity_types,
+            entity_type
+                .link_mappings()
+                .into_keys()
+                .chain(
+                    entity_type
+                        .link_mappings()
+                        .into_values()
+                        .flatten()
+                        .flatten(),
+                )
+                .chain(entity_type.inherits_from().all_of())
+                .map(EntityTypeReference::uri),
+            entity_type.id(),
+        )
+        .attach_printable("Could not find referenced entity types")
+    }
+
+    fn changed_element(metadata: OntologyElementMetadata) -> ChangedElement {
+        ChangedElement::EntityType(metadata)
+    }
+
+    fn into_vertex(self) -> OntologyVertex {
+        OntologyVertex::EntityType(Box::new(self))
+    }
+
+    fn traverse(
+        state: &MemoryState,
+        edition_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        state.traverse_entity_type(
+            edition_id,
+            dependency_context,
+            subgraph,
+            current_resolve_depth,
+            temporal_slice,
+        )
+    }
+}
+
+impl MemoryState {
+    /// Returns if any kind of ontology type uses `base_uri`.
+    fn contains_base_uri(&self, base_uri: &BaseUri) -> bool {
+        self.data_types.contains_base_uri(base_uri)
+            || self.property_types.contains_base_uri(base_uri)
+            || self.entity_types.contains_base_uri(base_uri)
+    }
+
+    fn insert_ontology_type<T: MemoryOntologyType>(
+        &mut self,
+        ontology_type: T::OntologyType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    ) -> Result<OntologyElementMetadata, InsertionError> {
+        let uri = T::versioned_uri(&ontology_type).clone();
+        T::ensure_references_exist(self, &ontology_type)?;
+
+        let schema = serde_json::to_value(<T::OntologyType as OntologyType>::Representation::from(
+            ontology_type.clone(),
+        ))
+        .into_report()
+        .change_context(InsertionError)?;
+
+        T::ontology_types_mut(self)
+            .types
+            .entry(uri.base_uri().clone())
+            .or_default()
+            .insert(uri.version(), StoredOntologyType {
+                ontology_type,
+                schema,
+                version_id: Uuid::new_v4(),
+                owned_by_id,
+                updated_by_id,
+            });
+
+        Ok(OntologyElementMetadata::new(
+            OntologyTypeEditionId::from(&uri),
+            ProvenanceMetadata::new(updated_by_id),
+            owned_by_id,
+        ))
+    }
+
+    pub(super) fn create_ontology_type<T: MemoryOntologyType>(
+        &mut self,
+        ontology_type: T::OntologyType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    ) -> Result<OntologyElementMetadata, InsertionError> {
+        let base_uri = T::versioned_uri(&ontology_type).base_uri();
+        if self.contains_base_uri(base_uri) {
+            return Err(Report::new(BaseUriAlreadyExists)
+                .attach_printable(base_uri.clone())
+                .change_context(InsertionError));
+        }
+
+        self.ensure_account_exists(owned_by_id.as_account_id())?;
+        self.ensure_account_exists(updated_by_id.as_account_id())?;
+
+        let metadata = self.insert_ontology_type::<T>(ontology_type, owned_by_id, updated_by_id)?;
+
+        let recorded_at = self.transaction_time();
+        self.insert_change_event(
+            ChangeKind::Create,
+            T::changed_element(metadata.clone()),
+            recorded_at,
+        );
+
+        Ok(metadata)
+    }
+
+    pub(super) fn update_ontology_type<T: MemoryOntologyType>(
+        &mut self,
+        ontology_type: T::OntologyType,
+        updated_by_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
+        let uri = T::versioned_uri(&ontology_type).clone();
+
+        let latest = T::ontology_types(self)
+            .latest(uri.base_uri())
+            .ok_or_else(|| {
+                Report::new(BaseUriDoesNotExist)
+                    .attach_printable(uri.base_uri().clone())
+                    .change_context(UpdateError)
+            })?;
+        let owned_by_id = latest.owned_by_id;
+        let compatibility = <T::OntologyType as CheckCompatibility>::check_compatibility(
+            &latest.ontology_type,
+            &ontology_type,
+        );
+
+        if T::ontology_types(self).contains_uri(&uri) {
+            return Err(Report::new(UpdateError)
+                .attach_printable(VersionedUriAlreadyExists)
+                .attach(uri));
+        }
+
+        self.ensure_account_exists(updated_by_id.as_account_id())
+            .change_context(UpdateError)?;
+        compatibility_policy
+            .enforce(&uri, &compatibility)
+            .change_context(UpdateError)?;
+
+        let metadata = self
+            .insert_ontology_type::<T>(ontology_type, owned_by_id, updated_by_id)
+            .change_context(UpdateError)?;
+
+        let recorded_at = self.transaction_time();
+        self.insert_change_event(
+            ChangeKind::Update,
+            T::changed_element(metadata.clone()),
+            recorded_at,
+        );
+
+        Ok(OntologyUpdateMetadata::new(metadata, compatibility))
+    }
+
+    /// Reads the ontology types matching `filter` alongside their [`VersionedUri`] ordered by the
+    /// [`VersionedUri`].
+    ///
+    /// If `cursor` is specified, only the ontology types after `cursor` are returned.
+    fn read_ontology_types<T>(
+        &self,
+        filter: &Filter<'_, T>,
+        cursor: Option<&str>,
+    ) -> Vec<(String, T)>
+    where
+        T: MemoryOntologyType,
+        for<'s> OntologyRow<'s, T::OntologyType>: Resolve<T>,
+    {
+        let mut records = T::ontology_types(self)
+            .rows(self)
+            .map(|row| (row.versioned_uri().to_string(), row))
+            .filter(|(versioned_uri, row)| {
+                cursor.map_or(true, |cursor| versioned_uri.as_str() > cursor)
+                    && filter::matches(filter, row)
+            })
+            .map(|(versioned_uri, row)| (versioned_uri, row.record()))
+            .collect::<Vec<_>>();
+        records.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        records
+    }
+
+    fn read_ontology_page<T>(
+        &self,
+        filter: &Filter<'_, T>,
+        pagination: &Pagination,
+    ) -> Result<Page<T>, QueryError>
+    where
+        T: MemoryOntologyType,
+        for<'s> OntologyRow<'s, T::OntologyType>: Resolve<T>,
+    {
+        let cursor = pagination
+            .after
+            .as_ref()
+            .map(|cursor| cursor.decode().change_context(QueryError))
+            .transpose()?;
+
+        let mut records = self.read_ontology_types(filter, cursor.as_deref());
+        records.truncate(pagination.limit.saturating_add(1));
+        let next_cursor =
+            pagination.next_cursor(&mut records, |(versioned_uri, _)| versioned_uri.clone());
+
+        Ok(Page {
+            records: records.into_iter().map(|(_, record)| record).collect(),
+            next_cursor,
+        })
+    }
+
+    /// Returns the full-text search relevance of the ontology types matching `filter`.
+    ///
+    /// Returns an empty map if `filter` does not contain a [`Filter::Search`].
+    fn ontology_search_ranks<T>(
+        &self,
+        filter: &Filter<'_, T>,
+    ) -> HashMap<OntologyTypeEditionId, f32>
+    where
+        T: MemoryOntologyType,
+        for<'s> OntologyRow<'s, T::OntologyType>: Resolve<T>,
+    {
+        let Some(query) = filter.search_query() else {
+            return HashMap::new();
+        };
+        let query = SearchQuery::parse(query);
+
+        T::ontology_types(self)
+            .rows(self)
+            .filter(|row| filter::matches(filter, row))
+            .map(|row| {
+                // Records matching another alternative of a `Filter::Any` have no relevance
+                let rank = query.rank(&row.search_text()).unwrap_or_default();
+                (row.edition_id(), rank)
+            })
+            .collect()
+    }
+
+    fn get_ontology_subgraph<T>(
+        &self,
+        query: &StructuralQuery<'_, T>,
+    ) -> Result<Subgraph, QueryError>
+    where
+        T: MemoryOntologyType,
+        for<'s> OntologyRow<'s, T::OntologyType>: Resolve<T>,
+    {
+        let StructuralQuery {
+            ref filter,
+            graph_resolve_depths,
+            ref pagination,
+            ref temporal_axes,
+        } = *query;
+
+        let temporal_slice = TemporalSlice::new(temporal_axes, self.now());
+        let mut subgraph = Subgraph::new(graph_resolve_depths);
+        let mut dependency_context = DependencyContext::default();
+
+        let records = match pagination {
+            Some(pagination) => {
+                let page = self.read_ontology_page(filter, pagination)?;
+                subgraph.next_cursor = page.next_cursor;
+                page.records
+            }
+            None => self
+                .read_ontology_types(filter, None)
+                .into_iter()
+                .map(|(_, record)| record)
+                .collect(),
+        };
+
+        for record in records {
+            let edition_id = record.metadata().edition_id().clone();
+
+            // Insert the vertex into the subgraph to avoid another lookup when traversing it
+            subgraph
+                .vertices
+                .ontology
+                .insert(edition_id.clone(), record.into_vertex());
+
+            T::traverse(
+                self,
+                &edition_id,
+                &mut dependency_context,
+                &mut subgraph,
+                graph_resolve_depths,
+                &temporal_slice,
+            )?;
+
+            subgraph
+                .roots
+                .insert(GraphElementEditionId::Ontology(edition_id));
+        }
+
+        subgraph.search_ranks.extend(
+            self.ontology_search_ranks(filter)
+                .into_iter()
+                .map(|(edition_id, rank)| (GraphElementEditionId::Ontology(edition_id), rank))
+                .filter(|(root, _)| subgraph.roots.contains(root)),
+        );
+
+        Ok(subgraph)
+    }
+
+    /// Inserts the vertex of the ontology type specified by `edition_id` into the subgraph.
+    ///
+    /// Returns the ontology type if its edges have to be resolved at `current_resolve_depth`, or
+    /// `None` if it was already resolved at this depth.
+    fn resolve_ontology_vertex<T: MemoryOntologyType>(
+        &self,
+        edition_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+    ) -> Result<Option<&T::OntologyType>, QueryError> {
+        let dependency_status = dependency_context
+            .ontology_dependency_map
+            .insert(edition_id, current_resolve_depth);
+        if matches!(dependency_status, DependencyStatus::Resolved) {
+            return Ok(None);
+        }
+
+        let uri = VersionedUri::from(edition_id);
+        let row = T::ontology_types(self)
+            .row(self, &uri)
+            .ok_or_else(|| Report::new(QueryError).attach_printable(uri))?;
+
+        subgraph
+            .vertices
+            .ontology
+            .entry(edition_id.clone())
+            .or_insert_with(|| row.record::<T>().into_vertex());
+
+        Ok(Some(row.ontology_type()))
+    }
+
+    /// Reads the data type specified by `data_type_id` and its dependencies into the subgraph.
+    pub(super) fn traverse_data_type(
+        &self,
+        data_type_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        if self
+            .resolve_ontology_vertex::<DataTypeWithMetadata>(
+                data_type_id,
+                dependency_context,
+                subgraph,
+                current_resolve_depth,
+            )?
+            .is_none()
+        {
+            return Ok(());
+        }
+
+        // Data types have no references to other types, so only incoming edges have to be
+        // resolved
+        if current_resolve_depth.constrains_values_on.incoming > 0 {
+            let referencing_property_types = self
+                .property_types
+                .rows(self)
+                .filter(|row| {
+                    row.ontology_type()
+                        .data_type_references()
+                        .into_iter()
+                        .any(|reference| {
+                            OntologyTypeEditionId::from(reference.uri()) == *data_type_id
+                        })
+                })
+                .map(|row| row.edition_id())
+                .collect::<Vec<_>>();
+
+            for property_type_id in referencing_property_types {
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: data_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsValuesOn,
+                        reversed: true,
+                        right_endpoint: property_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_property_type(
+                    &property_type_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        constrains_values_on: EdgeResolveDepths {
+                            incoming: current_resolve_depth.constrains_values_on.incoming - 1,
+                            ..current_resolve_depth.constrains_values_on
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the property type specified by `property_type_id` and its dependencies into the
+    /// subgraph.
+    #[expect(clippy::too_many_lines)]
+    pub(super) fn traverse_property_type(
+        &self,
+        property_type_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        let Some(property_type) = self.resolve_ontology_vertex::<PropertyTypeWithMetadata>(
+            property_type_id,
+            dependency_context,
+            subgraph,
+            current_resolve_depth,
+        )?
+        else {
+            return Ok(());
+        };
+
+        if current_resolve_depth.constrains_values_on.outgoing > 0 {
+            for data_type_ref in property_type.data_type_references() {
+                let data_type_id = OntologyTypeEditionId::from(data_type_ref.uri());
+
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: property_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsValuesOn,
+                        reversed: false,
+                        right_endpoint: data_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_data_type(
+                    &data_type_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        constrains_values_on: EdgeResolveDepths {
+                            outgoing: current_resolve_depth.constrains_values_on.outgoing - 1,
+                            ..current_resolve_depth.constrains_values_on
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        if current_resolve_depth.constrains_properties_on.outgoing > 0 {
+            for property_type_ref in property_type.property_type_references() {
+                let referenced_property_type_id =
+                    OntologyTypeEditionId::from(property_type_ref.uri());
+
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: property_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                        reversed: false,
+                        right_endpoint: referenced_property_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_property_type(
+                    &referenced_property_type_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        constrains_properties_on: EdgeResolveDepths {
+                            outgoing: current_resolve_depth.constrains_properties_on.outgoing - 1,
+                            ..current_resolve_depth.constrains_properties_on
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        if current_resolve_depth.constrains_properties_on.incoming > 0 {
+            let incoming_resolve_depth = GraphResolveDepths {
+                constrains_properties_on: EdgeResolveDepths {
+                    incoming: current_resolve_depth.constrains_properties_on.incoming - 1,
+                    ..current_resolve_depth.constrains_properties_on
+                },
+                ..current_resolve_depth
+            };
+            let is_referenced = |reference: &PropertyTypeReference| {
+                OntologyTypeEditionId::from(reference.uri()) == *property_type_id
+            };
+
+            let referencing_property_types = self
+                .property_types
+                .rows(self)
+                .filter(|row| {
+                    row.ontology_type()
+                        .property_type_references()
+                        .into_iter()
+                        .any(is_referenced)
+                })
+                .map(|row| row.edition_id())
+                .collect::<Vec<_>>();
+
+            for referencing_property_type_id in referencing_property_types {
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: property_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                        reversed: true,
+                        right_endpoint: referencing_property_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_property_type(
+                    &referencing_property_type_id,
+                    dependency_context,
+                    subgraph,
+                    incoming_resolve_depth,
+                    temporal_slice,
+                )?;
+            }
+
+            let referencing_entity_types = self
+                .entity_types
+                .rows(self)
+                .filter(|row| {
+                    row.ontology_type()
+                        .property_type_references()
+                        .into_iter()
+                        .any(is_referenced)
+                })
+                .map(|row| row.edition_id())
+                .collect::<Vec<_>>();
+
+            for entity_type_id in referencing_entity_types {
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: property_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                        reversed: true,
+                        right_endpoint: entity_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_entity_type(
+                    &entity_type_id,
+                    dependency_context,
+                    subgraph,
+                    incoming_resolve_depth,
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the entity type specified by `entity_type_id` and its dependencies into the subgraph.
+    #[expect(clippy::too_many_lines)]
+    pub(super) fn traverse_entity_type(
+        &self,
+        entity_type_id: &OntologyTypeEditionId,
+        dependency_context: &mut DependencyContext,
+        subgraph: &mut Subgraph,
+        current_resolve_depth: GraphResolveDepths,
+        temporal_slice: &TemporalSlice,
+    ) -> Result<(), QueryError> {
+        let Some(entity_type) = self.resolve_ontology_vertex::<EntityTypeWithMetadata>(
+            entity_type_id,
+            dependency_context,
+            subgraph,
+            current_resolve_depth,
+        )?
+        else {
+            return Ok(());
+        };
+
+        if current_resolve_depth.constrains_properties_on.outgoing > 0 {
+            for property_type_ref in entity_type.property_type_references() {
+                let property_type_id = OntologyTypeEditionId::from(property_type_ref.uri());
+
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: entity_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                        reversed: false,
+                        right_endpoint: property_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_property_type(
+                    &property_type_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        constrains_properties_on: EdgeResolveDepths {
+                            outgoing: current_resolve_depth.constrains_properties_on.outgoing - 1,
+                            ..current_resolve_depth.constrains_properties_on
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        if current_resolve_depth.inherits_from.outgoing > 0 {
+            for inherits_from_type_ref in entity_type.inherits_from().all_of() {
+                let inherits_from_type_id =
+                    OntologyTypeEditionId::from(inherits_from_type_ref.uri());
+
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: entity_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::InheritsFrom,
+                        reversed: false,
+                        right_endpoint: inherits_from_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_entity_type(
+                    &inherits_from_type_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        inherits_from: EdgeResolveDepths {
+                            outgoing: current_resolve_depth.inherits_from.outgoing - 1,
+                            ..current_resolve_depth.inherits_from
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        if current_resolve_depth.constrains_links_on.outgoing > 0 {
+            for (link_type_ref, destination_type_refs) in entity_type.link_mappings() {
+                let link_type_id = OntologyTypeEditionId::from(link_type_ref.uri());
+
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: entity_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                        kind: OntologyEdgeKind::ConstrainsLinksOn,
+                        reversed: false,
+                        right_endpoint: link_type_id.clone(),
+                    }),
+                });
+
+                self.traverse_entity_type(
+                    &link_type_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        constrains_links_on: EdgeResolveDepths {
+                            outgoing: current_resolve_depth.constrains_links_on.outgoing - 1,
+                            ..current_resolve_depth.constrains_links_on
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+
+                if current_resolve_depth
+                    .constrains_link_destinations_on
+                    .outgoing
+                    > 0
+                {
+                    for destination_type_ref in destination_type_refs.into_iter().flatten() {
+                        let destination_type_id =
+                            OntologyTypeEditionId::from(destination_type_ref.uri());
+
+                        subgraph.edges.insert(Edge::Ontology {
+                            edition_id: entity_type_id.clone(),
+                            outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                                kind: OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                                reversed: false,
+                                right_endpoint: destination_type_id.clone(),
+                            }),
+                        });
+
+                        self.traverse_entity_type(
+                            &destination_type_id,
+                            dependency_context,
+                            subgraph,
+                            GraphResolveDepths {
+                                constrains_link_destinations_on: EdgeResolveDepths {
+                                    outgoing: current_resolve_depth
+                                        .constrains_link_destinations_on
+                                        .outgoing
+                                        - 1,
+                                    ..current_resolve_depth.constrains_link_destinations_on
+                                },
+                                ..current_resolve_depth
+                            },
+                            temporal_slice,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        if current_resolve_depth.inherits_from.incoming > 0
+            || current_resolve_depth.constrains_links_on.incoming > 0
+            || current_resolve_depth
+                .constrains_link_destinations_on
+                .incoming
+                > 0
+        {
+            let is_referenced = |reference: &EntityTypeReference| {
+                OntologyTypeEditionId::from(reference.uri()) == *entity_type_id
+            };
+
+            for row in self.entity_types.rows(self) {
+                let schema = row.ontology_type();
+                let edges = [
+                    (
+                        OntologyEdgeKind::InheritsFrom,
+                        current_resolve_depth.inherits_from.incoming > 0
+                            && schema.inherits_from().all_of().iter().any(is_referenced),
+                        GraphResolveDepths {
+                            inherits_from: EdgeResolveDepths {
+                                incoming: current_resolve_depth
+                                    .inherits_from
+                                    .incoming
+                                    .saturating_sub(1),
+                                ..current_resolve_depth.inherits_from
+                            },
+                            ..current_resolve_depth
+                        },
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinksOn,
+                        current_resolve_depth.constrains_links_on.incoming > 0
+                            && schema.link_mappings().into_keys().any(is_referenced),
+                        GraphResolveDepths {
+                            constrains_links_on: EdgeResolveDepths {
+                                incoming: current_resolve_depth
+                                    .constrains_links_on
+                                    .incoming
+                                    .saturating_sub(1),
+                                ..current_resolve_depth.constrains_links_on
+                            },
+                            ..current_resolve_depth
+                        },
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        current_resolve_depth
+                            .constrains_link_destinations_on
+                            .incoming
+                            > 0
+                            && schema
+                                .link_mappings()
+                                .into_values()
+                                .flatten()
+                                .flatten()
+                                .any(is_referenced),
+                        GraphResolveDepths {
+                            constrains_link_destinations_on: EdgeResolveDepths {
+                                incoming: current_resolve_depth
+                                    .constrains_link_destinations_on
+                                    .incoming
+                                    .saturating_sub(1),
+                                ..current_resolve_depth.constrains_link_destinations_on
+                            },
+                            ..current_resolve_depth
+                        },
+                    ),
+                ];
+
+                let referencing_entity_type_id = row.edition_id();
+                for (kind, _, resolve_depth) in
+                    edges.into_iter().filter(|(_, is_resolved, _)| *is_resolved)
+                {
+                    subgraph.edges.insert(Edge::Ontology {
+                        edition_id: entity_type_id.clone(),
+                        outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                            kind,
+                            reversed: true,
+                            right_endpoint: referencing_entity_type_id.clone(),
+                        }),
+                    });
+
+                    self.traverse_entity_type(
+                        &referencing_entity_type_id,
+                        dependency_context,
+                        subgraph,
+                        resolve_depth,
+                        temporal_slice,
+                    )?;
+                }
+            }
+        }
+
+        if current_resolve_depth.is_of_type.incoming > 0 {
+            for entity in self.read_entities(
+                &Filter::for_latest_entities_by_entity_type(entity_type_id),
+                temporal_slice,
+                None,
+            ) {
+                let entity_edition_id = entity.metadata().edition_id();
+
+                subgraph.edges.insert(Edge::Ontology {
+                    edition_id: entity_type_id.clone(),
+                    outward_edge: OntologyOutwardEdges::ToKnowledgeGraph(OutwardEdge {
+                        kind: SharedEdgeKind::IsOfType,
+                        reversed: true,
+                        right_endpoint: entity_edition_id,
+                    }),
+                });
+
+                // Insert the vertex into the subgraph to avoid another lookup when traversing it
+                subgraph
+                    .vertices
+                    .knowledge_graph
+                    .insert(entity_edition_id, KnowledgeGraphVertex::Entity(entity));
+
+                self.traverse_entity(
+                    entity_edition_id,
+                    dependency_context,
+                    subgraph,
+                    GraphResolveDepths {
+                        is_of_type: EdgeResolveDepths {
+                            incoming: current_resolve_depth.is_of_type.incoming - 1,
+                            ..current_resolve_depth.is_of_type
+                        },
+                        ..current_resolve_depth
+                    },
+                    temporal_slice,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Ontology types are not versioned in time, so they are the same in every temporal slice.
+macro_rules! impl_read_ontology_type {
+    ($ontology_type:ty) => {
+        #[async_trait]
+        impl Read<$ontology_type> for MemoryStore {
+            async fn read(
+                &self,
+                filter: &Filter<$ontology_type>,
+                _temporal_axes: &TemporalAxes,
+            ) -> Result<Vec<$ontology_type>, QueryError> {
+                Ok(self
+                    .state()
+                    .read_ontology_types(filter, None)
+                    .into_iter()
+                    .map(|(_, record)| record)
+                    .collect())
+            }
+
+            async fn read_stream<'s>(
+                &'s mut self,
+                filter: &Filter<$ontology_type>,
+                temporal_axes: &TemporalAxes,
+            ) -> Result<BoxStream<'s, Result<$ontology_type, QueryError>>, QueryError> {
+                // The ontology types are held in memory anyway, so they are read at once.
+                let records = Read::<$ontology_type>::read(self, filter, temporal_axes).await?;
+                Ok(stream::iter(records.into_iter().map(Ok)).boxed())
+            }
+
+            async fn read_page(
+                &self,
+                filter: &Filter<$ontology_type>,
+                _temporal_axes: &TemporalAxes,
+                pagination: &Pagination,
+            ) -> Result<Page<$ontology_type>, QueryError> {
+                self.state().read_ontology_page(filter, pagination)
+            }
+        }
+    };
+}
+
+impl_read_ontology_type!(DataTypeWithMetadata);
+impl_read_ontology_type!(PropertyTypeWithMetadata);
+impl_read_ontology_type!(EntityTypeWithMetadata);
+
+#[async_trait]
+impl DataTypeStore for MemoryStore {
+    async fn create_data_type(
+        &mut self,
+        data_type: DataType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    ) -> Result<OntologyElementMetadata, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .create_ontology_type::<DataTypeWithMetadata>(data_type, owned_by_id, updated_by_id)
+    }
+
+    async fn get_data_type<'f: 'q, 'q>(
+        &self,
+        query: &'f StructuralQuery<'q, DataTypeWithMetadata>,
+    ) -> Result<Subgraph, QueryError> {
+        self.state().get_ontology_subgraph(query)
+    }
+
+    async fn update_data_type(
+        &mut self,
+        data_type: DataType,
+        updated_by_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .update_ontology_type::<DataTypeWithMetadata>(
+                data_type,
+                updated_by_id,
+                compatibility_policy,
+            )
+    }
+}
+
+#[async_trait]
+impl PropertyTypeStore for MemoryStore {
+    async fn create_property_type(
+        &mut self,
+        property_type: PropertyType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    ) -> Result<OntologyElementMetadata, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .create_ontology_type::<PropertyTypeWithMetadata>(
+                property_type,
+                owned_by_id,
+                updated_by_id,
+            )
+    }
+
+    async fn get_property_type<'f: 'q, 'q>(
+        &self,
+        query: &'f StructuralQuery<'q, PropertyTypeWithMetadata>,
+    ) -> Result<Subgraph, QueryError> {
+        self.state().get_ontology_subgraph(query)
+    }
+
+    async fn update_property_type(
+        &mut self,
+        property_type: PropertyType,
+        updated_by_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .update_ontology_type::<PropertyTypeWithMetadata>(
+                property_type,
+                updated_by_id,
+                compatibility_policy,
+            )
+    }
+}
+
+#[async_trait]
+impl EntityTypeStore for MemoryStore {
+    async fn create_entity_type(
+        &mut self,
+        entity_type: EntityType,
+        owned_by_id: OwnedById,
+        updated_by_id: UpdatedById,
+    ) -> Result<OntologyElementMetadata, InsertionError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .create_ontology_type::<EntityTypeWithMetadata>(entity_type, owned_by_id, updated_by_id)
+    }
+
+    async fn get_entity_type<'f: 'q, 'q>(
+        &self,
+        query: &'f StructuralQuery<'q, EntityTypeWithMetadata>,
+    ) -> Result<Subgraph, QueryError> {
+        self.state().get_ontology_subgraph(query)
+    }
+
+    async fn update_entity_type(
+        &mut self,
+        entity_type: EntityType,
+        updated_by_id: UpdatedById,
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
+        let _write_guard = self.write_lock.lock().await;
+        self.state_mut()
+            .update_ontology_type::<EntityTypeWithMetadata>(
+                entity_type,
+                updated_by_id,
+                compatibility_policy,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, collections::HashSet};
+
+    use super::*;
+    use crate::store::memory::tests::{person_type_id, seed};
+
+    fn text_type_id() -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/".to_owned(),
+            )
+            .expect("couldn't construct Base URI"),
+            1,
+        )
+    }
+
+    fn name_type_id() -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+                .expect("couldn't construct Base URI"),
+            1,
+        )
+    }
+
+    fn friend_of_type_id() -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new(
+                "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+            )
+            .expect("couldn't construct Base URI"),
+            1,
+        )
+    }
+
+    fn edition_ids<const N: usize>(uris: [VersionedUri; N]) -> HashSet<OntologyTypeEditionId> {
+        uris.iter().map(OntologyTypeEditionId::from).collect()
+    }
+
+    async fn read_person_subgraph(
+        store: &MemoryStore,
+        graph_resolve_depths: GraphResolveDepths,
+    ) -> Subgraph {
+        let person_type_id = person_type_id();
+        store
+            .get_entity_type(&StructuralQuery {
+                filter: Filter::for_versioned_uri(&person_type_id),
+                graph_resolve_depths,
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+            .expect("could not read subgraph")
+    }
+
+    fn vertex_ids(subgraph: &Subgraph) -> HashSet<OntologyTypeEditionId> {
+        subgraph.vertices.ontology.keys().cloned().collect()
+    }
+
+    #[tokio::test]
+    async fn traverse_entity_type() {
+        let (store, _) = seed().await;
+
+        let subgraph = read_person_subgraph(&store, GraphResolveDepths::default()).await;
+        assert_eq!(vertex_ids(&subgraph), edition_ids([person_type_id()]));
+        assert!(subgraph.edges.ontology.is_empty());
+
+        let subgraph = read_person_subgraph(&store, GraphResolveDepths {
+            constrains_properties_on: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await;
+        assert_eq!(
+            vertex_ids(&subgraph),
+            edition_ids([person_type_id(), name_type_id()])
+        );
+        assert!(
+            subgraph.edges.ontology[&OntologyTypeEditionId::from(&person_type_id())].contains(
+                &OntologyOutwardEdges::ToOntology(OutwardEdge {
+                    kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                    reversed: false,
+                    right_endpoint: OntologyTypeEditionId::from(&name_type_id()),
+                })
+            )
+        );
+
+        let subgraph = read_person_subgraph(&store, GraphResolveDepths {
+            constrains_values_on: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            constrains_properties_on: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            constrains_links_on: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await;
+        assert_eq!(
+            vertex_ids(&subgraph),
+            edition_ids([
+                person_type_id(),
+                name_type_id(),
+                text_type_id(),
+                friend_of_type_id(),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn traverse_data_type_incoming() {
+        let (store, _) = seed().await;
+
+        let text_type_id = text_type_id();
+        let subgraph = store
+            .get_data_type(&StructuralQuery {
+                filter: Filter::for_versioned_uri(&text_type_id),
+                graph_resolve_depths: GraphResolveDepths {
+                    constrains_values_on: EdgeResolveDepths {
+                        incoming: 1,
+                        outgoing: 0,
+                    },
+                    ..GraphResolveDepths::default()
+                },
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+            .expect("could not read subgraph");
+        assert_eq!(
+            vertex_ids(&subgraph),
+            edition_ids([text_type_id, name_type_id()])
+        );
+    }
+
+    #[tokio::test]
+    async fn filter_ontology_types() {
+        let (store, _) = seed().await;
+
+        let entity_type_ids = |entity_types: Vec<EntityTypeWithMetadata>| {
+            entity_types
+                .iter()
+                .map(|entity_type| entity_type.metadata().edition_id().clone())
+                .collect::<HashSet<_>>()
+        };
+
+        let latest = Read::<EntityTypeWithMetadata>::read(
+            &store,
+            &Filter::for_latest_version(),
+            &TemporalAxes::default(),
+        )
+        .await
+        .expect("could not read entity types");
+        assert_eq!(latest.len(), 3);
+
+        let name_edition_id = OntologyTypeEditionId::from(&name_type_id());
+        let by_property_type = Read::<EntityTypeWithMetadata>::read(
+            &store,
+            &Filter::for_entity_types_by_property_type(&name_edition_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .expect("could not read entity types");
+        assert_eq!(
+            entity_type_ids(by_property_type),
+            edition_ids([person_type_id()])
+        );
+
+        let searched = Read::<EntityTypeWithMetadata>::read(
+            &store,
+            &Filter::Search(Cow::Borrowed("person")),
+            &TemporalAxes::default(),
+        )
+        .await
+        .expect("could not read entity types");
+        assert_eq!(entity_type_ids(searched), edition_ids([person_type_id()]));
+
+        let text_edition_id = OntologyTypeEditionId::from(&text_type_id());
+        let property_types = Read::<PropertyTypeWithMetadata>::read(
+            &store,
+            &Filter::for_property_types_by_data_type(&text_edition_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .expect("could not read property types");
+        assert_eq!(property_types.len(), 1);
+        assert_eq!(property_types[0].inner().id(), &name_type_id());
+    }
+}
@@ -0,0 +1,34 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use error_stack::Result;
+
+use crate::store::{MemoryStore, StorePool};
+
+/// A [`StorePool`] handing out [`MemoryStore`]s, which share the same data.
+#[derive(Clone, Default)]
+pub struct MemoryStorePool {
+    store: MemoryStore,
+}
+
+impl MemoryStorePool {
+    /// Creates a new `MemoryStorePool` with an empty [`MemoryStore`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StorePool for MemoryStorePool {
+    type Error = Infallible;
+    type Store<'pool> = MemoryStore;
+
+    async fn acquire(&self) -> Result<Self::Store<'_>, Self::Error> {
+        Ok(self.store.clone())
+    }
+
+    async fn acquire_owned(&self) -> Result<Self::Store<'static>, Self::Error> {
+        Ok(self.store.clone())
+    }
+}
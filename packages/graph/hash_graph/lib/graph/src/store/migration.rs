@@ -0,0 +1,326 @@
+use std::fmt;
+
+use error_stack::{ensure, Context, Report, ResultExt};
+use serde::{Deserialize, Serialize};
+use type_system::uri::{BaseUri, VersionedUri};
+use utoipa::ToSchema;
+
+use crate::{
+    identifier::ontology::OntologyTypeEditionId,
+    knowledge::{Entity, EntityLinkOrder, EntityMetadata, EntityProperties},
+    provenance::UpdatedById,
+    store::{
+        crud::Read,
+        query::{Filter, TemporalAxes},
+        EntityStore, UpdateError,
+    },
+};
+
+#[derive(Debug)]
+#[must_use]
+pub struct InvalidEntityMigration;
+
+impl fmt::Display for InvalidEntityMigration {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("the entity migration is invalid")
+    }
+}
+
+impl Context for InvalidEntityMigration {}
+
+/// A change applied to the properties of every entity of an [`EntityMigration`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum PropertyMapping {
+    /// Moves the value of the property `from` to the property `to`.
+    ///
+    /// An existing value of `to` is overwritten. Entities without the property `from` are not
+    /// changed.
+    Rename {
+        #[schema(value_type = String)]
+        from: BaseUri,
+        #[schema(value_type = String)]
+        to: BaseUri,
+    },
+    /// Removes the property.
+    Drop {
+        #[schema(value_type = String)]
+        property: BaseUri,
+    },
+    /// Sets the property to `value` if the entity does not have a value for it yet.
+    Default {
+        #[schema(value_type = String)]
+        property: BaseUri,
+        #[schema(value_type = Object)]
+        value: serde_json::Value,
+    },
+}
+
+impl PropertyMapping {
+    fn apply(&self, properties: &mut EntityProperties) {
+        let properties = properties.properties_mut();
+        match self {
+            Self::Rename { from, to } => {
+                if let Some(value) = properties.remove(from) {
+                    properties.insert(to.clone(), value);
+                }
+            }
+            Self::Drop { property } => {
+                properties.remove(property);
+            }
+            Self::Default { property, value } => {
+                properties
+                    .entry(property.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+    }
+}
+
+/// Moves all entities of an entity type to a newer version of the same entity type.
+///
+/// The [`PropertyMapping`]s are applied in order to the properties of every entity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityMigration {
+    #[schema(value_type = String)]
+    source_entity_type_id: VersionedUri,
+    #[schema(value_type = String)]
+    target_entity_type_id: VersionedUri,
+    #[serde(default)]
+    mappings: Vec<PropertyMapping>,
+}
+
+impl EntityMigration {
+    #[must_use]
+    pub const fn new(
+        source_entity_type_id: VersionedUri,
+        target_entity_type_id: VersionedUri,
+        mappings: Vec<PropertyMapping>,
+    ) -> Self {
+        Self {
+            source_entity_type_id,
+            target_entity_type_id,
+            mappings,
+        }
+    }
+
+    #[must_use]
+    pub const fn source_entity_type_id(&self) -> &VersionedUri {
+        &self.source_entity_type_id
+    }
+
+    #[must_use]
+    pub const fn target_entity_type_id(&self) -> &VersionedUri {
+        &self.target_entity_type_id
+    }
+
+    #[must_use]
+    pub fn mappings(&self) -> &[PropertyMapping] {
+        &self.mappings
+    }
+
+    /// Checks that the migration moves entities to a newer version of the same entity type.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidEntityMigration`] if the target entity type has a different base URI than the
+    ///   source entity type
+    /// - [`InvalidEntityMigration`] if the target version is not newer than the source version
+    pub fn validate(&self) -> Result<(), Report<InvalidEntityMigration>> {
+        ensure!(
+            self.source_entity_type_id.base_uri() == self.target_entity_type_id.base_uri(),
+            Report::new(InvalidEntityMigration).attach_printable(format!(
+                "`{}` and `{}` are not versions of the same entity type",
+                self.source_entity_type_id, self.target_entity_type_id
+            ))
+        );
+        ensure!(
+            self.source_entity_type_id.version() < self.target_entity_type_id.version(),
+            Report::new(InvalidEntityMigration).attach_printable(format!(
+                "`{}` is not newer than `{}`",
+                self.target_entity_type_id, self.source_entity_type_id
+            ))
+        );
+        Ok(())
+    }
+
+    /// Returns the properties of an entity after applying all [`PropertyMapping`]s.
+    #[must_use]
+    pub fn map_properties(&self, properties: &EntityProperties) -> EntityProperties {
+        let mut properties = properties.clone();
+        for mapping in &self.mappings {
+            mapping.apply(&mut properties);
+        }
+        properties
+    }
+}
+
+/// An entity moved to the target entity type of an [`EntityMigration`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedEntity {
+    properties: EntityProperties,
+    metadata: EntityMetadata,
+}
+
+impl MigratedEntity {
+    #[must_use]
+    pub const fn properties(&self) -> &EntityProperties {
+        &self.properties
+    }
+
+    #[must_use]
+    pub const fn metadata(&self) -> &EntityMetadata {
+        &self.metadata
+    }
+}
+
+/// Creates a new edition of the latest edition of every entity of the source entity type of
+/// `migration`.
+///
+/// This does not open a transaction, so the caller is responsible for only persisting the changes
+/// if all entities were migrated.
+pub(super) async fn migrate_entities<S>(
+    store: &mut S,
+    migration: &EntityMigration,
+    actor_id: UpdatedById,
+) -> Result<Vec<MigratedEntity>, Report<UpdateError>>
+where
+    S: EntityStore + Send,
+{
+    migration.validate().change_context(UpdateError)?;
+
+    let source_entity_type_id = OntologyTypeEditionId::from(&migration.source_entity_type_id);
+    let entities = Read::<Entity>::read(
+        store,
+        &Filter::for_latest_entities_by_entity_type(&source_entity_type_id),
+        &TemporalAxes::default(),
+    )
+    .await
+    .change_context(UpdateError)?;
+
+    let mut migrated_entities = Vec::with_capacity(entities.len());
+    for entity in entities {
+        let entity_id = entity.metadata().edition_id().base_id();
+        let properties = migration.map_properties(entity.properties());
+        let link_order = entity
+            .link_data()
+            .map_or(EntityLinkOrder::new(None, None), |link_data| {
+                EntityLinkOrder::new(
+                    link_data.left_to_right_order(),
+                    link_data.right_to_left_order(),
+                )
+            });
+
+        let metadata = store
+            .update_entity(
+                entity_id,
+                None,
+                actor_id,
+                entity.metadata().archived(),
+                migration.target_entity_type_id.clone(),
+                properties.clone(),
+                link_order,
+            )
+            .await
+            .attach_printable_lazy(|| format!("could not migrate entity {entity_id:?}"))?;
+
+        migrated_entities.push(MigratedEntity {
+            properties,
+            metadata,
+        });
+    }
+
+    Ok(migrated_entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn base_uri(name: &str) -> BaseUri {
+        BaseUri::new(format!(
+            "https://blockprotocol.org/@alice/types/property-type/{name}/"
+        ))
+        .expect("invalid base uri")
+    }
+
+    fn person_type_id(version: u32) -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+                .expect("invalid base uri"),
+            version,
+        )
+    }
+
+    fn properties(value: serde_json::Value) -> EntityProperties {
+        serde_json::from_value(value).expect("invalid properties")
+    }
+
+    #[test]
+    fn map_properties() {
+        let migration = EntityMigration::new(person_type_id(1), person_type_id(2), vec![
+            PropertyMapping::Rename {
+                from: base_uri("name"),
+                to: base_uri("full-name"),
+            },
+            PropertyMapping::Drop {
+                property: base_uri("age"),
+            },
+            PropertyMapping::Default {
+                property: base_uri("email"),
+                value: json!("unknown"),
+            },
+            PropertyMapping::Default {
+                property: base_uri("full-name"),
+                value: json!("Anonymous"),
+            },
+        ]);
+
+        let mapped = migration.map_properties(&properties(json!({
+            "https://blockprotocol.org/@alice/types/property-type/name/": "Alice",
+            "https://blockprotocol.org/@alice/types/property-type/age/": 42,
+        })));
+
+        assert_eq!(
+            mapped,
+            properties(json!({
+                "https://blockprotocol.org/@alice/types/property-type/full-name/": "Alice",
+                "https://blockprotocol.org/@alice/types/property-type/email/": "unknown",
+            }))
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert!(
+            EntityMigration::new(person_type_id(1), person_type_id(2), Vec::new())
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            EntityMigration::new(person_type_id(2), person_type_id(2), Vec::new())
+                .validate()
+                .is_err()
+        );
+        assert!(
+            EntityMigration::new(person_type_id(2), person_type_id(1), Vec::new())
+                .validate()
+                .is_err()
+        );
+
+        let other_type_id = VersionedUri::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/page/".to_owned())
+                .expect("invalid base uri"),
+            2,
+        );
+        assert!(
+            EntityMigration::new(person_type_id(1), other_type_id, Vec::new())
+                .validate()
+                .is_err()
+        );
+    }
+}
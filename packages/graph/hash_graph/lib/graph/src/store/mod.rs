@@ -1,30 +1,57 @@
+pub mod archive;
 pub mod crud;
 pub mod error;
 pub mod query;
 
+mod batch;
+mod change_feed;
+mod history;
+mod memory;
+mod migration;
 mod pool;
 mod postgres;
 
-use std::fmt;
+use std::{collections::HashSet, fmt};
 
 use async_trait::async_trait;
-use error_stack::{Context, Result};
+use error_stack::{ensure, Context, Report, Result};
+use serde::{Deserialize, Serialize};
 use type_system::{uri::VersionedUri, DataType, EntityType, PropertyType};
+use utoipa::ToSchema;
 
 pub use self::{
-    error::{BaseUriAlreadyExists, BaseUriDoesNotExist, InsertionError, QueryError, UpdateError},
-    pool::StorePool,
+    batch::{
+        BatchLinkData, BatchOperation, BatchOperationResult, EntityReference,
+        InvalidEntityReference,
+    },
+    change_feed::{ChangeEvent, ChangeKind, ChangeOffset, ChangedElement},
+    error::{
+        BaseUriAlreadyExists, BaseUriDoesNotExist, DeletionError, InsertionError, QueryError,
+        UpdateError,
+    },
+    history::{diff_properties, PropertyPatch},
+    memory::{MemoryStore, MemoryStorePool},
+    migration::{EntityMigration, InvalidEntityMigration, MigratedEntity, PropertyMapping},
+    pool::{StorePool, StorePoolState},
     postgres::{AsClient, PostgresStore, PostgresStorePool},
 };
 use crate::{
-    identifier::{account::AccountId, knowledge::EntityId, DecisionTimestamp},
+    identifier::{
+        account::AccountId,
+        knowledge::{EntityId, EntityRecordId},
+        DecisionTimestamp, TransactionTimestamp,
+    },
     knowledge::{Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityUuid, LinkData},
     ontology::{
-        DataTypeWithMetadata, EntityTypeWithMetadata, OntologyElementMetadata,
-        PropertyTypeWithMetadata,
+        CompatibilityPolicy, DataTypeWithMetadata, EntityTypeWithMetadata, OntologyElementMetadata,
+        OntologyUpdateMetadata, PropertyTypeWithMetadata,
     },
     provenance::{OwnedById, UpdatedById},
-    subgraph::{query::StructuralQuery, Subgraph},
+    store::query::AggregationGroup,
+    subgraph::{
+        query::{AggregationQuery, StructuralQuery},
+        Subgraph,
+    },
 };
 
 #[derive(Debug)]
@@ -178,7 +205,15 @@ impl fmt::Display for DatabaseConnectionInfo {
 ///
 /// In addition to the errors described in the methods of this trait, further errors might also be
 /// raised depending on the implementation, e.g. connection issues.
-pub trait Store = AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore;
+pub trait Store = AccountStore
+    + DataTypeStore
+    + PropertyTypeStore
+    + EntityTypeStore
+    + EntityStore
+    + BatchStore
+    + EntityMigrationStore
+    + EntityHistoryStore
+    + ChangeFeedStore;
 
 /// Describes the API of a store implementation for accounts.
 #[async_trait]
@@ -189,6 +224,49 @@ pub trait AccountStore {
     ///
     /// - if insertion failed, e.g. because the [`AccountId`] already exists.
     async fn insert_account_id(&mut self, account_id: AccountId) -> Result<(), InsertionError>;
+
+    /// Adds `member_id` to the members of the account specified by `account_id`.
+    ///
+    /// Members of an account are allowed to write to the namespace of the account, i.e. they can
+    /// create and update ontology types and entities, which are owned by the account.
+    ///
+    /// # Errors
+    ///
+    /// - if one of the accounts does not exist
+    /// - if `member_id` is already a member of the account
+    async fn insert_account_member(
+        &mut self,
+        account_id: AccountId,
+        member_id: AccountId,
+    ) -> Result<(), InsertionError>;
+
+    /// Returns if `member_id` is a member of the account specified by `account_id`.
+    ///
+    /// Every account is a member of itself.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the members of the account failed
+    async fn is_account_member(
+        &self,
+        account_id: AccountId,
+        member_id: AccountId,
+    ) -> Result<bool, QueryError>;
+
+    /// Returns all [`AccountId`]s.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the accounts failed
+    async fn read_accounts(&self) -> Result<Vec<AccountId>, QueryError>;
+
+    /// Returns all pairs of `(account_id, member_id)`, where `member_id` was added to the members
+    /// of the account specified by `account_id`.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the account members failed
+    async fn read_account_members(&self) -> Result<Vec<(AccountId, AccountId)>, QueryError>;
 }
 
 /// Describes the API of a store implementation for [`DataType`]s.
@@ -221,14 +299,19 @@ pub trait DataTypeStore: crud::Read<DataTypeWithMetadata> {
 
     /// Update the definition of an existing [`DataType`].
     ///
+    /// The update is compared to the latest version of the [`DataType`] and handled as specified by
+    /// `compatibility_policy`.
+    ///
     /// # Errors
     ///
     /// - if the [`DataType`] doesn't exist.
+    /// - if the update is rejected by `compatibility_policy`.
     async fn update_data_type(
         &mut self,
         data_type: DataType,
         actor_id: UpdatedById,
-    ) -> Result<OntologyElementMetadata, UpdateError>;
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError>;
 }
 
 /// Describes the API of a store implementation for [`PropertyType`]s.
@@ -261,14 +344,19 @@ pub trait PropertyTypeStore: crud::Read<PropertyTypeWithMetadata> {
 
     /// Update the definition of an existing [`PropertyType`].
     ///
+    /// The update is compared to the latest version of the [`PropertyType`] and handled as
+    /// specified by `compatibility_policy`.
+    ///
     /// # Errors
     ///
     /// - if the [`PropertyType`] doesn't exist.
+    /// - if the update is rejected by `compatibility_policy`.
     async fn update_property_type(
         &mut self,
         property_type: PropertyType,
         actor_id: UpdatedById,
-    ) -> Result<OntologyElementMetadata, UpdateError>;
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError>;
 }
 
 /// Describes the API of a store implementation for [`EntityType`]s.
@@ -301,14 +389,53 @@ pub trait EntityTypeStore: crud::Read<EntityTypeWithMetadata> {
 
     /// Update the definition of an existing [`EntityType`].
     ///
+    /// The update is compared to the latest version of the [`EntityType`] and handled as specified
+    /// by `compatibility_policy`.
+    ///
     /// # Errors
     ///
     /// - if the [`EntityType`] doesn't exist.
+    /// - if the update is rejected by `compatibility_policy`.
     async fn update_entity_type(
         &mut self,
         entity_type: EntityType,
         actor_id: UpdatedById,
-    ) -> Result<OntologyElementMetadata, UpdateError>;
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError>;
+}
+
+/// Specifies how to handle link entities referencing an entity, which is purged.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkPurgeBehavior {
+    /// Refuse to purge the entity if it's the left or right entity of any link entity.
+    #[default]
+    Refuse,
+    /// Purge all link entities, which have the entity as their left or right entity, as well.
+    ///
+    /// This is applied recursively, so links on purged link entities are purged, too.
+    Cascade,
+}
+
+/// Ensures, that the entities removed by a purge are the entities the actor was authorized to
+/// remove.
+fn ensure_purge_is_authorized(
+    purged_entity_ids: &[EntityId],
+    authorized_entity_ids: &[EntityId],
+) -> Result<(), DeletionError> {
+    let purged = purged_entity_ids.iter().collect::<HashSet<_>>();
+    let authorized = authorized_entity_ids.iter().collect::<HashSet<_>>();
+    ensure!(
+        purged == authorized,
+        Report::new(error::PurgedEntitiesChanged)
+            .attach_printable(format!(
+                "{} entities would be purged, but {} were authorized",
+                purged.len(),
+                authorized.len()
+            ))
+            .change_context(DeletionError)
+    );
+    Ok(())
 }
 
 /// Describes the API of a store implementation for [Entities].
@@ -382,6 +509,18 @@ pub trait EntityStore: crud::Read<Entity> {
         query: &'f StructuralQuery<'q, Entity>,
     ) -> Result<Subgraph, QueryError>;
 
+    /// Computes the [`Aggregate`]s of the [`AggregationQuery`] for every group of entities.
+    ///
+    /// # Errors
+    ///
+    /// - if `min`, `max` or `sum` are computed over a path, which does not resolve to numbers
+    ///
+    /// [`Aggregate`]: crate::store::query::Aggregate
+    async fn aggregate_entities<'f: 'q, 'q>(
+        &self,
+        query: &'f AggregationQuery<'q, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError>;
+
     /// Update an existing [`Entity`].
     ///
     /// # Errors
@@ -401,4 +540,177 @@ pub trait EntityStore: crud::Read<Entity> {
         properties: EntityProperties,
         link_order: EntityLinkOrder,
     ) -> Result<EntityMetadata, UpdateError>;
+
+    /// Inserts an edition of an [`Entity`] exported from a graph with its original decision time
+    /// and transaction time.
+    ///
+    /// If the [`Entity`] doesn't exist, it's created with this edition. Otherwise, the edition
+    /// replaces the edition valid at `decision_time` and `transaction_time` and only the link order
+    /// of `link_data` is used. The editions of an [`Entity`] have to be inserted in the order of
+    /// their transaction time.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`EntityType`] doesn't exist
+    /// - if the [`EntityProperties`] is not valid with respect to the specified [`EntityType`]
+    /// - if the account referred to by the owner of `entity_id` or `updated_by_id` does not exist
+    /// - if `transaction_time` is in the future or before the transaction time of the latest
+    ///   edition of the [`Entity`]
+    #[expect(clippy::too_many_arguments)]
+    async fn import_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: DecisionTimestamp,
+        transaction_time: TransactionTimestamp,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<LinkData>,
+    ) -> Result<EntityMetadata, InsertionError>;
+
+    /// Permanently removes all editions of an existing [`Entity`].
+    ///
+    /// Link entities referencing the [`Entity`] are handled as specified by `link_behavior`. For
+    /// every removed entity an audit record is kept, which only contains its [`EntityId`], the
+    /// actor and the time of removal.
+    ///
+    /// `authorized_entity_ids` are the entities the actor was authorized to remove. They are
+    /// compared to the entities removed by the purge in the same transaction, so a link entity
+    /// created after the authorization is never removed.
+    ///
+    /// Returns the [`EntityId`]s of all removed entities.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] doesn't exist
+    /// - if the [`Entity`] is referenced by a link entity and `link_behavior` is
+    ///   [`LinkPurgeBehavior::Refuse`]
+    /// - [`PurgedEntitiesChanged`] if the removed entities differ from `authorized_entity_ids`
+    /// - if the account referred to by `actor_id` does not exist
+    ///
+    /// [`PurgedEntitiesChanged`]: error::PurgedEntitiesChanged
+    async fn purge_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: UpdatedById,
+        link_behavior: LinkPurgeBehavior,
+        authorized_entity_ids: &[EntityId],
+    ) -> Result<Vec<EntityId>, DeletionError>;
+
+    /// Reads the [`EntityId`]s of all entities, which are removed when purging the [`Entity`] with
+    /// [`LinkPurgeBehavior::Cascade`].
+    ///
+    /// These are the [`Entity`] itself and all link entities transitively referencing it.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] doesn't exist
+    async fn read_purged_entity_ids(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityId>, QueryError>;
+}
+
+/// Describes the API of a store implementation for applying batches of write operations.
+#[async_trait]
+pub trait BatchStore {
+    /// Applies the [`BatchOperation`]s in order in a single transaction.
+    ///
+    /// Operations can reference entities created or updated by preceding operations by using
+    /// [`EntityReference::Operation`]. Either all operations are applied or none of them.
+    ///
+    /// Returns the [`BatchOperationResult`] of every operation in the same order as the
+    /// operations.
+    ///
+    /// # Errors
+    ///
+    /// - if any of the operations fails as described by the corresponding method on the stores
+    /// - if an [`EntityReference`] does not refer to a preceding entity operation
+    async fn apply_batch(
+        &mut self,
+        operations: Vec<BatchOperation>,
+    ) -> Result<Vec<BatchOperationResult>, InsertionError>;
+}
+
+/// Describes the API of a store implementation for migrating entities between entity type versions.
+#[async_trait]
+pub trait EntityMigrationStore {
+    /// Moves the latest edition of every entity of the source entity type of `migration` to its
+    /// target entity type.
+    ///
+    /// A new edition is created for every entity with the properties returned by
+    /// [`EntityMigration::map_properties`]. All entities are migrated in a single transaction, so
+    /// either all entities are migrated or none of them. If `dry_run` is `true`, the transaction is
+    /// rolled back and the returned [`MigratedEntity`]s describe the editions which would have
+    /// been created.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidEntityMigration`] if `migration` does not target a newer version of the same
+    ///   entity type
+    /// - if updating any of the entities fails as described by [`EntityStore::update_entity`]
+    async fn migrate_entities(
+        &mut self,
+        migration: &EntityMigration,
+        actor_id: UpdatedById,
+        dry_run: bool,
+    ) -> Result<Vec<MigratedEntity>, UpdateError>;
+}
+
+/// Describes the API of a store implementation for the edition history of entities.
+#[async_trait]
+pub trait EntityHistoryStore {
+    /// Returns every edition of the [`Entity`] specified by `entity_id` ordered by its
+    /// [`EntityRecordId`], so the first edition is the one created initially.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the editions failed
+    async fn read_entity_history(&self, entity_id: EntityId) -> Result<Vec<Entity>, QueryError>;
+
+    /// Creates a new edition of the [`Entity`] specified by `entity_id`, which has the entity
+    /// type, the properties, the link order and the archived state of the edition specified by
+    /// `record_id`.
+    ///
+    /// The history of the entity is not rewritten, so the restored edition becomes the latest
+    /// edition of the entity.
+    ///
+    /// # Errors
+    ///
+    /// - [`EntityDoesNotExist`] if the entity has no edition with the specified `record_id`
+    /// - if updating the entity fails as described by [`EntityStore::update_entity`]
+    ///
+    /// [`EntityDoesNotExist`]: crate::store::error::EntityDoesNotExist
+    async fn restore_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        record_id: EntityRecordId,
+        actor_id: UpdatedById,
+    ) -> Result<EntityMetadata, UpdateError>;
+}
+
+/// Describes the API of a store implementation for reading the change feed.
+///
+/// The change feed contains an event for every created, updated or archived ontology type and
+/// entity. Events are recorded in the same transaction as the change itself, so an event is
+/// available exactly if the change was committed.
+#[async_trait]
+pub trait ChangeFeedStore {
+    /// Returns up to `limit` [`ChangeEvent`]s in the order they were recorded.
+    ///
+    /// If `after` is specified, only events after the event with this offset are returned, so
+    /// reading can be resumed after the last processed event.
+    ///
+    /// # Errors
+    ///
+    /// - [`ChangeOffsetDoesNotExist`] if there is no event with the offset `after`
+    /// - if reading the change feed failed
+    ///
+    /// [`ChangeOffsetDoesNotExist`]: error::ChangeOffsetDoesNotExist
+    async fn read_changes(
+        &self,
+        after: Option<ChangeOffset>,
+        limit: usize,
+    ) -> Result<Vec<ChangeEvent>, QueryError>;
 }
@@ -3,6 +3,15 @@ use error_stack::Result;
 
 use crate::store::Store;
 
+/// The number of connections managed by a [`StorePool`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StorePoolState {
+    /// The number of open connections, including the ones currently in use.
+    pub connections: u32,
+    /// The number of open connections, which are currently not in use.
+    pub idle_connections: u32,
+}
+
 /// Managed pool to keep track about [`Store`]s.
 #[async_trait]
 pub trait StorePool: Sync {
@@ -21,4 +30,11 @@ pub trait StorePool: Sync {
     /// [`StorePool::acquire`] (which stores a lifetime-bound reference to the `StorePool`) should
     /// be preferred whenever possible.
     async fn acquire_owned(&self) -> Result<Self::Store<'static>, Self::Error>;
+
+    /// Returns the number of connections managed by the pool.
+    ///
+    /// Returns `None` if the pool does not manage any connections.
+    fn state(&self) -> Option<StorePoolState> {
+        None
+    }
 }
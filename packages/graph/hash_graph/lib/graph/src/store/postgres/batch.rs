@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use error_stack::{IntoReport, Result, ResultExt};
+use tokio_postgres::GenericClient;
+
+use crate::store::{
+    batch::apply_operations, AsClient, BatchOperation, BatchOperationResult, BatchStore,
+    InsertionError, PostgresStore,
+};
+
+#[async_trait]
+impl<C: AsClient> BatchStore for PostgresStore<C> {
+    async fn apply_batch(
+        &mut self,
+        operations: Vec<BatchOperation>,
+    ) -> Result<Vec<BatchOperationResult>, InsertionError> {
+        // The store methods open their own (nested) transactions, so they are only committed
+        // after all operations succeeded.
+        let mut transaction = PostgresStore::new(
+            self.as_mut_client()
+                .transaction()
+                .await
+                .into_report()
+                .change_context(InsertionError)?,
+        );
+
+        let results = apply_operations(&mut transaction, operations).await?;
+
+        transaction
+            .client
+            .commit()
+            .await
+            .into_report()
+            .change_context(InsertionError)?;
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,275 @@
+use std::ops::{Bound, RangeBounds};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use error_stack::{ensure, IntoReport, Report, Result, ResultExt};
+use tokio_postgres::Row;
+use type_system::uri::{BaseUri, VersionedUri};
+use uuid::Uuid;
+
+use crate::{
+    identifier::{
+        account::AccountId,
+        knowledge::{EntityEditionId, EntityId, EntityRecordId, EntityVersion},
+        ontology::OntologyTypeEditionId,
+        DecisionTimespan, TransactionTimespan,
+    },
+    knowledge::{EntityMetadata, EntityUuid},
+    ontology::OntologyElementMetadata,
+    provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
+    store::{
+        error::ChangeOffsetDoesNotExist, AsClient, ChangeEvent, ChangeFeedStore, ChangeKind,
+        ChangeOffset, ChangedElement, InsertionError, PostgresStore, QueryError,
+    },
+};
+
+const fn change_kind_name(kind: ChangeKind) -> &'static str {
+    match kind {
+        ChangeKind::Create => "create",
+        ChangeKind::Update => "update",
+        ChangeKind::Archive => "archive",
+    }
+}
+
+fn start_timestamp<T: Copy>(range: &impl RangeBounds<T>) -> Option<T> {
+    match range.start_bound() {
+        Bound::Included(timestamp) | Bound::Excluded(timestamp) => Some(*timestamp),
+        Bound::Unbounded => None,
+    }
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Records a change of the graph in the change feed.
+    ///
+    /// This has to be called inside of the transaction writing the change, so the event is only
+    /// visible if the change was committed. The event records the id of the transaction, which
+    /// is used to order the events when reading the change feed.
+    ///
+    /// # Errors
+    ///
+    /// - if inserting the event failed
+    pub(super) async fn insert_change_event(
+        &self,
+        kind: ChangeKind,
+        element: &ChangedElement,
+    ) -> Result<(), InsertionError> {
+        let element_kind = match element {
+            ChangedElement::DataType(_) => "dataType",
+            ChangedElement::PropertyType(_) => "propertyType",
+            ChangedElement::EntityType(_) => "entityType",
+            ChangedElement::Entity(_) => "entity",
+        };
+
+        // For entities, the type columns refer to the entity type of the entity.
+        let (owned_by_id, updated_by_id, type_id, entity_metadata) = match element {
+            ChangedElement::DataType(metadata)
+            | ChangedElement::PropertyType(metadata)
+            | ChangedElement::EntityType(metadata) => (
+                metadata.owned_by_id(),
+                metadata.provenance_metadata().updated_by_id(),
+                VersionedUri::from(metadata.edition_id()),
+                None,
+            ),
+            ChangedElement::Entity(metadata) => (
+                metadata.edition_id().base_id().owned_by_id(),
+                metadata.provenance_metadata().updated_by_id(),
+                metadata.entity_type_id().clone(),
+                Some(metadata),
+            ),
+        };
+        let entity_version = entity_metadata.map(|metadata| metadata.edition_id().version());
+
+        self.as_client()
+            .query_one(
+                r#"
+                INSERT INTO graph_change_events (
+                    kind,
+                    element,
+                    owned_by_id,
+                    updated_by_id,
+                    base_uri,
+                    version,
+                    entity_uuid,
+                    entity_record_id,
+                    decision_time,
+                    transaction_time,
+                    archived
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                RETURNING event_offset;
+                "#,
+                &[
+                    &change_kind_name(kind),
+                    &element_kind,
+                    &owned_by_id,
+                    &updated_by_id,
+                    &type_id.base_uri().as_str(),
+                    &i64::from(type_id.version()),
+                    &entity_metadata.map(|metadata| metadata.edition_id().base_id().entity_uuid()),
+                    &entity_metadata.map(|metadata| metadata.edition_id().record_id()),
+                    &entity_version.and_then(|version| start_timestamp(&version.decision_time())),
+                    &entity_version
+                        .and_then(|version| start_timestamp(&version.transaction_time())),
+                    &entity_metadata.map(EntityMetadata::archived),
+                ],
+            )
+            .await
+            .into_report()
+            .change_context(InsertionError)?;
+
+        Ok(())
+    }
+}
+
+fn change_event_from_row(row: &Row) -> Result<ChangeEvent, QueryError> {
+    let kind = match row.get::<_, &str>("kind") {
+        "create" => ChangeKind::Create,
+        "update" => ChangeKind::Update,
+        "archive" => ChangeKind::Archive,
+        kind => {
+            return Err(
+                Report::new(QueryError).attach_printable(format!("unknown change kind `{kind}`"))
+            );
+        }
+    };
+
+    let base_uri = BaseUri::new(row.get("base_uri"))
+        .into_report()
+        .change_context(QueryError)?;
+    let version = u32::try_from(row.get::<_, i64>("version"))
+        .into_report()
+        .change_context(QueryError)?;
+    let type_id = VersionedUri::new(base_uri, version);
+
+    let owned_by_id = OwnedById::new(AccountId::new(row.get("owned_by_id")));
+    let provenance_metadata =
+        ProvenanceMetadata::new(UpdatedById::new(AccountId::new(row.get("updated_by_id"))));
+
+    let ontology_metadata = || {
+        OntologyElementMetadata::new(
+            OntologyTypeEditionId::from(&type_id),
+            provenance_metadata,
+            owned_by_id,
+        )
+    };
+
+    let element = match row.get::<_, &str>("element") {
+        "dataType" => ChangedElement::DataType(ontology_metadata()),
+        "propertyType" => ChangedElement::PropertyType(ontology_metadata()),
+        "entityType" => ChangedElement::EntityType(ontology_metadata()),
+        "entity" => {
+            let decision_time: DateTime<Utc> = row.get("decision_time");
+            let transaction_time: DateTime<Utc> = row.get("transaction_time");
+
+            ChangedElement::Entity(EntityMetadata::new(
+                EntityEditionId::new(
+                    EntityId::new(
+                        owned_by_id,
+                        EntityUuid::new(row.get::<_, Uuid>("entity_uuid")),
+                    ),
+                    EntityRecordId::new(row.get("entity_record_id")),
+                    EntityVersion::new(
+                        DecisionTimespan::from(decision_time..),
+                        TransactionTimespan::from(transaction_time..),
+                    ),
+                ),
+                type_id.clone(),
+                provenance_metadata,
+                row.get("archived"),
+            ))
+        }
+        element => {
+            return Err(Report::new(QueryError)
+                .attach_printable(format!("unknown changed element `{element}`")));
+        }
+    };
+
+    Ok(ChangeEvent::new(
+        ChangeOffset::new(row.get("event_offset")),
+        kind,
+        element,
+        row.get("recorded_at"),
+    ))
+}
+
+#[async_trait]
+impl<C: AsClient> ChangeFeedStore for PostgresStore<C> {
+    async fn read_changes(
+        &self,
+        after: Option<ChangeOffset>,
+        limit: usize,
+    ) -> Result<Vec<ChangeEvent>, QueryError> {
+        let limit = i64::try_from(limit)
+            .into_report()
+            .change_context(QueryError)?;
+
+        // The cursor is resolved to the event with the offset `after`, so an unknown offset would
+        // silently result in an empty change feed.
+        if let Some(after) = after {
+            let exists: bool = self
+                .as_client()
+                .query_one(
+                    r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM graph_change_events WHERE event_offset = $1
+                    );
+                    "#,
+                    &[&after.as_i64()],
+                )
+                .await
+                .into_report()
+                .change_context(QueryError)?
+                .get(0);
+            ensure!(
+                exists,
+                Report::new(ChangeOffsetDoesNotExist)
+                    .attach_printable(after.as_i64())
+                    .change_context(QueryError)
+            );
+        }
+
+        // Offsets are assigned when inserting an event, so a transaction which started earlier
+        // may commit an event with a lower offset after a later one was read. Instead of ordering
+        // by offset alone, events are ordered by the writing transaction first and only returned
+        // once all transactions with a lower id have ended. New events are therefore always
+        // appended after the events which were already read, without serializing the writers.
+        self.as_client()
+            .query(
+                r#"
+                SELECT
+                    event_offset,
+                    kind,
+                    element,
+                    owned_by_id,
+                    updated_by_id,
+                    base_uri,
+                    version,
+                    entity_uuid,
+                    entity_record_id,
+                    decision_time,
+                    transaction_time,
+                    archived,
+                    recorded_at
+                FROM graph_change_events
+                WHERE transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+                  AND (
+                    $1::BIGINT IS NULL
+                    OR (transaction_id, event_offset) > (
+                        SELECT transaction_id, event_offset
+                        FROM graph_change_events
+                        WHERE event_offset = $1
+                    )
+                  )
+                ORDER BY transaction_id, event_offset
+                LIMIT $2;
+                "#,
+                &[&after.map(ChangeOffset::as_i64), &limit],
+            )
+            .await
+            .into_report()
+            .change_context(QueryError)?
+            .iter()
+            .map(change_event_from_row)
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use error_stack::{IntoReport, Result, ResultExt};
+use tokio_postgres::GenericClient;
+
+use crate::{
+    identifier::knowledge::{EntityId, EntityRecordId},
+    knowledge::{Entity, EntityMetadata},
+    provenance::UpdatedById,
+    store::{
+        history::{read_entity_history, restore_entity_edition},
+        AsClient, EntityHistoryStore, PostgresStore, QueryError, UpdateError,
+    },
+};
+
+#[async_trait]
+impl<C: AsClient> EntityHistoryStore for PostgresStore<C> {
+    async fn read_entity_history(&self, entity_id: EntityId) -> Result<Vec<Entity>, QueryError> {
+        read_entity_history(self, entity_id).await
+    }
+
+    async fn restore_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        record_id: EntityRecordId,
+        actor_id: UpdatedById,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let mut transaction = PostgresStore::new(
+            self.as_mut_client()
+                .transaction()
+                .await
+                .into_report()
+                .change_context(UpdateError)?,
+        );
+
+        let metadata =
+            restore_entity_edition(&mut transaction, entity_id, record_id, actor_id).await?;
+
+        transaction
+            .client
+            .commit()
+            .await
+            .into_report()
+            .change_context(UpdateError)?;
+
+        Ok(metadata)
+    }
+}
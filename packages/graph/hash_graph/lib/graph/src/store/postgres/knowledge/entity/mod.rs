@@ -16,31 +16,315 @@ use crate::{
         },
         ontology::OntologyTypeEditionId,
         DecisionTimespan, DecisionTimestamp, GraphElementEditionId, TransactionTimespan,
+        TransactionTimestamp,
+    },
+    knowledge::{
+        Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityUuid, EntityValidator,
+        LinkData,
     },
-    knowledge::{Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityUuid, LinkData},
     provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
     store::{
         crud::Read,
-        error::{EntityDoesNotExist, RaceConditionOnUpdate},
+        ensure_purge_is_authorized,
+        error::{EntityDoesNotExist, EntityIsReferenced, RaceConditionOnUpdate},
         postgres::{DependencyContext, DependencyStatus},
-        query::Filter,
-        AsClient, EntityStore, InsertionError, PostgresStore, QueryError, UpdateError,
+        query::{AggregationGroup, Filter, TemporalAxes},
+        AsClient, ChangeKind, ChangedElement, DeletionError, EntityStore, InsertionError,
+        LinkPurgeBehavior, PostgresStore, QueryError, UpdateError,
     },
     subgraph::{
         edges::{
             Edge, EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind,
-            KnowledgeGraphOutwardEdges, OutgoingEdgeResolveDepth, OutwardEdge, SharedEdgeKind,
+            KnowledgeGraphOutwardEdges, OutwardEdge, SharedEdgeKind,
         },
-        query::StructuralQuery,
+        query::{AggregationQuery, StructuralQuery},
         vertices::KnowledgeGraphVertex,
         Subgraph,
     },
 };
 
 impl<C: AsClient> PostgresStore<C> {
+    /// Creates a new [`Entity`] with its first edition.
+    ///
+    /// If `transaction_time` is not specified, the current time is used.
+    #[expect(clippy::too_many_arguments, clippy::too_many_lines)]
+    async fn insert_entity(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: Option<DecisionTimestamp>,
+        transaction_time: Option<TransactionTimestamp>,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<LinkData>,
+    ) -> Result<EntityMetadata, InsertionError> {
+        let transaction = PostgresStore::new(
+            self.as_mut_client()
+                .transaction()
+                .await
+                .into_report()
+                .change_context(InsertionError)?,
+        );
+
+        let entity_type_version_id = transaction
+            .version_id_by_uri(&entity_type_id)
+            .await
+            .change_context(InsertionError)?;
+
+        EntityValidator::resolve(&transaction, &entity_type_id)
+            .await
+            .change_context(InsertionError)?
+            .validate(&properties, link_data.as_ref())
+            .change_context(InsertionError)?;
+
+        let properties = serde_json::to_value(properties)
+            .into_report()
+            .change_context(InsertionError)?;
+
+        let row = transaction
+            .as_client()
+            .query_one(
+                r#"
+                SELECT
+                    entity_record_id,
+                    decision_time,
+                    transaction_time
+                FROM
+                    create_entity(
+                        _owned_by_id := $1,
+                        _entity_uuid := $2,
+                        _decision_time := $3,
+                        _updated_by_id := $4,
+                        _archived := $5,
+                        _entity_type_version_id := $6,
+                        _properties := $7,
+                        _left_owned_by_id := $8,
+                        _left_entity_uuid := $9,
+                        _right_owned_by_id := $10,
+                        _right_entity_uuid := $11,
+                        _left_to_right_order := $12,
+                        _right_to_left_order := $13,
+                        _transaction_time := $14
+                    );
+                "#,
+                &[
+                    &entity_id.owned_by_id(),
+                    &entity_id.entity_uuid(),
+                    &decision_time,
+                    &updated_by_id,
+                    &archived,
+                    &entity_type_version_id,
+                    &properties,
+                    &link_data
+                        .as_ref()
+                        .map(|metadata| metadata.left_entity_id().owned_by_id()),
+                    &link_data
+                        .as_ref()
+                        .map(|metadata| metadata.left_entity_id().entity_uuid()),
+                    &link_data
+                        .as_ref()
+                        .map(|metadata| metadata.right_entity_id().owned_by_id()),
+                    &link_data
+                        .as_ref()
+                        .map(|metadata| metadata.right_entity_id().entity_uuid()),
+                    &link_data.as_ref().map(LinkData::left_to_right_order),
+                    &link_data.as_ref().map(LinkData::right_to_left_order),
+                    &transaction_time,
+                ],
+            )
+            .await
+            .into_report()
+            .change_context(InsertionError)?;
+
+        let metadata = EntityMetadata::new(
+            EntityEditionId::new(
+                entity_id,
+                EntityRecordId::new(row.get(0)),
+                EntityVersion::new(
+                    DecisionTimespan::new(row.get(1)),
+                    TransactionTimespan::new(row.get(2)),
+                ),
+            ),
+            entity_type_id,
+            ProvenanceMetadata::new(updated_by_id),
+            archived,
+        );
+
+        transaction
+            .insert_change_event(
+                ChangeKind::Create,
+                &ChangedElement::Entity(metadata.clone()),
+            )
+            .await?;
+
+        transaction
+            .client
+            .commit()
+            .await
+            .into_report()
+            .change_context(InsertionError)?;
+
+        Ok(metadata)
+    }
+
+    /// Replaces the latest edition of an existing [`Entity`].
+    ///
+    /// If `transaction_time` is not specified, the current time is used.
+    #[expect(clippy::too_many_arguments, clippy::too_many_lines)]
+    async fn update_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: Option<DecisionTimestamp>,
+        transaction_time: Option<TransactionTimestamp>,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_order: EntityLinkOrder,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let entity_type_version_id = self
+            .version_id_by_uri(&entity_type_id)
+            .await
+            .change_context(UpdateError)?;
+
+        // The transaction is required to check if the update happened. If there were no returned
+        // row, it either means, that there was no entity with that parameters or a race condition
+        // happened.
+        let transaction = PostgresStore::new(
+            self.as_mut_client()
+                .transaction()
+                .await
+                .into_report()
+                .change_context(UpdateError)?,
+        );
+
+        if transaction
+            .as_client()
+            .query_opt(
+                r#"
+                 SELECT EXISTS (
+                    SELECT 1 FROM entity_ids WHERE owned_by_id = $1 AND entity_uuid = $2
+                 );"#,
+                &[&entity_id.owned_by_id(), &entity_id.entity_uuid()],
+            )
+            .await
+            .into_report()
+            .change_context(UpdateError)?
+            .is_none()
+        {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .change_context(UpdateError));
+        }
+
+        // Whether an entity is a link is fixed on creation, so the link data of the latest
+        // edition is validated against the new entity type.
+        let link_data = Read::<Entity>::read(
+            &transaction,
+            &Filter::for_latest_entity_by_entity_id(entity_id),
+            &TemporalAxes::default(),
+        )
+        .await
+        .change_context(UpdateError)?
+        .pop()
+        .and_then(|entity| entity.link_data());
+
+        EntityValidator::resolve(&transaction, &entity_type_id)
+            .await
+            .change_context(UpdateError)?
+            .validate(&properties, link_data.as_ref())
+            .change_context(UpdateError)?;
+
+        let properties = serde_json::to_value(properties)
+            .into_report()
+            .change_context(UpdateError)?;
+
+        let row = transaction
+            .as_client()
+            .query_opt(
+                r#"
+                SELECT
+                    entity_record_id,
+                    decision_time,
+                    transaction_time
+                FROM
+                    update_entity(
+                        _owned_by_id := $1,
+                        _entity_uuid := $2,
+                        _decision_time := $3,
+                        _updated_by_id := $4,
+                        _archived := $5,
+                        _entity_type_version_id := $6,
+                        _properties := $7,
+                        _left_to_right_order := $8,
+                        _right_to_left_order := $9,
+                        _transaction_time := $10
+                    );
+                "#,
+                &[
+                    &entity_id.owned_by_id(),
+                    &entity_id.entity_uuid(),
+                    &decision_time,
+                    &updated_by_id,
+                    &archived,
+                    &entity_type_version_id,
+                    &properties,
+                    &link_order.left_to_right(),
+                    &link_order.right_to_left(),
+                    &transaction_time,
+                ],
+            )
+            .await
+            .into_report()
+            .change_context(UpdateError)?;
+
+        let Some(row) = row else {
+            return Err(Report::new(RaceConditionOnUpdate)
+                .attach(entity_id)
+                .change_context(UpdateError));
+        };
+
+        let metadata = EntityMetadata::new(
+            EntityEditionId::new(
+                entity_id,
+                EntityRecordId::new(row.get(0)),
+                EntityVersion::new(
+                    DecisionTimespan::new(row.get(1)),
+                    TransactionTimespan::new(row.get(2)),
+                ),
+            ),
+            entity_type_id,
+            ProvenanceMetadata::new(updated_by_id),
+            archived,
+        );
+
+        transaction
+            .insert_change_event(
+                if archived {
+                    ChangeKind::Archive
+                } else {
+                    ChangeKind::Update
+                },
+                &ChangedElement::Entity(metadata.clone()),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        transaction
+            .client
+            .commit()
+            .await
+            .into_report()
+            .change_context(UpdateError)?;
+
+        Ok(metadata)
+    }
+
     /// Internal method to read an [`Entity`] into a [`DependencyContext`].
     ///
-    /// This is used to recursively resolve a type, so the result can be reused.
+    /// This is used to recursively resolve a type, so the result can be reused. All entities are
+    /// resolved in the temporal slice specified by `temporal_axes`.
     #[expect(clippy::too_many_lines)]
     pub(crate) fn traverse_entity<'a>(
         &'a self,
@@ -48,6 +332,7 @@ impl<C: AsClient> PostgresStore<C> {
         dependency_context: &'a mut DependencyContext,
         subgraph: &'a mut Subgraph,
         current_resolve_depth: GraphResolveDepths,
+        temporal_axes: &'a TemporalAxes,
     ) -> Pin<Box<dyn Future<Output = Result<(), QueryError>> + Send + 'a>> {
         async move {
             let dependency_status = dependency_context
@@ -64,6 +349,7 @@ impl<C: AsClient> PostgresStore<C> {
                             let entity = Read::<Entity>::read_one(
                                 self,
                                 &Filter::for_entity_by_edition_id(entity_edition_id),
+                                temporal_axes,
                             )
                             .await?;
                             Some(entry.insert(KnowledgeGraphVertex::Entity(entity)))
@@ -93,12 +379,13 @@ impl<C: AsClient> PostgresStore<C> {
                         dependency_context,
                         subgraph,
                         GraphResolveDepths {
-                            is_of_type: OutgoingEdgeResolveDepth {
+                            is_of_type: EdgeResolveDepths {
                                 outgoing: current_resolve_depth.is_of_type.outgoing - 1,
                                 ..current_resolve_depth.is_of_type
                             },
                             ..current_resolve_depth
                         },
+                        temporal_axes,
                     )
                     .await?;
                 }
@@ -107,6 +394,7 @@ impl<C: AsClient> PostgresStore<C> {
                     for outgoing_link_entity in <Self as Read<Entity>>::read(
                         self,
                         &Filter::for_outgoing_link_by_source_entity_edition_id(entity_edition_id),
+                        temporal_axes,
                     )
                     .await?
                     {
@@ -121,6 +409,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 &Filter::for_entity_by_entity_id(
                                     outgoing_link_entity.metadata().edition_id().base_id(),
                                 ),
+                                temporal_axes,
                             )
                             .await?
                             .into_iter()
@@ -171,6 +460,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -180,6 +470,7 @@ impl<C: AsClient> PostgresStore<C> {
                     for incoming_link_entity in <Self as Read<Entity>>::read(
                         self,
                         &Filter::for_incoming_link_by_source_entity_edition_id(entity_edition_id),
+                        temporal_axes,
                     )
                     .await?
                     {
@@ -194,6 +485,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 &Filter::for_entity_by_entity_id(
                                     incoming_link_entity.metadata().edition_id().base_id(),
                                 ),
+                                temporal_axes,
                             )
                             .await?
                             .into_iter()
@@ -244,6 +536,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -253,6 +546,7 @@ impl<C: AsClient> PostgresStore<C> {
                     for left_entity in <Self as Read<Entity>>::read(
                         self,
                         &Filter::for_left_entity_by_entity_edition_id(entity_edition_id),
+                        temporal_axes,
                     )
                     .await?
                     {
@@ -264,6 +558,7 @@ impl<C: AsClient> PostgresStore<C> {
                             <Self as Read<Entity>>::read(
                                 self,
                                 &Filter::for_entity_by_entity_id(entity_edition_id.base_id()),
+                                temporal_axes,
                             )
                             .await?
                             .into_iter()
@@ -314,6 +609,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -323,6 +619,7 @@ impl<C: AsClient> PostgresStore<C> {
                     for right_entity in <Self as Read<Entity>>::read(
                         self,
                         &Filter::for_right_entity_by_entity_edition_id(entity_edition_id),
+                        temporal_axes,
                     )
                     .await?
                     {
@@ -334,6 +631,7 @@ impl<C: AsClient> PostgresStore<C> {
                             <Self as Read<Entity>>::read(
                                 self,
                                 &Filter::for_entity_by_entity_id(entity_edition_id.base_id()),
+                                temporal_axes,
                             )
                             .await?
                             .into_iter()
@@ -384,6 +682,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -409,86 +708,20 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         properties: EntityProperties,
         link_data: Option<LinkData>,
     ) -> Result<EntityMetadata, InsertionError> {
-        let entity_id = EntityId::new(
-            owned_by_id,
-            entity_uuid.unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
-        );
-
-        let entity_type_version_id = self
-            .version_id_by_uri(&entity_type_id)
-            .await
-            .change_context(InsertionError)?;
-
-        let properties = serde_json::to_value(properties)
-            .into_report()
-            .change_context(InsertionError)?;
-
-        let row = self
-            .as_client()
-            .query_one(
-                r#"
-                SELECT
-                    entity_record_id,
-                    decision_time,
-                    transaction_time
-                FROM
-                    create_entity(
-                        _owned_by_id := $1,
-                        _entity_uuid := $2,
-                        _decision_time := $3,
-                        _updated_by_id := $4,
-                        _archived := $5,
-                        _entity_type_version_id := $6,
-                        _properties := $7,
-                        _left_owned_by_id := $8,
-                        _left_entity_uuid := $9,
-                        _right_owned_by_id := $10,
-                        _right_entity_uuid := $11,
-                        _left_to_right_order := $12,
-                        _right_to_left_order := $13
-                    );
-                "#,
-                &[
-                    &entity_id.owned_by_id(),
-                    &entity_id.entity_uuid(),
-                    &decision_time,
-                    &updated_by_id,
-                    &archived,
-                    &entity_type_version_id,
-                    &properties,
-                    &link_data
-                        .as_ref()
-                        .map(|metadata| metadata.left_entity_id().owned_by_id()),
-                    &link_data
-                        .as_ref()
-                        .map(|metadata| metadata.left_entity_id().entity_uuid()),
-                    &link_data
-                        .as_ref()
-                        .map(|metadata| metadata.right_entity_id().owned_by_id()),
-                    &link_data
-                        .as_ref()
-                        .map(|metadata| metadata.right_entity_id().entity_uuid()),
-                    &link_data.as_ref().map(LinkData::left_to_right_order),
-                    &link_data.as_ref().map(LinkData::right_to_left_order),
-                ],
-            )
-            .await
-            .into_report()
-            .change_context(InsertionError)?;
-
-        Ok(EntityMetadata::new(
-            EntityEditionId::new(
-                entity_id,
-                EntityRecordId::new(row.get(0)),
-                EntityVersion::new(
-                    DecisionTimespan::new(row.get(1)),
-                    TransactionTimespan::new(row.get(2)),
-                ),
+        self.insert_entity(
+            EntityId::new(
+                owned_by_id,
+                entity_uuid.unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
             ),
-            entity_type_id,
-            ProvenanceMetadata::new(updated_by_id),
+            decision_time,
+            None,
+            updated_by_id,
             archived,
-        ))
+            entity_type_id,
+            properties,
+            link_data,
+        )
+        .await
     }
 
     #[doc(hidden)]
@@ -516,11 +749,19 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 .change_context(InsertionError)?,
         );
 
+        let validator = EntityValidator::resolve(&transaction, entity_type_id)
+            .await
+            .change_context(InsertionError)?;
+
         let entities = entities.into_iter();
         let mut entity_ids = Vec::with_capacity(entities.size_hint().0);
         let mut entity_editions = Vec::with_capacity(entities.size_hint().0);
         let mut entity_versions = Vec::with_capacity(entities.size_hint().0);
         for (owned_by_id, entity_uuid, properties, link_data, decision_time) in entities {
+            validator
+                .validate(&properties, link_data.as_ref())
+                .change_context(InsertionError)?;
+
             entity_ids.push((
                 EntityId::new(
                     owned_by_id,
@@ -568,14 +809,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             )
             .await?;
 
-        transaction
-            .client
-            .commit()
-            .await
-            .into_report()
-            .change_context(InsertionError)?;
-
-        Ok(entity_ids
+        let entity_metadata = entity_ids
             .into_iter()
             .zip(entity_versions)
             .zip(entity_record_ids)
@@ -587,7 +821,25 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                     false,
                 )
             })
-            .collect())
+            .collect::<Vec<_>>();
+
+        for metadata in &entity_metadata {
+            transaction
+                .insert_change_event(
+                    ChangeKind::Create,
+                    &ChangedElement::Entity(metadata.clone()),
+                )
+                .await?;
+        }
+
+        transaction
+            .client
+            .commit()
+            .await
+            .into_report()
+            .change_context(InsertionError)?;
+
+        Ok(entity_metadata)
     }
 
     async fn get_entity<'f: 'q, 'q>(
@@ -597,13 +849,26 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
+            ref pagination,
+            ref temporal_axes,
         } = *query;
 
         let mut subgraph = Subgraph::new(graph_resolve_depths);
         let mut dependency_context = DependencyContext::default();
 
-        for entity in Read::<Entity>::read(self, filter).await? {
+        let page = self
+            .read_entity_roots(filter, temporal_axes, pagination.as_ref())
+            .await?;
+        subgraph.next_cursor = page.next_cursor;
+
+        for (entity, search_rank) in page.records {
             let entity_edition_id = entity.metadata().edition_id();
+            if let Some(search_rank) = search_rank {
+                subgraph.search_ranks.insert(
+                    GraphElementEditionId::KnowledgeGraph(entity_edition_id),
+                    search_rank,
+                );
+            }
 
             // Insert the vertex into the subgraph to avoid another lookup when traversing it
             subgraph
@@ -616,6 +881,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 &mut dependency_context,
                 &mut subgraph,
                 graph_resolve_depths,
+                temporal_axes,
             )
             .await?;
 
@@ -627,6 +893,13 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         Ok(subgraph)
     }
 
+    async fn aggregate_entities<'f: 'q, 'q>(
+        &self,
+        query: &'f AggregationQuery<'q, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        self.read_aggregation_groups(query).await
+    }
+
     async fn update_entity(
         &mut self,
         entity_id: EntityId,
@@ -637,107 +910,241 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         properties: EntityProperties,
         link_order: EntityLinkOrder,
     ) -> Result<EntityMetadata, UpdateError> {
-        let entity_type_version_id = self
-            .version_id_by_uri(&entity_type_id)
-            .await
-            .change_context(UpdateError)?;
+        self.update_entity_edition(
+            entity_id,
+            decision_time,
+            None,
+            updated_by_id,
+            archived,
+            entity_type_id,
+            properties,
+            link_order,
+        )
+        .await
+    }
 
-        let properties = serde_json::to_value(properties)
+    async fn import_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        decision_time: DecisionTimestamp,
+        transaction_time: TransactionTimestamp,
+        updated_by_id: UpdatedById,
+        archived: bool,
+        entity_type_id: VersionedUri,
+        properties: EntityProperties,
+        link_data: Option<LinkData>,
+    ) -> Result<EntityMetadata, InsertionError> {
+        let entity_exists = self
+            .as_client()
+            .query_one(
+                r#"
+                 SELECT EXISTS (
+                    SELECT 1 FROM entity_ids WHERE owned_by_id = $1 AND entity_uuid = $2
+                 );"#,
+                &[&entity_id.owned_by_id(), &entity_id.entity_uuid()],
+            )
+            .await
             .into_report()
-            .change_context(UpdateError)?;
+            .change_context(InsertionError)?
+            .get(0);
 
-        // The transaction is required to check if the update happened. If there were no returned
-        // row, it either means, that there was no entity with that parameters or a race condition
-        // happened.
+        if entity_exists {
+            self.update_entity_edition(
+                entity_id,
+                Some(decision_time),
+                Some(transaction_time),
+                updated_by_id,
+                archived,
+                entity_type_id,
+                properties,
+                link_data.map_or(EntityLinkOrder::new(None, None), |link_data| {
+                    EntityLinkOrder::new(
+                        link_data.left_to_right_order(),
+                        link_data.right_to_left_order(),
+                    )
+                }),
+            )
+            .await
+            .change_context(InsertionError)
+        } else {
+            self.insert_entity(
+                entity_id,
+                Some(decision_time),
+                Some(transaction_time),
+                updated_by_id,
+                archived,
+                entity_type_id,
+                properties,
+                link_data,
+            )
+            .await
+        }
+    }
+
+    async fn purge_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: UpdatedById,
+        link_behavior: LinkPurgeBehavior,
+        authorized_entity_ids: &[EntityId],
+    ) -> Result<Vec<EntityId>, DeletionError> {
         let transaction = PostgresStore::new(
             self.as_mut_client()
                 .transaction()
                 .await
                 .into_report()
-                .change_context(UpdateError)?,
+                .change_context(DeletionError)?,
         );
 
-        if transaction
+        let entity_ids = transaction
+            .read_purged_entity_ids(entity_id)
+            .await
+            .change_context(DeletionError)?;
+
+        if link_behavior == LinkPurgeBehavior::Refuse && entity_ids.len() > 1 {
+            let mut report = Report::new(EntityIsReferenced).attach(entity_id);
+            for link_entity_id in entity_ids.into_iter().filter(|id| *id != entity_id) {
+                report = report.attach_printable(format!(
+                    "referenced by link entity {}",
+                    link_entity_id.entity_uuid().as_uuid()
+                ));
+            }
+            return Err(report.change_context(DeletionError));
+        }
+        ensure_purge_is_authorized(&entity_ids, authorized_entity_ids)?;
+
+        let owned_by_ids = entity_ids
+            .iter()
+            .map(|entity_id| entity_id.owned_by_id().as_uuid())
+            .collect::<Vec<_>>();
+        let entity_uuids = entity_ids
+            .iter()
+            .map(|entity_id| entity_id.entity_uuid().as_uuid())
+            .collect::<Vec<_>>();
+
+        let entity_record_ids = transaction
             .as_client()
-            .query_opt(
+            .query(
                 r#"
-                 SELECT EXISTS (
-                    SELECT 1 FROM entity_ids WHERE owned_by_id = $1 AND entity_uuid = $2
-                 );"#,
-                &[&entity_id.owned_by_id(), &entity_id.entity_uuid()],
+                DELETE FROM entity_versions
+                WHERE (owned_by_id, entity_uuid) IN (
+                    SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                )
+                RETURNING entity_record_id;
+                "#,
+                &[&owned_by_ids, &entity_uuids],
             )
             .await
             .into_report()
-            .change_context(UpdateError)?
-            .is_none()
-        {
-            return Err(Report::new(EntityDoesNotExist)
-                .attach(entity_id)
-                .change_context(UpdateError));
-        }
+            .change_context(DeletionError)?
+            .into_iter()
+            .map(|row| row.get::<_, i64>(0))
+            .collect::<Vec<_>>();
 
-        let row = transaction
+        transaction
             .as_client()
-            .query_opt(
+            .execute(
                 r#"
-                SELECT
-                    entity_record_id,
-                    decision_time,
-                    transaction_time
-                FROM
-                    update_entity(
-                        _owned_by_id := $1,
-                        _entity_uuid := $2,
-                        _decision_time := $3,
-                        _updated_by_id := $4,
-                        _archived := $5,
-                        _entity_type_version_id := $6,
-                        _properties := $7,
-                        _left_to_right_order := $8,
-                        _right_to_left_order := $9
-                    );
+                DELETE FROM entity_editions
+                WHERE entity_record_id = ANY($1);
+                "#,
+                &[&entity_record_ids],
+            )
+            .await
+            .into_report()
+            .change_context(DeletionError)?;
+
+        transaction
+            .as_client()
+            .execute(
+                r#"
+                DELETE FROM entity_ids
+                WHERE (owned_by_id, entity_uuid) IN (
+                    SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                );
+                "#,
+                &[&owned_by_ids, &entity_uuids],
+            )
+            .await
+            .into_report()
+            .change_context(DeletionError)?;
+
+        transaction
+            .as_client()
+            .execute(
+                r#"
+                INSERT INTO entity_purges (
+                    owned_by_id,
+                    entity_uuid,
+                    requested_owned_by_id,
+                    requested_entity_uuid,
+                    purged_by_id
+                )
+                SELECT owned_by_id, entity_uuid, $3, $4, $5
+                FROM UNNEST($1::UUID[], $2::UUID[]) AS purged_entities(owned_by_id, entity_uuid);
                 "#,
                 &[
+                    &owned_by_ids,
+                    &entity_uuids,
                     &entity_id.owned_by_id(),
                     &entity_id.entity_uuid(),
-                    &decision_time,
-                    &updated_by_id,
-                    &archived,
-                    &entity_type_version_id,
-                    &properties,
-                    &link_order.left_to_right(),
-                    &link_order.right_to_left(),
+                    &actor_id,
                 ],
             )
             .await
             .into_report()
-            .change_context(UpdateError)?;
-
-        let Some(row) = row else {
-            return Err(Report::new(RaceConditionOnUpdate)
-                .attach(entity_id)
-                .change_context(UpdateError));
-        };
+            .change_context(DeletionError)?;
 
         transaction
             .client
             .commit()
             .await
             .into_report()
-            .change_context(UpdateError)?;
+            .change_context(DeletionError)?;
 
-        Ok(EntityMetadata::new(
-            EntityEditionId::new(
-                entity_id,
-                EntityRecordId::new(row.get(0)),
-                EntityVersion::new(
-                    DecisionTimespan::new(row.get(1)),
-                    TransactionTimespan::new(row.get(2)),
-                ),
-            ),
-            entity_type_id,
-            ProvenanceMetadata::new(updated_by_id),
-            archived,
-        ))
+        Ok(entity_ids)
+    }
+
+    async fn read_purged_entity_ids(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityId>, QueryError> {
+        // Link entities can be the left or right entity of other link entities as well, so all
+        // link entities transitively referencing the entity are collected.
+        let entity_ids = self
+            .as_client()
+            .query(
+                r#"
+                WITH RECURSIVE purged_entities AS (
+                    SELECT owned_by_id, entity_uuid
+                    FROM entity_ids
+                    WHERE owned_by_id = $1 AND entity_uuid = $2
+                  UNION
+                    SELECT entity_ids.owned_by_id, entity_ids.entity_uuid
+                    FROM entity_ids
+                    JOIN purged_entities
+                      ON entity_ids.left_owned_by_id = purged_entities.owned_by_id
+                     AND entity_ids.left_entity_uuid = purged_entities.entity_uuid
+                      OR entity_ids.right_owned_by_id = purged_entities.owned_by_id
+                     AND entity_ids.right_entity_uuid = purged_entities.entity_uuid
+                )
+                SELECT owned_by_id, entity_uuid FROM purged_entities;
+                "#,
+                &[&entity_id.owned_by_id(), &entity_id.entity_uuid()],
+            )
+            .await
+            .into_report()
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| EntityId::new(OwnedById::new(row.get(0)), EntityUuid::new(row.get(1))))
+            .collect::<Vec<_>>();
+
+        if entity_ids.is_empty() {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .change_context(QueryError));
+        }
+
+        Ok(entity_ids)
     }
 }
@@ -2,8 +2,9 @@ use std::str::FromStr;
 
 use async_trait::async_trait;
 use error_stack::{IntoReport, Result, ResultExt};
-use futures::{StreamExt, TryStreamExt};
-use tokio_postgres::GenericClient;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use tokio_postgres::{GenericClient, Row};
+use tracing::Instrument;
 use type_system::uri::VersionedUri;
 use uuid::Uuid;
 
@@ -18,135 +19,404 @@ use crate::{
     provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
     store::{
         crud,
-        postgres::query::{Distinctness, SelectCompiler},
-        query::Filter,
+        postgres::{
+            portal::query_portal,
+            query::{statement_span, Distinctness, Ordering, SelectCompiler},
+        },
+        query::{Aggregate, AggregationGroup, Filter, Page, Pagination, TemporalAxes},
         AsClient, PostgresStore, QueryError,
     },
+    subgraph::query::AggregationQuery,
 };
 
-#[async_trait]
-impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
-    #[expect(clippy::too_many_lines)]
-    async fn read(&self, filter: &Filter<Entity>) -> Result<Vec<Entity>, QueryError> {
+/// The paths to the endpoints of a link entity.
+///
+/// They are selected by [`select_entity`], but have to be defined outside of it as they are
+/// borrowed by the [`SelectCompiler`].
+fn link_endpoint_paths() -> [EntityQueryPath<'static>; 4] {
+    [
+        EntityQueryPath::LeftEntity(Box::new(EntityQueryPath::OwnedById)),
+        EntityQueryPath::LeftEntity(Box::new(EntityQueryPath::Uuid)),
+        EntityQueryPath::RightEntity(Box::new(EntityQueryPath::OwnedById)),
+        EntityQueryPath::RightEntity(Box::new(EntityQueryPath::Uuid)),
+    ]
+}
+
+/// Adds the columns of an [`Entity`] to the selection of `compiler`.
+///
+/// Returns a function, which converts the rows returned by the compiled statement to [`Entity`]s.
+/// If `ordering` is specified, the rows are ordered by their [`EntityRecordId`].
+fn select_entity<'c>(
+    compiler: &mut SelectCompiler<'c, '_, Entity>,
+    link_endpoint_paths: &'c [EntityQueryPath<'static>; 4],
+    ordering: Option<Ordering>,
+) -> impl Fn(&Row) -> Result<Entity, QueryError> + Copy + Send + Sync + 'static {
+    let [left_owned_by_id_query_path, left_entity_uuid_path, right_owned_by_id_query_path, right_entity_uuid_path] =
+        link_endpoint_paths;
+
+    let owned_by_id_index = compiler.add_selection_path(&EntityQueryPath::OwnedById);
+    let entity_uuid_index = compiler.add_selection_path(&EntityQueryPath::Uuid);
+    let record_id_index = compiler.add_distinct_selection_with_ordering(
+        &EntityQueryPath::RecordId,
+        Distinctness::Distinct,
+        ordering,
+    );
+    let decision_time_index = compiler.add_selection_path(&EntityQueryPath::DecisionTime);
+    let transaction_time_index = compiler.add_selection_path(&EntityQueryPath::TransactionTime);
+
+    let type_id_index =
+        compiler.add_selection_path(&EntityQueryPath::Type(EntityTypeQueryPath::VersionedUri));
+
+    let properties_index = compiler.add_selection_path(&EntityQueryPath::Properties(None));
+
+    let left_entity_uuid_index = compiler.add_selection_path(left_entity_uuid_path);
+    let left_entity_owned_by_id_index = compiler.add_selection_path(left_owned_by_id_query_path);
+    let right_entity_uuid_index = compiler.add_selection_path(right_entity_uuid_path);
+    let right_entity_owned_by_id_index = compiler.add_selection_path(right_owned_by_id_query_path);
+    let left_to_right_order_index = compiler.add_selection_path(&EntityQueryPath::LeftToRightOrder);
+    let right_to_left_order_index = compiler.add_selection_path(&EntityQueryPath::RightToLeftOrder);
+
+    let updated_by_id_index = compiler.add_selection_path(&EntityQueryPath::UpdatedById);
+
+    let archived_index = compiler.add_selection_path(&EntityQueryPath::Archived);
+
+    move |row: &Row| {
+        let properties: EntityProperties = serde_json::from_value(row.get(properties_index))
+            .into_report()
+            .change_context(QueryError)?;
+        let entity_type_uri = VersionedUri::from_str(row.get(type_id_index))
+            .into_report()
+            .change_context(QueryError)?;
+
+        let link_data = {
+            let left_owned_by_id: Option<AccountId> = row.get(left_entity_owned_by_id_index);
+            let left_entity_uuid: Option<Uuid> = row.get(left_entity_uuid_index);
+            let right_owned_by_id: Option<AccountId> = row.get(right_entity_owned_by_id_index);
+            let right_entity_uuid: Option<Uuid> = row.get(right_entity_uuid_index);
+            match (
+                left_owned_by_id,
+                left_entity_uuid,
+                right_owned_by_id,
+                right_entity_uuid,
+            ) {
+                (
+                    Some(left_owned_by_id),
+                    Some(left_entity_uuid),
+                    Some(right_owned_by_id),
+                    Some(right_entity_uuid),
+                ) => Some(LinkData::new(
+                    EntityId::new(
+                        OwnedById::new(left_owned_by_id),
+                        EntityUuid::new(left_entity_uuid),
+                    ),
+                    EntityId::new(
+                        OwnedById::new(right_owned_by_id),
+                        EntityUuid::new(right_entity_uuid),
+                    ),
+                    row.get(left_to_right_order_index),
+                    row.get(right_to_left_order_index),
+                )),
+                (None, None, None, None) => None,
+                _ => unreachable!(
+                    "It's not possible to have a link entity with the left entityId or right \
+                     entityId unspecified"
+                ),
+            }
+        };
+
+        let owned_by_id = OwnedById::new(row.get(owned_by_id_index));
+        let entity_uuid = EntityUuid::new(row.get(entity_uuid_index));
+        let updated_by_id = UpdatedById::new(row.get(updated_by_id_index));
+
+        Ok(Entity::new(
+            properties,
+            link_data,
+            EntityEditionId::new(
+                EntityId::new(owned_by_id, entity_uuid),
+                EntityRecordId::new(row.get(record_id_index)),
+                EntityVersion::new(
+                    DecisionTimespan::new(row.get(decision_time_index)),
+                    TransactionTimespan::new(row.get(transaction_time_index)),
+                ),
+            ),
+            entity_type_uri,
+            ProvenanceMetadata::new(updated_by_id),
+            // TODO: only the historic table would have an `archived` field.
+            //   Consider what we should do about that.
+            row.get(archived_index),
+        ))
+    }
+}
+
+/// Decodes the [`EntityRecordId`] the `pagination` cursor is pointing after.
+fn decode_cursor(pagination: &Pagination) -> Result<Option<i64>, QueryError> {
+    pagination
+        .after
+        .as_ref()
+        .map(|cursor| {
+            cursor
+                .decode()
+                .change_context(QueryError)?
+                .parse::<i64>()
+                .into_report()
+                .change_context(QueryError)
+        })
+        .transpose()
+}
+
+/// Returns the cursor pointing after `entity`.
+fn sort_key(entity: &Entity) -> String {
+    entity
+        .metadata()
+        .edition_id()
+        .record_id()
+        .as_i64()
+        .to_string()
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the [`Entity`]s matching `filter` in the slice specified by `temporal_axes`.
+    ///
+    /// If a `limit` is specified, the entities are ordered by their [`EntityRecordId`] and only
+    /// the entities after `cursor` are returned.
+    async fn read_entities(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: &TemporalAxes,
+        cursor: Option<i64>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Entity>, QueryError> {
+        Ok(self
+            .read_entities_with_search_rank(filter, temporal_axes, cursor, limit, false)
+            .await?
+            .into_iter()
+            .map(|(entity, _)| entity)
+            .collect())
+    }
+
+    /// Like [`read_entities`], but if `search_rank` is set, the full-text search relevance of the
+    /// entities is selected alongside them.
+    ///
+    /// The relevance is only available if `filter` contains a search, see
+    /// [`Filter::search_query`].
+    ///
+    /// [`read_entities`]: Self::read_entities
+    async fn read_entities_with_search_rank(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: &TemporalAxes,
+        cursor: Option<i64>,
+        limit: Option<usize>,
+        search_rank: bool,
+    ) -> Result<Vec<(Entity, Option<f32>)>, QueryError> {
         // We can't define these inline otherwise we'll drop while borrowed
-        let left_entity_uuid_path = EntityQueryPath::LeftEntity(Box::new(EntityQueryPath::Uuid));
-        let left_owned_by_id_query_path =
-            EntityQueryPath::LeftEntity(Box::new(EntityQueryPath::OwnedById));
-        let right_entity_uuid_path = EntityQueryPath::RightEntity(Box::new(EntityQueryPath::Uuid));
-        let right_owned_by_id_query_path =
-            EntityQueryPath::RightEntity(Box::new(EntityQueryPath::OwnedById));
+        let link_endpoint_paths = link_endpoint_paths();
 
         let mut compiler = SelectCompiler::new();
+        compiler.set_temporal_axes(temporal_axes);
 
-        let owned_by_id_index = compiler.add_selection_path(&EntityQueryPath::OwnedById);
-        let entity_uuid_index = compiler.add_selection_path(&EntityQueryPath::Uuid);
-        let record_id_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::RecordId,
-            Distinctness::Distinct,
-            None,
+        let entity_from_row = select_entity(
+            &mut compiler,
+            &link_endpoint_paths,
+            limit.map(|_| Ordering::Ascending),
         );
-        let decision_time_index = compiler.add_selection_path(&EntityQueryPath::DecisionTime);
-        let transaction_time_index = compiler.add_selection_path(&EntityQueryPath::TransactionTime);
+        let search_rank_index = filter
+            .search_query()
+            .filter(|_| search_rank)
+            .map(|query| compiler.add_search_rank_selection(query));
+
+        if let Some(cursor) = &cursor {
+            compiler.add_cursor(&EntityQueryPath::RecordId, cursor);
+        }
+        if let Some(limit) = limit {
+            compiler.set_limit(limit);
+        }
+
+        compiler.add_filter(filter);
+        let (statement, parameters) = compiler.compile();
+
+        self.as_client()
+            .query_raw(&statement, parameters.iter().copied())
+            .instrument(statement_span(&statement))
+            .await
+            .into_report()
+            .change_context(QueryError)?
+            .map(|row| -> Result<_, QueryError> {
+                let row = row.into_report().change_context(QueryError)?;
+                Ok((
+                    entity_from_row(&row)?,
+                    search_rank_index.map(|index| row.get(index)),
+                ))
+            })
+            .try_collect()
+            .await
+    }
 
-        let type_id_index =
-            compiler.add_selection_path(&EntityQueryPath::Type(EntityTypeQueryPath::VersionedUri));
+    /// Reads the roots of a structural query alongside their full-text search relevance.
+    ///
+    /// If `pagination` is specified, only a single page of entities is read. The relevance is
+    /// selected by the same statement, so it's only computed for the returned entities.
+    pub(super) async fn read_entity_roots(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: &TemporalAxes,
+        pagination: Option<&Pagination>,
+    ) -> Result<Page<(Entity, Option<f32>)>, QueryError> {
+        let Some(pagination) = pagination else {
+            return Ok(Page {
+                records: self
+                    .read_entities_with_search_rank(filter, temporal_axes, None, None, true)
+                    .await?,
+                next_cursor: None,
+            });
+        };
 
-        let properties_index = compiler.add_selection_path(&EntityQueryPath::Properties(None));
+        let mut records = self
+            .read_entities_with_search_rank(
+                filter,
+                temporal_axes,
+                decode_cursor(pagination)?,
+                Some(pagination.limit.saturating_add(1)),
+                true,
+            )
+            .await?;
+        let next_cursor = pagination.next_cursor(&mut records, |(entity, _)| sort_key(entity));
+
+        Ok(Page {
+            records,
+            next_cursor,
+        })
+    }
 
-        let left_entity_uuid_index = compiler.add_selection_path(&left_entity_uuid_path);
-        let left_entity_owned_by_id_index =
-            compiler.add_selection_path(&left_owned_by_id_query_path);
-        let right_entity_uuid_index = compiler.add_selection_path(&right_entity_uuid_path);
-        let right_entity_owned_by_id_index =
-            compiler.add_selection_path(&right_owned_by_id_query_path);
-        let left_to_right_order_index =
-            compiler.add_selection_path(&EntityQueryPath::LeftToRightOrder);
-        let right_to_left_order_index =
-            compiler.add_selection_path(&EntityQueryPath::RightToLeftOrder);
+    /// Streams the [`Entity`]s matching `filter` in the slice specified by `temporal_axes`.
+    ///
+    /// The entities are fetched through a portal inside of a new transaction, which is kept open
+    /// until the returned stream is dropped.
+    async fn stream_entities(
+        &mut self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<BoxStream<'_, Result<Entity, QueryError>>, QueryError> {
+        // We can't define these inline otherwise we'll drop while borrowed
+        let link_endpoint_paths = link_endpoint_paths();
 
-        let updated_by_id_index = compiler.add_selection_path(&EntityQueryPath::UpdatedById);
+        let mut compiler = SelectCompiler::new();
+        compiler.set_temporal_axes(temporal_axes);
 
-        let archived_index = compiler.add_selection_path(&EntityQueryPath::Archived);
+        let entity_from_row = select_entity(&mut compiler, &link_endpoint_paths, None);
+
+        compiler.add_filter(filter);
+        let (statement, parameters) = compiler.compile();
+
+        let transaction = self
+            .as_mut_client()
+            .transaction()
+            .await
+            .into_report()
+            .change_context(QueryError)?;
+
+        Ok(query_portal(transaction, &statement, parameters)
+            .await?
+            .map(move |row| entity_from_row(&row?))
+            .boxed())
+    }
+
+    /// Computes the aggregates of `query` for every group of the [`Entity`]s matching its filter.
+    pub(super) async fn read_aggregation_groups(
+        &self,
+        query: &AggregationQuery<'_, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        let AggregationQuery {
+            ref filter,
+            ref group_by,
+            ref aggregates,
+            ref temporal_axes,
+        } = *query;
+
+        let mut compiler = SelectCompiler::new();
+        compiler.set_temporal_axes(temporal_axes);
+
+        let group_indices = group_by
+            .iter()
+            .map(|path| compiler.add_group_by_path(path))
+            .collect::<Vec<_>>();
+        let aggregate_indices = aggregates
+            .iter()
+            .map(|aggregate| (aggregate, compiler.add_aggregate(aggregate)))
+            .collect::<Vec<_>>();
 
         compiler.add_filter(filter);
         let (statement, parameters) = compiler.compile();
 
         self.as_client()
             .query_raw(&statement, parameters.iter().copied())
+            .instrument(statement_span(&statement))
             .await
             .into_report()
             .change_context(QueryError)?
-            .map(|row| row.into_report().change_context(QueryError))
-            .and_then(|row| async move {
-                let properties: EntityProperties =
-                    serde_json::from_value(row.get(properties_index))
-                        .into_report()
-                        .change_context(QueryError)?;
-                let entity_type_uri = VersionedUri::from_str(row.get(type_id_index))
-                    .into_report()
-                    .change_context(QueryError)?;
-
-                let link_data = {
-                    let left_owned_by_id: Option<AccountId> =
-                        row.get(left_entity_owned_by_id_index);
-                    let left_entity_uuid: Option<Uuid> = row.get(left_entity_uuid_index);
-                    let right_owned_by_id: Option<AccountId> =
-                        row.get(right_entity_owned_by_id_index);
-                    let right_entity_uuid: Option<Uuid> = row.get(right_entity_uuid_index);
-                    match (
-                        left_owned_by_id,
-                        left_entity_uuid,
-                        right_owned_by_id,
-                        right_entity_uuid,
-                    ) {
-                        (
-                            Some(left_owned_by_id),
-                            Some(left_entity_uuid),
-                            Some(right_owned_by_id),
-                            Some(right_entity_uuid),
-                        ) => Some(LinkData::new(
-                            EntityId::new(
-                                OwnedById::new(left_owned_by_id),
-                                EntityUuid::new(left_entity_uuid),
-                            ),
-                            EntityId::new(
-                                OwnedById::new(right_owned_by_id),
-                                EntityUuid::new(right_entity_uuid),
-                            ),
-                            row.get(left_to_right_order_index),
-                            row.get(right_to_left_order_index),
-                        )),
-                        (None, None, None, None) => None,
-                        _ => unreachable!(
-                            "It's not possible to have a link entity with the left entityId or \
-                             right entityId unspecified"
-                        ),
-                    }
-                };
-
-                let owned_by_id = OwnedById::new(row.get(owned_by_id_index));
-                let entity_uuid = EntityUuid::new(row.get(entity_uuid_index));
-                let updated_by_id = UpdatedById::new(row.get(updated_by_id_index));
-
-                Ok(Entity::new(
-                    properties,
-                    link_data,
-                    EntityEditionId::new(
-                        EntityId::new(owned_by_id, entity_uuid),
-                        EntityRecordId::new(row.get(record_id_index)),
-                        EntityVersion::new(
-                            DecisionTimespan::new(row.get(decision_time_index)),
-                            TransactionTimespan::new(row.get(transaction_time_index)),
-                        ),
-                    ),
-                    entity_type_uri,
-                    ProvenanceMetadata::new(updated_by_id),
-                    // TODO: only the historic table would have an `archived` field.
-                    //   Consider what we should do about that.
-                    row.get(archived_index),
-                ))
+            .map(|row| -> Result<AggregationGroup, QueryError> {
+                let row = row.into_report().change_context(QueryError)?;
+                Ok(AggregationGroup {
+                    group: group_indices
+                        .iter()
+                        .map(|index| {
+                            row.get::<_, Option<serde_json::Value>>(*index)
+                                .unwrap_or_default()
+                        })
+                        .collect(),
+                    values: aggregate_indices
+                        .iter()
+                        .map(|(aggregate, index)| match aggregate {
+                            Aggregate::Count => serde_json::Value::from(row.get::<_, i64>(*index)),
+                            Aggregate::Min(_) | Aggregate::Max(_) | Aggregate::Sum(_) => {
+                                serde_json::Value::from(row.get::<_, Option<f64>>(*index))
+                            }
+                        })
+                        .collect(),
+                })
             })
             .try_collect()
             .await
     }
 }
+
+#[async_trait]
+impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
+    async fn read(
+        &self,
+        filter: &Filter<Entity>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<Vec<Entity>, QueryError> {
+        self.read_entities(filter, temporal_axes, None, None).await
+    }
+
+    async fn read_stream<'s>(
+        &'s mut self,
+        filter: &Filter<Entity>,
+        temporal_axes: &TemporalAxes,
+    ) -> Result<BoxStream<'s, Result<Entity, QueryError>>, QueryError> {
+        self.stream_entities(filter, temporal_axes).await
+    }
+
+    async fn read_page(
+        &self,
+        filter: &Filter<Entity>,
+        temporal_axes: &TemporalAxes,
+        pagination: &Pagination,
+    ) -> Result<Page<Entity>, QueryError> {
+        let mut records = self
+            .read_entities(
+                filter,
+                temporal_axes,
+                decode_cursor(pagination)?,
+                Some(pagination.limit.saturating_add(1)),
+            )
+            .await?;
+        let next_cursor = pagination.next_cursor(&mut records, sort_key);
+
+        Ok(Page {
+            records,
+            next_cursor,
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use error_stack::{IntoReport, Result, ResultExt};
+use tokio_postgres::GenericClient;
+
+use crate::{
+    provenance::UpdatedById,
+    store::{
+        migration::migrate_entities, AsClient, EntityMigration, EntityMigrationStore,
+        MigratedEntity, PostgresStore, UpdateError,
+    },
+};
+
+#[async_trait]
+impl<C: AsClient> EntityMigrationStore for PostgresStore<C> {
+    async fn migrate_entities(
+        &mut self,
+        migration: &EntityMigration,
+        actor_id: UpdatedById,
+        dry_run: bool,
+    ) -> Result<Vec<MigratedEntity>, UpdateError> {
+        let mut transaction = PostgresStore::new(
+            self.as_mut_client()
+                .transaction()
+                .await
+                .into_report()
+                .change_context(UpdateError)?,
+        );
+
+        let migrated_entities = migrate_entities(&mut transaction, migration, actor_id).await?;
+
+        if dry_run {
+            transaction
+                .client
+                .rollback()
+                .await
+                .into_report()
+                .change_context(UpdateError)?;
+        } else {
+            transaction
+                .client
+                .commit()
+                .await
+                .into_report()
+                .change_context(UpdateError)?;
+        }
+
+        Ok(migrated_entities)
+    }
+}
@@ -1,8 +1,13 @@
 mod knowledge;
 mod ontology;
 
+mod batch;
+mod change_feed;
 mod context;
+mod history;
+mod migration;
 mod pool;
+mod portal;
 mod query;
 mod version_id;
 
@@ -27,7 +32,9 @@ use self::context::OntologyRecord;
 pub use self::pool::{AsClient, PostgresStorePool};
 use crate::{
     identifier::{account::AccountId, knowledge::EntityEditionId, ontology::OntologyTypeEditionId},
-    ontology::OntologyElementMetadata,
+    ontology::{
+        CheckCompatibility, CompatibilityPolicy, OntologyElementMetadata, OntologyUpdateMetadata,
+    },
     provenance::{OwnedById, ProvenanceMetadata, UpdatedById},
     store::{
         error::VersionedUriAlreadyExists,
@@ -317,21 +324,24 @@ where
 
     /// Updates the specified [`OntologyDatabaseType`].
     ///
-    /// First this ensures the [`BaseUri`] of the type already exists. It then creates a
-    /// new [`VersionId`] from the contained [`VersionedUri`] and inserts the type.
+    /// First this ensures the [`BaseUri`] of the type already exists and the changes to the latest
+    /// version are allowed by `compatibility_policy`. It then creates a new [`VersionId`] from the
+    /// contained [`VersionedUri`] and inserts the type.
     ///
     /// # Errors
     ///
     /// - If the [`BaseUri`] does not already exist
+    /// - If the update is rejected by `compatibility_policy`
     ///
     /// [`BaseUri`]: type_system::uri::BaseUri
     async fn update<T>(
         &self,
         database_type: T,
         updated_by_id: UpdatedById,
-    ) -> Result<(VersionId, OntologyElementMetadata), UpdateError>
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<(VersionId, OntologyUpdateMetadata), UpdateError>
     where
-        T: OntologyDatabaseType<Representation: Send> + Send + Sync,
+        T: OntologyDatabaseType<Representation: Send> + CheckCompatibility + Send + Sync,
     {
         let uri = database_type.versioned_uri().clone();
 
@@ -350,12 +360,19 @@ where
         // TODO - address potential race condition
         //  https://app.asana.com/0/1202805690238892/1203201674100967/f
 
-        let previous_ontology_type = self
+        let OntologyRecord {
+            record: previous_ontology_type,
+            owned_by_id,
+            ..
+        } = self
             .read_latest_ontology_type::<T>(base_uri)
             .await
             .change_context(UpdateError)?;
 
-        let OntologyRecord { owned_by_id, .. } = previous_ontology_type;
+        let compatibility = T::check_compatibility(&previous_ontology_type, &database_type);
+        compatibility_policy
+            .enforce(&uri, &compatibility)
+            .change_context(UpdateError)?;
 
         let version_id = VersionId::new(Uuid::new_v4());
         self.insert_version_id(version_id)
@@ -370,10 +387,13 @@ where
 
         Ok((
             version_id,
-            OntologyElementMetadata::new(
-                OntologyTypeEditionId::from(&uri),
-                ProvenanceMetadata::new(updated_by_id),
-                owned_by_id,
+            OntologyUpdateMetadata::new(
+                OntologyElementMetadata::new(
+                    OntologyTypeEditionId::from(&uri),
+                    ProvenanceMetadata::new(updated_by_id),
+                    owned_by_id,
+                ),
+                compatibility,
             ),
         ))
     }
@@ -922,4 +942,92 @@ impl<C: AsClient> AccountStore for PostgresStore<C> {
 
         Ok(())
     }
+
+    async fn insert_account_member(
+        &mut self,
+        account_id: AccountId,
+        member_id: AccountId,
+    ) -> Result<(), InsertionError> {
+        self.as_client()
+            .query_one(
+                r#"
+                INSERT INTO account_members (account_id, member_id)
+                VALUES ($1, $2)
+                RETURNING account_id;
+                "#,
+                &[&account_id, &member_id],
+            )
+            .await
+            .into_report()
+            .change_context(InsertionError)
+            .attach_printable(account_id)
+            .attach_printable(member_id)?;
+
+        Ok(())
+    }
+
+    async fn is_account_member(
+        &self,
+        account_id: AccountId,
+        member_id: AccountId,
+    ) -> Result<bool, QueryError> {
+        if account_id == member_id {
+            return Ok(true);
+        }
+
+        self.as_client()
+            .query_one(
+                r#"
+                SELECT EXISTS (
+                    SELECT 1 FROM account_members
+                    WHERE account_id = $1 AND member_id = $2
+                );
+                "#,
+                &[&account_id, &member_id],
+            )
+            .await
+            .into_report()
+            .change_context(QueryError)
+            .attach_printable(account_id)
+            .attach_printable(member_id)
+            .map(|row| row.get(0))
+    }
+
+    async fn read_accounts(&self) -> Result<Vec<AccountId>, QueryError> {
+        Ok(self
+            .as_client()
+            .query(
+                r#"
+                SELECT account_id
+                FROM accounts
+                ORDER BY account_id;
+                "#,
+                &[],
+            )
+            .await
+            .into_report()
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| row.get(0))
+            .collect())
+    }
+
+    async fn read_account_members(&self) -> Result<Vec<(AccountId, AccountId)>, QueryError> {
+        Ok(self
+            .as_client()
+            .query(
+                r#"
+                SELECT account_id, member_id
+                FROM account_members
+                ORDER BY account_id, member_id;
+                "#,
+                &[],
+            )
+            .await
+            .into_report()
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect())
+    }
 }
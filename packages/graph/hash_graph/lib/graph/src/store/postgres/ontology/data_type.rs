@@ -1,22 +1,33 @@
-use std::collections::hash_map::RawEntryMut;
+use std::{collections::hash_map::RawEntryMut, future::Future, pin::Pin};
 
 use async_trait::async_trait;
 use error_stack::{IntoReport, Result, ResultExt};
+use futures::FutureExt;
 use tokio_postgres::GenericClient;
 use type_system::DataType;
 
 use crate::{
     identifier::{ontology::OntologyTypeEditionId, GraphElementEditionId},
-    ontology::{DataTypeWithMetadata, OntologyElementMetadata},
+    ontology::{
+        CompatibilityPolicy, DataTypeWithMetadata, OntologyElementMetadata, OntologyUpdateMetadata,
+        PropertyTypeWithMetadata,
+    },
     provenance::{OwnedById, UpdatedById},
     store::{
         crud::Read,
         postgres::{DependencyContext, DependencyStatus},
-        query::Filter,
-        AsClient, DataTypeStore, InsertionError, PostgresStore, QueryError, UpdateError,
+        query::{Filter, TemporalAxes},
+        AsClient, ChangeKind, ChangedElement, DataTypeStore, InsertionError, PostgresStore,
+        QueryError, UpdateError,
     },
     subgraph::{
-        edges::GraphResolveDepths, query::StructuralQuery, vertices::OntologyVertex, Subgraph,
+        edges::{
+            Edge, EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
+            OutwardEdge,
+        },
+        query::StructuralQuery,
+        vertices::OntologyVertex,
+        Subgraph,
     },
 };
 
@@ -24,55 +35,101 @@ impl<C: AsClient> PostgresStore<C> {
     /// Internal method to read a [`DataTypeWithMetadata`] into a [`DependencyContext`].
     ///
     /// This is used to recursively resolve a type, so the result can be reused.
-    pub(crate) async fn traverse_data_type(
-        &self,
-        data_type_id: &OntologyTypeEditionId,
-        dependency_context: &mut DependencyContext,
-        subgraph: &mut Subgraph,
+    pub(crate) fn traverse_data_type<'a>(
+        &'a self,
+        data_type_id: &'a OntologyTypeEditionId,
+        dependency_context: &'a mut DependencyContext,
+        subgraph: &'a mut Subgraph,
         current_resolve_depth: GraphResolveDepths,
-    ) -> Result<(), QueryError> {
-        let dependency_status = dependency_context
-            .ontology_dependency_map
-            .insert(data_type_id, current_resolve_depth);
-
-        // Explicitly converting the unique reference to a shared reference to the vertex to
-        // avoid mutating it by accident
-        let data_type: Option<&OntologyVertex> = match dependency_status {
-            DependencyStatus::Unresolved => {
-                match subgraph
-                    .vertices
-                    .ontology
-                    .raw_entry_mut()
-                    .from_key(data_type_id)
-                {
-                    RawEntryMut::Occupied(entry) => Some(entry.into_mut()),
-                    RawEntryMut::Vacant(entry) => {
-                        let data_type = Read::<DataTypeWithMetadata>::read_one(
-                            self,
-                            &Filter::for_ontology_type_edition_id(data_type_id),
-                        )
-                        .await?;
-                        Some(
-                            entry
-                                .insert(
-                                    data_type_id.clone(),
-                                    OntologyVertex::DataType(Box::new(data_type)),
-                                )
-                                .1,
-                        )
+        temporal_axes: &'a TemporalAxes,
+    ) -> Pin<Box<dyn Future<Output = Result<(), QueryError>> + Send + 'a>> {
+        async move {
+            let dependency_status = dependency_context
+                .ontology_dependency_map
+                .insert(data_type_id, current_resolve_depth);
+
+            // Explicitly converting the unique reference to a shared reference to the vertex to
+            // avoid mutating it by accident
+            let data_type: Option<&OntologyVertex> = match dependency_status {
+                DependencyStatus::Unresolved => {
+                    match subgraph
+                        .vertices
+                        .ontology
+                        .raw_entry_mut()
+                        .from_key(data_type_id)
+                    {
+                        RawEntryMut::Occupied(entry) => Some(entry.into_mut()),
+                        RawEntryMut::Vacant(entry) => {
+                            let data_type = Read::<DataTypeWithMetadata>::read_one(
+                                self,
+                                &Filter::for_ontology_type_edition_id(data_type_id),
+                                temporal_axes,
+                            )
+                            .await?;
+                            Some(
+                                entry
+                                    .insert(
+                                        data_type_id.clone(),
+                                        OntologyVertex::DataType(Box::new(data_type)),
+                                    )
+                                    .1,
+                            )
+                        }
                     }
                 }
+                DependencyStatus::Resolved => None,
+            };
+
+            // Data types have no references to other types, so only incoming edges have to be
+            // resolved
+            if matches!(data_type, Some(OntologyVertex::DataType(_)))
+                && current_resolve_depth.constrains_values_on.incoming > 0
+            {
+                for property_type in Read::<PropertyTypeWithMetadata>::read(
+                    self,
+                    &Filter::for_property_types_by_data_type(data_type_id),
+                    temporal_axes,
+                )
+                .await?
+                {
+                    let property_type_id = property_type.metadata().edition_id().clone();
+
+                    subgraph.edges.insert(Edge::Ontology {
+                        edition_id: data_type_id.clone(),
+                        outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                            kind: OntologyEdgeKind::ConstrainsValuesOn,
+                            reversed: true,
+                            right_endpoint: property_type_id.clone(),
+                        }),
+                    });
+
+                    // Insert the vertex into the subgraph to avoid another lookup when traversing
+                    // it
+                    subgraph.vertices.ontology.insert(
+                        property_type_id.clone(),
+                        OntologyVertex::PropertyType(Box::new(property_type)),
+                    );
+
+                    self.traverse_property_type(
+                        &property_type_id,
+                        dependency_context,
+                        subgraph,
+                        GraphResolveDepths {
+                            constrains_values_on: EdgeResolveDepths {
+                                incoming: current_resolve_depth.constrains_values_on.incoming - 1,
+                                ..current_resolve_depth.constrains_values_on
+                            },
+                            ..current_resolve_depth
+                        },
+                        temporal_axes,
+                    )
+                    .await?;
+                }
             }
-            DependencyStatus::Resolved => None,
-        };
 
-        if let Some(OntologyVertex::DataType(_data_type)) = data_type {
-            // TODO: data types currently have no references to other types, so we don't need to do
-            //       anything here
-            //   see https://app.asana.com/0/1200211978612931/1202464168422955/f
+            Ok(())
         }
-
-        Ok(())
+        .boxed()
     }
 }
 
@@ -96,6 +153,13 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
             .create(data_type, owned_by_id, updated_by_id)
             .await?;
 
+        transaction
+            .insert_change_event(
+                ChangeKind::Create,
+                &ChangedElement::DataType(metadata.clone()),
+            )
+            .await?;
+
         transaction
             .client
             .commit()
@@ -113,13 +177,26 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
+            ref pagination,
+            ref temporal_axes,
         } = *query;
 
         let mut subgraph = Subgraph::new(graph_resolve_depths);
         let mut dependency_context = DependencyContext::default();
 
-        for data_type in Read::<DataTypeWithMetadata>::read(self, filter).await? {
+        let page = self
+            .read_ontology_roots::<DataTypeWithMetadata>(filter, pagination.as_ref())
+            .await?;
+        subgraph.next_cursor = page.next_cursor;
+
+        for (data_type, search_rank) in page.records {
             let data_type_id = data_type.metadata().edition_id().clone();
+            if let Some(search_rank) = search_rank {
+                subgraph.search_ranks.insert(
+                    GraphElementEditionId::Ontology(data_type_id.clone()),
+                    search_rank,
+                );
+            }
 
             // Insert the vertex into the subgraph to avoid another lookup when traversing it
             subgraph.vertices.ontology.insert(
@@ -132,6 +209,7 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
                 &mut dependency_context,
                 &mut subgraph,
                 graph_resolve_depths,
+                temporal_axes,
             )
             .await?;
 
@@ -147,7 +225,8 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
         &mut self,
         data_type: DataType,
         updated_by_id: UpdatedById,
-    ) -> Result<OntologyElementMetadata, UpdateError> {
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
         let transaction = PostgresStore::new(
             self.as_mut_client()
                 .transaction()
@@ -156,7 +235,17 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
                 .change_context(UpdateError)?,
         );
 
-        let (_, metadata) = transaction.update(data_type, updated_by_id).await?;
+        let (_, metadata) = transaction
+            .update(data_type, updated_by_id, compatibility_policy)
+            .await?;
+
+        transaction
+            .insert_change_event(
+                ChangeKind::Update,
+                &ChangedElement::DataType(metadata.metadata().clone()),
+            )
+            .await
+            .change_context(UpdateError)?;
 
         transaction
             .client
@@ -8,21 +8,26 @@ use type_system::{EntityType, EntityTypeReference, PropertyTypeReference};
 
 use crate::{
     identifier::{ontology::OntologyTypeEditionId, GraphElementEditionId},
-    ontology::{EntityTypeWithMetadata, OntologyElementMetadata},
+    knowledge::Entity,
+    ontology::{
+        CompatibilityPolicy, EntityTypeWithMetadata, OntologyElementMetadata,
+        OntologyUpdateMetadata,
+    },
     provenance::{OwnedById, UpdatedById},
     store::{
         crud::Read,
         postgres::{DependencyContext, DependencyStatus},
-        query::Filter,
-        AsClient, EntityTypeStore, InsertionError, PostgresStore, QueryError, UpdateError,
+        query::{Filter, TemporalAxes},
+        AsClient, ChangeKind, ChangedElement, EntityTypeStore, InsertionError, PostgresStore,
+        QueryError, UpdateError,
     },
     subgraph::{
         edges::{
-            Edge, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
-            OutgoingEdgeResolveDepth, OutwardEdge,
+            Edge, EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
+            OutwardEdge, SharedEdgeKind,
         },
         query::StructuralQuery,
-        vertices::OntologyVertex,
+        vertices::{KnowledgeGraphVertex, OntologyVertex},
         Subgraph,
     },
 };
@@ -41,6 +46,7 @@ impl<C: AsClient> PostgresStore<C> {
         dependency_context: &'a mut DependencyContext,
         subgraph: &'a mut Subgraph,
         current_resolve_depth: GraphResolveDepths,
+        temporal_axes: &'a TemporalAxes,
     ) -> Pin<Box<dyn Future<Output = Result<(), QueryError>> + Send + 'a>> {
         async move {
             let dependency_status = dependency_context
@@ -62,6 +68,7 @@ impl<C: AsClient> PostgresStore<C> {
                             let entity_type = Read::<EntityTypeWithMetadata>::read_one(
                                 self,
                                 &Filter::for_ontology_type_edition_id(entity_type_id),
+                                temporal_axes,
                             )
                             .await?;
                             Some(
@@ -144,7 +151,7 @@ impl<C: AsClient> PostgresStore<C> {
                             dependency_context,
                             subgraph,
                             GraphResolveDepths {
-                                constrains_properties_on: OutgoingEdgeResolveDepth {
+                                constrains_properties_on: EdgeResolveDepths {
                                     outgoing: current_resolve_depth
                                         .constrains_properties_on
                                         .outgoing
@@ -153,6 +160,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -176,12 +184,13 @@ impl<C: AsClient> PostgresStore<C> {
                             dependency_context,
                             subgraph,
                             GraphResolveDepths {
-                                inherits_from: OutgoingEdgeResolveDepth {
+                                inherits_from: EdgeResolveDepths {
                                     outgoing: current_resolve_depth.inherits_from.outgoing - 1,
                                     ..current_resolve_depth.inherits_from
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -204,7 +213,7 @@ impl<C: AsClient> PostgresStore<C> {
                                 dependency_context,
                                 subgraph,
                                 GraphResolveDepths {
-                                    constrains_links_on: OutgoingEdgeResolveDepth {
+                                    constrains_links_on: EdgeResolveDepths {
                                         outgoing: current_resolve_depth
                                             .constrains_links_on
                                             .outgoing
@@ -213,6 +222,7 @@ impl<C: AsClient> PostgresStore<C> {
                                     },
                                     ..current_resolve_depth
                                 },
+                                temporal_axes,
                             )
                             .await?;
 
@@ -241,17 +251,17 @@ impl<C: AsClient> PostgresStore<C> {
                                         dependency_context,
                                         subgraph,
                                         GraphResolveDepths {
-                                            constrains_link_destinations_on:
-                                                OutgoingEdgeResolveDepth {
-                                                    outgoing: current_resolve_depth
-                                                        .constrains_link_destinations_on
-                                                        .outgoing
-                                                        - 1,
-                                                    ..current_resolve_depth
-                                                        .constrains_link_destinations_on
-                                                },
+                                            constrains_link_destinations_on: EdgeResolveDepths {
+                                                outgoing: current_resolve_depth
+                                                    .constrains_link_destinations_on
+                                                    .outgoing
+                                                    - 1,
+                                                ..current_resolve_depth
+                                                    .constrains_link_destinations_on
+                                            },
                                             ..current_resolve_depth
                                         },
+                                        temporal_axes,
                                     )
                                     .await?;
                                 }
@@ -259,6 +269,165 @@ impl<C: AsClient> PostgresStore<C> {
                         }
                     }
                 }
+
+                if current_resolve_depth.inherits_from.incoming > 0
+                    || current_resolve_depth.constrains_links_on.incoming > 0
+                    || current_resolve_depth
+                        .constrains_link_destinations_on
+                        .incoming
+                        > 0
+                {
+                    for referencing_entity_type in Read::<EntityTypeWithMetadata>::read(
+                        self,
+                        &Filter::for_entity_types_by_entity_type(entity_type_id),
+                        temporal_axes,
+                    )
+                    .await?
+                    {
+                        let referencing_entity_type_id =
+                            referencing_entity_type.metadata().edition_id().clone();
+
+                        // The filter does not distinguish between the kinds of references, so
+                        // the kind is checked on the schema of the referencing entity type
+                        let is_referenced = |reference: &EntityTypeReference| {
+                            OntologyTypeEditionId::from(reference.uri()) == *entity_type_id
+                        };
+                        let schema = referencing_entity_type.inner();
+                        let edges = [
+                            (
+                                OntologyEdgeKind::InheritsFrom,
+                                current_resolve_depth.inherits_from.incoming > 0
+                                    && schema.inherits_from().all_of().iter().any(is_referenced),
+                                GraphResolveDepths {
+                                    inherits_from: EdgeResolveDepths {
+                                        incoming: current_resolve_depth
+                                            .inherits_from
+                                            .incoming
+                                            .saturating_sub(1),
+                                        ..current_resolve_depth.inherits_from
+                                    },
+                                    ..current_resolve_depth
+                                },
+                            ),
+                            (
+                                OntologyEdgeKind::ConstrainsLinksOn,
+                                current_resolve_depth.constrains_links_on.incoming > 0
+                                    && schema.link_mappings().into_keys().any(is_referenced),
+                                GraphResolveDepths {
+                                    constrains_links_on: EdgeResolveDepths {
+                                        incoming: current_resolve_depth
+                                            .constrains_links_on
+                                            .incoming
+                                            .saturating_sub(1),
+                                        ..current_resolve_depth.constrains_links_on
+                                    },
+                                    ..current_resolve_depth
+                                },
+                            ),
+                            (
+                                OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                                current_resolve_depth
+                                    .constrains_link_destinations_on
+                                    .incoming
+                                    > 0
+                                    && schema
+                                        .link_mappings()
+                                        .into_values()
+                                        .flatten()
+                                        .flatten()
+                                        .any(is_referenced),
+                                GraphResolveDepths {
+                                    constrains_link_destinations_on: EdgeResolveDepths {
+                                        incoming: current_resolve_depth
+                                            .constrains_link_destinations_on
+                                            .incoming
+                                            .saturating_sub(1),
+                                        ..current_resolve_depth.constrains_link_destinations_on
+                                    },
+                                    ..current_resolve_depth
+                                },
+                            ),
+                        ]
+                        .into_iter()
+                        .filter(|(_, is_resolved, _)| *is_resolved)
+                        .map(|(kind, _, resolve_depth)| (kind, resolve_depth))
+                        .collect::<Vec<_>>();
+
+                        if edges.is_empty() {
+                            continue;
+                        }
+
+                        // Insert the vertex into the subgraph to avoid another lookup when
+                        // traversing it
+                        subgraph.vertices.ontology.insert(
+                            referencing_entity_type_id.clone(),
+                            OntologyVertex::EntityType(Box::new(referencing_entity_type)),
+                        );
+
+                        for (kind, resolve_depth) in edges {
+                            subgraph.edges.insert(Edge::Ontology {
+                                edition_id: entity_type_id.clone(),
+                                outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                                    kind,
+                                    reversed: true,
+                                    right_endpoint: referencing_entity_type_id.clone(),
+                                }),
+                            });
+
+                            self.traverse_entity_type(
+                                &referencing_entity_type_id,
+                                dependency_context,
+                                subgraph,
+                                resolve_depth,
+                                temporal_axes,
+                            )
+                            .await?;
+                        }
+                    }
+                }
+
+                if current_resolve_depth.is_of_type.incoming > 0 {
+                    for entity in Read::<Entity>::read(
+                        self,
+                        &Filter::for_latest_entities_by_entity_type(entity_type_id),
+                        temporal_axes,
+                    )
+                    .await?
+                    {
+                        let entity_edition_id = entity.metadata().edition_id();
+
+                        subgraph.edges.insert(Edge::Ontology {
+                            edition_id: entity_type_id.clone(),
+                            outward_edge: OntologyOutwardEdges::ToKnowledgeGraph(OutwardEdge {
+                                kind: SharedEdgeKind::IsOfType,
+                                reversed: true,
+                                right_endpoint: entity_edition_id,
+                            }),
+                        });
+
+                        // Insert the vertex into the subgraph to avoid another lookup when
+                        // traversing it
+                        subgraph
+                            .vertices
+                            .knowledge_graph
+                            .insert(entity_edition_id, KnowledgeGraphVertex::Entity(entity));
+
+                        self.traverse_entity(
+                            entity_edition_id,
+                            dependency_context,
+                            subgraph,
+                            GraphResolveDepths {
+                                is_of_type: EdgeResolveDepths {
+                                    incoming: current_resolve_depth.is_of_type.incoming - 1,
+                                    ..current_resolve_depth.is_of_type
+                                },
+                                ..current_resolve_depth
+                            },
+                            temporal_axes,
+                        )
+                        .await?;
+                    }
+                }
             }
             Ok(())
         }
@@ -301,6 +470,13 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             })
             .attach_lazy(|| entity_type.clone())?;
 
+        transaction
+            .insert_change_event(
+                ChangeKind::Create,
+                &ChangedElement::EntityType(metadata.clone()),
+            )
+            .await?;
+
         transaction
             .client
             .commit()
@@ -318,13 +494,26 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
+            ref pagination,
+            ref temporal_axes,
         } = *query;
 
         let mut subgraph = Subgraph::new(graph_resolve_depths);
         let mut dependency_context = DependencyContext::default();
 
-        for entity_type in Read::<EntityTypeWithMetadata>::read(self, filter).await? {
+        let page = self
+            .read_ontology_roots::<EntityTypeWithMetadata>(filter, pagination.as_ref())
+            .await?;
+        subgraph.next_cursor = page.next_cursor;
+
+        for (entity_type, search_rank) in page.records {
             let entity_type_id = entity_type.metadata().edition_id().clone();
+            if let Some(search_rank) = search_rank {
+                subgraph.search_ranks.insert(
+                    GraphElementEditionId::Ontology(entity_type_id.clone()),
+                    search_rank,
+                );
+            }
 
             // Insert the vertex into the subgraph to avoid another lookup when traversing it
             subgraph.vertices.ontology.insert(
@@ -337,6 +526,7 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
                 &mut dependency_context,
                 &mut subgraph,
                 graph_resolve_depths,
+                temporal_axes,
             )
             .await?;
 
@@ -352,7 +542,8 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
         &mut self,
         entity_type: EntityType,
         updated_by: UpdatedById,
-    ) -> Result<OntologyElementMetadata, UpdateError> {
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
         let transaction = PostgresStore::new(
             self.as_mut_client()
                 .transaction()
@@ -364,7 +555,9 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
         // This clone is currently necessary because we extract the references as we insert them.
         // We can only insert them after the type has been created, and so we currently extract them
         // after as well. See `insert_entity_type_references` taking `&entity_type`
-        let (version_id, metadata) = transaction.update(entity_type.clone(), updated_by).await?;
+        let (version_id, metadata) = transaction
+            .update(entity_type.clone(), updated_by, compatibility_policy)
+            .await?;
 
         transaction
             .insert_entity_type_references(&entity_type, version_id)
@@ -378,6 +571,14 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             })
             .attach_lazy(|| entity_type.clone())?;
 
+        transaction
+            .insert_change_event(
+                ChangeKind::Update,
+                &ChangedElement::EntityType(metadata.metadata().clone()),
+            )
+            .await
+            .change_context(UpdateError)?;
+
         transaction
             .client
             .commit()
@@ -8,18 +8,22 @@ use type_system::{DataTypeReference, PropertyType, PropertyTypeReference};
 
 use crate::{
     identifier::{ontology::OntologyTypeEditionId, GraphElementEditionId},
-    ontology::{OntologyElementMetadata, PropertyTypeWithMetadata},
+    ontology::{
+        CompatibilityPolicy, EntityTypeWithMetadata, OntologyElementMetadata,
+        OntologyUpdateMetadata, PropertyTypeWithMetadata,
+    },
     provenance::{OwnedById, UpdatedById},
     store::{
         crud::Read,
         postgres::{DependencyContext, DependencyStatus},
-        query::Filter,
-        AsClient, InsertionError, PostgresStore, PropertyTypeStore, QueryError, UpdateError,
+        query::{Filter, TemporalAxes},
+        AsClient, ChangeKind, ChangedElement, InsertionError, PostgresStore, PropertyTypeStore,
+        QueryError, UpdateError,
     },
     subgraph::{
         edges::{
-            Edge, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
-            OutgoingEdgeResolveDepth, OutwardEdge,
+            Edge, EdgeResolveDepths, GraphResolveDepths, OntologyEdgeKind, OntologyOutwardEdges,
+            OutwardEdge,
         },
         query::StructuralQuery,
         vertices::OntologyVertex,
@@ -41,6 +45,7 @@ impl<C: AsClient> PostgresStore<C> {
         dependency_context: &'a mut DependencyContext,
         subgraph: &'a mut Subgraph,
         current_resolve_depth: GraphResolveDepths,
+        temporal_axes: &'a TemporalAxes,
     ) -> Pin<Box<dyn Future<Output = Result<(), QueryError>> + Send + 'a>> {
         async move {
             let dependency_status = dependency_context
@@ -62,6 +67,7 @@ impl<C: AsClient> PostgresStore<C> {
                             let property_type = Read::<PropertyTypeWithMetadata>::read_one(
                                 self,
                                 &Filter::for_ontology_type_edition_id(property_type_id),
+                                temporal_axes,
                             )
                             .await?;
                             Some(
@@ -119,13 +125,14 @@ impl<C: AsClient> PostgresStore<C> {
                             dependency_context,
                             subgraph,
                             GraphResolveDepths {
-                                constrains_values_on: OutgoingEdgeResolveDepth {
+                                constrains_values_on: EdgeResolveDepths {
                                     outgoing: current_resolve_depth.constrains_values_on.outgoing
                                         - 1,
                                     ..current_resolve_depth.constrains_values_on
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -147,7 +154,7 @@ impl<C: AsClient> PostgresStore<C> {
                             dependency_context,
                             subgraph,
                             GraphResolveDepths {
-                                constrains_properties_on: OutgoingEdgeResolveDepth {
+                                constrains_properties_on: EdgeResolveDepths {
                                     outgoing: current_resolve_depth
                                         .constrains_properties_on
                                         .outgoing
@@ -156,6 +163,88 @@ impl<C: AsClient> PostgresStore<C> {
                                 },
                                 ..current_resolve_depth
                             },
+                            temporal_axes,
+                        )
+                        .await?;
+                    }
+                }
+
+                if current_resolve_depth.constrains_properties_on.incoming > 0 {
+                    let incoming_resolve_depth = GraphResolveDepths {
+                        constrains_properties_on: EdgeResolveDepths {
+                            incoming: current_resolve_depth.constrains_properties_on.incoming - 1,
+                            ..current_resolve_depth.constrains_properties_on
+                        },
+                        ..current_resolve_depth
+                    };
+
+                    for referencing_property_type in Read::<PropertyTypeWithMetadata>::read(
+                        self,
+                        &Filter::for_property_types_by_property_type(property_type_id),
+                        temporal_axes,
+                    )
+                    .await?
+                    {
+                        let referencing_property_type_id =
+                            referencing_property_type.metadata().edition_id().clone();
+
+                        subgraph.edges.insert(Edge::Ontology {
+                            edition_id: property_type_id.clone(),
+                            outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                                kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                                reversed: true,
+                                right_endpoint: referencing_property_type_id.clone(),
+                            }),
+                        });
+
+                        // Insert the vertex into the subgraph to avoid another lookup when
+                        // traversing it
+                        subgraph.vertices.ontology.insert(
+                            referencing_property_type_id.clone(),
+                            OntologyVertex::PropertyType(Box::new(referencing_property_type)),
+                        );
+
+                        self.traverse_property_type(
+                            &referencing_property_type_id,
+                            dependency_context,
+                            subgraph,
+                            incoming_resolve_depth,
+                            temporal_axes,
+                        )
+                        .await?;
+                    }
+
+                    for entity_type in Read::<EntityTypeWithMetadata>::read(
+                        self,
+                        &Filter::for_entity_types_by_property_type(property_type_id),
+                        temporal_axes,
+                    )
+                    .await?
+                    {
+                        let entity_type_id = entity_type.metadata().edition_id().clone();
+
+                        subgraph.edges.insert(Edge::Ontology {
+                            edition_id: property_type_id.clone(),
+                            outward_edge: OntologyOutwardEdges::ToOntology(OutwardEdge {
+                                kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                                reversed: true,
+                                right_endpoint: entity_type_id.clone(),
+                            }),
+                        });
+
+                        // Insert the vertex into the subgraph to avoid another lookup when
+                        // traversing it
+                        subgraph.vertices.ontology.insert(
+                            entity_type_id.clone(),
+                            OntologyVertex::EntityType(Box::new(entity_type)),
+                        );
+
+                        self.traverse_entity_type(
+                            &entity_type_id,
+                            dependency_context,
+                            subgraph,
+                            incoming_resolve_depth,
+                            temporal_axes,
                         )
                         .await?;
                     }
@@ -203,6 +292,13 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
             })
             .attach_lazy(|| property_type.clone())?;
 
+        transaction
+            .insert_change_event(
+                ChangeKind::Create,
+                &ChangedElement::PropertyType(metadata.clone()),
+            )
+            .await?;
+
         transaction
             .client
             .commit()
@@ -220,13 +316,26 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
+            ref pagination,
+            ref temporal_axes,
         } = *query;
 
         let mut subgraph = Subgraph::new(graph_resolve_depths);
         let mut dependency_context = DependencyContext::default();
 
-        for property_type in Read::<PropertyTypeWithMetadata>::read(self, filter).await? {
+        let page = self
+            .read_ontology_roots::<PropertyTypeWithMetadata>(filter, pagination.as_ref())
+            .await?;
+        subgraph.next_cursor = page.next_cursor;
+
+        for (property_type, search_rank) in page.records {
             let property_type_id = property_type.metadata().edition_id().clone();
+            if let Some(search_rank) = search_rank {
+                subgraph.search_ranks.insert(
+                    GraphElementEditionId::Ontology(property_type_id.clone()),
+                    search_rank,
+                );
+            }
 
             // Insert the vertex into the subgraph to avoid another lookup when traversing it
             subgraph.vertices.ontology.insert(
@@ -239,6 +348,7 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
                 &mut dependency_context,
                 &mut subgraph,
                 graph_resolve_depths,
+                temporal_axes,
             )
             .await?;
 
@@ -254,7 +364,8 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
         &mut self,
         property_type: PropertyType,
         updated_by: UpdatedById,
-    ) -> Result<OntologyElementMetadata, UpdateError> {
+        compatibility_policy: CompatibilityPolicy,
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
         let transaction = PostgresStore::new(
             self.as_mut_client()
                 .transaction()
@@ -267,7 +378,7 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
         // We can only insert them after the type has been created, and so we currently extract them
         // after as well. See `insert_property_type_references` taking `&property_type`
         let (version_id, metadata) = transaction
-            .update(property_type.clone(), updated_by)
+            .update(property_type.clone(), updated_by, compatibility_policy)
             .await?;
 
         transaction
@@ -282,6 +393,14 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
             })
             .attach_lazy(|| property_type.clone())?;
 
+        transaction
+            .insert_change_event(
+                ChangeKind::Update,
+                &ChangedElement::PropertyType(metadata.metadata().clone()),
+            )
+            .await
+            .change_context(UpdateError)?;
+
         transaction
             .client
             .commit()
@@ -2,8 +2,9 @@ use std::str::FromStr;
 
 use async_trait::async_trait;
 use error_stack::{IntoReport, Result, ResultExt};
-use futures::{StreamExt, TryStreamExt};
-use tokio_postgres::GenericClient;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use tokio_postgres::{GenericClient, Row};
+use tracing::Instrument;
 use type_system::uri::VersionedUri;
 
 use crate::{
@@ -14,70 +15,294 @@ use crate::{
         crud::Read,
         postgres::{
             ontology::OntologyDatabaseType,
-            query::{Distinctness, PostgresQueryRecord, SelectCompiler},
+            portal::query_portal,
+            query::{statement_span, Distinctness, Ordering, PostgresQueryRecord, SelectCompiler},
         },
-        query::{Filter, OntologyPath},
+        query::{Filter, OntologyPath, Page, Pagination, TemporalAxes},
         AsClient, PostgresStore, QueryError,
     },
 };
 
-#[async_trait]
-impl<C: AsClient, T> Read<T> for PostgresStore<C>
+/// The paths to the columns of an ontology type.
+///
+/// They are selected by [`select_ontology_type`], but have to be defined outside of it as they are
+/// borrowed by the [`SelectCompiler`].
+fn ontology_type_paths<T>() -> [T::Path<'static>; 4]
+where
+    T: PostgresQueryRecord,
+    for<'q> T::Path<'q>: OntologyPath,
+{
+    [
+        <T::Path<'static> as OntologyPath>::versioned_uri(),
+        <T::Path<'static> as OntologyPath>::schema(),
+        <T::Path<'static> as OntologyPath>::owned_by_id(),
+        <T::Path<'static> as OntologyPath>::updated_by_id(),
+    ]
+}
+
+/// Adds the columns of an ontology type to the selection of `compiler`.
+///
+/// Returns a function, which converts the rows returned by the compiled statement to ontology
+/// types alongside their [`VersionedUri`]. If `ordering` is specified, the rows are ordered by
+/// their [`VersionedUri`].
+fn select_ontology_type<'c, T>(
+    compiler: &mut SelectCompiler<'c, '_, T>,
+    ontology_type_paths: &'c [T::Path<'static>; 4],
+    ordering: Option<Ordering>,
+) -> impl Fn(&Row) -> Result<(VersionedUri, T), QueryError> + Copy + Send + Sync
 where
     T: PersistedOntologyType + PostgresQueryRecord + Send,
     T::OntologyType: OntologyDatabaseType,
     for<'q> T::Path<'q>: Send + Sync + OntologyPath,
 {
-    async fn read(&self, filter: &Filter<T>) -> Result<Vec<T>, QueryError> {
-        let versioned_uri_path = <T::Path<'static> as OntologyPath>::versioned_uri();
-        let schema_path = <T::Path<'static> as OntologyPath>::schema();
-        let owned_by_id_path = <T::Path<'static> as OntologyPath>::owned_by_id();
-        let updated_by_id_path = <T::Path<'static> as OntologyPath>::updated_by_id();
+    let [versioned_uri_path, schema_path, owned_by_id_path, updated_by_id_path] =
+        ontology_type_paths;
+
+    let versioned_uri_index = compiler.add_distinct_selection_with_ordering(
+        versioned_uri_path,
+        Distinctness::Distinct,
+        ordering,
+    );
+    let schema_index = compiler.add_selection_path(schema_path);
+    let owned_by_id_index = compiler.add_selection_path(owned_by_id_path);
+    let updated_by_id_path_index = compiler.add_selection_path(updated_by_id_path);
+
+    move |row: &Row| {
+        let versioned_uri = VersionedUri::from_str(row.get(versioned_uri_index))
+            .into_report()
+            .change_context(QueryError)?;
+        let record_repr: <T::OntologyType as OntologyType>::Representation =
+            serde_json::from_value(row.get(schema_index))
+                .into_report()
+                .change_context(QueryError)?;
+        let record = T::OntologyType::try_from(record_repr)
+            .into_report()
+            .change_context(QueryError)?;
+        let owned_by_id = OwnedById::new(row.get(owned_by_id_index));
+        let updated_by_id = UpdatedById::new(row.get(updated_by_id_path_index));
+
+        let record = T::new(
+            record,
+            OntologyElementMetadata::new(
+                OntologyTypeEditionId::from(&versioned_uri),
+                ProvenanceMetadata::new(updated_by_id),
+                owned_by_id,
+            ),
+        );
+        Ok((versioned_uri, record))
+    }
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the ontology types matching `filter` alongside their [`VersionedUri`].
+    ///
+    /// If a `limit` is specified, the ontology types are ordered by their [`VersionedUri`] and
+    /// only the ontology types after `cursor` are returned. If `search_rank` is set, the full-text
+    /// search relevance is selected alongside the ontology types. The relevance is only available
+    /// if `filter` contains a search, see [`Filter::search_query`].
+    async fn read_ontology_types<T>(
+        &self,
+        filter: &Filter<'_, T>,
+        cursor: Option<String>,
+        limit: Option<usize>,
+        search_rank: bool,
+    ) -> Result<Vec<(VersionedUri, T, Option<f32>)>, QueryError>
+    where
+        T: PersistedOntologyType + PostgresQueryRecord + Send,
+        T::OntologyType: OntologyDatabaseType,
+        for<'q> T::Path<'q>: Send + Sync + OntologyPath,
+    {
+        let ontology_type_paths = ontology_type_paths::<T>();
 
         let mut compiler = SelectCompiler::new();
 
-        let versioned_uri_index = compiler.add_distinct_selection_with_ordering(
-            &versioned_uri_path,
-            Distinctness::Distinct,
-            None,
+        let ontology_type_from_row = select_ontology_type(
+            &mut compiler,
+            &ontology_type_paths,
+            limit.map(|_| Ordering::Ascending),
         );
-        let schema_index = compiler.add_selection_path(&schema_path);
-        let owned_by_id_index = compiler.add_selection_path(&owned_by_id_path);
-        let updated_by_id_path_index = compiler.add_selection_path(&updated_by_id_path);
+        let search_rank_index = filter
+            .search_query()
+            .filter(|_| search_rank)
+            .map(|query| compiler.add_search_rank_selection(query));
+
+        if let Some(cursor) = &cursor {
+            let [versioned_uri_path, ..] = &ontology_type_paths;
+            compiler.add_cursor(versioned_uri_path, cursor);
+        }
+        if let Some(limit) = limit {
+            compiler.set_limit(limit);
+        }
 
         compiler.add_filter(filter);
         let (statement, parameters) = compiler.compile();
 
         self.as_client()
             .query_raw(&statement, parameters.iter().copied())
+            .instrument(statement_span(&statement))
             .await
             .into_report()
             .change_context(QueryError)?
-            .map(|row| row.into_report().change_context(QueryError))
-            .and_then(|row| async move {
-                let versioned_uri = VersionedUri::from_str(row.get(versioned_uri_index))
-                    .into_report()
-                    .change_context(QueryError)?;
-                let record_repr: <T::OntologyType as OntologyType>::Representation =
-                    serde_json::from_value(row.get(schema_index))
-                        .into_report()
-                        .change_context(QueryError)?;
-                let record = T::OntologyType::try_from(record_repr)
-                    .into_report()
-                    .change_context(QueryError)?;
-                let owned_by_id = OwnedById::new(row.get(owned_by_id_index));
-                let updated_by_id = UpdatedById::new(row.get(updated_by_id_path_index));
-
-                Ok(T::new(
+            .map(|row| -> Result<_, QueryError> {
+                let row = row.into_report().change_context(QueryError)?;
+                let (versioned_uri, record) = ontology_type_from_row(&row)?;
+                Ok((
+                    versioned_uri,
                     record,
-                    OntologyElementMetadata::new(
-                        OntologyTypeEditionId::from(&versioned_uri),
-                        ProvenanceMetadata::new(updated_by_id),
-                        owned_by_id,
-                    ),
+                    search_rank_index.map(|index| row.get(index)),
                 ))
             })
             .try_collect()
             .await
     }
+
+    /// Reads a page of the ontology types matching `filter`.
+    ///
+    /// If `search_rank` is set, the full-text search relevance is read alongside the ontology
+    /// types.
+    async fn read_ontology_page<T>(
+        &self,
+        filter: &Filter<'_, T>,
+        pagination: &Pagination,
+        search_rank: bool,
+    ) -> Result<Page<(T, Option<f32>)>, QueryError>
+    where
+        T: PersistedOntologyType + PostgresQueryRecord + Send,
+        T::OntologyType: OntologyDatabaseType,
+        for<'q> T::Path<'q>: Send + Sync + OntologyPath,
+    {
+        let cursor = pagination
+            .after
+            .as_ref()
+            .map(|cursor| cursor.decode().change_context(QueryError))
+            .transpose()?;
+
+        let mut records = self
+            .read_ontology_types(
+                filter,
+                cursor,
+                Some(pagination.limit.saturating_add(1)),
+                search_rank,
+            )
+            .await?;
+        let next_cursor = pagination.next_cursor(&mut records, |(versioned_uri, ..)| {
+            versioned_uri.to_string()
+        });
+
+        Ok(Page {
+            records: records
+                .into_iter()
+                .map(|(_, record, search_rank)| (record, search_rank))
+                .collect(),
+            next_cursor,
+        })
+    }
+
+    /// Reads the roots of a structural query alongside their full-text search relevance.
+    ///
+    /// If `pagination` is specified, only a single page of ontology types is read. The relevance
+    /// is selected by the same statement, so it's only computed for the returned ontology types.
+    pub(super) async fn read_ontology_roots<T>(
+        &self,
+        filter: &Filter<'_, T>,
+        pagination: Option<&Pagination>,
+    ) -> Result<Page<(T, Option<f32>)>, QueryError>
+    where
+        T: PersistedOntologyType + PostgresQueryRecord + Send,
+        T::OntologyType: OntologyDatabaseType,
+        for<'q> T::Path<'q>: Send + Sync + OntologyPath,
+    {
+        match pagination {
+            Some(pagination) => self.read_ontology_page(filter, pagination, true).await,
+            None => Ok(Page {
+                records: self
+                    .read_ontology_types(filter, None, None, true)
+                    .await?
+                    .into_iter()
+                    .map(|(_, record, search_rank)| (record, search_rank))
+                    .collect(),
+                next_cursor: None,
+            }),
+        }
+    }
+
+    /// Streams the ontology types matching `filter`.
+    ///
+    /// The ontology types are fetched through a portal inside of a new transaction, which is kept
+    /// open until the returned stream is dropped.
+    async fn stream_ontology_types<T>(
+        &mut self,
+        filter: &Filter<'_, T>,
+    ) -> Result<BoxStream<'_, Result<T, QueryError>>, QueryError>
+    where
+        T: PersistedOntologyType + PostgresQueryRecord + Send + 'static,
+        T::OntologyType: OntologyDatabaseType,
+        for<'q> T::Path<'q>: Send + Sync + OntologyPath,
+    {
+        let ontology_type_paths = ontology_type_paths::<T>();
+
+        let mut compiler = SelectCompiler::new();
+
+        let ontology_type_from_row =
+            select_ontology_type(&mut compiler, &ontology_type_paths, None);
+
+        compiler.add_filter(filter);
+        let (statement, parameters) = compiler.compile();
+
+        let transaction = self
+            .as_mut_client()
+            .transaction()
+            .await
+            .into_report()
+            .change_context(QueryError)?;
+
+        Ok(query_portal(transaction, &statement, parameters)
+            .await?
+            .map(move |row| ontology_type_from_row(&row?).map(|(_, record)| record))
+            .boxed())
+    }
+}
+
+#[async_trait]
+impl<C: AsClient, T> Read<T> for PostgresStore<C>
+where
+    T: PersistedOntologyType + PostgresQueryRecord + Send + 'static,
+    T::OntologyType: OntologyDatabaseType,
+    for<'q> T::Path<'q>: Send + Sync + OntologyPath,
+{
+    // Ontology types are not versioned in time, so they are the same in every temporal slice.
+    async fn read(
+        &self,
+        filter: &Filter<T>,
+        _temporal_axes: &TemporalAxes,
+    ) -> Result<Vec<T>, QueryError> {
+        Ok(self
+            .read_ontology_types(filter, None, None, false)
+            .await?
+            .into_iter()
+            .map(|(_, record, _)| record)
+            .collect())
+    }
+
+    async fn read_stream<'s>(
+        &'s mut self,
+        filter: &Filter<T>,
+        _temporal_axes: &TemporalAxes,
+    ) -> Result<BoxStream<'s, Result<T, QueryError>>, QueryError> {
+        self.stream_ontology_types(filter).await
+    }
+
+    async fn read_page(
+        &self,
+        filter: &Filter<T>,
+        _temporal_axes: &TemporalAxes,
+        pagination: &Pagination,
+    ) -> Result<Page<T>, QueryError> {
+        let page = self.read_ontology_page(filter, pagination, false).await?;
+
+        Ok(Page {
+            records: page.records.into_iter().map(|(record, _)| record).collect(),
+            next_cursor: page.next_cursor,
+        })
+    }
 }
@@ -9,7 +9,7 @@ use tokio_postgres::{
     Client, Config, Error, GenericClient, Socket, Transaction,
 };
 
-use crate::store::{DatabaseConnectionInfo, PostgresStore, StoreError, StorePool};
+use crate::store::{DatabaseConnectionInfo, PostgresStore, StoreError, StorePool, StorePoolState};
 
 pub struct PostgresStorePool<Tls>
 where
@@ -86,6 +86,14 @@ where
     async fn acquire_owned(&self) -> Result<Self::Store<'static>, Self::Error> {
         Ok(PostgresStore::new(self.pool.get_owned().await?))
     }
+
+    fn state(&self) -> Option<StorePoolState> {
+        let state = self.pool.state();
+        Some(StorePoolState {
+            connections: state.connections,
+            idle_connections: state.idle_connections,
+        })
+    }
 }
 
 pub trait AsClient: Send + Sync {
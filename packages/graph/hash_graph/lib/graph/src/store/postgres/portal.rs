@@ -0,0 +1,58 @@
+use error_stack::{IntoReport, Result, ResultExt};
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
+use tokio_postgres::{types::ToSql, Portal, Row, Transaction};
+use tracing::Instrument;
+
+use crate::store::{postgres::query::statement_span, QueryError};
+
+/// The number of rows fetched from a [`Portal`] in a single round-trip.
+const PORTAL_BATCH_SIZE: i32 = 1000;
+
+/// Returns a [`Stream`] of the rows returned by `statement`.
+///
+/// The statement is bound to a [`Portal`] inside of `transaction`, from which the rows are fetched
+/// in batches of [`PORTAL_BATCH_SIZE`] as the stream is polled. The returned stream owns
+/// `transaction`, so the portal is closed when the stream is dropped. Binding the statement and
+/// fetching the batches is instrumented by the [`statement_span`] of `statement`.
+///
+/// [`Stream`]: futures::Stream
+pub async fn query_portal<'t>(
+    transaction: Transaction<'t>,
+    statement: &str,
+    parameters: &[&(dyn ToSql + Sync)],
+) -> Result<BoxStream<'t, Result<Row, QueryError>>, QueryError> {
+    let span = statement_span(statement);
+    let portal = transaction
+        .bind(statement, parameters)
+        .instrument(span.clone())
+        .await
+        .into_report()
+        .change_context(QueryError)?;
+
+    Ok(stream::try_unfold(
+        (transaction, portal, span),
+        |(transaction, portal, span)| async move {
+            let rows = transaction
+                .query_portal(&portal, PORTAL_BATCH_SIZE)
+                .instrument(span.clone())
+                .await
+                .into_report()
+                .change_context(QueryError);
+
+            // A portal, which has run to completion, returns no further rows.
+            rows.map(|rows| {
+                (!rows.is_empty()).then(|| {
+                    (
+                        stream::iter(rows.into_iter().map(Result::<Row, QueryError>::Ok)),
+                        (transaction, portal, span),
+                    )
+                })
+            })
+        },
+    )
+    .try_flatten()
+    .boxed())
+}
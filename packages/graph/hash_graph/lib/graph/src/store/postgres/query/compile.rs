@@ -5,14 +5,14 @@ use tokio_postgres::row::RowIndex;
 
 use crate::store::{
     postgres::query::{
-        expression::Constant,
+        expression::{Constant, PostgresType},
         table::{Entities, EntityTypes, JsonField, Relation, TypeIds},
         Alias, AliasedColumn, AliasedTable, Column, Condition, Distinctness, EqualityOperator,
         Expression, Function, JoinExpression, OrderByExpression, Ordering, Path,
         PostgresQueryRecord, SelectExpression, SelectStatement, Table, Transpile, WhereExpression,
         WindowStatement, WithExpression,
     },
-    query::{Filter, FilterExpression, Parameter},
+    query::{Aggregate, Filter, FilterExpression, Parameter, TemporalAxes},
 };
 
 // # Lifetime guidance
@@ -24,6 +24,9 @@ pub struct CompilerArtifacts<'p> {
     parameters: Vec<&'p (dyn ToSql + Sync)>,
     condition_index: usize,
     required_tables: HashSet<AliasedTable>,
+    temporal_axes: Option<&'p TemporalAxes>,
+    decision_time_index: Option<usize>,
+    transaction_time_index: Option<usize>,
 }
 
 pub struct SelectCompiler<'c, 'p, T> {
@@ -47,12 +50,17 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
                 }),
                 joins: Vec::new(),
                 where_expression: WhereExpression::default(),
+                group_by: Vec::new(),
                 order_by_expression: OrderByExpression::default(),
+                limit: None,
             },
             artifacts: CompilerArtifacts {
                 parameters: Vec::new(),
                 condition_index: 0,
                 required_tables: HashSet::new(),
+                temporal_axes: None,
+                decision_time_index: None,
+                transaction_time_index: None,
             },
             _marker: PhantomData,
         }
@@ -106,6 +114,97 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
         self.statement.selects.len() - 1
     }
 
+    /// Restricts the selection to rows, where `path` is greater than `cursor`.
+    ///
+    /// To be used for pagination, `path` should be a unique sort key of the selection, e.g. added
+    /// by [`add_distinct_selection_with_ordering`] with ascending [`Ordering`].
+    ///
+    /// [`add_distinct_selection_with_ordering`]: Self::add_distinct_selection_with_ordering
+    pub fn add_cursor(&mut self, path: &'c T::Path<'_>, cursor: &'p (dyn ToSql + Sync)) {
+        let column = path
+            .terminating_column()
+            .aliased(self.add_join_statements(path));
+        self.artifacts.parameters.push(cursor);
+        self.statement
+            .where_expression
+            .add_condition(Condition::Greater(
+                Expression::Column(column),
+                Expression::Parameter(self.artifacts.parameters.len()),
+            ));
+    }
+
+    /// Adds the relevance of the selected rows for the full-text search `query` to the selection.
+    pub fn add_search_rank_selection(
+        &mut self,
+        query: &'p Cow<'_, str>,
+    ) -> impl RowIndex + Display + Copy {
+        let vector = self.compile_search_vector();
+        self.artifacts.parameters.push(query);
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::TsRank(
+                Box::new(vector),
+                Box::new(Expression::Function(Function::WebSearchToTsQuery(
+                    Box::new(Expression::Parameter(self.artifacts.parameters.len())),
+                ))),
+            )),
+            Some(Cow::Borrowed("search_rank")),
+        ));
+        self.statement.selects.len() - 1
+    }
+
+    /// Groups the selection by `path` and adds its value to the selection.
+    ///
+    /// The value is selected as `jsonb`, so paths of different types can be read uniformly.
+    pub fn add_group_by_path<'f: 'p>(
+        &mut self,
+        path: &'p T::Path<'f>,
+    ) -> impl RowIndex + Display + Copy {
+        let column = self.compile_path_column(path);
+        self.statement.group_by.push(column);
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::ToJson(Box::new(Expression::Column(column)))),
+            None,
+        ));
+        self.statement.selects.len() - 1
+    }
+
+    /// Adds `aggregate` computed over each group of the selection to the selection.
+    ///
+    /// The selected value is a `bigint` for [`Aggregate::Count`] and a nullable `double precision`
+    /// otherwise.
+    pub fn add_aggregate<'f: 'p>(
+        &mut self,
+        aggregate: &'p Aggregate<'f, T>,
+    ) -> impl RowIndex + Display + Copy {
+        let function = match aggregate {
+            // Joined relations may result in multiple rows per record, so only distinct records
+            // are counted
+            Aggregate::Count => Function::CountDistinct(Box::new(Expression::Column(
+                T::record_id().aliased(self.statement.from.alias),
+            ))),
+            Aggregate::Min(path) => Function::Min(Box::new(self.compile_numeric_path(path))),
+            Aggregate::Max(path) => Function::Max(Box::new(self.compile_numeric_path(path))),
+            Aggregate::Sum(path) => Function::Sum(Box::new(self.compile_numeric_path(path))),
+        };
+        self.statement
+            .selects
+            .push(SelectExpression::new(Expression::Function(function), None));
+        self.statement.selects.len() - 1
+    }
+
+    /// Resolves the selection in the temporal slice specified by `temporal_axes`.
+    ///
+    /// This only affects records, which are versioned in time. If no slice is set, the decision
+    /// time is pinned to now and the transaction time is not restricted.
+    pub fn set_temporal_axes(&mut self, temporal_axes: &'p TemporalAxes) {
+        self.artifacts.temporal_axes = Some(temporal_axes);
+    }
+
+    /// Limits the number of returned rows.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.statement.limit = Some(limit);
+    }
+
     /// Adds a new filter to the selection.
     pub fn add_filter<'f: 'p>(&mut self, filter: &'p Filter<'f, T>) {
         let condition = self.compile_filter(filter);
@@ -153,6 +252,57 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
                 rhs.as_ref()
                     .map(|expression| self.compile_filter_expression(expression)),
             ),
+            Filter::Less(lhs, rhs) => Condition::Less(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::LessOrEqual(lhs, rhs) => Condition::LessOrEqual(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::Greater(lhs, rhs) => Condition::Greater(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::GreaterOrEqual(lhs, rhs) => Condition::GreaterOrEqual(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::In(lhs, rhs) => Condition::In(
+                self.compile_filter_expression(lhs),
+                rhs.iter()
+                    .map(|expression| self.compile_filter_expression(expression))
+                    .collect(),
+            ),
+            Filter::StartsWith(lhs, rhs) => Condition::StartsWith(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::EndsWith(lhs, rhs) => Condition::EndsWith(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::ContainsSegment(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_typed_filter_expression(lhs, rhs),
+                self.compile_typed_filter_expression(rhs, lhs),
+            ),
+            Filter::Exists(expression) => {
+                Condition::NotEqual(Some(self.compile_filter_expression(expression)), None)
+            }
+            Filter::Search(query) => {
+                if T::base_table() == Table::Entities {
+                    self.add_temporal_conditions(self.statement.from.alias);
+                }
+
+                let vector = self.compile_search_vector();
+                self.artifacts.parameters.push(query);
+                Condition::TextSearch(
+                    vector,
+                    Expression::Function(Function::WebSearchToTsQuery(Box::new(
+                        Expression::Parameter(self.artifacts.parameters.len()),
+                    ))),
+                )
+            }
         }
     }
 
@@ -194,7 +344,9 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
                 from: version_column.table(),
                 joins: vec![],
                 where_expression: WhereExpression::default(),
+                group_by: Vec::new(),
                 order_by_expression: OrderByExpression::default(),
+                limit: None,
             });
 
         let alias = self.add_join_statements(path);
@@ -220,15 +372,16 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
         operator: EqualityOperator,
     ) -> Condition<'c> {
         let alias = self.add_join_statements(path);
+        let decision_time = self.decision_time_expression();
         self.statement
             .where_expression
             .add_condition(Condition::RangeContains(
                 Expression::Column(Column::Entities(Entities::DecisionTime).aliased(alias)),
-                Expression::Function(Function::Now),
+                decision_time,
             ));
         let transaction_time_condition = Condition::RangeContains(
             Expression::Column(Column::Entities(Entities::TransactionTime).aliased(alias)),
-            Expression::Function(Function::Now),
+            self.transaction_time_expression(),
         );
 
         match operator {
@@ -298,18 +451,183 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
 
         let alias = self.add_join_statements(path);
 
-        // TODO: Remove special casing when adjusting structural queries
-        //   see https://app.asana.com/0/0/1203491211535116/f
         if matches!(column, Column::Entities(_)) {
+            self.add_temporal_conditions(alias);
+        }
+
+        column.aliased(alias)
+    }
+
+    /// Restricts the entities aliased by `alias` to the temporal slice of the selection.
+    fn add_temporal_conditions(&mut self, alias: Alias) {
+        let decision_time = self.decision_time_expression();
+        self.statement
+            .where_expression
+            .add_condition(Condition::RangeContains(
+                Expression::Column(Column::Entities(Entities::DecisionTime).aliased(alias)),
+                decision_time,
+            ));
+
+        if self.artifacts.temporal_axes.map_or(false, |temporal_axes| {
+            temporal_axes.transaction_time.is_some()
+        }) {
+            let transaction_time = self.transaction_time_expression();
             self.statement
                 .where_expression
                 .add_condition(Condition::RangeContains(
-                    Expression::Column(Column::Entities(Entities::DecisionTime).aliased(alias)),
-                    Expression::Function(Function::Now),
+                    Expression::Column(Column::Entities(Entities::TransactionTime).aliased(alias)),
+                    transaction_time,
                 ));
         }
+    }
 
-        column.aliased(alias)
+    /// Returns the point in decision time the selection is resolved at, defaults to `now()`.
+    ///
+    /// The parameter is only added once it's used as Postgres rejects unused parameters.
+    fn decision_time_expression(&mut self) -> Expression<'c> {
+        let Some(decision_time) = self
+            .artifacts
+            .temporal_axes
+            .and_then(|temporal_axes| temporal_axes.decision_time.as_ref())
+        else {
+            return Expression::Function(Function::Now);
+        };
+
+        let parameters = &mut self.artifacts.parameters;
+        Expression::Parameter(*self.artifacts.decision_time_index.get_or_insert_with(|| {
+            parameters.push(decision_time);
+            parameters.len()
+        }))
+    }
+
+    /// Returns the point in transaction time the selection is resolved at, defaults to `now()`.
+    ///
+    /// The parameter is only added once it's used as Postgres rejects unused parameters.
+    fn transaction_time_expression(&mut self) -> Expression<'c> {
+        let Some(transaction_time) = self
+            .artifacts
+            .temporal_axes
+            .and_then(|temporal_axes| temporal_axes.transaction_time.as_ref())
+        else {
+            return Expression::Function(Function::Now);
+        };
+
+        let parameters = &mut self.artifacts.parameters;
+        Expression::Parameter(
+            *self
+                .artifacts
+                .transaction_time_index
+                .get_or_insert_with(|| {
+                    parameters.push(transaction_time);
+                    parameters.len()
+                }),
+        )
+    }
+
+    /// Compiles the value of `path` as number to be used in an aggregate function.
+    ///
+    /// Property values, which are not numbers, are ignored by the aggregate function. Other paths
+    /// are cast to a number, so they are expected to be validated by [`Aggregate::validate`].
+    fn compile_numeric_path(&mut self, path: &'p T::Path<'_>) -> Expression<'c> {
+        if let Column::Entities(Entities::Properties(Some(JsonField::Text(field)))) =
+            path.terminating_column()
+        {
+            return self.compile_typed_property(path, field, Some(PostgresType::DoublePrecision));
+        }
+
+        Expression::Cast(
+            Box::new(Expression::Column(self.compile_path_column(path))),
+            PostgresType::DoublePrecision,
+        )
+    }
+
+    /// Compiles the search vector of the base table.
+    fn compile_search_vector(&self) -> Expression<'c> {
+        Expression::Column(T::search_vector().aliased(self.statement.from.alias))
+    }
+
+    /// Compiles `expression`, which is compared with `other` by an ordering or a string operation.
+    ///
+    /// A property does not have a fixed type, so if it's compared with a parameter, only values of
+    /// the same type as the parameter are compared, e.g. numbers are compared numerically with a
+    /// number parameter. Values of a different type evaluate to `NULL` and don't match.
+    fn compile_typed_filter_expression<'f: 'p>(
+        &mut self,
+        expression: &'p FilterExpression<'f, T>,
+        other: &'p FilterExpression<'f, T>,
+    ) -> Expression<'c> {
+        let (FilterExpression::Path(path), FilterExpression::Parameter(parameter)) =
+            (expression, other)
+        else {
+            return self.compile_filter_expression(expression);
+        };
+        let Column::Entities(Entities::Properties(Some(JsonField::Text(field)))) =
+            path.terminating_column()
+        else {
+            return self.compile_filter_expression(expression);
+        };
+
+        // Text values are compared as text, other values are cast to the type of the parameter
+        let r#type = match parameter {
+            Parameter::Number(_) => Some(PostgresType::DoublePrecision),
+            Parameter::Boolean(_) => Some(PostgresType::Boolean),
+            Parameter::Text(_)
+            | Parameter::Uuid(_)
+            | Parameter::SignedInteger(_)
+            | Parameter::Timestamp(_) => None,
+        };
+        self.compile_typed_property(path, field, r#type)
+    }
+
+    /// Compiles the value of the property `field` at `path` as `type`, or as text if no type is
+    /// specified.
+    ///
+    /// Values, which are not of the corresponding JSON type, evaluate to `NULL`, so they are
+    /// neither matched by filters nor taken into account by aggregate functions.
+    fn compile_typed_property(
+        &mut self,
+        path: &'p T::Path<'_>,
+        field: &'p Cow<'p, str>,
+        r#type: Option<PostgresType>,
+    ) -> Expression<'c> {
+        self.artifacts.parameters.push(field);
+        let field_index = self.artifacts.parameters.len();
+        let alias = self.add_join_statements(path);
+        self.add_temporal_conditions(alias);
+
+        let json_value = || {
+            Expression::Function(Function::JsonExtractPath(vec![
+                Expression::Column(Column::Entities(Entities::Properties(None)).aliased(alias)),
+                Expression::Parameter(field_index),
+            ]))
+        };
+        let (json_type, value) = match r#type {
+            Some(r#type @ PostgresType::DoublePrecision) => {
+                ("number", Expression::Cast(Box::new(json_value()), r#type))
+            }
+            Some(r#type @ PostgresType::Boolean) => {
+                ("boolean", Expression::Cast(Box::new(json_value()), r#type))
+            }
+            None => (
+                "string",
+                Expression::Column(
+                    Column::Entities(Entities::Properties(Some(JsonField::Parameter(
+                        field_index,
+                    ))))
+                    .aliased(alias),
+                ),
+            ),
+        };
+
+        Expression::CaseWhen(
+            Box::new(Condition::Equal(
+                Some(Expression::Function(Function::JsonTypeof(Box::new(
+                    json_value(),
+                )))),
+                Some(Expression::Constant(Constant::String(json_type))),
+            )),
+            Box::new(value),
+        )
     }
 
     pub fn compile_filter_expression<'f: 'p>(
@@ -471,17 +789,8 @@ impl<'c, 'p: 'c, T: PostgresQueryRecord> SelectCompiler<'c, 'p, T> {
                     current_table = join_expression.join.table();
                     self.statement.joins.push(join_expression);
 
-                    // TODO: Remove special casing when adjusting structural queries
-                    //   see https://app.asana.com/0/0/1203491211535116/f
                     if matches!(current_column.column, Column::Entities(_)) {
-                        self.statement
-                            .where_expression
-                            .add_condition(Condition::RangeContains(
-                                Expression::Column(
-                                    Column::Entities(Entities::DecisionTime).aliased(current_alias),
-                                ),
-                                Expression::Function(Function::Now),
-                            ));
+                        self.add_temporal_conditions(current_alias);
                     }
                 }
             }
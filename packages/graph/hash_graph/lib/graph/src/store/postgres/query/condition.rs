@@ -13,6 +13,15 @@ pub enum Condition<'p> {
     Equal(Option<Expression<'p>>, Option<Expression<'p>>),
     NotEqual(Option<Expression<'p>>, Option<Expression<'p>>),
     RangeContains(Expression<'p>, Expression<'p>),
+    Less(Expression<'p>, Expression<'p>),
+    LessOrEqual(Expression<'p>, Expression<'p>),
+    Greater(Expression<'p>, Expression<'p>),
+    GreaterOrEqual(Expression<'p>, Expression<'p>),
+    In(Expression<'p>, Vec<Expression<'p>>),
+    StartsWith(Expression<'p>, Expression<'p>),
+    EndsWith(Expression<'p>, Expression<'p>),
+    ContainsSegment(Expression<'p>, Expression<'p>),
+    TextSearch(Expression<'p>, Expression<'p>),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -82,6 +91,66 @@ impl Transpile for Condition<'_> {
                 fmt.write_str(" @> ")?;
                 rhs.transpile(fmt)
             }
+            Condition::Less(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" < ")?;
+                rhs.transpile(fmt)
+            }
+            Condition::LessOrEqual(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" <= ")?;
+                rhs.transpile(fmt)
+            }
+            Condition::Greater(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" > ")?;
+                rhs.transpile(fmt)
+            }
+            Condition::GreaterOrEqual(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" >= ")?;
+                rhs.transpile(fmt)
+            }
+            Condition::In(_, values) if values.is_empty() => fmt.write_str("FALSE"),
+            Condition::In(lhs, values) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" IN (")?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    value.transpile(fmt)?;
+                }
+                fmt.write_char(')')
+            }
+            // `LIKE` is avoided for string operations as it would require escaping the pattern
+            Condition::StartsWith(lhs, rhs) => {
+                fmt.write_str("starts_with(")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Condition::EndsWith(lhs, rhs) => {
+                fmt.write_str("right(")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(", length(")?;
+                rhs.transpile(fmt)?;
+                fmt.write_str(")) = ")?;
+                rhs.transpile(fmt)
+            }
+            Condition::ContainsSegment(lhs, rhs) => {
+                fmt.write_str("strpos(")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_str(") > 0")
+            }
+            Condition::TextSearch(vector, query) => {
+                vector.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                query.transpile(fmt)
+            }
         }
     }
 }
@@ -266,4 +335,88 @@ mod tests {
             &[],
         );
     }
+
+    #[test]
+    fn transpile_comparison_condition() {
+        test_condition(
+            &Filter::All(vec![
+                Filter::Greater(
+                    FilterExpression::Path(DataTypeQueryPath::Version),
+                    FilterExpression::Parameter(Parameter::SignedInteger(1)),
+                ),
+                Filter::LessOrEqual(
+                    FilterExpression::Path(DataTypeQueryPath::Version),
+                    FilterExpression::Parameter(Parameter::SignedInteger(3)),
+                ),
+            ]),
+            r#"("type_ids_0_1_0"."version" > $1) AND ("type_ids_0_1_0"."version" <= $2)"#,
+            &[&1_i64, &3_i64],
+        );
+    }
+
+    #[test]
+    fn transpile_in_condition() {
+        test_condition(
+            &Filter::In(FilterExpression::Path(DataTypeQueryPath::Title), vec![
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Text"))),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Number"))),
+            ]),
+            r#""data_types_0_0_0"."schema"->>'title' IN ($1, $2)"#,
+            &[&"Text", &"Number"],
+        );
+
+        test_condition(
+            &Filter::In(FilterExpression::Path(DataTypeQueryPath::Title), vec![]),
+            "FALSE",
+            &[],
+        );
+    }
+
+    #[test]
+    fn transpile_string_condition() {
+        test_condition(
+            &Filter::StartsWith(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Te"))),
+            ),
+            r#"starts_with("data_types_0_0_0"."schema"->>'title', $1)"#,
+            &[&"Te"],
+        );
+
+        test_condition(
+            &Filter::EndsWith(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("xt"))),
+            ),
+            r#"right("data_types_0_0_0"."schema"->>'title', length($1)) = $1"#,
+            &[&"xt"],
+        );
+
+        test_condition(
+            &Filter::ContainsSegment(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("ex"))),
+            ),
+            r#"strpos("data_types_0_0_0"."schema"->>'title', $1) > 0"#,
+            &[&"ex"],
+        );
+    }
+
+    #[test]
+    fn transpile_exists_condition() {
+        test_condition(
+            &Filter::Exists(FilterExpression::Path(DataTypeQueryPath::Description)),
+            r#""data_types_0_0_0"."schema"->>'description' IS NOT NULL"#,
+            &[],
+        );
+    }
+
+    #[test]
+    fn transpile_search_condition() {
+        test_condition(
+            &Filter::Search(Cow::Borrowed("text")),
+            r#""data_types_0_0_0"."search_vector" @@ websearch_to_tsquery('english', $1)"#,
+            &[&"text"],
+        );
+    }
 }
@@ -12,6 +12,14 @@ impl PostgresQueryRecord for DataTypeWithMetadata {
     fn base_table() -> Table {
         Table::DataTypes
     }
+
+    fn search_vector() -> Column<'static> {
+        Column::DataTypes(DataTypes::SearchVector)
+    }
+
+    fn record_id() -> Column<'static> {
+        Column::DataTypes(DataTypes::VersionId)
+    }
 }
 
 impl Path for DataTypeQueryPath {
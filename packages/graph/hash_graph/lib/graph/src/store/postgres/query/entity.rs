@@ -12,6 +12,14 @@ impl PostgresQueryRecord for Entity {
     fn base_table() -> Table {
         Table::Entities
     }
+
+    fn search_vector() -> Column<'static> {
+        Column::Entities(Entities::SearchVector)
+    }
+
+    fn record_id() -> Column<'static> {
+        Column::Entities(Entities::RecordId)
+    }
 }
 
 impl Path for EntityQueryPath<'_> {
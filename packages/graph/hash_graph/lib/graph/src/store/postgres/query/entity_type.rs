@@ -12,6 +12,14 @@ impl PostgresQueryRecord for EntityTypeWithMetadata {
     fn base_table() -> Table {
         Table::EntityTypes
     }
+
+    fn search_vector() -> Column<'static> {
+        Column::EntityTypes(EntityTypes::SearchVector)
+    }
+
+    fn record_id() -> Column<'static> {
+        Column::EntityTypes(EntityTypes::VersionId)
+    }
 }
 
 impl Path for EntityTypeQueryPath {
@@ -1,18 +1,29 @@
 use std::fmt::{self, Write};
 
-use crate::store::postgres::query::{AliasedColumn, Transpile, WindowStatement};
+use crate::store::postgres::query::{AliasedColumn, Condition, Transpile, WindowStatement};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum Function<'q> {
     Min(Box<Expression<'q>>),
     Max(Box<Expression<'q>>),
+    CountDistinct(Box<Expression<'q>>),
+    Sum(Box<Expression<'q>>),
+    /// Converts any value to `jsonb`.
+    ToJson(Box<Expression<'q>>),
     JsonExtractPath(Vec<Expression<'q>>),
     JsonContains(Box<Expression<'q>>, Box<Expression<'q>>),
     JsonBuildArray(Vec<Expression<'q>>),
     JsonBuildObject(Vec<(Expression<'q>, Expression<'q>)>),
+    /// Returns the type of a `jsonb` value as text, e.g. `number` or `string`.
+    JsonTypeof(Box<Expression<'q>>),
     Lower(Box<Expression<'q>>),
     Upper(Box<Expression<'q>>),
     Now,
+    /// Converts a search query in web search syntax to a `tsquery`.
+    ///
+    /// The used text search configuration has to match the one used for the search vectors.
+    WebSearchToTsQuery(Box<Expression<'q>>),
+    TsRank(Box<Expression<'q>>, Box<Expression<'q>>),
 }
 
 impl Transpile for Function<'_> {
@@ -28,6 +39,21 @@ impl Transpile for Function<'_> {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::CountDistinct(expression) => {
+                fmt.write_str("COUNT(DISTINCT ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Sum(expression) => {
+                fmt.write_str("SUM(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::ToJson(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonExtractPath(paths) => {
                 fmt.write_str("jsonb_extract_path(")?;
                 for (i, expression) in paths.iter().enumerate() {
@@ -67,6 +93,11 @@ impl Transpile for Function<'_> {
                 }
                 fmt.write_char(')')
             }
+            Self::JsonTypeof(expression) => {
+                fmt.write_str("jsonb_typeof(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::Now => fmt.write_str("now()"),
             Self::Lower(expression) => {
                 fmt.write_str("lower(")?;
@@ -78,6 +109,18 @@ impl Transpile for Function<'_> {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::WebSearchToTsQuery(query) => {
+                fmt.write_str("websearch_to_tsquery('english', ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::TsRank(vector, query) => {
+                fmt.write_str("ts_rank(")?;
+                vector.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -97,6 +140,22 @@ impl Transpile for Constant {
     }
 }
 
+/// A type, to which an [`Expression`] can be cast.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PostgresType {
+    Boolean,
+    DoublePrecision,
+}
+
+impl Transpile for PostgresType {
+    fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Boolean => fmt.write_str("boolean"),
+            Self::DoublePrecision => fmt.write_str("double precision"),
+        }
+    }
+}
+
 /// A compiled expression in Postgres.
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum Expression<'q> {
@@ -109,6 +168,9 @@ pub enum Expression<'q> {
     Constant(Constant),
     Function(Function<'q>),
     Window(Box<Self>, WindowStatement<'q>),
+    Cast(Box<Self>, PostgresType),
+    /// Evaluates to the expression if the condition holds and to `NULL` otherwise.
+    CaseWhen(Box<Condition<'q>>, Box<Self>),
 }
 
 impl Transpile for Expression<'_> {
@@ -125,6 +187,20 @@ impl Transpile for Expression<'_> {
                 window.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Cast(expression, r#type) => {
+                fmt.write_str("CAST(")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(" AS ")?;
+                r#type.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::CaseWhen(condition, expression) => {
+                fmt.write_str("CASE WHEN ")?;
+                condition.transpile(fmt)?;
+                fmt.write_str(" THEN ")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(" END")
+            }
         }
     }
 }
@@ -170,4 +246,24 @@ mod tests {
             r#"MIN("type_ids_1_2_3"."version")"#
         );
     }
+
+    #[test]
+    fn transpile_cast_expression() {
+        assert_eq!(
+            Expression::Function(Function::Sum(Box::new(Expression::Cast(
+                Box::new(Expression::Column(
+                    DataTypeQueryPath::Version
+                        .terminating_column()
+                        .aliased(Alias {
+                            condition_index: 1,
+                            chain_depth: 2,
+                            number: 3
+                        })
+                )),
+                PostgresType::DoublePrecision
+            ))))
+            .transpile_to_string(),
+            r#"SUM(CAST("type_ids_1_2_3"."version" AS double precision))"#
+        );
+    }
 }
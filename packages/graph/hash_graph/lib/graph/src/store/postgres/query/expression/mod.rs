@@ -6,7 +6,7 @@ mod where_clause;
 mod with_clause;
 
 pub use self::{
-    conditional::{Constant, Expression, Function},
+    conditional::{Constant, Expression, Function, PostgresType},
     join_clause::JoinExpression,
     order_clause::{OrderByExpression, Ordering},
     select_clause::SelectExpression,
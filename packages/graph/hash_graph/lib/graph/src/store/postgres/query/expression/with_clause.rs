@@ -84,7 +84,9 @@ mod tests {
             }),
             joins: vec![],
             where_expression: WhereExpression::default(),
+            group_by: Vec::new(),
             order_by_expression: OrderByExpression::default(),
+            limit: None,
         });
 
         assert_eq!(
@@ -106,7 +108,9 @@ mod tests {
             }),
             joins: vec![],
             where_expression: WhereExpression::default(),
+            group_by: Vec::new(),
             order_by_expression: OrderByExpression::default(),
+            limit: None,
         });
 
         assert_eq!(
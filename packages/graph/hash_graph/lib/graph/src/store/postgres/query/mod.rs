@@ -32,6 +32,16 @@ use crate::store::{
 pub trait PostgresQueryRecord: for<'q> QueryRecord<Path<'q>: Path> {
     /// The [`Table`] used for this `Query`.
     fn base_table() -> Table;
+
+    /// The [`Column`] of the [`base_table`] containing the full-text search vector.
+    ///
+    /// [`base_table`]: Self::base_table
+    fn search_vector() -> Column<'static>;
+
+    /// The [`Column`] of the [`base_table`] uniquely identifying a record.
+    ///
+    /// [`base_table`]: Self::base_table
+    fn record_id() -> Column<'static>;
 }
 
 /// An absolute path inside of a query pointing to an attribute.
@@ -43,6 +53,19 @@ pub trait Path {
     fn terminating_column(&self) -> Column;
 }
 
+/// Returns the span in which a statement compiled by the [`SelectCompiler`] is executed.
+///
+/// The span is annotated following the OpenTelemetry conventions for database clients, so
+/// exported traces show the statement as a child of the request it was executed for.
+pub fn statement_span(statement: &str) -> tracing::Span {
+    tracing::info_span!(
+        "SELECT",
+        otel.kind = "client",
+        db.system = "postgresql",
+        db.statement = statement,
+    )
+}
+
 /// Renders the object into a Postgres compatible format.
 pub trait Transpile {
     /// Renders the value using the given [`Formatter`].
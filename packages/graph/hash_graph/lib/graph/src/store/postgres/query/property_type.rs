@@ -12,6 +12,14 @@ impl PostgresQueryRecord for PropertyTypeWithMetadata {
     fn base_table() -> Table {
         Table::PropertyTypes
     }
+
+    fn search_vector() -> Column<'static> {
+        Column::PropertyTypes(PropertyTypes::SearchVector)
+    }
+
+    fn record_id() -> Column<'static> {
+        Column::PropertyTypes(PropertyTypes::VersionId)
+    }
 }
 
 impl Path for PropertyTypeQueryPath {
@@ -13,7 +13,9 @@ pub struct SelectStatement<'q> {
     pub from: AliasedTable,
     pub joins: Vec<JoinExpression<'q>>,
     pub where_expression: WhereExpression<'q>,
+    pub group_by: Vec<AliasedColumn<'q>>,
     pub order_by_expression: OrderByExpression<'q>,
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -64,11 +66,26 @@ impl Transpile for SelectStatement<'_> {
             self.where_expression.transpile(fmt)?;
         }
 
+        if !self.group_by.is_empty() {
+            fmt.write_str("\nGROUP BY ")?;
+
+            for (idx, column) in self.group_by.iter().enumerate() {
+                if idx > 0 {
+                    fmt.write_str(", ")?;
+                }
+                column.transpile(fmt)?;
+            }
+        }
+
         if !self.order_by_expression.is_empty() {
             fmt.write_char('\n')?;
             self.order_by_expression.transpile(fmt)?;
         }
 
+        if let Some(limit) = self.limit {
+            write!(fmt, "\nLIMIT {limit}")?;
+        }
+
         Ok(())
     }
 }
@@ -81,6 +98,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
+        identifier::{DecisionTimestamp, TransactionTimestamp},
         knowledge::{Entity, EntityQueryPath},
         ontology::{
             DataTypeQueryPath, DataTypeWithMetadata, EntityTypeQueryPath, EntityTypeWithMetadata,
@@ -91,7 +109,7 @@ mod tests {
                 test_helper::trim_whitespace, Distinctness, Ordering, PostgresQueryRecord,
                 SelectCompiler,
             },
-            query::{Filter, FilterExpression, Parameter},
+            query::{Aggregate, Filter, FilterExpression, Parameter, TemporalAxes},
         },
     };
 
@@ -517,6 +535,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_with_cursor() {
+        let mut compiler = SelectCompiler::<Entity>::new();
+        compiler.add_distinct_selection_with_ordering(
+            &EntityQueryPath::RecordId,
+            Distinctness::Distinct,
+            Some(Ordering::Ascending),
+        );
+        let cursor = 10_i64;
+        compiler.add_cursor(&EntityQueryPath::RecordId, &cursor);
+        compiler.set_limit(5);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                DISTINCT ON("entities_0_0_0"."entity_record_id")
+                "entities_0_0_0"."entity_record_id"
+            FROM "entities" AS "entities_0_0_0"
+            WHERE "entities_0_0_0"."entity_record_id" > $1
+            ORDER BY "entities_0_0_0"."entity_record_id" ASC
+            LIMIT 5
+            "#,
+            &[&10_i64],
+        );
+    }
+
+    #[test]
+    fn entity_search_query() {
+        let mut compiler = SelectCompiler::<Entity>::new();
+        compiler.add_selection_path(&EntityQueryPath::RecordId);
+        let query = Cow::Borrowed("time machine");
+        compiler.add_search_rank_selection(&query);
+
+        let filter = Filter::Search(Cow::Borrowed("time machine"));
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                "entities_0_0_0"."entity_record_id",
+                ts_rank("entities_0_0_0"."search_vector", websearch_to_tsquery('english', $1)) AS "search_rank"
+            FROM "entities" AS "entities_0_0_0"
+            WHERE "entities_0_0_0"."decision_time" @> now()
+              AND "entities_0_0_0"."search_vector" @@ websearch_to_tsquery('english', $2)
+            "#,
+            &[&"time machine", &"time machine"],
+        );
+    }
+
     #[test]
     fn entity_property_query() {
         let mut compiler = SelectCompiler::<Entity>::with_asterisk();
@@ -570,6 +639,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_property_ordering_query() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk();
+
+        let filter = Filter::Less(
+            FilterExpression::Path(EntityQueryPath::Properties(Some(Cow::Borrowed(
+                "https://blockprotocol.org/@alice/types/property-type/age/",
+            )))),
+            FilterExpression::Parameter(Parameter::Number(30.0)),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entities" AS "entities_0_0_0"
+            WHERE "entities_0_0_0"."decision_time" @> now()
+              AND CASE WHEN jsonb_typeof(jsonb_extract_path("entities_0_0_0"."properties", $1)) = 'number'
+                  THEN CAST(jsonb_extract_path("entities_0_0_0"."properties", $1) AS double precision)
+                  END < $2
+            "#,
+            &[
+                &"https://blockprotocol.org/@alice/types/property-type/age/",
+                &30.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_aggregation() {
+        let mut compiler = SelectCompiler::<Entity>::new();
+
+        let group_by = EntityQueryPath::OwnedById;
+        compiler.add_group_by_path(&group_by);
+        let aggregates = [
+            Aggregate::Count,
+            Aggregate::Sum(EntityQueryPath::Properties(Some(Cow::Borrowed(
+                "https://blockprotocol.org/@alice/types/property-type/age/",
+            )))),
+        ];
+        for aggregate in &aggregates {
+            compiler.add_aggregate(aggregate);
+        }
+
+        let filter = Filter::for_all_latest_entities();
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                to_jsonb("entities_0_0_0"."owned_by_id"),
+                COUNT(DISTINCT "entities_0_0_0"."entity_record_id"),
+                SUM(CASE WHEN jsonb_typeof(jsonb_extract_path("entities_0_0_0"."properties", $1)) = 'number'
+                    THEN CAST(jsonb_extract_path("entities_0_0_0"."properties", $1) AS double precision)
+                    END)
+            FROM "entities" AS "entities_0_0_0"
+            WHERE "entities_0_0_0"."decision_time" @> now()
+              AND "entities_0_0_0"."transaction_time" @> now()
+            GROUP BY "entities_0_0_0"."owned_by_id"
+            "#,
+            &[&"https://blockprotocol.org/@alice/types/property-type/age/"],
+        );
+    }
+
     #[test]
     fn entity_outgoing_link_query() {
         let mut compiler = SelectCompiler::<Entity>::with_asterisk();
@@ -638,6 +773,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_with_transaction_time() {
+        let transaction_time: TransactionTimestamp = "2022-12-01T12:00:00Z"
+            .parse()
+            .expect("could not parse timestamp");
+        let temporal_axes = TemporalAxes {
+            transaction_time: Some(transaction_time),
+            decision_time: None,
+        };
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk();
+        compiler.set_temporal_axes(&temporal_axes);
+
+        let filter = Filter::Equal(
+            Some(FilterExpression::Path(EntityQueryPath::Uuid)),
+            Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                "12345678-ABCD-4321-5678-ABCD5555DCBA",
+            )))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entities" AS "entities_0_0_0"
+            WHERE "entities_0_0_0"."decision_time" @> now()
+              AND "entities_0_0_0"."transaction_time" @> $1
+              AND "entities_0_0_0"."entity_uuid" = $2
+            "#,
+            &[&transaction_time, &"12345678-ABCD-4321-5678-ABCD5555DCBA"],
+        );
+    }
+
+    #[test]
+    fn entity_outgoing_link_query_with_temporal_axes() {
+        let transaction_time: TransactionTimestamp = "2022-12-01T12:00:00Z"
+            .parse()
+            .expect("could not parse timestamp");
+        let decision_time: DecisionTimestamp = "2022-11-01T12:00:00Z"
+            .parse()
+            .expect("could not parse timestamp");
+        let temporal_axes = TemporalAxes {
+            transaction_time: Some(transaction_time),
+            decision_time: Some(decision_time),
+        };
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk();
+        compiler.set_temporal_axes(&temporal_axes);
+
+        let filter = Filter::Equal(
+            Some(FilterExpression::Path(EntityQueryPath::OutgoingLinks(
+                Box::new(EntityQueryPath::RightEntity(Box::new(
+                    EntityQueryPath::LowerTransactionTime,
+                ))),
+            ))),
+            Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                "latest",
+            )))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entities" AS "entities_0_0_0"
+            LEFT OUTER JOIN "entities" AS "entities_0_1_0"
+              ON "entities_0_1_0"."left_entity_uuid" = "entities_0_0_0"."entity_uuid"
+            RIGHT OUTER JOIN "entities" AS "entities_0_2_0"
+              ON "entities_0_2_0"."entity_uuid" = "entities_0_1_0"."right_entity_uuid"
+            WHERE "entities_0_0_0"."decision_time" @> $1
+              AND "entities_0_0_0"."transaction_time" @> $2
+              AND "entities_0_1_0"."decision_time" @> $1
+              AND "entities_0_1_0"."transaction_time" @> $2
+              AND "entities_0_2_0"."decision_time" @> $1
+              AND "entities_0_2_0"."transaction_time" @> $2
+            "#,
+            &[&decision_time, &transaction_time],
+        );
+    }
+
     #[test]
     fn link_entity_left_right_id() {
         let mut compiler = SelectCompiler::<Entity>::with_asterisk();
@@ -946,6 +1161,7 @@ mod tests {
                   AND ("entities_0_0_0"."left_owned_by_id" = $1)
                   AND ("entities_0_0_0"."left_entity_uuid" = $2)
                   AND ("entities_0_1_0"."entity_record_id" = $3)
+                  AND ("entities_0_0_0"."transaction_time" @> now())
                 "#,
                 &[
                     &entity_edition_id.base_id().owned_by_id().as_uuid(),
@@ -986,6 +1202,7 @@ mod tests {
                   AND ("entities_0_1_0"."owned_by_id" = $1)
                   AND ("entities_0_1_0"."entity_uuid" = $2)
                   AND ("entities_0_1_0"."entity_record_id" = $3)
+                  AND ("entities_0_0_0"."transaction_time" @> now())
                 "#,
                 &[
                     &entity_edition_id.base_id().owned_by_id().as_uuid(),
@@ -1026,6 +1243,7 @@ mod tests {
                   AND ("entities_0_1_0"."owned_by_id" = $1)
                   AND ("entities_0_1_0"."entity_uuid" = $2)
                   AND ("entities_0_1_0"."entity_record_id" = $3)
+                  AND ("entities_0_0_0"."transaction_time" @> now())
                 "#,
                 &[
                     &entity_edition_id.base_id().owned_by_id().as_uuid(),
@@ -82,6 +82,7 @@ macro_rules! impl_ontology_column {
                 OwnedById,
                 UpdatedById,
                 Schema(Option<JsonField<'static>>),
+                SearchVector,
             }
 
             impl $name {
@@ -89,7 +90,8 @@ macro_rules! impl_ontology_column {
                     match self {
                         Self::VersionId
                         | Self::OwnedById
-                        | Self::UpdatedById => false,
+                        | Self::UpdatedById
+                        | Self::SearchVector => false,
                         Self::Schema(_) => true,
                     }
                 }
@@ -101,6 +103,7 @@ macro_rules! impl_ontology_column {
                         Self::VersionId => "version_id",
                         Self::OwnedById => "owned_by_id",
                         Self::UpdatedById => "updated_by_id",
+                        Self::SearchVector => "search_vector",
                         Self::Schema(None) => "schema",
                         Self::Schema(Some(path)) => match path {
                             JsonField::Json(field) => {
@@ -145,6 +148,7 @@ pub enum Entities<'p> {
     RightEntityUuid,
     LeftEntityOwnedById,
     RightEntityOwnedById,
+    SearchVector,
 }
 
 impl Entities<'_> {
@@ -158,7 +162,8 @@ impl Entities<'_> {
             | Self::Archived
             | Self::OwnedById
             | Self::UpdatedById
-            | Self::EntityTypeVersionId => false,
+            | Self::EntityTypeVersionId
+            | Self::SearchVector => false,
             Self::Properties(_)
             | Self::LeftEntityUuid
             | Self::RightEntityUuid
@@ -201,6 +206,7 @@ impl Transpile for Entities<'_> {
             Self::RightEntityUuid => "right_entity_uuid",
             Self::LeftEntityOwnedById => "left_owned_by_id",
             Self::RightEntityOwnedById => "right_owned_by_id",
+            Self::SearchVector => "search_vector",
         };
         write!(fmt, r#"."{column}""#)
     }
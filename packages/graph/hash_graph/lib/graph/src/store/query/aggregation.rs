@@ -0,0 +1,152 @@
+use std::fmt::{self, Debug, Formatter};
+
+use error_stack::{ensure, Context, Report, Result};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::store::query::{ParameterType, QueryRecord, RecordPath};
+
+#[derive(Debug)]
+#[must_use]
+pub struct InvalidAggregate;
+
+impl fmt::Display for InvalidAggregate {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str("`min`, `max` and `sum` require a path, which resolves to numbers")
+    }
+}
+
+impl Context for InvalidAggregate {}
+
+/// A function, which is computed over the records of every group of an aggregation.
+///
+/// `min`, `max` and `sum` require the path to resolve to numbers, e.g. a property with a number
+/// value or the order of a link. Records, for which the path resolves to `null`, are ignored.
+#[derive(Deserialize)]
+#[serde(
+    rename_all = "camelCase",
+    bound = "'de: 'q, T::Path<'q>: Deserialize<'de>"
+)]
+pub enum Aggregate<'q, T: QueryRecord> {
+    /// The number of records in the group.
+    Count,
+    Min(T::Path<'q>),
+    Max(T::Path<'q>),
+    Sum(T::Path<'q>),
+}
+
+impl<'q, T: QueryRecord> Aggregate<'q, T> {
+    /// Checks, that the path of the aggregate is able to resolve to numbers.
+    ///
+    /// Properties don't have a fixed type, so they are accepted and values, which are not numbers,
+    /// are ignored when computing the aggregate.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidAggregate`] if the path of `min`, `max` or `sum` resolves to a non-numeric type
+    pub fn validate(&self) -> Result<(), InvalidAggregate> {
+        if let Self::Min(path) | Self::Max(path) | Self::Sum(path) = self {
+            let expected_type = path.expected_type();
+            ensure!(
+                matches!(
+                    expected_type,
+                    ParameterType::Number | ParameterType::UnsignedInteger | ParameterType::Any
+                ),
+                Report::new(InvalidAggregate)
+                    .attach_printable(format!("the path resolves to {expected_type}"))
+            );
+        }
+        Ok(())
+    }
+}
+
+// TODO: Derive traits when bounds are generated correctly
+//   see https://github.com/rust-lang/rust/issues/26925
+impl<'q, T> Debug for Aggregate<'q, T>
+where
+    T: QueryRecord<Path<'q>: Debug>,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Count => fmt.write_str("Count"),
+            Self::Min(path) => fmt.debug_tuple("Min").field(path).finish(),
+            Self::Max(path) => fmt.debug_tuple("Max").field(path).finish(),
+            Self::Sum(path) => fmt.debug_tuple("Sum").field(path).finish(),
+        }
+    }
+}
+
+impl<'q, T> PartialEq for Aggregate<'q, T>
+where
+    T: QueryRecord<Path<'q>: PartialEq>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Count, Self::Count) => true,
+            (Self::Min(lhs), Self::Min(rhs))
+            | (Self::Max(lhs), Self::Max(rhs))
+            | (Self::Sum(lhs), Self::Sum(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+}
+
+/// The result of an aggregation for a single group of records.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregationGroup {
+    /// The values of the group-by paths shared by all records of the group, in the order the
+    /// paths were requested.
+    #[schema(value_type = Vec<Object>)]
+    pub group: Vec<serde_json::Value>,
+    /// The values of the aggregates, in the order the aggregates were requested.
+    ///
+    /// `min`, `max` and `sum` are `null` if the path did not resolve to a number for any record of
+    /// the group.
+    #[schema(value_type = Vec<Object>)]
+    pub values: Vec<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::knowledge::{Entity, EntityQueryPath};
+
+    #[test]
+    fn deserialize() {
+        let aggregates: Vec<Aggregate<Entity>> = Vec::deserialize(json!([
+            "count",
+            { "sum": ["properties", "https://example.com/property-type/age/"] },
+            { "max": ["leftToRightOrder"] },
+        ]))
+        .expect("could not deserialize aggregates");
+
+        assert_eq!(aggregates, [
+            Aggregate::Count,
+            Aggregate::Sum(EntityQueryPath::Properties(Some(Cow::Borrowed(
+                "https://example.com/property-type/age/"
+            )))),
+            Aggregate::Max(EntityQueryPath::LeftToRightOrder),
+        ]);
+    }
+
+    #[test]
+    fn validate() {
+        let numeric: Aggregate<Entity> = Aggregate::Max(EntityQueryPath::LeftToRightOrder);
+        numeric.validate().expect("order of a link is numeric");
+
+        let property: Aggregate<Entity> = Aggregate::Sum(EntityQueryPath::Properties(Some(
+            Cow::Borrowed("https://example.com/property-type/age/"),
+        )));
+        property.validate().expect("property may be numeric");
+
+        let report = Aggregate::<Entity>::Max(EntityQueryPath::OwnedById)
+            .validate()
+            .expect_err("owner is not numeric");
+        assert!(report.contains::<InvalidAggregate>());
+    }
+}
@@ -17,10 +17,23 @@ use crate::{
         ontology::OntologyTypeEditionId,
     },
     knowledge::{Entity, EntityQueryPath, EntityUuid},
+    ontology::{
+        DataTypeQueryPath, EntityTypeQueryPath, EntityTypeWithMetadata, PropertyTypeQueryPath,
+        PropertyTypeWithMetadata,
+    },
     store::query::{OntologyPath, ParameterType, QueryRecord, RecordPath},
 };
 
 /// A set of conditions used for queries.
+///
+/// Ordering comparisons (`less`, `lessOrEqual`, `greater`, `greaterOrEqual`) are only supported on
+/// paths, which can be ordered, i.e. numbers, text, URIs and timestamps. String operations
+/// (`startsWith`, `endsWith`, `containsSegment`) are only supported on textual paths and require a
+/// text parameter.
+///
+/// Properties don't have a fixed type, so these operations only match property values of the same
+/// type as the parameter, e.g. a number parameter is compared numerically with number values and
+/// doesn't match text values.
 #[derive(Deserialize)]
 #[serde(
     rename_all = "camelCase",
@@ -38,6 +51,24 @@ pub enum Filter<'q, T: QueryRecord> {
         Option<FilterExpression<'q, T>>,
         Option<FilterExpression<'q, T>>,
     ),
+    Less(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    LessOrEqual(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    Greater(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    GreaterOrEqual(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    /// Matches if the left hand side is equal to any of the expressions in the list.
+    In(FilterExpression<'q, T>, Vec<FilterExpression<'q, T>>),
+    StartsWith(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    EndsWith(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    /// Matches if the left hand side contains the right hand side as a substring.
+    ContainsSegment(FilterExpression<'q, T>, FilterExpression<'q, T>),
+    /// Matches if the expression is not `null`, e.g. if a property is present.
+    Exists(FilterExpression<'q, T>),
+    /// Matches records, whose searchable text matches the query.
+    ///
+    /// For entities, the searchable text consists of the values of their properties, for ontology
+    /// types it consists of their title and description. The query supports the web search syntax,
+    /// e.g. `"quoted phrases"`, `or` and `-excluded` words.
+    Search(Cow<'q, str>),
 }
 
 impl<'q, T> Filter<'q, T>
@@ -100,6 +131,8 @@ where
 
 impl<'q> Filter<'q, Entity> {
     /// Creates a `Filter` to search for all entities at their latest version.
+    ///
+    /// The latest version is resolved at the transaction time of the query, which defaults to now.
     #[must_use]
     pub const fn for_all_latest_entities() -> Self {
         Self::Equal(
@@ -171,8 +204,6 @@ impl<'q> Filter<'q, Entity> {
     /// [`EntityEditionId`].
     #[must_use]
     pub fn for_entity_by_edition_id(edition_id: EntityEditionId) -> Self {
-        // TODO: Adjust structural queries for temporal versioning
-        //   see https://app.asana.com/0/0/1203491211535116/f
         Self::All(vec![
             Self::Equal(
                 Some(FilterExpression::Path(EntityQueryPath::OwnedById)),
@@ -195,11 +226,10 @@ impl<'q> Filter<'q, Entity> {
         ])
     }
 
-    /// TODO
+    /// Creates a `Filter` to search for the latest link entities, which have the entity
+    /// identified by [`EntityEditionId`] as their left entity.
     #[must_use]
     pub fn for_outgoing_link_by_source_entity_edition_id(edition_id: EntityEditionId) -> Self {
-        // TODO: Adjust structural queries for temporal versioning
-        //   see https://app.asana.com/0/0/1203491211535116/f
         Self::All(vec![
             Self::Equal(
                 Some(FilterExpression::Path(EntityQueryPath::LeftEntity(
@@ -225,14 +255,14 @@ impl<'q> Filter<'q, Entity> {
                     edition_id.record_id().as_i64(),
                 ))),
             ),
+            Self::for_all_latest_entities(),
         ])
     }
 
-    /// TODO
+    /// Creates a `Filter` to search for the latest link entities, which have the entity
+    /// identified by [`EntityEditionId`] as their right entity.
     #[must_use]
     pub fn for_incoming_link_by_source_entity_edition_id(edition_id: EntityEditionId) -> Self {
-        // TODO: Adjust structural queries for temporal versioning
-        //   see https://app.asana.com/0/0/1203491211535116/f
         Self::All(vec![
             Self::Equal(
                 Some(FilterExpression::Path(EntityQueryPath::RightEntity(
@@ -258,14 +288,14 @@ impl<'q> Filter<'q, Entity> {
                     edition_id.record_id().as_i64(),
                 ))),
             ),
+            Self::for_all_latest_entities(),
         ])
     }
 
-    /// TODO
+    /// Creates a `Filter` to search for the latest left entity of the link entity identified by
+    /// [`EntityEditionId`].
     #[must_use]
     pub fn for_left_entity_by_entity_edition_id(edition_id: EntityEditionId) -> Self {
-        // TODO: Adjust structural queries for temporal versioning
-        //   see https://app.asana.com/0/0/1203491211535116/f
         Self::All(vec![
             Self::Equal(
                 Some(FilterExpression::Path(EntityQueryPath::OutgoingLinks(
@@ -291,14 +321,14 @@ impl<'q> Filter<'q, Entity> {
                     edition_id.record_id().as_i64(),
                 ))),
             ),
+            Self::for_all_latest_entities(),
         ])
     }
 
-    /// TODO
+    /// Creates a `Filter` to search for the latest right entity of the link entity identified by
+    /// [`EntityEditionId`].
     #[must_use]
     pub fn for_right_entity_by_entity_edition_id(edition_id: EntityEditionId) -> Self {
-        // TODO: Adjust structural queries for temporal versioning
-        //   see https://app.asana.com/0/0/1203491211535116/f
         Self::All(vec![
             Self::Equal(
                 Some(FilterExpression::Path(EntityQueryPath::IncomingLinks(
@@ -324,10 +354,160 @@ impl<'q> Filter<'q, Entity> {
                     edition_id.record_id().as_i64(),
                 ))),
             ),
+            Self::for_all_latest_entities(),
+        ])
+    }
+
+    /// Creates a `Filter` to search for the latest entities, which are of the entity type
+    /// identified by [`OntologyTypeEditionId`].
+    #[must_use]
+    pub fn for_latest_entities_by_entity_type(entity_type_id: &'q OntologyTypeEditionId) -> Self {
+        Self::All(vec![
+            Self::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::Type(
+                    EntityTypeQueryPath::BaseUri,
+                ))),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    entity_type_id.base_id().as_str(),
+                )))),
+            ),
+            Self::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::Type(
+                    EntityTypeQueryPath::Version,
+                ))),
+                Some(FilterExpression::Parameter(Parameter::SignedInteger(
+                    entity_type_id.version().inner().into(),
+                ))),
+            ),
+            Self::for_all_latest_entities(),
+        ])
+    }
+}
+
+impl<'q> Filter<'q, PropertyTypeWithMetadata> {
+    /// Creates a `Filter` to search for the property types, which constrain their values on the
+    /// data type identified by [`OntologyTypeEditionId`].
+    #[must_use]
+    pub fn for_property_types_by_data_type(data_type_id: &'q OntologyTypeEditionId) -> Self {
+        Self::All(vec![
+            Self::Equal(
+                Some(FilterExpression::Path(PropertyTypeQueryPath::DataTypes(
+                    DataTypeQueryPath::BaseUri,
+                ))),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    data_type_id.base_id().as_str(),
+                )))),
+            ),
+            Self::Equal(
+                Some(FilterExpression::Path(PropertyTypeQueryPath::DataTypes(
+                    DataTypeQueryPath::Version,
+                ))),
+                Some(FilterExpression::Parameter(Parameter::SignedInteger(
+                    data_type_id.version().inner().into(),
+                ))),
+            ),
+        ])
+    }
+
+    /// Creates a `Filter` to search for the property types, which constrain their properties on
+    /// the property type identified by [`OntologyTypeEditionId`].
+    #[must_use]
+    pub fn for_property_types_by_property_type(
+        property_type_id: &'q OntologyTypeEditionId,
+    ) -> Self {
+        Self::All(vec![
+            Self::Equal(
+                Some(FilterExpression::Path(
+                    PropertyTypeQueryPath::PropertyTypes(Box::new(PropertyTypeQueryPath::BaseUri)),
+                )),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    property_type_id.base_id().as_str(),
+                )))),
+            ),
+            Self::Equal(
+                Some(FilterExpression::Path(
+                    PropertyTypeQueryPath::PropertyTypes(Box::new(PropertyTypeQueryPath::Version)),
+                )),
+                Some(FilterExpression::Parameter(Parameter::SignedInteger(
+                    property_type_id.version().inner().into(),
+                ))),
+            ),
+        ])
+    }
+}
+
+impl<'q> Filter<'q, EntityTypeWithMetadata> {
+    /// Creates a `Filter` to search for the entity types, which constrain their properties on the
+    /// property type identified by [`OntologyTypeEditionId`].
+    #[must_use]
+    pub fn for_entity_types_by_property_type(property_type_id: &'q OntologyTypeEditionId) -> Self {
+        Self::All(vec![
+            Self::Equal(
+                Some(FilterExpression::Path(EntityTypeQueryPath::Properties(
+                    PropertyTypeQueryPath::BaseUri,
+                ))),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    property_type_id.base_id().as_str(),
+                )))),
+            ),
+            Self::Equal(
+                Some(FilterExpression::Path(EntityTypeQueryPath::Properties(
+                    PropertyTypeQueryPath::Version,
+                ))),
+                Some(FilterExpression::Parameter(Parameter::SignedInteger(
+                    property_type_id.version().inner().into(),
+                ))),
+            ),
+        ])
+    }
+
+    /// Creates a `Filter` to search for the entity types, which reference the entity type
+    /// identified by [`OntologyTypeEditionId`].
+    ///
+    /// Links, link destinations and inherited types are stored alongside each other, so the
+    /// returned entity types reference the entity type in at least one of these ways. The kind of
+    /// the reference has to be checked on the schema of the returned entity types.
+    #[must_use]
+    pub fn for_entity_types_by_entity_type(entity_type_id: &'q OntologyTypeEditionId) -> Self {
+        Self::All(vec![
+            Self::Equal(
+                Some(FilterExpression::Path(EntityTypeQueryPath::Links(
+                    Box::new(EntityTypeQueryPath::BaseUri),
+                ))),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    entity_type_id.base_id().as_str(),
+                )))),
+            ),
+            Self::Equal(
+                Some(FilterExpression::Path(EntityTypeQueryPath::Links(
+                    Box::new(EntityTypeQueryPath::Version),
+                ))),
+                Some(FilterExpression::Parameter(Parameter::SignedInteger(
+                    entity_type_id.version().inner().into(),
+                ))),
+            ),
         ])
     }
 }
 
+impl<'q, T: QueryRecord> Filter<'q, T> {
+    /// Returns the query of the first [`Filter::Search`], which determines the relevance of the
+    /// matching records.
+    ///
+    /// Searches nested inside of [`Filter::Any`] are considered as well, even though a record may
+    /// match another alternative. The relevance of such a record is zero. Searches nested inside
+    /// of [`Filter::Not`] are not considered as they don't contribute to the relevance of a
+    /// record.
+    #[must_use]
+    pub fn search_query(&self) -> Option<&Cow<'q, str>> {
+        match self {
+            Self::Search(query) => Some(query),
+            Self::All(filters) | Self::Any(filters) => filters.iter().find_map(Self::search_query),
+            _ => None,
+        }
+    }
+}
+
 impl<'q, T: QueryRecord> Filter<'q, T>
 where
     T::Path<'q>: Display,
@@ -354,6 +534,35 @@ where
                 ) => parameter.convert_to_parameter_type(path.expected_type())?,
                 (..) => {}
             },
+            Self::Less(lhs, rhs)
+            | Self::LessOrEqual(lhs, rhs)
+            | Self::Greater(lhs, rhs)
+            | Self::GreaterOrEqual(lhs, rhs) => match (lhs, rhs) {
+                (FilterExpression::Parameter(parameter), FilterExpression::Path(path))
+                | (FilterExpression::Path(path), FilterExpression::Parameter(parameter)) => {
+                    parameter.convert_to_ordered_parameter_type(path.expected_type())?;
+                }
+                (..) => {}
+            },
+            Self::In(lhs, rhs) => {
+                if let FilterExpression::Path(path) = lhs {
+                    for expression in rhs {
+                        if let FilterExpression::Parameter(parameter) = expression {
+                            parameter.convert_to_parameter_type(path.expected_type())?;
+                        }
+                    }
+                }
+            }
+            Self::StartsWith(lhs, rhs)
+            | Self::EndsWith(lhs, rhs)
+            | Self::ContainsSegment(lhs, rhs) => match (lhs, rhs) {
+                (FilterExpression::Parameter(parameter), FilterExpression::Path(path))
+                | (FilterExpression::Path(path), FilterExpression::Parameter(parameter)) => {
+                    parameter.convert_to_text_parameter_type(path.expected_type())?;
+                }
+                (..) => {}
+            },
+            Self::Exists(_) | Self::Search(_) => {}
         }
 
         Ok(())
@@ -373,6 +582,28 @@ where
             Self::Not(filter) => f.debug_tuple("Not").field(filter).finish(),
             Self::Equal(lhs, rhs) => f.debug_tuple("Equal").field(lhs).field(rhs).finish(),
             Self::NotEqual(lhs, rhs) => f.debug_tuple("NotEqual").field(lhs).field(rhs).finish(),
+            Self::Less(lhs, rhs) => f.debug_tuple("Less").field(lhs).field(rhs).finish(),
+            Self::LessOrEqual(lhs, rhs) => {
+                f.debug_tuple("LessOrEqual").field(lhs).field(rhs).finish()
+            }
+            Self::Greater(lhs, rhs) => f.debug_tuple("Greater").field(lhs).field(rhs).finish(),
+            Self::GreaterOrEqual(lhs, rhs) => f
+                .debug_tuple("GreaterOrEqual")
+                .field(lhs)
+                .field(rhs)
+                .finish(),
+            Self::In(lhs, rhs) => f.debug_tuple("In").field(lhs).field(rhs).finish(),
+            Self::StartsWith(lhs, rhs) => {
+                f.debug_tuple("StartsWith").field(lhs).field(rhs).finish()
+            }
+            Self::EndsWith(lhs, rhs) => f.debug_tuple("EndsWith").field(lhs).field(rhs).finish(),
+            Self::ContainsSegment(lhs, rhs) => f
+                .debug_tuple("ContainsSegment")
+                .field(lhs)
+                .field(rhs)
+                .finish(),
+            Self::Exists(expression) => f.debug_tuple("Exists").field(expression).finish(),
+            Self::Search(query) => f.debug_tuple("Search").field(query).finish(),
         }
     }
 }
@@ -391,6 +622,18 @@ where
             | (Self::NotEqual(lhs_1, lhs_2), Self::NotEqual(rhs_1, rhs_2)) => {
                 lhs_1 == rhs_1 && lhs_2 == rhs_2
             }
+            (Self::Less(lhs_1, lhs_2), Self::Less(rhs_1, rhs_2))
+            | (Self::LessOrEqual(lhs_1, lhs_2), Self::LessOrEqual(rhs_1, rhs_2))
+            | (Self::Greater(lhs_1, lhs_2), Self::Greater(rhs_1, rhs_2))
+            | (Self::GreaterOrEqual(lhs_1, lhs_2), Self::GreaterOrEqual(rhs_1, rhs_2))
+            | (Self::StartsWith(lhs_1, lhs_2), Self::StartsWith(rhs_1, rhs_2))
+            | (Self::EndsWith(lhs_1, lhs_2), Self::EndsWith(rhs_1, rhs_2))
+            | (Self::ContainsSegment(lhs_1, lhs_2), Self::ContainsSegment(rhs_1, rhs_2)) => {
+                lhs_1 == rhs_1 && lhs_2 == rhs_2
+            }
+            (Self::In(lhs_1, lhs_2), Self::In(rhs_1, rhs_2)) => lhs_1 == rhs_1 && lhs_2 == rhs_2,
+            (Self::Exists(lhs), Self::Exists(rhs)) => lhs == rhs,
+            (Self::Search(lhs), Self::Search(rhs)) => lhs == rhs,
             _ => false,
         }
     }
@@ -554,6 +797,71 @@ impl Parameter<'_> {
 
         Ok(())
     }
+
+    /// Like [`convert_to_parameter_type`], but additionally ensures, that the values of `expected`
+    /// can be ordered.
+    ///
+    /// [`convert_to_parameter_type`]: Self::convert_to_parameter_type
+    fn convert_to_ordered_parameter_type(
+        &mut self,
+        expected: ParameterType,
+    ) -> Result<(), Report<ParameterConversionError>> {
+        let is_ordered = matches!(
+            expected,
+            ParameterType::Number
+                | ParameterType::UnsignedInteger
+                | ParameterType::Text
+                | ParameterType::BaseUri
+                | ParameterType::VersionedUri
+                | ParameterType::Timestamp
+                | ParameterType::Any
+        );
+        ensure!(
+            is_ordered,
+            Report::new(ParameterConversionError {
+                actual: self.to_owned(),
+                expected,
+            })
+            .attach_printable(format!("{expected} values cannot be ordered"))
+        );
+        ensure!(
+            !matches!(self, Parameter::Text(text) if text == "latest"),
+            Report::new(ParameterConversionError {
+                actual: self.to_owned(),
+                expected,
+            })
+            .attach_printable("`latest` cannot be used in ordering comparisons")
+        );
+
+        self.convert_to_parameter_type(expected)
+    }
+
+    /// Ensures, that the parameter is a text and values of `expected` are textual, so they can be
+    /// used in string operations.
+    fn convert_to_text_parameter_type(
+        &mut self,
+        expected: ParameterType,
+    ) -> Result<(), Report<ParameterConversionError>> {
+        let is_textual = matches!(
+            expected,
+            ParameterType::Text
+                | ParameterType::BaseUri
+                | ParameterType::VersionedUri
+                | ParameterType::Any
+        );
+        ensure!(
+            is_textual,
+            Report::new(ParameterConversionError {
+                actual: self.to_owned(),
+                expected,
+            })
+            .attach_printable(format!(
+                "string operations are not supported on {expected} values"
+            ))
+        );
+
+        self.convert_to_parameter_type(ParameterType::Text)
+    }
 }
 
 #[cfg(test)]
@@ -571,7 +879,7 @@ mod tests {
             ontology::OntologyTypeVersion,
             DecisionTimespan, TransactionTimespan,
         },
-        ontology::{DataTypeQueryPath, DataTypeWithMetadata},
+        ontology::DataTypeWithMetadata,
         provenance::OwnedById,
     };
 
@@ -810,6 +1118,10 @@ mod tests {
             { "equal": [
               { "path": ["leftEntity", "recordId"] },
               { "parameter": entity_edition_id.record_id() }
+            ]},
+            { "equal": [
+              { "path": ["version"] },
+              { "parameter": "latest" }
             ]}
           ]
         }};
@@ -847,6 +1159,10 @@ mod tests {
             { "equal": [
               { "path": ["outgoingLinks", "recordId"] },
               { "parameter": entity_edition_id.record_id() }
+            ]},
+            { "equal": [
+              { "path": ["version"] },
+              { "parameter": "latest" }
             ]}
           ]
         }};
@@ -884,6 +1200,10 @@ mod tests {
             { "equal": [
               { "path": ["incomingLinks", "recordId"] },
               { "parameter": entity_edition_id.record_id() }
+            ]},
+            { "equal": [
+              { "path": ["version"] },
+              { "parameter": "latest" }
             ]}
           ]
         }};
@@ -894,6 +1214,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn for_latest_entities_by_entity_type() {
+        let entity_type_id = OntologyTypeEditionId::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+                .expect("invalid base uri"),
+            OntologyTypeVersion::new(1),
+        );
+
+        let expected = json! {{
+          "all": [
+            { "equal": [
+              { "path": ["type", "baseUri"] },
+              { "parameter": entity_type_id.base_id() }
+            ]},
+            { "equal": [
+              { "path": ["type", "version"] },
+              { "parameter": entity_type_id.version() }
+            ]},
+            { "equal": [
+              { "path": ["version"] },
+              { "parameter": "latest" }
+            ]}
+          ]
+        }};
+
+        test_filter_representation(
+            &Filter::for_latest_entities_by_entity_type(&entity_type_id),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn for_property_types_by_data_type() {
+        let data_type_id = OntologyTypeEditionId::new(
+            BaseUri::new(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/".to_owned(),
+            )
+            .expect("invalid base uri"),
+            OntologyTypeVersion::new(1),
+        );
+
+        let expected = json! {{
+          "all": [
+            { "equal": [
+              { "path": ["dataTypes", "*", "baseUri"] },
+              { "parameter": data_type_id.base_id() }
+            ]},
+            { "equal": [
+              { "path": ["dataTypes", "*", "version"] },
+              { "parameter": data_type_id.version() }
+            ]}
+          ]
+        }};
+
+        test_filter_representation(
+            &Filter::for_property_types_by_data_type(&data_type_id),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn for_entity_types_by_entity_type() {
+        let entity_type_id = OntologyTypeEditionId::new(
+            BaseUri::new(
+                "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+            )
+            .expect("invalid base uri"),
+            OntologyTypeVersion::new(1),
+        );
+
+        let expected = json! {{
+          "all": [
+            { "equal": [
+              { "path": ["links", "*", "baseUri"] },
+              { "parameter": entity_type_id.base_id() }
+            ]},
+            { "equal": [
+              { "path": ["links", "*", "version"] },
+              { "parameter": entity_type_id.version() }
+            ]}
+          ]
+        }};
+
+        test_filter_representation(
+            &Filter::for_entity_types_by_entity_type(&entity_type_id),
+            &expected,
+        );
+    }
+
     #[test]
     fn null_check() {
         let expected = json! {{
@@ -913,4 +1322,160 @@ mod tests {
             &expected,
         );
     }
+
+    #[test]
+    fn comparison() {
+        let expected = json! {{
+          "all": [
+            { "greaterOrEqual": [
+              { "path": ["version"] },
+              { "parameter": 2 }
+            ]},
+            { "less": [
+              { "path": ["version"] },
+              { "parameter": 5 }
+            ]}
+          ]
+        }};
+
+        test_filter_representation(
+            &Filter::All(vec![
+                Filter::GreaterOrEqual(
+                    FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Version),
+                    FilterExpression::Parameter(Parameter::SignedInteger(2)),
+                ),
+                Filter::Less(
+                    FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Version),
+                    FilterExpression::Parameter(Parameter::SignedInteger(5)),
+                ),
+            ]),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn membership() {
+        let expected = json! {{
+          "in": [
+            { "path": ["title"] },
+            [
+              { "parameter": "Text" },
+              { "parameter": "Number" }
+            ]
+          ]
+        }};
+
+        test_filter_representation(
+            &Filter::In(
+                FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Title),
+                vec![
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Text"))),
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Number"))),
+                ],
+            ),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn string_operations() {
+        let expected = json! {{
+          "any": [
+            { "startsWith": [
+              { "path": ["baseUri"] },
+              { "parameter": "https://blockprotocol.org/" }
+            ]},
+            { "endsWith": [
+              { "path": ["title"] },
+              { "parameter": "Text" }
+            ]},
+            { "containsSegment": [
+              { "path": ["description"] },
+              { "parameter": "string" }
+            ]}
+          ]
+        }};
+
+        test_filter_representation(
+            &Filter::Any(vec![
+                Filter::StartsWith(
+                    FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::BaseUri),
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                        "https://blockprotocol.org/",
+                    ))),
+                ),
+                Filter::EndsWith(
+                    FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Title),
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Text"))),
+                ),
+                Filter::ContainsSegment(
+                    FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Description),
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("string"))),
+                ),
+            ]),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn exists() {
+        let expected = json! {{
+          "exists": { "path": ["description"] }
+        }};
+
+        test_filter_representation(
+            &Filter::Exists(FilterExpression::<DataTypeWithMetadata>::Path(
+                DataTypeQueryPath::Description,
+            )),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn invalid_parameter_types() {
+        let invalid_filters = [
+            json! {{ "less": [{ "path": ["versionId"] }, { "parameter": "abc" }] }},
+            json! {{ "greater": [{ "path": ["version"] }, { "parameter": "latest" }] }},
+            json! {{ "startsWith": [{ "path": ["version"] }, { "parameter": "1" }] }},
+            json! {{ "endsWith": [{ "path": ["title"] }, { "parameter": 1 }] }},
+            json! {{ "in": [{ "path": ["version"] }, [{ "parameter": 1 }, { "parameter": true }]] }},
+        ];
+
+        for filter in &invalid_filters {
+            let mut filter = Filter::<DataTypeWithMetadata>::deserialize(filter)
+                .expect("Could not deserialize filter");
+            assert!(
+                filter.convert_parameters().is_err(),
+                "{filter:?} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn search() {
+        let expected = json! {{
+          "all": [
+            { "search": "time machine" },
+            { "equal": [
+              { "path": ["version"] },
+              { "parameter": "latest" }
+            ]}
+          ]
+        }};
+
+        let filter = Filter::All(vec![
+            Filter::Search(Cow::Borrowed("time machine")),
+            Filter::<DataTypeWithMetadata>::for_latest_version(),
+        ]);
+        test_filter_representation(&filter, &expected);
+
+        assert_eq!(
+            filter.search_query().map(AsRef::as_ref),
+            Some("time machine")
+        );
+        assert_eq!(
+            Filter::<DataTypeWithMetadata>::Not(Box::new(filter)).search_query(),
+            None
+        );
+    }
 }
@@ -1,8 +1,16 @@
+mod aggregation;
 mod filter;
+mod pagination;
+mod temporal_axes;
 
 use std::fmt;
 
-pub use self::filter::{Filter, FilterExpression, Parameter, ParameterConversionError};
+pub use self::{
+    aggregation::{Aggregate, AggregationGroup, InvalidAggregate},
+    filter::{Filter, FilterExpression, Parameter, ParameterConversionError},
+    pagination::{Cursor, InvalidCursor, InvalidPageSize, Page, Pagination},
+    temporal_axes::TemporalAxes,
+};
 
 /// A record stored in the [`store`].
 ///
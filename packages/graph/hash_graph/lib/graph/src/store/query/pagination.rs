@@ -0,0 +1,187 @@
+use std::{fmt, fmt::Write};
+
+use error_stack::{ensure, Context, Report, Result};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug)]
+#[must_use]
+pub struct InvalidCursor;
+
+impl fmt::Display for InvalidCursor {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("the provided cursor is invalid")
+    }
+}
+
+impl Context for InvalidCursor {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct InvalidPageSize;
+
+impl fmt::Display for InvalidPageSize {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "the page size has to be between 1 and {}",
+            Pagination::MAX_LIMIT
+        )
+    }
+}
+
+impl Context for InvalidPageSize {}
+
+/// An opaque position inside of an ordered list of records.
+///
+/// A cursor is returned alongside a page of records, if more records are available. It has to be
+/// passed back unchanged to read the next page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Creates a cursor pointing after the record identified by `sort_key`.
+    #[must_use]
+    pub fn encode(sort_key: &str) -> Self {
+        let mut cursor = String::with_capacity(sort_key.len() * 2);
+        for byte in sort_key.bytes() {
+            write!(cursor, "{byte:02x}").expect("writing to a string does not fail");
+        }
+        Self(cursor)
+    }
+
+    /// Returns the sort key of the record this cursor is pointing after.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidCursor`] if the cursor was not created by [`Cursor::encode`]
+    pub fn decode(&self) -> Result<String, InvalidCursor> {
+        let bytes = self
+            .0
+            .as_bytes()
+            .chunks(2)
+            .map(|chunk| {
+                std::str::from_utf8(chunk)
+                    .ok()
+                    .filter(|chunk| chunk.len() == 2)
+                    .and_then(|chunk| u8::from_str_radix(chunk, 16).ok())
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Report::new(InvalidCursor).attach_printable(self.0.clone()))?;
+
+        String::from_utf8(bytes)
+            .map_err(|_| Report::new(InvalidCursor).attach_printable(self.0.clone()))
+    }
+}
+
+/// Restricts a read to a single page of records.
+///
+/// Records are returned in the order of a stable sort key, which depends on the kind of record. To
+/// continue reading after a page, the [`Cursor`] returned with the page is passed as `after`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Pagination {
+    /// The maximum number of records to return, between 1 and 1000.
+    pub limit: usize,
+    /// Only return records after this cursor.
+    #[serde(default)]
+    pub after: Option<Cursor>,
+}
+
+impl Pagination {
+    /// The maximum number of records on a single page.
+    pub const MAX_LIMIT: usize = 1000;
+
+    /// Checks that the page size is within `1..=MAX_LIMIT`.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidPageSize`] if `limit` is zero or exceeds [`Self::MAX_LIMIT`]
+    pub fn validate(&self) -> Result<(), InvalidPageSize> {
+        ensure!(
+            (1..=Self::MAX_LIMIT).contains(&self.limit),
+            Report::new(InvalidPageSize).attach_printable(format!("limit: {}", self.limit))
+        );
+        Ok(())
+    }
+
+    /// Truncates `records` to the page size and returns the [`Cursor`] for the next page.
+    ///
+    /// `records` are expected to be read with a limit of one more than the page size, so the
+    /// additional record indicates, that another page is available.
+    pub fn next_cursor<T>(
+        &self,
+        records: &mut Vec<T>,
+        sort_key: impl FnOnce(&T) -> String,
+    ) -> Option<Cursor> {
+        if records.len() > self.limit {
+            records.truncate(self.limit);
+            records
+                .last()
+                .map(|record| Cursor::encode(&sort_key(record)))
+        } else {
+            None
+        }
+    }
+}
+
+/// A page of records as returned by [`Read::read_page`].
+///
+/// [`Read::read_page`]: crate::store::crud::Read::read_page
+#[derive(Debug)]
+pub struct Page<T> {
+    pub records: Vec<T>,
+    /// The cursor to read the next page, if there are more records.
+    pub next_cursor: Option<Cursor>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_roundtrip() {
+        let sort_key = "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1";
+        let cursor = Cursor::encode(sort_key);
+        assert_eq!(cursor.decode().expect("could not decode cursor"), sort_key);
+    }
+
+    #[test]
+    fn invalid_cursor() {
+        assert!(Cursor("abc".to_owned()).decode().is_err());
+        assert!(Cursor("zz".to_owned()).decode().is_err());
+        assert!(Cursor("ff".to_owned()).decode().is_err());
+    }
+
+    #[test]
+    fn next_cursor() {
+        let pagination = Pagination {
+            limit: 2,
+            after: None,
+        };
+
+        let mut records = vec![1, 2, 3];
+        let cursor = pagination.next_cursor(&mut records, ToString::to_string);
+        assert_eq!(records, [1, 2]);
+        assert_eq!(cursor, Some(Cursor::encode("2")));
+
+        let mut records = vec![3];
+        assert_eq!(
+            pagination.next_cursor(&mut records, ToString::to_string),
+            None
+        );
+        assert_eq!(records, [3]);
+    }
+
+    #[test]
+    fn page_size() {
+        let pagination = |limit| Pagination { limit, after: None };
+
+        assert!(pagination(0).validate().is_err());
+        assert!(pagination(1).validate().is_ok());
+        assert!(pagination(Pagination::MAX_LIMIT).validate().is_ok());
+        assert!(pagination(Pagination::MAX_LIMIT + 1).validate().is_err());
+        assert!(pagination(usize::MAX).validate().is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use utoipa::{
+    openapi::{self, KnownFormat, SchemaFormat},
+    ToSchema,
+};
+
+use crate::identifier::{DecisionTimestamp, TransactionTimestamp};
+
+/// The temporal slice of the graph a query is resolved in.
+///
+/// Entity editions are versioned along two time axes:
+///
+/// - the decision time, which is the time an edition was decided to be valid, e.g. the time a
+///   person changed their name, and
+/// - the transaction time, which is the time an edition was recorded in the graph.
+///
+/// By default, a query is resolved with the decision time pinned to now, while the transaction time
+/// is only restricted when asking for the latest editions. Pinning the transaction time returns the
+/// graph as it was known at that time, pinning the decision time returns the graph as it was
+/// decided to be at that time. Both can be combined, e.g. to ask what was known at time `T` about
+/// the state at time `D`.
+///
+/// The same slice is used when traversing the graph from the root vertices.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TemporalAxes {
+    /// The point in transaction time to resolve the query at.
+    #[serde(default)]
+    pub transaction_time: Option<TransactionTimestamp>,
+    /// The point in decision time to resolve the query at, defaults to now.
+    #[serde(default)]
+    pub decision_time: Option<DecisionTimestamp>,
+}
+
+impl ToSchema for TemporalAxes {
+    fn schema() -> openapi::Schema {
+        let timestamp = || {
+            openapi::ObjectBuilder::new()
+                .schema_type(openapi::SchemaType::String)
+                .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+        };
+
+        openapi::ObjectBuilder::new()
+            .property("transactionTime", timestamp())
+            .property("decisionTime", timestamp())
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let axes: TemporalAxes = serde_json::from_value(json!({
+            "transactionTime": "2022-12-01T12:00:00Z",
+        }))
+        .expect("could not deserialize temporal axes");
+        assert_eq!(
+            axes.transaction_time,
+            Some(
+                "2022-12-01T12:00:00Z"
+                    .parse()
+                    .expect("could not parse timestamp")
+            )
+        );
+        assert_eq!(axes.decision_time, None);
+
+        assert_eq!(
+            serde_json::from_value::<TemporalAxes>(json!({})).expect("could not deserialize"),
+            TemporalAxes::default()
+        );
+        assert!(serde_json::from_value::<TemporalAxes>(json!({ "time": 0 })).is_err());
+    }
+}
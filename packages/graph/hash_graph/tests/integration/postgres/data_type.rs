@@ -1,3 +1,5 @@
+use graph::ontology::{CompatibilityLevel, IncompatibleOntologyTypeUpdate};
+use serde_json::json;
 use type_system::{repr, DataType};
 
 use crate::postgres::DatabaseTestWrapper;
@@ -67,9 +69,14 @@ async fn update() {
         .await
         .expect("could not create data type");
 
-    api.update_data_type(object_dt_v2.clone())
+    let metadata = api
+        .update_data_type(object_dt_v2.clone())
         .await
         .expect("could not update data type");
+    assert_eq!(
+        metadata.compatibility().level(),
+        CompatibilityLevel::Additive
+    );
 
     let returned_object_dt_v1 = api
         .get_data_type(object_dt_v1.id())
@@ -87,3 +94,35 @@ async fn update() {
     assert_eq!(&object_dt_v1, returned_object_dt_v1.inner());
     assert_eq!(&object_dt_v2, returned_object_dt_v2.inner());
 }
+
+#[tokio::test]
+async fn reject_incompatible_update() {
+    let text_dt_repr: repr::DataType = serde_json::from_str(graph_test_data::data_type::TEXT_V1)
+        .expect("could not parse data type representation");
+    let text_dt = DataType::try_from(text_dt_repr).expect("could not parse data type");
+
+    let number_dt_repr: repr::DataType = serde_json::from_value(json!({
+        "kind": "dataType",
+        "$id": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/2",
+        "title": "Text",
+        "type": "number"
+    }))
+    .expect("could not parse data type representation");
+    let number_dt = DataType::try_from(number_dt_repr).expect("could not parse data type");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+
+    api.create_data_type(text_dt)
+        .await
+        .expect("could not create data type");
+
+    let report = api
+        .update_data_type(number_dt)
+        .await
+        .expect_err("changing the type of a data type should be rejected");
+    assert!(report.contains::<IncompatibleOntologyTypeUpdate>());
+}
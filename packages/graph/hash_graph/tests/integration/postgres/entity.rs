@@ -1,6 +1,24 @@
-use graph::knowledge::{EntityLinkOrder, EntityProperties};
+use std::{borrow::Cow, collections::HashSet, str::FromStr};
+
+use graph::{
+    identifier::{DecisionTimestamp, GraphElementEditionId},
+    knowledge::{EntityLinkOrder, EntityProperties, EntityQueryPath, EntityValidationError},
+    store::{
+        diff_properties,
+        query::{
+            Aggregate, AggregationGroup, Filter, FilterExpression, Pagination, Parameter,
+            TemporalAxes,
+        },
+        ChangeKind, ChangedElement, EntityMigration, PropertyMapping,
+    },
+};
 use graph_test_data::{data_type, entity, entity_type, property_type};
-use type_system::uri::{BaseUri, VersionedUri};
+use serde_json::json;
+use type_system::{
+    repr,
+    uri::{BaseUri, VersionedUri},
+    EntityType,
+};
 
 use crate::postgres::DatabaseTestWrapper;
 
@@ -78,6 +96,164 @@ async fn query() {
     assert_eq!(&organization, queried_organization.properties());
 }
 
+#[tokio::test]
+async fn stream() {
+    let person: EntityProperties =
+        serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+    let person_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let mut created_entity_ids = Vec::new();
+    for _ in 0..3 {
+        let metadata = api
+            .create_entity(person.clone(), person_type_id.clone(), None)
+            .await
+            .expect("could not create entity");
+        created_entity_ids.push(metadata.edition_id().base_id());
+    }
+
+    let entities = api
+        .stream_latest_entities(&person_type_id)
+        .await
+        .expect("could not stream entities");
+
+    let mut streamed_entity_ids = entities
+        .iter()
+        .map(|entity| entity.metadata().edition_id().base_id())
+        .collect::<Vec<_>>();
+    streamed_entity_ids.sort();
+    created_entity_ids.sort();
+    assert_eq!(streamed_entity_ids, created_entity_ids);
+    assert!(entities.iter().all(|entity| entity.properties() == &person));
+}
+
+#[tokio::test]
+async fn query_numeric_property() {
+    let employee_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/employee/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [entity_type::EMPLOYEE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    for age in [9, 10, 100] {
+        api.create_entity(
+            serde_json::from_value(json!({
+                "https://blockprotocol.org/@alice/types/property-type/name/": "Alice",
+                "https://blockprotocol.org/@alice/types/property-type/age/": age,
+            }))
+            .expect("could not parse entity"),
+            employee_type_id.clone(),
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    // Compared as text, "10" and "100" would be less than "9.5"
+    let filter = Filter::Greater(
+        FilterExpression::Path(EntityQueryPath::Properties(Some(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/age/",
+        )))),
+        FilterExpression::Parameter(Parameter::Number(9.5)),
+    );
+    let age_property =
+        BaseUri::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+            .expect("couldn't construct Base URI");
+    let mut ages = api
+        .get_latest_entities_by_filter(&filter)
+        .await
+        .expect("could not query entities")
+        .iter()
+        .map(|entity| entity.properties().properties()[&age_property].clone())
+        .collect::<Vec<_>>();
+    ages.sort_by_key(|age| age.as_i64());
+    assert_eq!(ages, [json!(10), json!(100)]);
+}
+
+#[tokio::test]
+async fn aggregate() {
+    let employee_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/employee/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+    let age = || {
+        EntityQueryPath::Properties(Some(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/age/",
+        )))
+    };
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [entity_type::EMPLOYEE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let mut owned_by_id = None;
+    for properties in [
+        json!({ "https://blockprotocol.org/@alice/types/property-type/age/": 20 }),
+        json!({ "https://blockprotocol.org/@alice/types/property-type/age/": 45.5 }),
+        json!({ "https://blockprotocol.org/@alice/types/property-type/age/": 100 }),
+        // Entities without the property are counted but don't affect the other aggregates
+        json!({ "https://blockprotocol.org/@alice/types/property-type/name/": "Alice" }),
+    ] {
+        let metadata = api
+            .create_entity(
+                serde_json::from_value(properties).expect("could not parse entity"),
+                employee_type_id.clone(),
+                None,
+            )
+            .await
+            .expect("could not create entity");
+        owned_by_id = Some(metadata.edition_id().base_id().owned_by_id());
+    }
+
+    let groups = api
+        .aggregate_latest_entities_by_owner(&employee_type_id, vec![
+            Aggregate::Count,
+            Aggregate::Min(age()),
+            Aggregate::Max(age()),
+            Aggregate::Sum(age()),
+        ])
+        .await
+        .expect("could not aggregate entities");
+
+    assert_eq!(groups, [AggregationGroup {
+        group: vec![
+            serde_json::to_value(owned_by_id.expect("no entity was created"))
+                .expect("could not serialize owner")
+        ],
+        values: vec![json!(4), json!(20.0), json!(100.0), json!(165.5)],
+    }]);
+}
+
 #[tokio::test]
 async fn update() {
     let page_v1: EntityProperties =
@@ -127,3 +303,421 @@ async fn update() {
 
     assert_eq!(entity_v2.properties(), &page_v2);
 }
+
+#[tokio::test]
+async fn change_feed() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: EntityProperties =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database:");
+
+    let page_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/page/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let v1_metadata = api
+        .create_entity(page_v1, page_type_id.clone(), None)
+        .await
+        .expect("could not create entity");
+
+    let v2_metadata = api
+        .update_entity(
+            v1_metadata.edition_id().base_id(),
+            page_v2,
+            page_type_id,
+            EntityLinkOrder::new(None, None),
+        )
+        .await
+        .expect("could not update entity");
+
+    let entity_id = v1_metadata.edition_id().base_id();
+    let changes = api
+        .read_all_changes()
+        .await
+        .expect("could not read change feed")
+        .into_iter()
+        .filter_map(|event| match event.element() {
+            ChangedElement::Entity(metadata) if metadata.edition_id().base_id() == entity_id => {
+                Some((event.kind(), metadata.edition_id().record_id()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(changes, [
+        (ChangeKind::Create, v1_metadata.edition_id().record_id()),
+        (ChangeKind::Update, v2_metadata.edition_id().record_id()),
+    ]);
+}
+
+#[tokio::test]
+async fn query_at_decision_time() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: EntityProperties =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database:");
+
+    let page_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/page/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let metadata = api
+        .create_entity(page_v1.clone(), page_type_id.clone(), None)
+        .await
+        .expect("could not create entity");
+
+    api.update_entity(
+        metadata.edition_id().base_id(),
+        page_v2.clone(),
+        page_type_id,
+        EntityLinkOrder::new(None, None),
+    )
+    .await
+    .expect("could not update entity");
+
+    // The entity is created at 2000-01-01 and updated at 2000-01-03 in decision time
+    let entity_v1 = api
+        .get_entity_at(metadata.edition_id().base_id(), TemporalAxes {
+            transaction_time: None,
+            decision_time: Some(
+                DecisionTimestamp::from_str("2000-01-02T00:00:00Z")
+                    .expect("could not parse timestamp"),
+            ),
+        })
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity_v1.properties(), &page_v1);
+
+    let entity_v2 = api
+        .get_entity_at(metadata.edition_id().base_id(), TemporalAxes::default())
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity_v2.properties(), &page_v2);
+}
+
+#[tokio::test]
+async fn insert_invalid() {
+    let person: EntityProperties = serde_json::from_value(serde_json::json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": 5,
+    }))
+    .expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let report = api
+        .create_entity(
+            person,
+            VersionedUri::new(
+                BaseUri::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URI"),
+                1,
+            ),
+            None,
+        )
+        .await
+        .expect_err("could create invalid entity");
+
+    assert!(report.contains::<EntityValidationError>());
+}
+
+#[tokio::test]
+async fn search() {
+    let organization: EntityProperties =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+    let person: EntityProperties =
+        serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+            entity_type::ORGANIZATION_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let organization_metadata = api
+        .create_entity(
+            organization,
+            VersionedUri::new(
+                BaseUri::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+                )
+                .expect("couldn't construct Base URI"),
+                1,
+            ),
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    api.create_entity(
+        person,
+        VersionedUri::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+                .expect("couldn't construct Base URI"),
+            1,
+        ),
+        None,
+    )
+    .await
+    .expect("could not create entity");
+
+    let subgraph = api
+        .search_latest_entities("hash")
+        .await
+        .expect("could not search entities");
+
+    let organization_id = GraphElementEditionId::KnowledgeGraph(organization_metadata.edition_id());
+    assert_eq!(subgraph.roots.len(), 1);
+    assert!(subgraph.roots.contains(&organization_id));
+    assert!(subgraph.search_ranks[&organization_id] > 0.0);
+
+    let subgraph = api
+        .search_latest_entities("bob")
+        .await
+        .expect("could not search entities");
+    assert!(subgraph.roots.is_empty());
+    assert!(subgraph.search_ranks.is_empty());
+}
+
+#[tokio::test]
+async fn migrate() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_et_v2_repr: repr::EntityType = serde_json::from_str(entity_type::PAGE_V2)
+        .expect("could not parse entity type representation");
+    let page_et_v2 = EntityType::try_from(page_et_v2_repr).expect("could not parse entity type");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1, property_type::NAME_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+                entity_type::PAGE_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    api.update_entity_type(page_et_v2)
+        .await
+        .expect("could not update entity type");
+
+    let page_type_id = |version| {
+        VersionedUri::new(
+            BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/page/".to_owned())
+                .expect("couldn't construct Base URI"),
+            version,
+        )
+    };
+
+    let metadata = api
+        .create_entity(page_v1.clone(), page_type_id(1), None)
+        .await
+        .expect("could not create entity");
+    let entity_id = metadata.edition_id().base_id();
+
+    let migration = EntityMigration::new(page_type_id(1), page_type_id(2), vec![
+        PropertyMapping::Drop {
+            property: BaseUri::new(
+                "https://blockprotocol.org/@alice/types/property-type/text/".to_owned(),
+            )
+            .expect("couldn't construct Base URI"),
+        },
+    ]);
+
+    let dry_run = api
+        .migrate_entities(&migration, true)
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(dry_run.len(), 1);
+    assert_eq!(dry_run[0].properties(), &EntityProperties::empty());
+    assert_eq!(dry_run[0].metadata().entity_type_id(), &page_type_id(2));
+
+    let entity = api
+        .get_entity_at(entity_id, TemporalAxes::default())
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties(), &page_v1);
+    assert_eq!(entity.metadata().entity_type_id(), &page_type_id(1));
+
+    let migrated = api
+        .migrate_entities(&migration, false)
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(migrated.len(), 1);
+
+    let entity = api
+        .get_entity_at(entity_id, TemporalAxes::default())
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties(), &EntityProperties::empty());
+    assert_eq!(entity.metadata().entity_type_id(), &page_type_id(2));
+
+    assert!(api
+        .migrate_entities(
+            &EntityMigration::new(page_type_id(2), page_type_id(1), Vec::new()),
+            false
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn history() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: EntityProperties =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database:");
+
+    let page_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/page/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let v1_metadata = api
+        .create_entity(page_v1.clone(), page_type_id.clone(), None)
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_metadata.edition_id().base_id();
+
+    api.update_entity(
+        entity_id,
+        page_v2.clone(),
+        page_type_id,
+        EntityLinkOrder::new(None, None),
+    )
+    .await
+    .expect("could not update entity");
+
+    let history = api
+        .read_entity_history(entity_id)
+        .await
+        .expect("could not read entity history");
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].properties(), &page_v1);
+    assert_eq!(history[1].properties(), &page_v2);
+    assert!(!diff_properties(history[0].properties(), history[1].properties()).is_empty());
+
+    let v3_metadata = api
+        .restore_entity_edition(entity_id, v1_metadata.edition_id().record_id())
+        .await
+        .expect("could not restore entity edition");
+
+    let history = api
+        .read_entity_history(entity_id)
+        .await
+        .expect("could not read entity history");
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history[2].metadata().edition_id().record_id(),
+        v3_metadata.edition_id().record_id()
+    );
+    assert_eq!(history[2].properties(), &page_v1);
+    assert!(diff_properties(history[0].properties(), history[2].properties()).is_empty());
+}
+
+#[tokio::test]
+async fn paginate() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let mut created = HashSet::new();
+    for name in ["Alice", "Bob", "Charlie", "Dave", "Eve"] {
+        let metadata = api
+            .create_entity(
+                serde_json::from_value(json!({
+                    "https://blockprotocol.org/@alice/types/property-type/name/": name,
+                }))
+                .expect("could not parse entity"),
+                person_type_id.clone(),
+                None,
+            )
+            .await
+            .expect("could not create entity");
+        created.insert(GraphElementEditionId::KnowledgeGraph(metadata.edition_id()));
+    }
+
+    let mut read = HashSet::new();
+    let mut page_sizes = Vec::new();
+    let mut after = None;
+    loop {
+        let subgraph = api
+            .get_latest_entities_page(&person_type_id, Pagination { limit: 2, after })
+            .await
+            .expect("could not read page");
+        page_sizes.push(subgraph.roots.len());
+        for root in subgraph.roots {
+            assert!(read.insert(root), "entity was returned on multiple pages");
+        }
+
+        after = subgraph.next_cursor;
+        if after.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(page_sizes, [2, 2, 1]);
+    assert_eq!(read, created);
+}
@@ -1,4 +1,12 @@
-use graph_test_data::{data_type, entity_type, property_type};
+use std::collections::HashSet;
+
+use graph::{
+    identifier::{ontology::OntologyTypeEditionId, GraphElementEditionId},
+    knowledge::EntityProperties,
+    store::query::Pagination,
+    subgraph::edges::{EdgeResolveDepths, GraphResolveDepths},
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
 use type_system::{repr, EntityType};
 
 use crate::postgres::DatabaseTestWrapper;
@@ -99,3 +107,175 @@ async fn update() {
     assert_eq!(&page_et_v1, returned_page_et_v1.inner());
     assert_eq!(&page_et_v2, returned_page_et_v2.inner());
 }
+
+#[tokio::test]
+async fn query_incoming_edges() {
+    let person: EntityProperties =
+        serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+    let person_et_repr: repr::EntityType = serde_json::from_str(entity_type::PERSON_V1)
+        .expect("could not parse entity type representation");
+    let person_et = EntityType::try_from(person_et_repr).expect("could not parse entity type");
+    let friend_of_et_repr: repr::EntityType = serde_json::from_str(entity_type::link::FRIEND_OF_V1)
+        .expect("could not parse entity type representation");
+    let friend_of_et =
+        EntityType::try_from(friend_of_et_repr).expect("could not parse entity type");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let metadata = api
+        .create_entity(person, person_et.id().clone(), None)
+        .await
+        .expect("could not create entity");
+
+    let mut subgraph = api
+        .get_entity_type_subgraph(person_et.id(), GraphResolveDepths {
+            is_of_type: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 0,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await
+        .expect("could not get entity type");
+    assert!(
+        subgraph
+            .vertices
+            .remove(&GraphElementEditionId::KnowledgeGraph(
+                metadata.edition_id()
+            ))
+            .is_some(),
+        "entity of the entity type was not resolved"
+    );
+
+    // `Person` constrains its links on `Friend Of`
+    let mut subgraph = api
+        .get_entity_type_subgraph(friend_of_et.id(), GraphResolveDepths {
+            constrains_links_on: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 0,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await
+        .expect("could not get entity type");
+    assert!(
+        subgraph
+            .vertices
+            .remove(&GraphElementEditionId::Ontology(
+                OntologyTypeEditionId::from(person_et.id())
+            ))
+            .is_some(),
+        "entity type linking to the link type was not resolved"
+    );
+
+    let link_et_repr: repr::EntityType = serde_json::from_str(entity_type::LINK_V1)
+        .expect("could not parse entity type representation");
+    let link_et = EntityType::try_from(link_et_repr).expect("could not parse entity type");
+
+    let mut subgraph = api
+        .get_entity_type_subgraph(link_et.id(), GraphResolveDepths {
+            inherits_from: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 0,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await
+        .expect("could not get entity type");
+    assert!(
+        subgraph
+            .vertices
+            .remove(&GraphElementEditionId::Ontology(
+                OntologyTypeEditionId::from(friend_of_et.id())
+            ))
+            .is_some(),
+        "entity type inheriting from the link type was not resolved"
+    );
+
+    // `Friend Of` only inherits from `Link`, so it must not be resolved as linking to it
+    let mut subgraph = api
+        .get_entity_type_subgraph(link_et.id(), GraphResolveDepths {
+            constrains_links_on: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 0,
+            },
+            ..GraphResolveDepths::default()
+        })
+        .await
+        .expect("could not get entity type");
+    assert!(
+        subgraph
+            .vertices
+            .remove(&GraphElementEditionId::Ontology(
+                OntologyTypeEditionId::from(friend_of_et.id())
+            ))
+            .is_none(),
+        "entity type inheriting from the link type was resolved as linking to it"
+    );
+}
+
+#[tokio::test]
+async fn paginate() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+            entity_type::ORGANIZATION_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let expected = [
+        entity_type::LINK_V1,
+        entity_type::link::FRIEND_OF_V1,
+        entity_type::PERSON_V1,
+        entity_type::ORGANIZATION_V1,
+    ]
+    .into_iter()
+    .map(|entity_type| {
+        let entity_type: repr::EntityType =
+            serde_json::from_str(entity_type).expect("could not parse entity type");
+        let entity_type = EntityType::try_from(entity_type).expect("could not parse entity type");
+        GraphElementEditionId::Ontology(OntologyTypeEditionId::from(entity_type.id()))
+    })
+    .collect::<HashSet<_>>();
+
+    let mut read = HashSet::new();
+    let mut pages = 0;
+    let mut after = None;
+    loop {
+        let subgraph = api
+            .get_latest_entity_types_page(Pagination { limit: 3, after })
+            .await
+            .expect("could not read page");
+        assert!(subgraph.roots.len() <= 3);
+        pages += 1;
+        for root in subgraph.roots {
+            assert!(
+                read.insert(root),
+                "entity type was returned on multiple pages"
+            );
+        }
+
+        after = subgraph.next_cursor;
+        if after.is_none() {
+            break;
+        }
+    }
+
+    assert!(
+        pages >= 2,
+        "all entity types were returned on a single page"
+    );
+    assert!(read.is_superset(&expected));
+}
@@ -1,4 +1,11 @@
-use graph::knowledge::{EntityLinkOrder, EntityProperties};
+use graph::{
+    knowledge::{EntityLinkOrder, EntityProperties},
+    provenance::{OwnedById, UpdatedById},
+    store::{
+        error::EntityIsReferenced, BatchLinkData, BatchOperation, BatchOperationResult,
+        EntityReference, InvalidEntityReference, LinkPurgeBehavior,
+    },
+};
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use type_system::uri::{BaseUri, VersionedUri};
 
@@ -259,3 +266,194 @@ async fn remove_link() {
             .is_empty()
     );
 }
+
+#[tokio::test]
+async fn purge_linked_entity() {
+    let person_a = serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+    let person_b = serde_json::from_str(entity::PERSON_B_V1).expect("could not parse entity");
+    let friend_of = EntityProperties::empty();
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let person_a_metadata = api
+        .create_entity(person_a, person_type_id.clone(), None)
+        .await
+        .expect("could not create entity");
+
+    let person_b_metadata = api
+        .create_entity(person_b, person_type_id, None)
+        .await
+        .expect("could not create entity");
+
+    let friend_of_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let link_metadata = api
+        .create_link_entity(
+            friend_of,
+            friend_of_type_id,
+            None,
+            person_a_metadata.edition_id().base_id(),
+            person_b_metadata.edition_id().base_id(),
+        )
+        .await
+        .expect("could not create link");
+
+    let report = api
+        .purge_entity(
+            person_b_metadata.edition_id().base_id(),
+            LinkPurgeBehavior::Refuse,
+        )
+        .await
+        .expect_err("could purge referenced entity");
+    assert!(report.contains::<EntityIsReferenced>());
+    api.get_entity(person_b_metadata.edition_id())
+        .await
+        .expect("entity was purged although the purge was refused");
+
+    let mut purged_entity_ids = api
+        .purge_entity(
+            person_b_metadata.edition_id().base_id(),
+            LinkPurgeBehavior::Cascade,
+        )
+        .await
+        .expect("could not purge entity");
+    purged_entity_ids.sort();
+    let mut expected_entity_ids = vec![
+        person_b_metadata.edition_id().base_id(),
+        link_metadata.edition_id().base_id(),
+    ];
+    expected_entity_ids.sort();
+    assert_eq!(purged_entity_ids, expected_entity_ids);
+
+    assert!(api
+        .get_latest_entity_links(person_a_metadata.edition_id().base_id())
+        .await
+        .expect("could not fetch links")
+        .is_empty());
+
+    // The left entity of the purged link is not affected
+    api.get_entity(person_a_metadata.edition_id())
+        .await
+        .expect("could not get entity");
+}
+
+#[tokio::test]
+async fn batch_insert_link() {
+    let person_a = serde_json::from_str(entity::PERSON_A_V1).expect("could not parse entity");
+    let person_b = serde_json::from_str(entity::PERSON_B_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/person/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+    let friend_of_type_id = VersionedUri::new(
+        BaseUri::new("https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned())
+            .expect("couldn't construct Base URI"),
+        1,
+    );
+
+    let owned_by_id = OwnedById::new(api.account_id);
+    let actor_id = UpdatedById::new(api.account_id);
+    let create_person = |properties: EntityProperties| BatchOperation::CreateEntity {
+        owned_by_id,
+        entity_uuid: None,
+        actor_id,
+        entity_type_id: person_type_id.clone(),
+        properties,
+        link_data: None,
+    };
+    let create_friend_of = |left_entity, right_entity| BatchOperation::CreateEntity {
+        owned_by_id,
+        entity_uuid: None,
+        actor_id,
+        entity_type_id: friend_of_type_id.clone(),
+        properties: EntityProperties::empty(),
+        link_data: Some(BatchLinkData {
+            left_entity,
+            right_entity,
+            order: EntityLinkOrder::new(None, None),
+        }),
+    };
+
+    let results = api
+        .apply_batch(vec![
+            create_person(person_a),
+            create_person(person_b),
+            create_friend_of(EntityReference::Operation(0), EntityReference::Operation(1)),
+        ])
+        .await
+        .expect("could not apply batch");
+    let entity_ids = results
+        .iter()
+        .map(|result| match result {
+            BatchOperationResult::Entity(metadata) => metadata.edition_id().base_id(),
+            BatchOperationResult::Ontology(_) | BatchOperationResult::OntologyUpdate(_) => {
+                panic!("unexpected ontology result")
+            }
+        })
+        .collect::<Vec<_>>();
+    let [person_a_id, person_b_id, _] = entity_ids[..] else {
+        panic!(
+            "unexpected number of results, expected 3 but received {}",
+            entity_ids.len()
+        );
+    };
+
+    let link_entity = api
+        .get_link_entity_target(person_a_id, friend_of_type_id.clone())
+        .await
+        .expect("could not fetch entity");
+    let link_data = link_entity.link_data().expect("entity is not a link");
+    assert_eq!(link_data.right_entity_id(), person_b_id);
+
+    // The second link refers to an operation, which does not precede it, so the whole batch has to
+    // be rolled back.
+    let report = api
+        .apply_batch(vec![
+            create_friend_of(
+                EntityReference::EntityId(person_a_id),
+                EntityReference::EntityId(person_b_id),
+            ),
+            create_friend_of(EntityReference::Operation(0), EntityReference::Operation(2)),
+        ])
+        .await
+        .expect_err("could apply batch with invalid reference");
+    assert!(report.contains::<InvalidEntityReference>());
+
+    assert_eq!(
+        api.get_latest_entity_links(person_a_id)
+            .await
+            .expect("could not fetch links")
+            .len(),
+        1
+    );
+}
@@ -6,11 +6,12 @@ mod property_type;
 
 use std::{borrow::Cow, str::FromStr};
 
-use error_stack::Result;
+use error_stack::{Result, ResultExt};
+use futures::TryStreamExt;
 use graph::{
     identifier::{
         account::AccountId,
-        knowledge::{EntityEditionId, EntityId},
+        knowledge::{EntityEditionId, EntityId, EntityRecordId},
         ontology::OntologyTypeEditionId,
         DecisionTimestamp, GraphElementEditionId,
     },
@@ -19,20 +20,27 @@ use graph::{
         LinkData,
     },
     ontology::{
-        DataTypeWithMetadata, EntityTypeQueryPath, EntityTypeWithMetadata, OntologyElementMetadata,
-        PropertyTypeWithMetadata,
+        CompatibilityPolicy, DataTypeWithMetadata, EntityTypeQueryPath, EntityTypeWithMetadata,
+        OntologyElementMetadata, OntologyUpdateMetadata, PropertyTypeWithMetadata,
     },
     provenance::{OwnedById, UpdatedById},
     store::{
-        query::{Filter, FilterExpression, Parameter},
-        AccountStore, AsClient, DataTypeStore, DatabaseConnectionInfo, DatabaseType, EntityStore,
-        EntityTypeStore, InsertionError, PostgresStore, PostgresStorePool, PropertyTypeStore,
-        QueryError, StorePool, UpdateError,
+        crud::Read,
+        query::{
+            Aggregate, AggregationGroup, Filter, FilterExpression, Pagination, Parameter,
+            TemporalAxes,
+        },
+        AccountStore, AsClient, BatchOperation, BatchOperationResult, BatchStore, ChangeEvent,
+        ChangeFeedStore, DataTypeStore, DatabaseConnectionInfo, DatabaseType, DeletionError,
+        EntityHistoryStore, EntityMigration, EntityMigrationStore, EntityStore, EntityTypeStore,
+        InsertionError, LinkPurgeBehavior, MigratedEntity, PostgresStore, PostgresStorePool,
+        PropertyTypeStore, QueryError, StorePool, UpdateError,
     },
     subgraph::{
         edges::GraphResolveDepths,
-        query::StructuralQuery,
+        query::{AggregationQuery, StructuralQuery},
         vertices::{KnowledgeGraphVertex, OntologyVertex, Vertex},
+        Subgraph,
     },
 };
 use tokio_postgres::{NoTls, Transaction};
@@ -171,6 +179,8 @@ impl DatabaseApi<'_> {
             .get_data_type(&StructuralQuery {
                 filter: Filter::for_versioned_uri(uri),
                 graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await?
             .vertices
@@ -187,9 +197,13 @@ impl DatabaseApi<'_> {
     pub async fn update_data_type(
         &mut self,
         data_type: DataType,
-    ) -> Result<OntologyElementMetadata, UpdateError> {
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
         self.store
-            .update_data_type(data_type, UpdatedById::new(self.account_id))
+            .update_data_type(
+                data_type,
+                UpdatedById::new(self.account_id),
+                CompatibilityPolicy::default(),
+            )
             .await
     }
 
@@ -215,6 +229,8 @@ impl DatabaseApi<'_> {
             .get_property_type(&StructuralQuery {
                 filter: Filter::for_versioned_uri(uri),
                 graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await?
             .vertices
@@ -231,9 +247,13 @@ impl DatabaseApi<'_> {
     pub async fn update_property_type(
         &mut self,
         property_type: PropertyType,
-    ) -> Result<OntologyElementMetadata, UpdateError> {
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
         self.store
-            .update_property_type(property_type, UpdatedById::new(self.account_id))
+            .update_property_type(
+                property_type,
+                UpdatedById::new(self.account_id),
+                CompatibilityPolicy::default(),
+            )
             .await
     }
 
@@ -259,6 +279,8 @@ impl DatabaseApi<'_> {
             .get_entity_type(&StructuralQuery {
                 filter: Filter::for_versioned_uri(uri),
                 graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await?
             .vertices
@@ -272,12 +294,45 @@ impl DatabaseApi<'_> {
         Ok(*entity_type)
     }
 
+    pub async fn get_entity_type_subgraph(
+        &self,
+        uri: &VersionedUri,
+        graph_resolve_depths: GraphResolveDepths,
+    ) -> Result<Subgraph, QueryError> {
+        self.store
+            .get_entity_type(&StructuralQuery {
+                filter: Filter::for_versioned_uri(uri),
+                graph_resolve_depths,
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+    }
+
+    pub async fn get_latest_entity_types_page(
+        &self,
+        pagination: Pagination,
+    ) -> Result<Subgraph, QueryError> {
+        self.store
+            .get_entity_type(&StructuralQuery {
+                filter: Filter::for_latest_version(),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: Some(pagination),
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+    }
+
     pub async fn update_entity_type(
         &mut self,
         entity_type: EntityType,
-    ) -> Result<OntologyElementMetadata, UpdateError> {
+    ) -> Result<OntologyUpdateMetadata, UpdateError> {
         self.store
-            .update_entity_type(entity_type, UpdatedById::new(self.account_id))
+            .update_entity_type(
+                entity_type,
+                UpdatedById::new(self.account_id),
+                CompatibilityPolicy::default(),
+            )
             .await
     }
 
@@ -310,6 +365,8 @@ impl DatabaseApi<'_> {
             .get_entity(&StructuralQuery {
                 filter: Filter::for_entity_by_edition_id(entity_edition_id),
                 graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await?
             .vertices
@@ -321,6 +378,59 @@ impl DatabaseApi<'_> {
         Ok(persisted_entity)
     }
 
+    pub async fn get_entity_at(
+        &self,
+        entity_id: EntityId,
+        temporal_axes: TemporalAxes,
+    ) -> Result<Entity, QueryError> {
+        let mut subgraph = self
+            .store
+            .get_entity(&StructuralQuery {
+                filter: Filter::for_latest_entity_by_entity_id(entity_id),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes,
+            })
+            .await?;
+
+        let root = subgraph.roots.drain().next().expect("no entity found");
+        let Some(Vertex::KnowledgeGraph(vertex)) = subgraph.vertices.remove(&root) else {
+            unreachable!()
+        };
+        let KnowledgeGraphVertex::Entity(persisted_entity) = *vertex;
+        Ok(persisted_entity)
+    }
+
+    pub async fn get_latest_entities_page(
+        &self,
+        entity_type_id: &VersionedUri,
+        pagination: Pagination,
+    ) -> Result<Subgraph, QueryError> {
+        let entity_type_id = OntologyTypeEditionId::from(entity_type_id);
+        self.store
+            .get_entity(&StructuralQuery {
+                filter: Filter::for_latest_entities_by_entity_type(&entity_type_id),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: Some(pagination),
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+    }
+
+    pub async fn search_latest_entities(&self, query: &str) -> Result<Subgraph, QueryError> {
+        self.store
+            .get_entity(&StructuralQuery {
+                filter: Filter::All(vec![
+                    Filter::Search(Cow::Borrowed(query)),
+                    Filter::for_all_latest_entities(),
+                ]),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+    }
+
     pub async fn update_entity(
         &mut self,
         entity_id: EntityId,
@@ -408,6 +518,8 @@ impl DatabaseApi<'_> {
             .get_entity(&StructuralQuery {
                 filter,
                 graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await?;
 
@@ -468,6 +580,8 @@ impl DatabaseApi<'_> {
             .get_entity(&StructuralQuery {
                 filter,
                 graph_resolve_depths: GraphResolveDepths::default(),
+                pagination: None,
+                temporal_axes: TemporalAxes::default(),
             })
             .await?;
 
@@ -483,6 +597,115 @@ impl DatabaseApi<'_> {
             .collect())
     }
 
+    pub async fn stream_latest_entities(
+        &mut self,
+        entity_type_id: &VersionedUri,
+    ) -> Result<Vec<Entity>, QueryError> {
+        let entity_type_id = OntologyTypeEditionId::from(entity_type_id);
+        Read::<Entity>::read_stream(
+            &mut self.store,
+            &Filter::for_latest_entities_by_entity_type(&entity_type_id),
+            &TemporalAxes::default(),
+        )
+        .await?
+        .try_collect()
+        .await
+    }
+
+    pub async fn get_latest_entities_by_filter(
+        &self,
+        filter: &Filter<'_, Entity>,
+    ) -> Result<Vec<Entity>, QueryError> {
+        Read::<Entity>::read(&self.store, filter, &TemporalAxes::default()).await
+    }
+
+    pub async fn aggregate_latest_entities_by_owner(
+        &self,
+        entity_type_id: &VersionedUri,
+        aggregates: Vec<Aggregate<'_, Entity>>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        let entity_type_id = OntologyTypeEditionId::from(entity_type_id);
+        self.store
+            .aggregate_entities(&AggregationQuery {
+                filter: Filter::for_latest_entities_by_entity_type(&entity_type_id),
+                group_by: vec![EntityQueryPath::OwnedById],
+                aggregates,
+                temporal_axes: TemporalAxes::default(),
+            })
+            .await
+    }
+
+    pub async fn purge_entity(
+        &mut self,
+        entity_id: EntityId,
+        link_behavior: LinkPurgeBehavior,
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        let authorized_entity_ids = match link_behavior {
+            LinkPurgeBehavior::Refuse => vec![entity_id],
+            LinkPurgeBehavior::Cascade => self
+                .store
+                .read_purged_entity_ids(entity_id)
+                .await
+                .change_context(DeletionError)?,
+        };
+        self.store
+            .purge_entity(
+                entity_id,
+                UpdatedById::new(self.account_id),
+                link_behavior,
+                &authorized_entity_ids,
+            )
+            .await
+    }
+
+    pub async fn apply_batch(
+        &mut self,
+        operations: Vec<BatchOperation>,
+    ) -> Result<Vec<BatchOperationResult>, InsertionError> {
+        self.store.apply_batch(operations).await
+    }
+
+    pub async fn migrate_entities(
+        &mut self,
+        migration: &EntityMigration,
+        dry_run: bool,
+    ) -> Result<Vec<MigratedEntity>, UpdateError> {
+        self.store
+            .migrate_entities(migration, UpdatedById::new(self.account_id), dry_run)
+            .await
+    }
+
+    pub async fn read_entity_history(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<Entity>, QueryError> {
+        self.store.read_entity_history(entity_id).await
+    }
+
+    pub async fn restore_entity_edition(
+        &mut self,
+        entity_id: EntityId,
+        record_id: EntityRecordId,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store
+            .restore_entity_edition(entity_id, record_id, UpdatedById::new(self.account_id))
+            .await
+    }
+
+    pub async fn read_all_changes(&self) -> Result<Vec<ChangeEvent>, QueryError> {
+        let mut events = Vec::new();
+        loop {
+            let page = self
+                .store
+                .read_changes(events.last().map(ChangeEvent::offset), 1000)
+                .await?;
+            if page.is_empty() {
+                return Ok(events);
+            }
+            events.extend(page);
+        }
+    }
+
     async fn archive_entity(
         &mut self,
         entity_id: EntityId,
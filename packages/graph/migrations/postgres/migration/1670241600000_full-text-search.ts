@@ -0,0 +1,72 @@
+import { MigrationBuilder } from "node-pg-migrate";
+
+const ontologyTables = ["data_types", "property_types", "entity_types"];
+
+const entitiesView = (additionalColumns: string[]) => `
+    SELECT
+      entity_versions.entity_record_id,
+      entity_versions.owned_by_id,
+      entity_versions.entity_uuid,
+      entity_versions.decision_time,
+      entity_versions.transaction_time,
+      entity_editions.entity_type_version_id,
+      entity_editions.updated_by_id,
+      entity_editions.properties,
+      entity_editions.archived,
+      entity_ids.left_owned_by_id,
+      entity_ids.left_entity_uuid,
+      entity_editions.left_to_right_order,
+      entity_ids.right_owned_by_id,
+      entity_ids.right_entity_uuid,
+      entity_editions.right_to_left_order${additionalColumns
+        .map((column) => `,\n      ${column}`)
+        .join("")}
+    FROM entity_versions
+    JOIN entity_editions ON entity_versions.entity_record_id = entity_editions.entity_record_id
+    JOIN entity_ids ON entity_versions.owned_by_id = entity_ids.owned_by_id AND entity_versions.entity_uuid = entity_ids.entity_uuid
+    `;
+
+export const up = (pgm: MigrationBuilder): void => {
+  /**
+   * The search vectors are generated columns, so they are always in sync with the searched data.
+   * The text search configuration has to match the one used when compiling the search filter.
+   */
+  for (const tableName of ontologyTables) {
+    pgm.addColumn(tableName, {
+      search_vector: {
+        type: "tsvector",
+        notNull: true,
+        expressionGenerated: `to_tsvector('english', coalesce(schema->>'title', '') || ' ' || coalesce(schema->>'description', ''))`,
+      },
+    });
+    pgm.createIndex(tableName, "search_vector", { method: "gin" });
+  }
+
+  pgm.addColumn("entity_editions", {
+    search_vector: {
+      type: "tsvector",
+      notNull: true,
+      expressionGenerated: `jsonb_to_tsvector('english', properties, '["string", "numeric"]')`,
+    },
+  });
+  pgm.createIndex("entity_editions", "search_vector", { method: "gin" });
+
+  pgm.createView(
+    "entities",
+    { replace: true },
+    entitiesView(["entity_editions.search_vector"]),
+  );
+};
+
+export const down = (pgm: MigrationBuilder): void => {
+  pgm.dropView("entities");
+  pgm.createView("entities", {}, entitiesView([]));
+
+  pgm.dropIndex("entity_editions", "search_vector");
+  pgm.dropColumn("entity_editions", "search_vector");
+
+  for (const tableName of ontologyTables) {
+    pgm.dropIndex(tableName, "search_vector");
+    pgm.dropColumn(tableName, "search_vector");
+  }
+};
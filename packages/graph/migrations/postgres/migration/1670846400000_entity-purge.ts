@@ -0,0 +1,41 @@
+import { MigrationBuilder } from "node-pg-migrate";
+
+export const up = (pgm: MigrationBuilder): void => {
+  /**
+   * Purging an entity removes all of its editions, so only the identifiers of purged entities are
+   * kept to be able to audit who purged which entity and when. `requested_*` refers to the entity
+   * the purge was requested for, which differs from the purged entity for cascaded link entities.
+   */
+  pgm.createTable("entity_purges", {
+    owned_by_id: {
+      type: "UUID",
+      notNull: true,
+    },
+    entity_uuid: {
+      type: "UUID",
+      notNull: true,
+    },
+    requested_owned_by_id: {
+      type: "UUID",
+      notNull: true,
+    },
+    requested_entity_uuid: {
+      type: "UUID",
+      notNull: true,
+    },
+    purged_by_id: {
+      type: "UUID",
+      notNull: true,
+      references: "accounts",
+    },
+    purged_at: {
+      type: "TIMESTAMP WITH TIME ZONE",
+      notNull: true,
+      default: pgm.func("now()"),
+    },
+  });
+};
+
+export const down = (pgm: MigrationBuilder): void => {
+  pgm.dropTable("entity_purges");
+};
@@ -0,0 +1,27 @@
+import { MigrationBuilder } from "node-pg-migrate";
+
+export const up = (pgm: MigrationBuilder): void => {
+  /**
+   * Members of an account are allowed to write to the namespace of the account. An account is
+   * implicitly a member of itself, so this is not stored in this table.
+   */
+  pgm.createTable("account_members", {
+    account_id: {
+      type: "UUID",
+      notNull: true,
+      references: "accounts",
+    },
+    member_id: {
+      type: "UUID",
+      notNull: true,
+      references: "accounts",
+    },
+  });
+  pgm.addConstraint("account_members", "account_members_primary_key", {
+    primaryKey: ["account_id", "member_id"],
+  });
+};
+
+export const down = (pgm: MigrationBuilder): void => {
+  pgm.dropTable("account_members");
+};
@@ -0,0 +1,78 @@
+import { MigrationBuilder } from "node-pg-migrate";
+
+export const up = (pgm: MigrationBuilder): void => {
+  /**
+   * Outbox of the change feed. An event is inserted in the same transaction as the change it
+   * describes. `base_uri` and `version` refer to the changed ontology type or, for entities, to the
+   * entity type of the entity. The entity columns are only set for entity events.
+   *
+   * `transaction_id` is the writing transaction. Events are read in the order of
+   * `(transaction_id, event_offset)` and only once every transaction with a lower id has ended, so
+   * no event can appear before an event which was already read.
+   */
+  pgm.createTable("graph_change_events", {
+    event_offset: {
+      type: "BIGINT",
+      primaryKey: true,
+      sequenceGenerated: {
+        precedence: "ALWAYS",
+      },
+    },
+    kind: {
+      type: "TEXT",
+      notNull: true,
+      check: "kind IN ('create', 'update', 'archive')",
+    },
+    element: {
+      type: "TEXT",
+      notNull: true,
+      check: "element IN ('dataType', 'propertyType', 'entityType', 'entity')",
+    },
+    owned_by_id: {
+      type: "UUID",
+      notNull: true,
+    },
+    updated_by_id: {
+      type: "UUID",
+      notNull: true,
+    },
+    base_uri: {
+      type: "TEXT",
+      notNull: true,
+    },
+    version: {
+      type: "BIGINT",
+      notNull: true,
+    },
+    entity_uuid: {
+      type: "UUID",
+    },
+    entity_record_id: {
+      type: "BIGINT",
+    },
+    decision_time: {
+      type: "TIMESTAMP WITH TIME ZONE",
+    },
+    transaction_time: {
+      type: "TIMESTAMP WITH TIME ZONE",
+    },
+    archived: {
+      type: "BOOLEAN",
+    },
+    transaction_id: {
+      type: "XID8",
+      notNull: true,
+      default: pgm.func("pg_current_xact_id()"),
+    },
+    recorded_at: {
+      type: "TIMESTAMP WITH TIME ZONE",
+      notNull: true,
+      default: pgm.func("now()"),
+    },
+  });
+  pgm.createIndex("graph_change_events", ["transaction_id", "event_offset"]);
+};
+
+export const down = (pgm: MigrationBuilder): void => {
+  pgm.dropTable("graph_change_events");
+};
@@ -0,0 +1,199 @@
+import { MigrationBuilder } from "node-pg-migrate";
+
+const createEntityParams = [
+  { name: "_owned_by_id", type: "UUID" },
+  { name: "_entity_uuid", type: "UUID" },
+  { name: "_decision_time", type: "TIMESTAMP WITH TIME ZONE" },
+  { name: "_updated_by_id", type: "UUID" },
+  { name: "_archived", type: "BOOLEAN" },
+  { name: "_entity_type_version_id", type: "UUID" },
+  { name: "_properties", type: "JSONB" },
+  { name: "_left_owned_by_id", type: "UUID" },
+  { name: "_left_entity_uuid", type: "UUID" },
+  { name: "_right_owned_by_id", type: "UUID" },
+  { name: "_right_entity_uuid", type: "UUID" },
+  { name: "_left_to_right_order", type: "INTEGER" },
+  { name: "_right_to_left_order", type: "INTEGER" },
+];
+
+const updateEntityParams = [
+  { name: "_owned_by_id", type: "UUID" },
+  { name: "_entity_uuid", type: "UUID" },
+  { name: "_decision_time", type: "TIMESTAMP WITH TIME ZONE" },
+  { name: "_updated_by_id", type: "UUID" },
+  { name: "_archived", type: "BOOLEAN" },
+  { name: "_entity_type_version_id", type: "UUID" },
+  { name: "_properties", type: "JSONB" },
+  { name: "_left_to_right_order", type: "INTEGER" },
+  { name: "_right_to_left_order", type: "INTEGER" },
+];
+
+const transactionTimeParam = {
+  name: "_transaction_time",
+  type: "TIMESTAMP WITH TIME ZONE",
+};
+
+const functionOptions = {
+  returns:
+    "TABLE (entity_record_id BIGINT, decision_time tstzrange, transaction_time tstzrange)",
+  language: "plpgsql",
+  replace: true,
+};
+
+/**
+ * `transactionTime` is the expression used as start of the transaction time of the written edition,
+ * `prelude` is run before writing.
+ */
+const createEntityBody = (transactionTime: string, prelude = "") => `
+    DECLARE
+      _entity_record_id BIGINT;
+    BEGIN${prelude}
+      IF _decision_time IS NULL THEN _decision_time := now(); END IF;
+
+      INSERT INTO entity_ids (
+        owned_by_id,
+        entity_uuid,
+        left_owned_by_id,
+        left_entity_uuid,
+        right_owned_by_id,
+        right_entity_uuid
+      ) VALUES (
+        _owned_by_id,
+        _entity_uuid,
+        _left_owned_by_id,
+        _left_entity_uuid,
+        _right_owned_by_id,
+        _right_entity_uuid
+      );
+
+      -- insert the data of the entity
+      INSERT INTO entity_editions (
+        updated_by_id,
+        archived,
+        entity_type_version_id,
+        properties,
+        left_to_right_order,
+        right_to_left_order
+      ) VALUES (
+        _updated_by_id,
+        _archived,
+        _entity_type_version_id,
+        _properties,
+        _left_to_right_order,
+        _right_to_left_order
+      ) RETURNING entity_editions.entity_record_id INTO _entity_record_id;
+
+      RETURN QUERY
+      INSERT INTO entity_versions (
+        owned_by_id,
+        entity_uuid,
+        entity_record_id,
+        decision_time,
+        transaction_time
+      ) VALUES (
+        _owned_by_id,
+        _entity_uuid,
+        _entity_record_id,
+        tstzrange(_decision_time, 'infinity', '[)'),
+        tstzrange(${transactionTime}, 'infinity', '[)')
+      ) RETURNING entity_versions.entity_record_id, entity_versions.decision_time, entity_versions.transaction_time;
+    END
+    `;
+
+/**
+ * `transactionTime` is the expression used as start of the transaction time of the written edition,
+ * `prelude` is run before writing.
+ */
+const updateEntityBody = (transactionTime: string, prelude = "") => `
+    DECLARE
+      _new_entity_record_id BIGINT;
+    BEGIN${prelude}
+      IF _decision_time IS NULL THEN _decision_time := now(); END IF;
+
+      INSERT INTO entity_editions (
+        updated_by_id,
+        archived,
+        entity_type_version_id,
+        properties,
+        left_to_right_order,
+        right_to_left_order
+      ) VALUES (
+        _updated_by_id,
+        _archived,
+        _entity_type_version_id,
+        _properties,
+        _left_to_right_order,
+        _right_to_left_order
+      )
+      RETURNING entity_editions.entity_record_id INTO _new_entity_record_id;
+
+      RETURN QUERY
+      UPDATE entity_versions
+      SET decision_time = tstzrange(_decision_time, upper(entity_versions.decision_time), '[)'),
+          transaction_time = tstzrange(${transactionTime}, 'infinity', '[)'),
+          entity_record_id = _new_entity_record_id
+      WHERE entity_versions.owned_by_id = _owned_by_id
+        AND entity_versions.entity_uuid = _entity_uuid
+        AND entity_versions.decision_time @> _decision_time
+        AND entity_versions.transaction_time @> ${transactionTime}
+      RETURNING entity_versions.entity_record_id, entity_versions.decision_time, entity_versions.transaction_time;
+    END
+    `;
+
+/**
+ * Validates `_transaction_time` and defaults it to the current time. A transaction time is only
+ * specified when importing editions from an archive, it must not be in the future, as the edition
+ * would not be visible until then.
+ */
+const resolveTransactionTime = `
+      IF _transaction_time IS NULL THEN
+        _transaction_time := now();
+      ELSIF _transaction_time > now() THEN
+        RAISE EXCEPTION 'the transaction time % is in the future', _transaction_time;
+      END IF;`;
+
+export const up = (pgm: MigrationBuilder): void => {
+  /**
+   * `create_entity` and `update_entity` take an optional transaction time, so imported editions
+   * keep the transaction time they were exported with.
+   */
+  pgm.dropFunction("create_entity", createEntityParams);
+  pgm.createFunction(
+    "create_entity",
+    [...createEntityParams, transactionTimeParam],
+    functionOptions,
+    createEntityBody("_transaction_time", resolveTransactionTime),
+  );
+
+  pgm.dropFunction("update_entity", updateEntityParams);
+  pgm.createFunction(
+    "update_entity",
+    [...updateEntityParams, transactionTimeParam],
+    functionOptions,
+    updateEntityBody("_transaction_time", resolveTransactionTime),
+  );
+};
+
+export const down = (pgm: MigrationBuilder): void => {
+  pgm.dropFunction("create_entity", [
+    ...createEntityParams,
+    transactionTimeParam,
+  ]);
+  pgm.createFunction(
+    "create_entity",
+    createEntityParams,
+    functionOptions,
+    createEntityBody("now()"),
+  );
+
+  pgm.dropFunction("update_entity", [
+    ...updateEntityParams,
+    transactionTimeParam,
+  ]);
+  pgm.createFunction(
+    "update_entity",
+    updateEntityParams,
+    functionOptions,
+    updateEntityBody("now()"),
+  );
+};
@@ -1 +1,362 @@
-//! Placeholder for future support of macros.
+//! Derive macros for the `error-stack` crate.
+//!
+//! The macros are re-exported by `error-stack` when its `derive` feature is enabled, so this crate
+//! should not be used directly.
+#![warn(
+    missing_docs,
+    unreachable_pub,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::print_stderr
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Ident, Index, LitStr, Meta,
+    Result,
+};
+
+/// Derives [`Display`] and `Context` for a struct or an enum.
+///
+/// The [`Display`] output is specified by the `#[display("...")]` attribute on the struct or on
+/// every variant of the enum. The fields are available by their name in the format string, the
+/// fields of tuple structs and tuple variants by their index, e.g. `{0}`. Format specs are
+/// supported as in [`format!`], e.g. `{value:?}` uses the [`Debug`] output of the field.
+///
+/// Fields annotated with `#[provide]` are provided to the `Report` and can be requested via
+/// `Report::request_ref`. As the Provider API is unstable, this requires a nightly toolchain with
+/// `#![feature(provide_any)]` enabled, on other toolchains the attribute is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::{Context, Report};
+///
+/// #[derive(Debug, Context)]
+/// #[display("could not parse `{path}` in line {line}")]
+/// pub struct ParseError {
+///     path: &'static str,
+///     line: usize,
+/// }
+///
+/// #[derive(Debug, Context)]
+/// pub enum ConfigError {
+///     #[display("the configuration is missing")]
+///     Missing,
+///     #[display("the value of `{0}` is invalid: {1:?}")]
+///     InvalidValue(&'static str, Option<u32>),
+/// }
+///
+/// let report = Report::new(ParseError {
+///     path: "config.toml",
+///     line: 3,
+/// });
+/// assert_eq!(
+///     report.to_string(),
+///     "could not parse `config.toml` in line 3"
+/// );
+///
+/// let report = Report::new(ConfigError::InvalidValue("port", None));
+/// assert_eq!(report.to_string(), "the value of `port` is invalid: None");
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+/// [`Debug`]: core::fmt::Debug
+#[proc_macro_derive(Context, attributes(display, provide))]
+pub fn derive_context(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A struct or a single variant of an enum.
+struct Variant {
+    /// The path used in patterns, e.g. `Self` or `Self::Variant`.
+    path: TokenStream2,
+    display: LitStr,
+    /// The patterns binding the fields, e.g. `name` or `0: _0`.
+    fields: Vec<TokenStream2>,
+    bindings: Vec<Ident>,
+    provided: Vec<usize>,
+}
+
+impl Variant {
+    fn new(path: TokenStream2, display: &LitStr, fields: &Fields) -> Result<Self> {
+        let mut patterns = Vec::new();
+        let mut bindings = Vec::new();
+        let mut provided = Vec::new();
+
+        for (index, field) in fields.iter().enumerate() {
+            if let Some(ident) = &field.ident {
+                patterns.push(quote!(#ident));
+                bindings.push(ident.clone());
+            } else {
+                let member = Index::from(index);
+                let binding = format_ident!("_{}", index);
+                patterns.push(quote!(#member: #binding));
+                bindings.push(binding);
+            }
+
+            if is_provided(&field.attrs)? {
+                provided.push(index);
+            }
+        }
+
+        let display = LitStr::new(
+            &rewrite_arguments(&display.value(), &bindings)
+                .map_err(|message| Error::new(display.span(), message))?,
+            display.span(),
+        );
+
+        Ok(Self {
+            path,
+            display,
+            fields: patterns,
+            bindings,
+            provided,
+        })
+    }
+
+    /// Writes the `Display` output of the variant to `__formatter`.
+    fn display_arm(&self) -> TokenStream2 {
+        let Self {
+            path,
+            display,
+            fields,
+            ..
+        } = self;
+
+        quote! {
+            #path { #(#fields),* } => ::core::write!(__formatter, #display),
+        }
+    }
+
+    /// Provides the fields annotated with `#[provide]` to `__demand`.
+    fn provide_arm(&self) -> TokenStream2 {
+        let path = &self.path;
+        let fields = self.provided.iter().map(|&index| &self.fields[index]);
+        let bindings = self.provided.iter().map(|&index| &self.bindings[index]);
+
+        quote! {
+            #path { #(#fields,)* .. } => {
+                #(__demand.provide_ref(#bindings);)*
+            }
+        }
+    }
+}
+
+/// Replaces the arguments in the format string `format` by the `bindings` of the fields.
+///
+/// Fields of tuple structs and tuple variants are referred to by their index, so e.g. `{0:?}`
+/// becomes `{_0:?}`. Returns an error message if an argument does not refer to a field.
+fn rewrite_arguments(format: &str, bindings: &[Ident]) -> core::result::Result<String, String> {
+    let mut output = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        output.push(character);
+        if character != '{' {
+            continue;
+        }
+        if chars.peek() == Some(&'{') {
+            // escaped brace
+            output.extend(chars.next());
+            continue;
+        }
+
+        let mut argument = String::new();
+        while let Some(next) = chars.next_if(|&next| next != '}' && next != ':') {
+            argument.push(next);
+        }
+
+        if argument.is_empty() {
+            return Err("fields have to be referred to by their name or their index".to_owned());
+        }
+        let binding = if argument.starts_with(|first: char| first.is_ascii_digit()) {
+            format!("_{argument}")
+        } else {
+            argument.clone()
+        };
+        if !bindings.iter().any(|ident| *ident == binding) {
+            return Err(format!("`{argument}` does not refer to a field"));
+        }
+        output.push_str(&binding);
+    }
+
+    Ok(output)
+}
+
+/// Returns the format string of the `#[display("...")]` attribute if present.
+fn find_display(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    let mut display = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("display")) {
+        if display.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "duplicate `#[display(...)]` attribute",
+            ));
+        }
+        display = Some(attr.parse_args::<LitStr>()?);
+    }
+
+    Ok(display)
+}
+
+fn is_provided(attrs: &[Attribute]) -> Result<bool> {
+    let mut provided = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("provide")) {
+        if !matches!(attr.parse_meta()?, Meta::Path(_)) {
+            return Err(Error::new_spanned(
+                attr,
+                "`#[provide]` does not take any arguments",
+            ));
+        }
+        provided = true;
+    }
+
+    Ok(provided)
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = match &input.data {
+        Data::Struct(data) => {
+            let display = find_display(&input.attrs)?.ok_or_else(|| {
+                Error::new_spanned(
+                    ident,
+                    "missing `#[display(...)]` attribute to derive `Context`",
+                )
+            })?;
+
+            vec![Variant::new(quote!(Self), &display, &data.fields)?]
+        }
+        Data::Enum(data) => {
+            if let Some(attr) = input
+                .attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("display"))
+            {
+                return Err(Error::new_spanned(
+                    attr,
+                    "`#[display(...)]` has to be specified on every variant of an enum",
+                ));
+            }
+            if data.variants.is_empty() {
+                return Err(Error::new_spanned(
+                    ident,
+                    "`Context` can't be derived for enums without variants",
+                ));
+            }
+
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let display = find_display(&variant.attrs)?.ok_or_else(|| {
+                        Error::new_spanned(
+                            variant,
+                            "missing `#[display(...)]` attribute to derive `Context`",
+                        )
+                    })?;
+                    let variant_ident = &variant.ident;
+
+                    Variant::new(quote!(Self::#variant_ident), &display, &variant.fields)
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`Context` can't be derived for unions",
+            ));
+        }
+    };
+
+    let display_arms = variants.iter().map(Variant::display_arm);
+
+    let provide = variants
+        .iter()
+        .any(|variant| !variant.provided.is_empty())
+        .then(|| {
+            let provide_arms = variants.iter().map(Variant::provide_arm);
+
+            quote! {
+                ::error_stack::__provide!(self, __demand => {
+                    match self {
+                        #(#provide_arms)*
+                    }
+                });
+            }
+        });
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fmt(&self, __formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#display_arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::error_stack::Context for #ident #ty_generics #where_clause {
+            #provide
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+
+    fn bindings(names: &[&str]) -> Vec<Ident> {
+        names
+            .iter()
+            .map(|name| Ident::new(name, Span::call_site()))
+            .collect()
+    }
+
+    #[test]
+    fn named_arguments() {
+        let bindings = bindings(&["path", "line"]);
+
+        assert_eq!(
+            rewrite_arguments("{path} in line {line:>4}", &bindings),
+            Ok("{path} in line {line:>4}".to_owned())
+        );
+        assert_eq!(
+            rewrite_arguments("{{path}} {{{line}}}", &bindings),
+            Ok("{{path}} {{{line}}}".to_owned())
+        );
+        assert_eq!(
+            rewrite_arguments("{file}", &bindings),
+            Err("`file` does not refer to a field".to_owned())
+        );
+        assert!(rewrite_arguments("{}", &bindings).is_err());
+    }
+
+    #[test]
+    fn positional_arguments() {
+        let bindings = bindings(&["_0", "_1"]);
+
+        assert_eq!(
+            rewrite_arguments("{0} and {1:?}", &bindings),
+            Ok("{_0} and {_1:?}".to_owned())
+        );
+        assert_eq!(
+            rewrite_arguments("{2}", &bindings),
+            Err("`2` does not refer to a field".to_owned())
+        );
+    }
+}
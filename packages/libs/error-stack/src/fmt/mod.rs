@@ -425,6 +425,10 @@ enum Instruction {
     Attachment {
         position: Position,
     },
+    /// A branch of a report with multiple current frames, which are not grouped under a context.
+    Branch {
+        position: Position,
+    },
 
     Indent(Indent),
 }
@@ -462,6 +466,12 @@ impl Instruction {
                 Position::Final => PreparedInstruction::Symbols(sym!('╰', '╴', ' ')),
             },
 
+            Self::Branch { position } => match position {
+                Position::First => PreparedInstruction::Symbols(sym!('┬', '▶', ' ')),
+                Position::Inner => PreparedInstruction::Symbols(sym!('├', '▶', ' ')),
+                Position::Final => PreparedInstruction::Symbols(sym!('╰', '▶', ' ')),
+            },
+
             // Indentation (like `|   ` or ` |  `)
             Self::Indent(indent) => PreparedInstruction::Symbols(indent.prepare()),
         }
@@ -944,13 +954,67 @@ fn debug_frame(
     vec![debug_render(head, contexts, sources)]
 }
 
+/// Renders the frames of a report with multiple current frames as parallel branches.
+///
+/// ```text
+/// ┬▶ Context A
+/// │  ╰╴Attachment B
+/// │
+/// ╰▶ Context C
+///    ╰╴Attachment D
+/// ```
+fn debug_branches(branches: Vec<Lines>) -> Lines {
+    let len = branches.len();
+    if len == 1 {
+        return branches.into_iter().flat_map(Lines::into_vec).collect();
+    }
+
+    branches
+        .into_iter()
+        .enumerate()
+        .flat_map(|(idx, lines)| {
+            let position = match idx {
+                // this is first to make sure that 0 is caught as `Last` instead of `First`
+                pos if pos + 1 == len => Position::Final,
+                0 => Position::First,
+                _ => Position::Inner,
+            };
+
+            let lines = lines
+                .into_iter()
+                .enumerate()
+                .map(|(idx, line)| {
+                    if idx == 0 {
+                        line.push(Instruction::Branch { position })
+                    } else {
+                        line.push(
+                            Indent::no_group()
+                                .visible(!matches!(position, Position::Final))
+                                .spacing(Spacing::Minimal)
+                                .into(),
+                        )
+                    }
+                })
+                .collect::<Lines>();
+
+            if idx == 0 {
+                lines.into_vec()
+            } else {
+                // add a buffer line for readability
+                lines
+                    .before(Line::new().push(Indent::no_group().spacing(None).into()))
+                    .into_vec()
+            }
+        })
+        .collect()
+}
+
 impl<C> Debug for Report<C> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         #[cfg(any(feature = "std", feature = "hooks"))]
         let mut context = HookContext::new(fmt.alternate());
 
-        #[cfg_attr(not(any(feature = "std", feature = "hooks")), allow(unused_mut))]
-        let mut lines = self
+        let branches = self
             .current_frames()
             .iter()
             .flat_map(|frame| {
@@ -961,19 +1025,11 @@ impl<C> Debug for Report<C> {
                     &mut context,
                 )
             })
-            .enumerate()
-            .flat_map(|(idx, lines)| {
-                if idx == 0 {
-                    lines.into_vec()
-                } else {
-                    lines
-                        .before(
-                            Line::new()
-                                .push(Indent::no_group().visible(false).spacing(None).into()),
-                        )
-                        .into_vec()
-                }
-            })
+            .collect::<Vec<_>>();
+
+        #[cfg_attr(not(any(feature = "std", feature = "hooks")), allow(unused_mut))]
+        let mut lines = debug_branches(branches)
+            .into_iter()
             .map(|line| line.to_string())
             .collect::<Vec<_>>()
             .join("\n");
@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
 
+#[cfg(feature = "serde")]
+use crate::serde::SerializeHooks;
 use crate::{
     fmt::{install_builtin_hooks, HookContext, Hooks},
     Report,
@@ -15,6 +17,9 @@ type RwLock<T> = spin::rwlock::RwLock<T>;
 
 static FMT_HOOK: RwLock<Hooks> = RwLock::new(Hooks { inner: Vec::new() });
 
+#[cfg(feature = "serde")]
+static SERIALIZE_HOOK: RwLock<SerializeHooks> = RwLock::new(SerializeHooks::new());
+
 impl Report<()> {
     /// Can be used to globally set a [`Debug`] format hook, for a specific type `T`.
     ///
@@ -179,4 +184,74 @@ impl Report<()> {
 
         closure(&hook)
     }
+
+    /// Can be used to globally set a serialization hook for a specific type `T`.
+    ///
+    /// Contexts and attachments of type `T` will be serialized using the [`Serialize`]
+    /// implementation of `T` when serializing a [`Report`]. The serialized value is tagged with
+    /// the name of `T` as returned by [`type_name`]:
+    ///
+    /// ```json
+    /// {
+    ///     "type": "my_crate::Suggestion",
+    ///     "value": "oh no, try again"
+    /// }
+    /// ```
+    ///
+    /// Without a hook, contexts are serialized using their [`Display`] output, printable
+    /// attachments using their [`Display`] output, and opaque attachments are skipped.
+    ///
+    /// [`Serialize`]: serde::Serialize
+    /// [`type_name`]: core::any::type_name
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{report, Report};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Suggestion(&'static str);
+    ///
+    /// Report::install_serialize_hook::<Suggestion>();
+    ///
+    /// let report =
+    ///     report!(Error::from(ErrorKind::InvalidInput)).attach(Suggestion("oh no, try again"));
+    ///
+    /// let value = serde_json::to_value(&report)?;
+    /// assert_eq!(
+    ///     value[0]["attachments"][0]["value"],
+    ///     serde_json::json!("oh no, try again")
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_serialize_hook<T: serde::Serialize + Send + Sync + 'static>() {
+        #[cfg(feature = "std")]
+        let mut lock = SERIALIZE_HOOK.write().expect("should not be poisoned");
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERIALIZE_HOOK.write();
+
+        lock.insert::<T>();
+    }
+
+    /// Returns the hooks that were previously set by [`install_serialize_hook`]
+    ///
+    /// [`install_serialize_hook`]: Self::install_serialize_hook
+    #[cfg(feature = "serde")]
+    pub(crate) fn invoke_serialize_hook<T>(closure: impl FnOnce(&SerializeHooks) -> T) -> T {
+        #[cfg(feature = "std")]
+        let hook = SERIALIZE_HOOK.read().expect("should not be poisoned");
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let hook = SERIALIZE_HOOK.read();
+
+        closure(&hook)
+    }
 }
@@ -407,7 +407,8 @@
 //! an error happens.
 //!
 //! In addition to [`ResultExt`], this crate also comes with [`FutureExt`], which provides the same
-//! functionality for [`Future`]s.
+//! functionality for [`Future`]s. With the `futures` feature enabled, `StreamExt` and `SinkExt`
+//! apply the same methods to every error of a `Stream` or a `Sink` respectively.
 //!
 //! [`Future`]: core::future::Future
 //!
@@ -421,6 +422,9 @@
 //! `hooks`        | Enables hooks on `no-std` platforms using spin locks               | disabled
 //! `anyhow`       | Provides `into_report` to convert [`anyhow::Error`] to [`Report`]  | disabled
 //! `eyre`         | Provides `into_report` to convert [`eyre::Report`] to [`Report`]   | disabled
+//! `serde`        | Implements `Serialize` for [`Report`], extensible through hooks    | disabled
+//! `derive`       | Derives `Display` and [`Context`] via `#[derive(Context)]`         | disabled
+//! `futures`      | Provides `StreamExt` and `SinkExt` for `Stream`s and `Sink`s       | disabled
 //!
 //! [^color]: error-stack supports the [`NO_COLOR`](http://no-color.org/)
 //!     and `FORCE_COLOR` environment variables through the [owo-colors crate](https://crates.io/crates/owo-colors)
@@ -468,6 +472,10 @@ extern crate core;
 
 pub mod future;
 pub mod iter;
+#[cfg(feature = "futures")]
+pub mod sink;
+#[cfg(feature = "futures")]
+pub mod stream;
 
 mod compat;
 mod frame;
@@ -485,6 +493,9 @@ mod hook;
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "derive")]
+pub use error_stack_macros::Context;
+
 pub use self::{
     compat::IntoReportCompat,
     context::Context,
@@ -496,8 +507,11 @@ pub use self::{
 #[doc(inline)]
 pub use self::{
     future::FutureExt,
-    result::{IntoReport, ResultExt},
+    result::{IntoReport, IteratorExt, ResultExt, ResultZipExt},
 };
+#[cfg(feature = "futures")]
+#[doc(inline)]
+pub use self::{sink::SinkExt, stream::StreamExt};
 
 #[cfg(test)]
 mod tests {
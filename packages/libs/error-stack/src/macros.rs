@@ -60,12 +60,35 @@ pub mod __private {
         }
     }
 
+    #[cfg(all(nightly, feature = "derive"))]
+    pub use core::any::Demand;
+
     // false-positive lint
     #[allow(unreachable_pub)]
     // Import anonymously to allow calling `__kind` but forbid implementing the tag-traits.
     pub use self::specialization::{ContextTag as _, ReportTag as _};
 }
 
+/// Implements [`Context::provide`] for `#[derive(Context)]`.
+///
+/// [`Context::provide`]: crate::Context
+#[cfg(all(nightly, feature = "derive"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __provide {
+    ($self:ident, $demand:ident => $body:block) => {
+        fn provide<'a>(&'a $self, $demand: &mut $crate::__private::Demand<'a>) $body
+    };
+}
+
+/// `Context::provide` is only available on nightly, so the implementation is discarded.
+#[cfg(all(not(nightly), feature = "derive"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __provide {
+    ($self:ident, $demand:ident => $body:block) => {};
+}
+
 /// Creates a [`Report`] from the given parameters.
 ///
 /// The parameters may either be [`Context`] or a [`Report`]. The returned [`Report`] will use the
@@ -43,7 +43,12 @@ use crate::{
 ///
 /// `Report` is able to represent multiple errors that have occurred. Errors can be combined using
 /// the [`extend_one()`], which will add the [`Frame`] stack of the other error as an additional
-/// source to the current report.
+/// source to the current report. To collect all errors of an iterator of [`Result`]s, use
+/// [`IteratorExt::try_collect_reports`], to combine two [`Result`]s, use [`ResultZipExt::zip`].
+///
+/// [`Result`]: crate::Result
+/// [`IteratorExt::try_collect_reports`]: crate::IteratorExt::try_collect_reports
+/// [`ResultZipExt::zip`]: crate::ResultZipExt::zip
 ///
 /// ## `Backtrace` and `SpanTrace`
 ///
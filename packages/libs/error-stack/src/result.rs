@@ -177,6 +177,116 @@ impl<T, C> ResultExt for Result<T, C> {
     }
 }
 
+/// Extension trait for [`Result`] to combine multiple [`Result`]s without losing any [`Report`].
+pub trait ResultZipExt<T, C> {
+    /// Combines the [`Ok`] values of this and another [`Result`] into a tuple.
+    ///
+    /// If both results are [`Err`], the [`Report`] of `other` is added to the [`Report`] of this
+    /// result via [`Report::extend_one`], so no error is lost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use core::fmt;
+    /// use error_stack::{report, Result, ResultZipExt};
+    ///
+    /// # #[derive(Debug)] struct ParseError;
+    /// # impl fmt::Display for ParseError {
+    /// #     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result { fmt.write_str("invalid") }
+    /// # }
+    /// # impl error_stack::Context for ParseError {}
+    /// fn parse(value: &str) -> Result<u16, ParseError> {
+    ///     value.parse().map_err(|_| report!(ParseError))
+    /// }
+    ///
+    /// assert_eq!(parse("80").zip(parse("443")).ok(), Some((80, 443)));
+    ///
+    /// let report = parse("http").zip(parse("https")).unwrap_err();
+    /// assert_eq!(report.current_frames().len(), 2);
+    /// ```
+    fn zip<U>(self, other: Result<U, C>) -> Result<(T, U), C>;
+}
+
+impl<T, C> ResultZipExt<T, C> for Result<T, C> {
+    fn zip<U>(self, other: Result<U, C>) -> Result<(T, U), C> {
+        match (self, other) {
+            (Ok(ok), Ok(other)) => Ok((ok, other)),
+            (Err(report), Ok(_)) | (Ok(_), Err(report)) => Err(report),
+            (Err(mut report), Err(other)) => {
+                report.extend_one(other);
+                Err(report)
+            }
+        }
+    }
+}
+
+/// Extension trait for [`Iterator`]s over [`Result`]s to accumulate all errors into a single
+/// [`Report`].
+pub trait IteratorExt<T, C>: Iterator<Item = Result<T, C>> {
+    /// Collects the [`Ok`] values of the iterator, or all [`Err`] values into a single [`Report`].
+    ///
+    /// Unlike collecting into a [`Result`], this does not stop at the first [`Err`] but consumes
+    /// the whole iterator. Every further [`Report`] is added to the first one via
+    /// [`Report::extend_one`], so they are sibling sources of the returned [`Report`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use core::fmt;
+    /// use error_stack::{report, IteratorExt, Result};
+    ///
+    /// # #[derive(Debug)] struct ParseError;
+    /// # impl fmt::Display for ParseError {
+    /// #     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result { fmt.write_str("invalid") }
+    /// # }
+    /// # impl error_stack::Context for ParseError {}
+    /// fn parse(value: &str) -> Result<u16, ParseError> {
+    ///     value.parse().map_err(|_| report!(ParseError))
+    /// }
+    ///
+    /// let ports: Vec<_> = ["80", "443"].into_iter().map(parse).try_collect_reports()?;
+    /// assert_eq!(ports, [80, 443]);
+    ///
+    /// let report = ["http", "80", "https"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .try_collect_reports::<Vec<_>>()
+    ///     .unwrap_err();
+    /// assert_eq!(report.current_frames().len(), 2);
+    /// # Result::Ok(())
+    /// ```
+    fn try_collect_reports<A>(self) -> Result<A, C>
+    where
+        A: FromIterator<T>;
+}
+
+impl<I, T, C> IteratorExt<T, C> for I
+where
+    I: Iterator<Item = Result<T, C>>,
+{
+    fn try_collect_reports<A>(self) -> Result<A, C>
+    where
+        A: FromIterator<T>,
+    {
+        let mut report: Option<Report<C>> = None;
+
+        let collection = self
+            .filter_map(|result| match result {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    match &mut report {
+                        Some(report) => report.extend_one(error),
+                        None => report = Some(error),
+                    }
+                    None
+                }
+            })
+            .collect();
+
+        report.map_or(Ok(collection), Err)
+    }
+}
+
 /// Extends [`Result`] to convert the [`Err`] variant to a [`Report`]
 pub trait IntoReport: Sized {
     /// Type of the [`Ok`] value in the [`Result`]
@@ -1,7 +1,9 @@
 //! Implementation of general [`Report`] serialization.
 //!
-//! The value can be of any type, currently only printable attachments and context are supported, in
-//! the near future any values will be supported through the use of hooks.
+//! Contexts are serialized using their [`Display`] output and printable attachments using their
+//! [`Display`] output as well. Any other value, be it a context or an attachment, can be
+//! serialized with a type tag by installing a hook via [`Report::install_serialize_hook`], which
+//! takes precedence over the [`Display`] output. Opaque attachments without a hook are skipped.
 //!
 //! The serialized [`Report`] is a list of all current sources with the following output:
 //!
@@ -12,33 +14,133 @@
 //!     "sources": [] // recursive render using `frame.sources()`
 //! }
 //! ```
+//!
+//! A context or an attachment serialized through a hook is output as:
+//!
+//! ```json
+//! {
+//!     "type": "type name of the value",
+//!     "value": "serialized value"
+//! }
+//! ```
+//!
+//! [`Display`]: core::fmt::Display
 
 use alloc::{format, vec, vec::Vec};
+use core::any::TypeId;
 
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
 use crate::{AttachmentKind, Context, Frame, FrameKind, Report};
 
-struct SerializeAttachment<'a>(&'a Frame);
+type ErasedSerialize<'a> = &'a dyn erased_serde::Serialize;
+type Downcast = for<'a> fn(&'a Frame) -> Option<ErasedSerialize<'a>>;
+
+/// Holds the list of types, which are serialized using their [`Serialize`] implementation.
+///
+/// Types are added via [`.insert()`], which stores a function to downcast the [`Frame`] to the
+/// requested type.
+///
+/// [`.insert()`]: SerializeHooks::insert
+pub(crate) struct SerializeHooks {
+    // We use `Vec`, instead of `HashMap` or `BTreeMap`, as it can be created in a `const` context,
+    // which is required for the global registry.
+    inner: Vec<(TypeId, &'static str, Downcast)>,
+}
+
+impl SerializeHooks {
+    pub(crate) const fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    pub(crate) fn insert<T: Serialize + Send + Sync + 'static>(&mut self) {
+        fn downcast<T: Serialize + Send + Sync + 'static>(
+            frame: &Frame,
+        ) -> Option<ErasedSerialize<'_>> {
+            frame
+                .downcast_ref::<T>()
+                .map(|value| value as ErasedSerialize<'_>)
+        }
+
+        let type_id = TypeId::of::<T>();
+
+        // make sure that previous hooks of the same TypeId are deleted.
+        self.inner.retain(|(id, ..)| *id != type_id);
+        self.inner
+            .push((type_id, core::any::type_name::<T>(), downcast::<T>));
+    }
+
+    fn get<'a>(&self, frame: &'a Frame) -> Option<SerializeTagged<'a>> {
+        let type_id = frame.type_id();
+
+        self.inner
+            .iter()
+            .filter(|(id, ..)| *id == type_id)
+            .find_map(|(_, name, downcast)| {
+                downcast(frame).map(|value| SerializeTagged {
+                    type_name: name,
+                    value,
+                })
+            })
+    }
+}
+
+struct SerializeTagged<'a> {
+    type_name: &'static str,
+    value: ErasedSerialize<'a>,
+}
+
+impl<'a> Serialize for SerializeTagged<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("type", self.type_name)?;
+        map.serialize_entry("value", self.value)?;
+
+        map.end()
+    }
+}
 
-impl<'a> Serialize for SerializeAttachment<'a> {
+struct SerializeAttachment<'a, 'b> {
+    frame: &'a Frame,
+    hooks: &'b SerializeHooks,
+}
+
+impl<'a, 'b> SerializeAttachment<'a, 'b> {
+    /// Returns if the attachment can be serialized, either by a hook or as it's printable.
+    fn is_serializable(&self) -> bool {
+        matches!(
+            self.frame.kind(),
+            FrameKind::Attachment(AttachmentKind::Printable(_))
+        ) || self.hooks.get(self.frame).is_some()
+    }
+}
+
+impl<'a, 'b> Serialize for SerializeAttachment<'a, 'b> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let Self(frame) = self;
+        let Self { frame, hooks } = self;
+
+        if let Some(tagged) = hooks.get(frame) {
+            return tagged.serialize(serializer);
+        }
 
         #[allow(clippy::match_same_arms)]
         match frame.kind() {
             FrameKind::Context(_) => {
-                // TODO: for now `Context` is unsupported, upcoming PR will fix via hooks
-                // `SerializeAttachmentList` ensures that no context is ever serialized
-                todo!()
+                // `SerializeAttachmentList` ensures that no context is ever serialized as an
+                // attachment
+                unreachable!("contexts are not serialized as attachments")
             }
             FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
-                // TODO: for now opaque attachments are unsupported, upcoming PR will fix that
-                // `SerializeAttachmentList` ensures that no such attachment is added
-                todo!()
+                // `SerializeAttachmentList` ensures that only opaque attachments with a hook are
+                // added
+                unreachable!("opaque attachments without a hook are not serialized")
             }
             FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
                 format!("{attachment}").serialize(serializer)
@@ -47,68 +149,90 @@ impl<'a> Serialize for SerializeAttachment<'a> {
     }
 }
 
-struct SerializeAttachmentList<'a, 'b>(&'a [&'b Frame]);
+struct SerializeAttachmentList<'a, 'b, 'c> {
+    attachments: &'a [&'b Frame],
+    hooks: &'c SerializeHooks,
+}
 
-impl<'a, 'b> Serialize for SerializeAttachmentList<'a, 'b> {
+impl<'a, 'b, 'c> Serialize for SerializeAttachmentList<'a, 'b, 'c> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         serializer.collect_seq(
-            self.0
+            self.attachments
                 .iter()
-                .copied()
-                .filter(|attachment| {
-                    // for now opaque attachments are ignored
-                    !matches!(
-                        attachment.kind(),
-                        FrameKind::Attachment(AttachmentKind::Opaque(_))
-                    )
+                .map(|frame| SerializeAttachment {
+                    frame,
+                    hooks: self.hooks,
                 })
-                .map(SerializeAttachment),
+                // opaque attachments without a hook are ignored
+                .filter(SerializeAttachment::is_serializable),
         )
     }
 }
 
-struct SerializeContext<'a> {
+struct SerializeContext<'a, 'b> {
     attachments: Vec<&'a Frame>,
+    frame: &'a Frame,
     context: &'a dyn Context,
-    sources: &'a [Frame],
+    hooks: &'b SerializeHooks,
 }
 
-impl<'a> Serialize for SerializeContext<'a> {
+impl<'a, 'b> Serialize for SerializeContext<'a, 'b> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let Self {
-            context,
             attachments,
-            sources,
+            frame,
+            context,
+            hooks,
         } = self;
 
         let mut map = serializer.serialize_map(Some(3))?;
-        map.serialize_entry("context", &format!("{context}").as_str())?;
-        map.serialize_entry("attachments", &SerializeAttachmentList(&attachments[..]))?;
-        map.serialize_entry("sources", &SerializeSources(sources))?;
+        match hooks.get(frame) {
+            Some(tagged) => map.serialize_entry("context", &tagged)?,
+            None => map.serialize_entry("context", &format!("{context}").as_str())?,
+        }
+        map.serialize_entry("attachments", &SerializeAttachmentList {
+            attachments: &attachments[..],
+            hooks,
+        })?;
+        map.serialize_entry("sources", &SerializeSources {
+            frames: frame.sources(),
+            hooks,
+        })?;
 
         map.end()
     }
 }
 
-struct SerializeSources<'a>(&'a [Frame]);
+struct SerializeSources<'a, 'b> {
+    frames: &'a [Frame],
+    hooks: &'b SerializeHooks,
+}
 
-impl<'a> Serialize for SerializeSources<'a> {
+impl<'a, 'b> Serialize for SerializeSources<'a, 'b> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.collect_seq(self.0.iter().flat_map(|source| find_next(&[], source)))
+        serializer.collect_seq(
+            self.frames
+                .iter()
+                .flat_map(|source| find_next(&[], source, self.hooks)),
+        )
     }
 }
 
 // find the next applicable context and return the serializer
-fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeContext<'a>> {
+fn find_next<'a, 'b>(
+    head: &[&'a Frame],
+    mut current: &'a Frame,
+    hooks: &'b SerializeHooks,
+) -> Vec<SerializeContext<'a, 'b>> {
     let mut attachments = vec![];
     attachments.extend(head);
 
@@ -119,8 +243,9 @@ fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeCon
 
             return vec![SerializeContext {
                 attachments,
+                frame: current,
                 context,
-                sources: current.sources(),
+                hooks,
             }];
         } else if current.sources().len() > 1 {
             // current is an attachment, add to attachments and recursively probe
@@ -129,7 +254,7 @@ fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeCon
             return current
                 .sources()
                 .iter()
-                .flat_map(|source| find_next(&attachments, source))
+                .flat_map(|source| find_next(&attachments, source, hooks))
                 .collect();
         } else if current.sources().len() == 1 {
             attachments.push(current);
@@ -143,11 +268,27 @@ fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeCon
     }
 }
 
+#[cfg(any(feature = "std", feature = "hooks"))]
+fn with_hooks<T>(closure: impl FnOnce(&SerializeHooks) -> T) -> T {
+    Report::invoke_serialize_hook(closure)
+}
+
+#[cfg(not(any(feature = "std", feature = "hooks")))]
+fn with_hooks<T>(closure: impl FnOnce(&SerializeHooks) -> T) -> T {
+    closure(&SerializeHooks::new())
+}
+
 impl<C: Context> Serialize for Report<C> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        SerializeSources(self.current_frames()).serialize(serializer)
+        with_hooks(|hooks| {
+            SerializeSources {
+                frames: self.current_frames(),
+                hooks,
+            }
+            .serialize(serializer)
+        })
     }
 }
@@ -0,0 +1,310 @@
+//! Extension for convenient usage of [`Report`]s returned by [`Sink`]s.
+//!
+//! Extends [`Sink`] with the same methods as [`ResultExt`] but calls the methods on every error
+//! returned by the [`Sink`], i.e. when sending an item, flushing, or closing the [`Sink`].
+//!
+//! [`Report`]: crate::Report
+//! [`ResultExt`]: crate::ResultExt
+
+use core::{
+    fmt::{Debug, Display},
+    pin::Pin,
+    task::{Context as TaskContext, Poll},
+};
+
+use futures_sink::Sink;
+
+use crate::{Context, Report, ResultExt};
+
+macro_rules! implement_sink_adaptor {
+    ($sink:ident, $method:ident, $lazy:ident, $bound:ident $(+ $bounds:ident)* $(+ $lifetime:lifetime)*, $error:ty) => {
+        #[doc = concat!("Adaptor returned by [`SinkExt::", stringify!( $method ), "`].")]
+        pub struct $sink<Si, T> {
+            sink: Si,
+            inner: T,
+        }
+
+        impl<Si, T> $sink<Si, T> {
+            fn project(self: Pin<&mut Self>) -> (Pin<&mut Si>, &mut T) {
+                // SAFETY: The pointee of `inner` will not move. Only `sink` is pinned, `inner` is
+                //         never moved out of `Self`. Additionally, `Self` does not implement
+                //         `Drop`, nor is it `#[repr(packed)]`
+                //         See the `pin` module: https://doc.rust-lang.org/core/pin/index.html
+                unsafe {
+                    let Self { sink, inner } = self.get_unchecked_mut();
+                    (Pin::new_unchecked(sink), inner)
+                }
+            }
+        }
+
+        impl<Si, T, Item, C> Sink<Item> for $sink<Si, T>
+        where
+            Si: Sink<Item, Error = Report<C>>,
+            T: Clone + $bound $(+ $bounds)* $(+ $lifetime)*
+        {
+            type Error = $error;
+
+            fn poll_ready(
+                self: Pin<&mut Self>,
+                cx: &mut TaskContext,
+            ) -> Poll<Result<(), Self::Error>> {
+                let (sink, inner) = self.project();
+                // The value is only cloned in case of an error
+                sink.poll_ready(cx).map(|result| result.$lazy(|| inner.clone()))
+            }
+
+            fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+                let (sink, inner) = self.project();
+                sink.start_send(item).$lazy(|| inner.clone())
+            }
+
+            fn poll_flush(
+                self: Pin<&mut Self>,
+                cx: &mut TaskContext,
+            ) -> Poll<Result<(), Self::Error>> {
+                let (sink, inner) = self.project();
+                sink.poll_flush(cx).map(|result| result.$lazy(|| inner.clone()))
+            }
+
+            fn poll_close(
+                self: Pin<&mut Self>,
+                cx: &mut TaskContext,
+            ) -> Poll<Result<(), Self::Error>> {
+                let (sink, inner) = self.project();
+                sink.poll_close(cx).map(|result| result.$lazy(|| inner.clone()))
+            }
+        }
+    };
+}
+
+macro_rules! implement_lazy_sink_adaptor {
+    ($sink:ident, $method:ident, $bound:ident $(+ $bounds:ident)* $(+ $lifetime:lifetime)*, $error:ty) => {
+        #[doc = concat!("Adaptor returned by [`SinkExt::", stringify!( $method ), "`].")]
+        pub struct $sink<Si, F> {
+            sink: Si,
+            inner: F,
+        }
+
+        impl<Si, F> $sink<Si, F> {
+            fn project(self: Pin<&mut Self>) -> (Pin<&mut Si>, &mut F) {
+                // SAFETY: The pointee of `inner` will not move. Only `sink` is pinned, `inner` is
+                //         never moved out of `Self`. Additionally, `Self` does not implement
+                //         `Drop`, nor is it `#[repr(packed)]`
+                //         See the `pin` module: https://doc.rust-lang.org/core/pin/index.html
+                unsafe {
+                    let Self { sink, inner } = self.get_unchecked_mut();
+                    (Pin::new_unchecked(sink), inner)
+                }
+            }
+        }
+
+        impl<Si, F, T, Item, C> Sink<Item> for $sink<Si, F>
+        where
+            Si: Sink<Item, Error = Report<C>>,
+            F: Fn() -> T,
+            T: $bound $(+ $bounds)* $(+ $lifetime)*
+        {
+            type Error = $error;
+
+            fn poll_ready(
+                self: Pin<&mut Self>,
+                cx: &mut TaskContext,
+            ) -> Poll<Result<(), Self::Error>> {
+                let (sink, inner) = self.project();
+                sink.poll_ready(cx).map(|result| result.$method(&*inner))
+            }
+
+            fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+                let (sink, inner) = self.project();
+                sink.start_send(item).$method(&*inner)
+            }
+
+            fn poll_flush(
+                self: Pin<&mut Self>,
+                cx: &mut TaskContext,
+            ) -> Poll<Result<(), Self::Error>> {
+                let (sink, inner) = self.project();
+                sink.poll_flush(cx).map(|result| result.$method(&*inner))
+            }
+
+            fn poll_close(
+                self: Pin<&mut Self>,
+                cx: &mut TaskContext,
+            ) -> Poll<Result<(), Self::Error>> {
+                let (sink, inner) = self.project();
+                sink.poll_close(cx).map(|result| result.$method(&*inner))
+            }
+        }
+    };
+}
+
+implement_sink_adaptor!(
+    SinkWithAttachment,
+    attach,
+    attach_lazy,
+    Send + Sync + 'static,
+    Report<C>
+);
+
+implement_lazy_sink_adaptor!(
+    SinkWithLazyAttachment,
+    attach_lazy,
+    Send + Sync + 'static,
+    Report<C>
+);
+
+implement_sink_adaptor!(
+    SinkWithPrintableAttachment,
+    attach_printable,
+    attach_printable_lazy,
+    Display + Debug + Send + Sync + 'static,
+    Report<C>
+);
+
+implement_lazy_sink_adaptor!(
+    SinkWithLazyPrintableAttachment,
+    attach_printable_lazy,
+    Display + Debug + Send + Sync + 'static,
+    Report<C>
+);
+
+implement_sink_adaptor!(
+    SinkWithContext,
+    change_context,
+    change_context_lazy,
+    Context,
+    Report<T>
+);
+
+implement_lazy_sink_adaptor!(SinkWithLazyContext, change_context_lazy, Context, Report<T>);
+
+/// Extension trait for [`Sink`] to provide contextual information on [`Report`]s.
+///
+/// As a sink may return multiple errors, the eager methods require the value to be [`Clone`], and
+/// the lazy methods take a [`Fn`], which is called for every error.
+pub trait SinkExt<Item>: Sink<Item> + Sized {
+    /// Adds a new attachment to the [`Report`] returned by the [`Sink`].
+    ///
+    /// Applies [`Report::attach`] on every error, refer to it for more information.
+    #[track_caller]
+    fn attach<A>(self, attachment: A) -> SinkWithAttachment<Self, A>
+    where
+        A: Clone + Send + Sync + 'static;
+
+    /// Lazily adds a new attachment to the [`Report`] returned by the [`Sink`].
+    ///
+    /// Applies [`Report::attach`] on every error, refer to it for more information.
+    #[track_caller]
+    fn attach_lazy<A, F>(self, attachment: F) -> SinkWithLazyAttachment<Self, F>
+    where
+        A: Send + Sync + 'static,
+        F: Fn() -> A;
+
+    /// Adds a new printable attachment to the [`Report`] returned by the [`Sink`].
+    ///
+    /// Applies [`Report::attach_printable`] on every error, refer to it for more information.
+    #[track_caller]
+    fn attach_printable<A>(self, attachment: A) -> SinkWithPrintableAttachment<Self, A>
+    where
+        A: Clone + Display + Debug + Send + Sync + 'static;
+
+    /// Lazily adds a new printable attachment to the [`Report`] returned by the [`Sink`].
+    ///
+    /// Applies [`Report::attach_printable`] on every error, refer to it for more information.
+    #[track_caller]
+    fn attach_printable_lazy<A, F>(self, attachment: F) -> SinkWithLazyPrintableAttachment<Self, F>
+    where
+        A: Display + Debug + Send + Sync + 'static,
+        F: Fn() -> A;
+
+    /// Changes the [`Context`] of the [`Report`] returned by the [`Sink`].
+    ///
+    /// Applies [`Report::change_context`] on every error, refer to it for more information.
+    #[track_caller]
+    fn change_context<C>(self, context: C) -> SinkWithContext<Self, C>
+    where
+        C: Clone + Context;
+
+    /// Lazily changes the [`Context`] of the [`Report`] returned by the [`Sink`].
+    ///
+    /// Applies [`Report::change_context`] on every error, refer to it for more information.
+    #[track_caller]
+    fn change_context_lazy<C, F>(self, context: F) -> SinkWithLazyContext<Self, F>
+    where
+        C: Context,
+        F: Fn() -> C;
+}
+
+impl<Si, Item, C> SinkExt<Item> for Si
+where
+    Si: Sink<Item, Error = Report<C>>,
+{
+    #[track_caller]
+    fn attach<A>(self, attachment: A) -> SinkWithAttachment<Self, A>
+    where
+        A: Clone + Send + Sync + 'static,
+    {
+        SinkWithAttachment {
+            sink: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn attach_lazy<A, F>(self, attachment: F) -> SinkWithLazyAttachment<Self, F>
+    where
+        A: Send + Sync + 'static,
+        F: Fn() -> A,
+    {
+        SinkWithLazyAttachment {
+            sink: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn attach_printable<A>(self, attachment: A) -> SinkWithPrintableAttachment<Self, A>
+    where
+        A: Clone + Display + Debug + Send + Sync + 'static,
+    {
+        SinkWithPrintableAttachment {
+            sink: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn attach_printable_lazy<A, F>(self, attachment: F) -> SinkWithLazyPrintableAttachment<Self, F>
+    where
+        A: Display + Debug + Send + Sync + 'static,
+        F: Fn() -> A,
+    {
+        SinkWithLazyPrintableAttachment {
+            sink: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn change_context<C2>(self, context: C2) -> SinkWithContext<Self, C2>
+    where
+        C2: Clone + Context,
+    {
+        SinkWithContext {
+            sink: self,
+            inner: context,
+        }
+    }
+
+    #[track_caller]
+    fn change_context_lazy<C2, F>(self, context: F) -> SinkWithLazyContext<Self, F>
+    where
+        C2: Context,
+        F: Fn() -> C2,
+    {
+        SinkWithLazyContext {
+            sink: self,
+            inner: context,
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Extension for convenient usage of [`Report`]s returned by [`Stream`]s.
+//!
+//! Extends [`Stream`] with the same methods as [`ResultExt`] but calls the methods on every [`Err`]
+//! item when [`poll`]ing the next item.
+//!
+//! [`Report`]: crate::Report
+//! [`poll`]: Stream::poll_next
+
+use core::{
+    fmt::{Debug, Display},
+    pin::Pin,
+    task::{Context as TaskContext, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::{Context, Result, ResultExt};
+
+macro_rules! implement_stream_adaptor {
+    ($stream:ident, $method:ident, $lazy:ident, $bound:ident $(+ $bounds:ident)* $(+ $lifetime:lifetime)*, $output:ty) => {
+        #[doc = concat!("Adaptor returned by [`StreamExt::", stringify!( $method ), "`].")]
+        pub struct $stream<S, T> {
+            stream: S,
+            inner: T,
+        }
+
+        impl<S, T> Stream for $stream<S, T>
+        where
+            S: Stream,
+            S::Item: ResultExt,
+            T: Clone + $bound $(+ $bounds)* $(+ $lifetime)*
+        {
+            type Item = $output;
+
+            #[track_caller]
+            fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext) -> Poll<Option<Self::Item>> {
+                // SAFETY: The pointee of `inner` will not move. Only `stream` is pinned, `inner` is
+                //         never moved out of `Self`. Additionally, `Self` does not implement
+                //         `Drop`, nor is it `#[repr(packed)]`
+                //         See the `pin` module: https://doc.rust-lang.org/core/pin/index.html
+                let (stream, inner) = unsafe {
+                    let Self { stream, inner } = self.get_unchecked_mut();
+                    (Pin::new_unchecked(stream), inner)
+                };
+
+                // Can't use `map` as `#[track_caller]` is unstable on closures
+                match stream.poll_next(cx) {
+                    // The value is only cloned for `Err` items
+                    Poll::Ready(Some(value)) => Poll::Ready(Some(value.$lazy(|| inner.clone()))),
+                    Poll::Ready(None) => Poll::Ready(None),
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.stream.size_hint()
+            }
+        }
+    };
+}
+
+macro_rules! implement_lazy_stream_adaptor {
+    ($stream:ident, $method:ident, $bound:ident $(+ $bounds:ident)* $(+ $lifetime:lifetime)*, $output:ty) => {
+        #[doc = concat!("Adaptor returned by [`StreamExt::", stringify!( $method ), "`].")]
+        pub struct $stream<S, F> {
+            stream: S,
+            inner: F,
+        }
+
+        impl<S, F, T> Stream for $stream<S, F>
+        where
+            S: Stream,
+            S::Item: ResultExt,
+            F: Fn() -> T,
+            T: $bound $(+ $bounds)* $(+ $lifetime)*
+        {
+            type Item = $output;
+
+            #[track_caller]
+            fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext) -> Poll<Option<Self::Item>> {
+                // SAFETY: The pointee of `inner` will not move. Only `stream` is pinned, `inner` is
+                //         never moved out of `Self`. Additionally, `Self` does not implement
+                //         `Drop`, nor is it `#[repr(packed)]`
+                //         See the `pin` module: https://doc.rust-lang.org/core/pin/index.html
+                let (stream, inner) = unsafe {
+                    let Self { stream, inner } = self.get_unchecked_mut();
+                    (Pin::new_unchecked(stream), inner)
+                };
+
+                // Can't use `map` as `#[track_caller]` is unstable on closures
+                match stream.poll_next(cx) {
+                    Poll::Ready(Some(value)) => Poll::Ready(Some(value.$method(&*inner))),
+                    Poll::Ready(None) => Poll::Ready(None),
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.stream.size_hint()
+            }
+        }
+    };
+}
+
+implement_stream_adaptor!(
+    StreamWithAttachment,
+    attach,
+    attach_lazy,
+    Send + Sync + 'static,
+    S::Item
+);
+
+implement_lazy_stream_adaptor!(
+    StreamWithLazyAttachment,
+    attach_lazy,
+    Send + Sync + 'static,
+    S::Item
+);
+
+implement_stream_adaptor!(
+    StreamWithPrintableAttachment,
+    attach_printable,
+    attach_printable_lazy,
+    Display + Debug + Send + Sync + 'static,
+    S::Item
+);
+
+implement_lazy_stream_adaptor!(
+    StreamWithLazyPrintableAttachment,
+    attach_printable_lazy,
+    Display + Debug + Send + Sync + 'static,
+    S::Item
+);
+
+implement_stream_adaptor!(
+    StreamWithContext,
+    change_context,
+    change_context_lazy,
+    Context,
+    Result<<S::Item as ResultExt>::Ok, T>
+);
+
+implement_lazy_stream_adaptor!(
+    StreamWithLazyContext,
+    change_context_lazy,
+    Context,
+    Result<<S::Item as ResultExt>::Ok, T>
+);
+
+/// Extension trait for [`Stream`] to provide contextual information on [`Report`]s.
+///
+/// As a stream may yield multiple [`Err`] items, the eager methods require the value to be
+/// [`Clone`], and the lazy methods take a [`Fn`], which is called for every [`Err`] item.
+///
+/// # Example
+///
+/// ```rust
+/// # use core::fmt;
+/// use error_stack::{report, StreamExt};
+/// use futures::stream::{self, StreamExt as _};
+///
+/// # #[derive(Debug)] struct ParseError;
+/// # impl fmt::Display for ParseError {
+/// #     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result { fmt.write_str("invalid") }
+/// # }
+/// # impl error_stack::Context for ParseError {}
+/// let items = stream::iter(["80", "http"])
+///     .map(|value| value.parse::<u16>().map_err(|_| report!(ParseError)))
+///     .attach_printable("could not parse the port");
+///
+/// let items = futures::executor::block_on(items.collect::<Vec<_>>());
+/// assert!(items[0].is_ok());
+/// assert!(items[1].is_err());
+/// ```
+///
+/// [`Report`]: crate::Report
+pub trait StreamExt: Stream + Sized {
+    /// Adds a new attachment to the [`Report`] inside every [`Err`] item when [`poll`]ing the
+    /// [`Stream`].
+    ///
+    /// Applies [`Report::attach`] on the [`Err`] variants, refer to it for more information.
+    ///
+    /// [`Report`]: crate::Report
+    /// [`Report::attach`]: crate::Report::attach
+    /// [`poll`]: Stream::poll_next
+    #[track_caller]
+    fn attach<A>(self, attachment: A) -> StreamWithAttachment<Self, A>
+    where
+        A: Clone + Send + Sync + 'static;
+
+    /// Lazily adds a new attachment to the [`Report`] inside every [`Err`] item when [`poll`]ing
+    /// the [`Stream`].
+    ///
+    /// Applies [`Report::attach`] on the [`Err`] variants, refer to it for more information.
+    ///
+    /// [`Report`]: crate::Report
+    /// [`Report::attach`]: crate::Report::attach
+    /// [`poll`]: Stream::poll_next
+    #[track_caller]
+    fn attach_lazy<A, F>(self, attachment: F) -> StreamWithLazyAttachment<Self, F>
+    where
+        A: Send + Sync + 'static,
+        F: Fn() -> A;
+
+    /// Adds a new printable attachment to the [`Report`] inside every [`Err`] item when
+    /// [`poll`]ing the [`Stream`].
+    ///
+    /// Applies [`Report::attach_printable`] on the [`Err`] variants, refer to it for more
+    /// information.
+    ///
+    /// [`Report`]: crate::Report
+    /// [`Report::attach_printable`]: crate::Report::attach_printable
+    /// [`poll`]: Stream::poll_next
+    #[track_caller]
+    fn attach_printable<A>(self, attachment: A) -> StreamWithPrintableAttachment<Self, A>
+    where
+        A: Clone + Display + Debug + Send + Sync + 'static;
+
+    /// Lazily adds a new printable attachment to the [`Report`] inside every [`Err`] item when
+    /// [`poll`]ing the [`Stream`].
+    ///
+    /// Applies [`Report::attach_printable`] on the [`Err`] variants, refer to it for more
+    /// information.
+    ///
+    /// [`Report`]: crate::Report
+    /// [`Report::attach_printable`]: crate::Report::attach_printable
+    /// [`poll`]: Stream::poll_next
+    #[track_caller]
+    fn attach_printable_lazy<A, F>(
+        self,
+        attachment: F,
+    ) -> StreamWithLazyPrintableAttachment<Self, F>
+    where
+        A: Display + Debug + Send + Sync + 'static,
+        F: Fn() -> A;
+
+    /// Changes the [`Context`] of the [`Report`] inside every [`Err`] item when [`poll`]ing the
+    /// [`Stream`].
+    ///
+    /// Applies [`Report::change_context`] on the [`Err`] variants, refer to it for more
+    /// information.
+    ///
+    /// [`Report`]: crate::Report
+    /// [`Report::change_context`]: crate::Report::change_context
+    /// [`poll`]: Stream::poll_next
+    #[track_caller]
+    fn change_context<C>(self, context: C) -> StreamWithContext<Self, C>
+    where
+        C: Clone + Context;
+
+    /// Lazily changes the [`Context`] of the [`Report`] inside every [`Err`] item when [`poll`]ing
+    /// the [`Stream`].
+    ///
+    /// Applies [`Report::change_context`] on the [`Err`] variants, refer to it for more
+    /// information.
+    ///
+    /// [`Report`]: crate::Report
+    /// [`Report::change_context`]: crate::Report::change_context
+    /// [`poll`]: Stream::poll_next
+    #[track_caller]
+    fn change_context_lazy<C, F>(self, context: F) -> StreamWithLazyContext<Self, F>
+    where
+        C: Context,
+        F: Fn() -> C;
+}
+
+impl<S: Stream> StreamExt for S
+where
+    S::Item: ResultExt,
+{
+    #[track_caller]
+    fn attach<A>(self, attachment: A) -> StreamWithAttachment<Self, A>
+    where
+        A: Clone + Send + Sync + 'static,
+    {
+        StreamWithAttachment {
+            stream: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn attach_lazy<A, F>(self, attachment: F) -> StreamWithLazyAttachment<Self, F>
+    where
+        A: Send + Sync + 'static,
+        F: Fn() -> A,
+    {
+        StreamWithLazyAttachment {
+            stream: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn attach_printable<A>(self, attachment: A) -> StreamWithPrintableAttachment<Self, A>
+    where
+        A: Clone + Display + Debug + Send + Sync + 'static,
+    {
+        StreamWithPrintableAttachment {
+            stream: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn attach_printable_lazy<A, F>(
+        self,
+        attachment: F,
+    ) -> StreamWithLazyPrintableAttachment<Self, F>
+    where
+        A: Display + Debug + Send + Sync + 'static,
+        F: Fn() -> A,
+    {
+        StreamWithLazyPrintableAttachment {
+            stream: self,
+            inner: attachment,
+        }
+    }
+
+    #[track_caller]
+    fn change_context<C>(self, context: C) -> StreamWithContext<Self, C>
+    where
+        C: Clone + Context,
+    {
+        StreamWithContext {
+            stream: self,
+            inner: context,
+        }
+    }
+
+    #[track_caller]
+    fn change_context_lazy<C, F>(self, context: F) -> StreamWithLazyContext<Self, F>
+    where
+        C: Context,
+        F: Fn() -> C,
+    {
+        StreamWithLazyContext {
+            stream: self,
+            inner: context,
+        }
+    }
+}
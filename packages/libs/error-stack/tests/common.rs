@@ -18,6 +18,11 @@ use core::{
     iter,
     sync::atomic::{AtomicI8, Ordering},
 };
+#[cfg(feature = "futures")]
+use core::{
+    pin::Pin,
+    task::{Context as TaskContext, Poll},
+};
 #[cfg(all(rust_1_65, feature = "std"))]
 use std::backtrace::Backtrace;
 
@@ -170,6 +175,36 @@ pub fn create_future() -> impl Future<Output = Result<(), RootError>> {
     futures::future::err(create_report())
 }
 
+#[cfg(feature = "futures")]
+pub fn create_stream() -> impl futures::Stream<Item = Result<(), RootError>> {
+    futures::stream::iter([Ok(()), Err(create_report()), Err(create_report())])
+}
+
+/// A [`Sink`](futures::Sink), which fails to send any item.
+#[cfg(feature = "futures")]
+pub struct FailingSink;
+
+#[cfg(feature = "futures")]
+impl futures::Sink<()> for FailingSink {
+    type Error = Report<RootError>;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut TaskContext) -> Poll<Result<(), RootError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, _: ()) -> Result<(), RootError> {
+        Err(create_report())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut TaskContext) -> Poll<Result<(), RootError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut TaskContext) -> Poll<Result<(), RootError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
 pub fn capture_ok<E>(closure: impl FnOnce() -> Result<(), E>) {
     closure().expect("expected an OK value, found an error")
 }
@@ -4,4 +4,6 @@
 fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "derive")]
+    t.compile_fail("tests/ui/derive/*.rs");
 }
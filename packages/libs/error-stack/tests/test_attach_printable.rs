@@ -6,6 +6,8 @@ mod common;
 
 use common::*;
 use error_stack::{AttachmentKind, FrameKind, FutureExt, Report, ResultExt};
+#[cfg(feature = "futures")]
+use error_stack::{SinkExt, StreamExt};
 
 fn test_messages<E>(report: &Report<E>) {
     assert_eq!(
@@ -69,3 +71,39 @@ fn attach_future() {
     test_messages(&report);
     test_kinds(&report);
 }
+
+#[test]
+#[cfg(feature = "futures")]
+fn attach_stream() {
+    let stream = create_stream()
+        .attach_printable(PrintableA(0))
+        .attach_printable_lazy(|| PrintableB(0))
+        .attach_printable(ContextA(0))
+        .attach_printable_lazy(|| ContextB(0));
+
+    let results = futures::executor::block_on(futures::StreamExt::collect::<Vec<_>>(stream));
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    for result in &results[1..] {
+        let report = result.as_ref().expect_err("Not an error");
+        test_messages(report);
+        test_kinds(report);
+    }
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn attach_sink() {
+    let mut sink = FailingSink
+        .attach_printable(PrintableA(0))
+        .attach_printable_lazy(|| PrintableB(0))
+        .attach_printable(ContextA(0))
+        .attach_printable_lazy(|| ContextB(0));
+
+    let error = futures::executor::block_on(futures::SinkExt::send(&mut sink, ()));
+
+    let report = error.expect_err("Not an error");
+    test_messages(&report);
+    test_kinds(&report);
+}
@@ -608,4 +608,15 @@ mod full {
 
         assert_snapshot!(format!("{report:?}"));
     }
+
+    #[test]
+    fn sources_root() {
+        let _guard = prepare(false);
+
+        let mut report = create_report().attach_printable(PrintableA(1));
+        report.extend_one(create_report().attach_printable(PrintableB(2)));
+        report.extend_one(create_report().attach(AttachmentA(3)));
+
+        assert_snapshot!(format!("{report:?}"));
+    }
 }
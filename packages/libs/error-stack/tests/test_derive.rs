@@ -0,0 +1,115 @@
+#![cfg(feature = "derive")]
+#![cfg_attr(nightly, feature(provide_any))]
+// Provided fields are only read on nightly
+#![cfg_attr(not(nightly), allow(dead_code))]
+
+use error_stack::{Context, Report};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Suggestion(&'static str);
+
+#[derive(Debug, Context)]
+#[display("could not parse `{path}` in line {line}")]
+pub struct ParseError {
+    path: &'static str,
+    line: usize,
+    #[provide]
+    suggestion: Suggestion,
+}
+
+#[derive(Debug, Context)]
+#[display("the value `{0:?}` is out of range")]
+pub struct OutOfRange(i64);
+
+#[derive(Debug, Context)]
+#[display("the configuration is {{invalid}}")]
+pub struct InvalidConfig;
+
+#[derive(Debug, Context)]
+pub enum ConfigError {
+    #[display("the configuration is missing")]
+    Missing,
+    #[display("the value of `{0}` is invalid")]
+    InvalidValue(&'static str, #[provide] Suggestion),
+    #[display("`{key}` is specified {count} times")]
+    Duplicate { key: &'static str, count: usize },
+}
+
+#[derive(Debug, Context)]
+#[display("the limit of {limit} was exceeded")]
+pub struct LimitExceeded<T>
+where
+    T: core::fmt::Debug + core::fmt::Display + Send + Sync + 'static,
+{
+    limit: T,
+}
+
+fn parse_error() -> ParseError {
+    ParseError {
+        path: "config.toml",
+        line: 3,
+        suggestion: Suggestion("try quoting the value"),
+    }
+}
+
+#[test]
+fn display_struct() {
+    assert_eq!(
+        parse_error().to_string(),
+        "could not parse `config.toml` in line 3"
+    );
+    assert_eq!(OutOfRange(-1).to_string(), "the value `-1` is out of range");
+    assert_eq!(InvalidConfig.to_string(), "the configuration is {invalid}");
+    assert_eq!(
+        LimitExceeded { limit: 10 }.to_string(),
+        "the limit of 10 was exceeded"
+    );
+}
+
+#[test]
+fn display_enum() {
+    assert_eq!(
+        ConfigError::Missing.to_string(),
+        "the configuration is missing"
+    );
+    assert_eq!(
+        ConfigError::InvalidValue("port", Suggestion("use a number")).to_string(),
+        "the value of `port` is invalid"
+    );
+    assert_eq!(
+        ConfigError::Duplicate {
+            key: "port",
+            count: 2
+        }
+        .to_string(),
+        "`port` is specified 2 times"
+    );
+}
+
+#[test]
+fn report() {
+    let report = Report::new(parse_error()).change_context(ConfigError::Missing);
+
+    assert!(report.contains::<ParseError>());
+    assert_eq!(report.to_string(), "the configuration is missing");
+}
+
+#[test]
+#[cfg(nightly)]
+fn provide() {
+    let report = Report::new(parse_error());
+    assert_eq!(report.request_ref::<Suggestion>().collect::<Vec<_>>(), [
+        &Suggestion("try quoting the value")
+    ]);
+
+    let report = Report::new(ConfigError::InvalidValue(
+        "port",
+        Suggestion("use a number"),
+    ));
+    assert_eq!(report.request_ref::<Suggestion>().collect::<Vec<_>>(), [
+        &Suggestion("use a number")
+    ]);
+
+    let report = Report::new(ConfigError::Missing);
+    assert_eq!(report.request_ref::<Suggestion>().count(), 0);
+}
@@ -5,7 +5,7 @@ mod common;
 use core::fmt::{Display, Formatter};
 
 use common::*;
-use error_stack::{report, Context, Report};
+use error_stack::{report, Context, IteratorExt, Report, Result, ResultZipExt};
 
 #[derive(Debug)]
 struct Error;
@@ -76,3 +76,51 @@ fn collect_none() {
 
     assert!(report.is_none());
 }
+
+fn check(value: u32) -> Result<u32, Error> {
+    if value % 2 == 0 {
+        Ok(value)
+    } else {
+        Err(report!(Error).attach_printable(value))
+    }
+}
+
+#[test]
+fn try_collect_reports() {
+    let values: Vec<_> = [2, 4, 6]
+        .into_iter()
+        .map(check)
+        .try_collect_reports()
+        .expect("should be ok");
+
+    assert_eq!(values, [2, 4, 6]);
+}
+
+#[test]
+fn try_collect_reports_err() {
+    let report = [1, 2, 3, 4, 5]
+        .into_iter()
+        .map(check)
+        .try_collect_reports::<Vec<_>>()
+        .expect_err("should be err");
+
+    assert_eq!(report.current_frames().len(), 3);
+    assert_eq!(
+        report.downcast_ref::<u32>().copied(),
+        Some(1),
+        "the first error should be the first source"
+    );
+    assert_eq!(report.frames().count(), expect_count(2) * 3);
+}
+
+#[test]
+fn zip() {
+    assert_eq!(check(2).zip(check(4)).ok(), Some((2, 4)));
+
+    let report = check(1).zip(check(4)).expect_err("should be err");
+    assert_eq!(report.current_frames().len(), 1);
+
+    let report = check(1).zip(check(3)).expect_err("should be err");
+    assert_eq!(report.current_frames().len(), 2);
+    assert_eq!(report.frames().count(), expect_count(2) * 2);
+}
@@ -7,7 +7,11 @@
 #![cfg_attr(all(nightly, feature = "std"), feature(error_generic_member_access))]
 #![cfg_attr(nightly, feature(provide_any))]
 
+use core::fmt;
+
+use error_stack::{Context, Report};
 use insta::assert_ron_snapshot;
+use serde::Serialize;
 
 use crate::common::{create_report, ContextA, PrintableA, PrintableC};
 
@@ -69,3 +73,55 @@ fn multiple_sources_at_root() {
 
     assert_ron_snapshot!(a);
 }
+
+#[derive(Serialize)]
+struct Suggestion {
+    message: &'static str,
+    retry: bool,
+}
+
+#[test]
+fn hook_for_attachment() {
+    let _guard = prepare();
+
+    Report::install_serialize_hook::<Suggestion>();
+
+    let report = create_report()
+        .attach(Suggestion {
+            message: "try again",
+            retry: true,
+        })
+        .attach_printable(PrintableA(2));
+
+    assert_ron_snapshot!(report);
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigError {
+    path: &'static str,
+    line: u32,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("invalid configuration")
+    }
+}
+
+impl Context for ConfigError {}
+
+#[test]
+fn hook_for_context() {
+    let _guard = prepare();
+
+    Report::install_serialize_hook::<ConfigError>();
+
+    let report = create_report()
+        .attach_printable(PrintableA(2))
+        .change_context(ConfigError {
+            path: "config.toml",
+            line: 3,
+        });
+
+    assert_ron_snapshot!(report);
+}
@@ -0,0 +1,26 @@
+use error_stack::Context;
+
+#[derive(Debug, Context)]
+#[display(message = "root error")]
+pub struct RootError;
+
+#[derive(Debug, Context)]
+#[display("first")]
+#[display("second")]
+pub struct DuplicateError;
+
+#[derive(Debug, Context)]
+#[display("could not parse the value")]
+pub struct ParseError {
+    #[provide(value)]
+    value: u32,
+}
+
+#[derive(Debug, Context)]
+#[display("the configuration is invalid")]
+pub enum ConfigError {
+    #[display("the configuration is missing")]
+    Missing,
+}
+
+fn main() {}
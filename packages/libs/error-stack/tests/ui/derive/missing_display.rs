@@ -0,0 +1,13 @@
+use error_stack::Context;
+
+#[derive(Debug, Context)]
+pub struct RootError;
+
+#[derive(Debug, Context)]
+pub enum ConfigError {
+    #[display("the configuration is missing")]
+    Missing,
+    Invalid,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use error_stack::Context;
+
+#[derive(Context)]
+#[display("value")]
+pub union Value {
+    integer: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use error_stack::Context;
+
+#[derive(Debug, Context)]
+#[display("could not parse `{name}`")]
+pub struct ParseError {
+    file: &'static str,
+}
+
+#[derive(Debug, Context)]
+#[display("the value `{1}` is invalid")]
+pub struct InvalidValue(u32);
+
+#[derive(Debug, Context)]
+#[display("the value `{:?}` is invalid")]
+pub struct ImplicitPosition(u32);
+
+fn main() {}